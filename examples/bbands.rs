@@ -35,6 +35,6 @@ fn main() {
     let next = indicator.next(last_data);
     println!(
         "Adding {}. New BBands value: {}, lower: {}, upper: {}",
-        last_data, next.1, next.0, next.2
+        last_data, next.middle, next.lower, next.upper
     );
 }
@@ -0,0 +1,59 @@
+//! Demonstrates how to hold different indicators together and update them from the same data.
+use tatk::indicators::{
+    AverageTrueRange, ExponentialMovingAverage, MovingAverageConvergenceDivergence,
+    ParkinsonVolatility, RelativeStrengthIndex, SimpleMovingAverage, TrueRange,
+};
+use tatk::test_data::TestData;
+use tatk::traits::{CandleIndicator, Indicator};
+
+fn main() {
+    let candles = TestData::candles();
+    let closes = TestData::closes();
+
+    // Indicators consuming closes, created from the first 50 closes.
+    let start = &closes[..50];
+    let mut indicators: Vec<Box<dyn Indicator>> = vec![
+        Box::new(SimpleMovingAverage::new(20, start).unwrap()),
+        Box::new(ExponentialMovingAverage::new(20, start).unwrap()),
+        Box::new(RelativeStrengthIndex::new(14, start).unwrap()),
+        Box::new(MovingAverageConvergenceDivergence::new(12, 26, 9, start).unwrap()),
+    ];
+
+    // Indicators consuming candles, created from the first 50 candles.
+    let start = &candles[..50];
+    let mut candle_indicators: Vec<Box<dyn CandleIndicator>> = vec![
+        Box::new(TrueRange::new(14, start).unwrap()),
+        Box::new(AverageTrueRange::new(14, start).unwrap()),
+        Box::new(ParkinsonVolatility::new(20, 365.0, start).unwrap()),
+    ];
+
+    // Supply the remainder of the data to every indicator.
+    for (close, candle) in closes[50..].iter().zip(candles[50..].iter()) {
+        for indicator in indicators.iter_mut() {
+            indicator.update(*close);
+        }
+
+        for indicator in candle_indicators.iter_mut() {
+            indicator.update(candle);
+        }
+    }
+
+    println!("Data (total): {:?}", closes.len());
+    for indicator in indicators.iter() {
+        println!(
+            "{} ({}): {}",
+            indicator.name(),
+            indicator.period(),
+            indicator.value()
+        );
+    }
+
+    for indicator in candle_indicators.iter() {
+        println!(
+            "{} ({}): {}",
+            indicator.name(),
+            indicator.period(),
+            indicator.value()
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Demonstrates how to supply the same candles to a set of named indicators.
+use tatk::indicators::{
+    AverageTrueRange, IndicatorSet, MovingAverageConvergenceDivergence, PriceSource,
+    RelativeStrengthIndex, SimpleMovingAverage,
+};
+use tatk::test_data::TestData;
+use tatk::traits::{Next, Ready};
+
+fn main() {
+    let candles = TestData::candles();
+
+    // Indicators consuming single values are supplied the close of each candle.
+    let mut set = IndicatorSet::new(PriceSource::Close);
+    set.add("SMA (20)", SimpleMovingAverage::with_period(20).unwrap())
+        .unwrap()
+        .add("RSI (14)", RelativeStrengthIndex::with_period(14).unwrap())
+        .unwrap()
+        .add(
+            "MACD (12, 26, 9)",
+            MovingAverageConvergenceDivergence::with_period(12, 26, 9).unwrap(),
+        )
+        .unwrap()
+        .add_candle("ATR (14)", AverageTrueRange::with_period(14).unwrap())
+        .unwrap();
+
+    // Supply every candle, indicators without a value are not ready yet.
+    for (i, candle) in candles.iter().enumerate() {
+        let values = set.next(candle);
+        if i == 20 {
+            println!("Candle {}: {:?}", i, values);
+        }
+    }
+
+    println!("\nData (total): {:?}", candles.len());
+    println!("Ready: {}", set.is_ready());
+    for name in set.names() {
+        println!("{}: {:?}", name, set.value(name));
+    }
+}
@@ -27,9 +27,9 @@ fn main() {
         indicator.value(),
         indicator.signal_value()
     );
+    let next = indicator.next(last_data);
     println!(
-        "Adding {}. New MACD: {}",
-        last_data,
-        indicator.next(last_data).0
+        "Adding {}. New MACD: {}, signal: {}, histogram: {}",
+        last_data, next.macd, next.signal, next.histogram
     );
 }
@@ -1,18 +1,53 @@
 //! Buffer with maximum capacity that rotates itself.
 //!
 //! Removes oldest values when a newer value is added. The oldest value is returned.
+//!
+//! Values are held in a ring, each value is written twice so the values from oldest to newest
+//! are always contiguous. Adding a value never moves the values already held. The minimum and
+//! maximum are tracked with monotonic queues, also updated as each value is added.
+//!
+//! The sum is kept with compensated (Neumaier) summation, the rounding error of each addition is
+//! carried separately so the sum does not drift from the values held after many shifts.
+//!
+//! The sum of squared differences from the mean is updated with Welford's method as each value is
+//! added and removed, making the variance constant time. It is recalculated from the values once
+//! every `capacity` shifts to prevent drift.
 use crate::error::TAError;
-use crate::Num;
+use crate::indicators::MonotonicQueue;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{Snapshot, Window};
+use crate::{IndicatorState, Num};
+use core::ops::Index;
 
 /// Buffer with maximum capacity that rotates itself.
-#[derive(Debug)]
+///
+/// Equality compares the capacity and the values held, oldest to newest.
+#[derive(Debug, Clone)]
 pub struct Buffer {
     /// Maximum capacity of the buffer.
     capacity: usize,
-    /// Data the buffer current holds.
+    /// Ring of `capacity` values followed by a copy of the same values.
     data: Vec<Num>,
-    /// Sum of the buffer
+    /// Position of the oldest value within the ring.
+    head: usize,
+    /// Amount of values the buffer currently holds.
+    len: usize,
+    /// Sum of the buffer, without the compensation.
     sum: Num,
+    /// Rounding error lost from the sum, added back when the sum is obtained.
+    compensation: Num,
+    /// Sum of the squared differences from the mean.
+    m2: Num,
+    /// Values removed since the squared differences were last recalculated.
+    shifts: usize,
+    /// Total amount of values added since created or cleared.
+    count: usize,
+    /// Candidates for the maximum value.
+    highs: MonotonicQueue,
+    /// Candidates for the minimum value.
+    lows: MonotonicQueue,
 }
 
 impl Buffer {
@@ -28,26 +63,45 @@ impl Buffer {
     pub fn from_array(capacity: usize, data: &[Num]) -> Result<Self, TAError> {
         if capacity == 0 {
             return Err(TAError::InvalidSize(String::from("capacity cannot be 0")));
-        } else if data.len() == 0 {
-            return Err(TAError::InvalidData(String::from("no data provided")));
+        } else if data.is_empty() {
+            return Err(TAError::InvalidData {
+                indicator: "buffer",
+                required: 1,
+                received: 0,
+            });
         }
 
-        let vec: Vec<Num>;
-        if data.len() >= capacity {
+        let values: &[Num] = if data.len() >= capacity {
             // Place the last `capacity` elements into the buffer.
-            vec = data[(data.len() - capacity)..].to_vec();
+            &data[(data.len() - capacity)..]
         } else {
-            // Partially fill the vector.
-            vec = data.to_vec();
-        }
-
-        let sum = vec.iter().sum();
+            // Partially fill the buffer.
+            data
+        };
 
-        Ok(Self {
+        let mut buffer = Self {
             capacity,
-            data: vec,
-            sum,
-        })
+            data: vec![0.0; capacity * 2],
+            head: 0,
+            len: 0,
+            sum: 0.0,
+            compensation: 0.0,
+            m2: 0.0,
+            shifts: 0,
+            count: 0,
+            highs: MonotonicQueue::new(capacity, true),
+            lows: MonotonicQueue::new(capacity, false),
+        };
+
+        for value in values.iter() {
+            buffer.write(buffer.len, *value);
+            buffer.len += 1;
+            buffer.add(*value);
+            buffer.track(*value);
+        }
+
+        buffer.recalculate();
+        Ok(buffer)
     }
 
     /// Maximum capacity the buffer can hold.
@@ -57,24 +111,70 @@ impl Buffer {
 
     /// Checks if the buffer is ready indicating it has data to meet its capacity.
     pub fn is_ready(&self) -> bool {
-        self.data.len() >= self.capacity()
+        self.len >= self.capacity()
     }
 
-    /// Gets the oldest value in the buffer, this is the next value that will be removed.
+    /// Gets the oldest value in the buffer, this is the next value that will be removed. NaN if
+    /// the buffer is empty.
     pub fn oldest(&self) -> Num {
-        self.data.first().unwrap().clone()
+        self.queue().first().copied().unwrap_or(Num::NAN)
     }
 
     /// Gets the newest value in the buffer, this value will current live the longest in the
-    /// buffer.
+    /// buffer. NaN if the buffer is empty.
     pub fn newest(&self) -> Num {
-        self.data.last().unwrap().clone()
+        self.queue().last().copied().unwrap_or(Num::NAN)
     }
 
     /// Returns the data held by the buffer from Oldest -> Newest. Index 0 being the oldest and
     /// next value to be removed. Index (len-1) being the newest data.
     pub fn queue(&self) -> &[Num] {
-        &self.data[..]
+        &self.data[self.head..(self.head + self.len)]
+    }
+
+    /// Returns the newest `n` values held by the buffer from Oldest -> Newest. If the buffer holds
+    /// fewer than `n` values, all of the values are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Amount of the newest values to obtain.
+    pub fn newest_n(&self, n: usize) -> &[Num] {
+        let queue = self.queue();
+        &queue[queue.len().saturating_sub(n)..]
+    }
+
+    /// Returns the newest `n` values held by the buffer from Oldest -> Newest, the same as
+    /// `newest_n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Amount of the newest values to obtain.
+    pub fn last_n(&self, n: usize) -> &[Num] {
+        self.newest_n(n)
+    }
+
+    /// Iterates the values held by the buffer from Oldest -> Newest.
+    pub fn iter(&self) -> core::slice::Iter<'_, Num> {
+        self.queue().iter()
+    }
+
+    /// Gets the value at the index, Oldest -> Newest. None if the index is not held.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the value, 0 being the oldest.
+    pub fn get(&self, index: usize) -> Option<Num> {
+        self.queue().get(index).copied()
+    }
+
+    /// Amount of values currently held by the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the buffer holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
     /// Adds a new (newest) value to the buffer. Oldest value is removed and returned.
@@ -84,31 +184,189 @@ impl Buffer {
     /// * `value` - New (newest) value to add to the buffer.
     pub fn shift(&mut self, value: Num) -> Num {
         let mut oldest = 0.0;
-        if self.is_ready() {
-            // Extract the oldest value to remove from the sum.
-            oldest = self.data.remove(0usize);
+        let is_ready = self.is_ready();
+        let last_mean = if self.is_empty() { value } else { self.mean() };
+        if is_ready {
+            // Extract the oldest value to remove from the sum, the newest takes its place.
+            oldest = self.data[self.head];
+            self.write(0, value);
+            self.head = (self.head + 1) % self.capacity;
+        } else {
+            self.write(self.len, value);
+            self.len += 1;
         }
 
-        self.sum += value - oldest;
-        self.data.push(value);
+        self.add(value);
+        self.add(-oldest);
+        self.track(value);
 
-        // Resize, trimming oldest if extends past capacity.
-        if self.data.len() > self.capacity() {
-            oldest = self.data[self.data.len() - self.capacity() - 1];
-            self.data.drain(..(self.data.len() - self.capacity()));
+        // Update the squared differences, recalculated once per capacity to remove drift.
+        let mean = self.mean();
+        if is_ready {
+            self.shifts += 1;
+            self.m2 += (value - oldest) * (value - mean + oldest - last_mean);
+        } else {
+            self.m2 += (value - last_mean) * (value - mean);
+        }
+
+        if self.shifts >= self.capacity || !self.m2.is_finite() {
+            self.recalculate();
         }
 
         oldest
     }
 
+    /// Recalculates the sum of the squared differences from the mean with the values held.
+    fn recalculate(&mut self) {
+        let mean = self.mean();
+        self.shifts = 0;
+        self.m2 = self.queue().iter().map(|x| Num::powi(x - mean, 2)).sum();
+    }
+
+    /// Adds a value to the sum, keeping the rounding error in the compensation.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to add to the sum.
+    fn add(&mut self, value: Num) {
+        compensated_add(&mut self.sum, &mut self.compensation, value);
+    }
+
+    /// Adds the newest value to the minimum and maximum candidates.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New (newest) value added to the buffer.
+    fn track(&mut self, value: Num) {
+        self.highs.push(self.count, value);
+        self.lows.push(self.count, value);
+        self.count += 1;
+    }
+
+    /// Writes a value to both copies of the ring.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Position relative to the oldest value.
+    /// * `value` - Value to write.
+    fn write(&mut self, offset: usize, value: Num) {
+        let index = (self.head + offset) % self.capacity;
+        self.data[index] = value;
+        self.data[index + self.capacity] = value;
+    }
+
+    /// Clears the buffer, removing all values. The buffer is empty until a value is added.
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+        self.sum = 0.0;
+        self.compensation = 0.0;
+        self.m2 = 0.0;
+        self.shifts = 0;
+        self.count = 0;
+        self.highs.clear();
+        self.lows.clear();
+    }
+
+    /// Clears the buffer, leaving only the value supplied.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Only value to keep in the buffer.
+    pub(crate) fn reset(&mut self, value: Num) {
+        self.clear();
+        self.write(0, value);
+        self.len = 1;
+        self.add(value);
+        self.track(value);
+    }
+
     /// Obtain the sum of the buffer.
     pub fn sum(&self) -> Num {
-        self.sum
+        self.sum + self.compensation
+    }
+
+    /// Obtain the maximum value of the buffer, NaN if the buffer is empty.
+    pub fn max(&self) -> Num {
+        self.highs.value()
+    }
+
+    /// Obtain the minimum value of the buffer, NaN if the buffer is empty.
+    pub fn min(&self) -> Num {
+        self.lows.value()
+    }
+
+    /// Calculates the sum of the buffer with each value multiplied by its weight. Weights are
+    /// oldest to newest, extra weights or values are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - Weight of each value, oldest to newest.
+    pub fn weighted_sum(&self, weights: &[Num]) -> Num {
+        self.queue()
+            .iter()
+            .zip(weights.iter())
+            .map(|(v, w)| v * w)
+            .sum()
     }
 
     /// Calculates the mean of the buffer.
     pub fn mean(&self) -> Num {
-        self.sum() / self.data.len() as Num
+        self.sum() / self.len as Num
+    }
+
+    /// Calculates the median of the buffer. For an even amount of values, this is the mean of the
+    /// two middle values.
+    pub fn median(&self) -> Num {
+        let mut sorted = self.queue().to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        Self::sorted_median(&sorted)
+    }
+
+    /// Calculates the percentile of the buffer, linearly interpolating between the two closest
+    /// values. A percentile of 0 is the minimum and 100 is the maximum, NaN if the percentile is
+    /// outside of that range or the buffer is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Percentile to calculate, from 0 to 100.
+    pub fn percentile(&self, p: Num) -> Num {
+        let mut sorted = self.queue().to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        Self::sorted_percentile(&sorted, p)
+    }
+
+    /// Obtains the percentile from values that are already sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted` - Values sorted from lowest to highest.
+    /// * `p` - Percentile to calculate, from 0 to 100.
+    pub(crate) fn sorted_percentile(sorted: &[Num], p: Num) -> Num {
+        if sorted.is_empty() || !(0.0..=100.0).contains(&p) {
+            return Num::NAN;
+        }
+
+        // Position between the lowest (0) and highest (len-1) value.
+        let rank = p / 100.0 * (sorted.len() - 1) as Num;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as Num)
+    }
+
+    /// Obtains the median from values that are already sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted` - Values sorted from lowest to highest.
+    pub(crate) fn sorted_median(sorted: &[Num]) -> Num {
+        let mid = sorted.len() / 2;
+        if sorted.is_empty() {
+            Num::NAN
+        } else if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2.0
+        } else {
+            sorted[mid]
+        }
     }
 
     /// Calculates the variance of the buffer.
@@ -117,18 +375,13 @@ impl Buffer {
     ///
     /// * `is_sample` - If the data is a Sample or Population, default should be True.
     pub fn variance(&self, is_sample: bool) -> Num {
-        let mean = self.mean();
         let divisor: Num = if is_sample {
-            (self.data.len() - 1) as Num
+            self.len.saturating_sub(1) as Num
         } else {
-            self.data.len() as Num
+            self.len as Num
         };
 
-        self.data
-            .iter()
-            .map(|x| Num::powi(x - mean, 2))
-            .sum::<Num>()
-            / divisor
+        self.m2.max(0.0) / divisor
     }
 
     /// Calculates the standard deviation of the buffer.
@@ -140,3 +393,164 @@ impl Buffer {
         self.variance(is_sample).sqrt()
     }
 }
+
+/// Adds a value to a compensated (Neumaier) sum, keeping the rounding error in the compensation.
+///
+/// # Arguments
+///
+/// * `sum` - Sum without the compensation.
+/// * `compensation` - Rounding error lost from the sum.
+/// * `value` - Value to add to the sum.
+pub(crate) fn compensated_add(sum: &mut Num, compensation: &mut Num, value: Num) {
+    let total = *sum + value;
+    if sum.abs() >= value.abs() {
+        *compensation += (*sum - total) + value;
+    } else {
+        *compensation += (value - total) + *sum;
+    }
+    *sum = total;
+}
+
+/// Calculates the variance of the values.
+///
+/// # Arguments
+///
+/// * `values` - Values to calculate the variance of.
+/// * `mean` - Mean of the values.
+/// * `is_sample` - If the data is a Sample or Population, default should be True.
+pub(crate) fn variance_of(values: &[Num], mean: Num, is_sample: bool) -> Num {
+    let divisor: Num = if is_sample {
+        values.len().saturating_sub(1) as Num
+    } else {
+        values.len() as Num
+    };
+
+    values.iter().map(|x| Num::powi(x - mean, 2)).sum::<Num>() / divisor
+}
+
+impl PartialEq for Buffer {
+    /// Compares the capacity and the values held, regardless of where they are within the ring.
+    fn eq(&self, other: &Self) -> bool {
+        self.capacity == other.capacity
+            && self.sum() == other.sum()
+            && self.queue() == other.queue()
+    }
+}
+
+impl Index<usize> for Buffer {
+    type Output = Num;
+
+    /// Value at the index, Oldest -> Newest. Panics if the index is not held.
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.queue()[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a Buffer {
+    type Item = &'a Num;
+    type IntoIter = core::slice::Iter<'a, Num>;
+
+    /// Iterates the values held by the buffer from Oldest -> Newest.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Window for Buffer {
+    fn from_array(capacity: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::from_array(capacity, data)
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.is_ready()
+    }
+
+    fn queue(&self) -> &[Num] {
+        self.queue()
+    }
+
+    fn shift(&mut self, value: Num) -> Num {
+        self.shift(value)
+    }
+
+    fn clear(&mut self) {
+        self.clear()
+    }
+
+    fn sum(&self) -> Num {
+        self.sum()
+    }
+
+    fn weighted_sum(&self, weights: &[Num]) -> Num {
+        self.weighted_sum(weights)
+    }
+
+    fn mean(&self) -> Num {
+        self.mean()
+    }
+
+    fn variance(&self, is_sample: bool) -> Num {
+        self.variance(is_sample)
+    }
+
+    fn stdev(&self, is_sample: bool) -> Num {
+        self.stdev(is_sample)
+    }
+}
+
+impl Snapshot for Buffer {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("buffer")
+            .count(self.capacity)
+            .count(self.shifts)
+            .count(self.count)
+            .scalar(self.sum)
+            .scalar(self.compensation)
+            .scalar(self.m2)
+            .buffer(self.queue())
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "buffer")?;
+        let capacity = reader.count()?;
+        let shifts = reader.count()?;
+        let count = reader.count()?;
+        let (sum, compensation, m2) = (reader.scalar()?, reader.scalar()?, reader.scalar()?);
+        let values = reader.buffer()?;
+        if capacity == 0 {
+            return Err(TAError::InvalidSize(String::from("capacity cannot be 0")));
+        } else if values.len() > capacity || values.len() > count {
+            return Err(TAError::InvalidState(String::from(
+                "buffer holds more values than it can",
+            )));
+        }
+
+        let mut buffer = Self {
+            capacity,
+            data: vec![0.0; capacity * 2],
+            head: 0,
+            len: values.len(),
+            sum,
+            compensation,
+            m2,
+            shifts,
+            count,
+            highs: MonotonicQueue::new(capacity, true),
+            lows: MonotonicQueue::new(capacity, false),
+        };
+
+        // Candidates for the extremes only depend on the values held.
+        let first = count - values.len();
+        for (i, value) in values.iter().enumerate() {
+            buffer.write(i, *value);
+            buffer.highs.push(first + i, *value);
+            buffer.lows.push(first + i, *value);
+        }
+
+        Ok(buffer)
+    }
+}
@@ -0,0 +1,137 @@
+//! Candle, the Open, High, Low, Close, and Volume of a bar.
+//!
+//! Supplied directly to the indicators consuming candles, such as the ATR and OBV. Indicators
+//! consuming single values accept the candle as its close.
+use crate::error::TAError;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::traits::{AsValue, Close, High, Hl2, Hlc3, Low, Ohlc4, Ohlcv, Open, Volume};
+use crate::Num;
+use tatk_derive::{AsValue, Close, High, Low, Open, Volume};
+
+/// Holds snapshot / bar data for a product, supplied as its closing value to indicators consuming
+/// single values.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Open, Close, Low, High, Volume, AsValue)]
+#[as_value(close)]
+pub struct Candle {
+    /// Opening value.
+    pub open: Num,
+    /// Closing value.
+    pub close: Num,
+    /// Highest value.
+    pub high: Num,
+    /// Lowest value.
+    pub low: Num,
+    /// Total volume.
+    pub volume: Num,
+}
+
+impl Candle {
+    /// Creates a new Candle from the values supplied.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Values must be finite.
+    /// * High must be greater than or equal to the open, close, and low.
+    /// * Low must be less than or equal to the open and close.
+    /// * Volume must be positive or 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `open` - Opening value.
+    /// * `high` - Highest value.
+    /// * `low` - Lowest value.
+    /// * `close` - Closing value.
+    /// * `volume` - Total volume.
+    pub fn new(open: Num, high: Num, low: Num, close: Num, volume: Num) -> Result<Self, TAError> {
+        let candle = Self {
+            open,
+            close,
+            high,
+            low,
+            volume,
+        };
+
+        candle.validate()?;
+        Ok(candle)
+    }
+
+    /// Creates a new Candle without a body or range, every value being the price.
+    ///
+    /// ## Arguments
+    ///
+    /// * `price` - Open, high, low, and close.
+    /// * `volume` - Total volume.
+    pub fn flat(price: Num, volume: Num) -> Self {
+        Self {
+            open: price,
+            close: price,
+            high: price,
+            low: price,
+            volume,
+        }
+    }
+
+    /// Checks the values are consistent, the high and low containing the open and close.
+    pub fn validate(&self) -> Result<(), TAError> {
+        TAError::check_finite([self.open, self.high, self.low, self.close, self.volume])?;
+        if self.high < self.low {
+            return Err(TAError::InvalidInput(format!(
+                "high {} is less than the low {}",
+                self.high, self.low
+            )));
+        } else if self.high < self.open.max(self.close) {
+            return Err(TAError::InvalidInput(format!(
+                "high {} is less than the open or close",
+                self.high
+            )));
+        } else if self.low > self.open.min(self.close) {
+            return Err(TAError::InvalidInput(format!(
+                "low {} is greater than the open or close",
+                self.low
+            )));
+        } else if self.volume < 0.0 {
+            return Err(TAError::InvalidInput(format!(
+                "volume {} cannot be negative",
+                self.volume
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Candle covering this candle followed by a later candle. The open of this candle, the close
+    /// of the later candle, the highest high, the lowest low, and the summed volume.
+    ///
+    /// # Arguments
+    ///
+    /// * `later` - Candle following this candle.
+    pub fn merge<T: Ohlcv + ?Sized>(&self, later: &T) -> Self {
+        Self {
+            open: self.open,
+            close: later.close(),
+            high: self.high.max(later.high()),
+            low: self.low.min(later.low()),
+            volume: self.volume + later.volume(),
+        }
+    }
+
+    /// Difference between the high and low.
+    pub fn range(&self) -> Num {
+        self.high - self.low
+    }
+
+    /// Difference between the close and open, positive if the candle closed higher.
+    pub fn body(&self) -> Num {
+        self.close - self.open
+    }
+}
+
+// Average of the high and low.
+impl Hl2 for Candle {}
+
+// Average of the high, low, and close.
+impl Hlc3 for Candle {}
+
+// Average of the open, high, low, and close.
+impl Ohlc4 for Candle {}
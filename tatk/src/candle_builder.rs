@@ -0,0 +1,119 @@
+//! Candle Builder, aggregates individual trades into candles.
+//!
+//! A candle is completed once its boundary is reached, either an amount of trades or an amount of
+//! volume. The trade reaching the boundary is part of the candle it completes, trades are never
+//! split between candles.
+use crate::error::TAError;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Candle, Num};
+
+/// When a candle being built is completed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BarBoundary {
+    /// Completed after the amount of trades.
+    Trades(usize),
+    /// Completed once the volume of the trades is the amount or more.
+    Volume(Num),
+}
+
+/// Candle Builder, aggregates individual trades into candles.
+///
+/// A candle is completed once its boundary is reached, either an amount of trades or an amount of
+/// volume. The trade reaching the boundary is part of the candle it completes, trades are never
+/// split between candles. Trades with a price or size that is not finite, or a negative size, are
+/// ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandleBuilder {
+    /// When a candle is completed.
+    boundary: BarBoundary,
+    /// Candle being built, `None` until its first trade.
+    current: Option<Candle>,
+    /// Amount of trades in the candle being built.
+    trades: usize,
+}
+
+impl CandleBuilder {
+    /// Creates a new Candle Builder completing candles on the boundary.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Amount of trades or volume of the boundary must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `boundary` - When a candle is completed.
+    pub fn new(boundary: BarBoundary) -> Result<Self, TAError> {
+        match boundary {
+            BarBoundary::Trades(0) => {
+                return Err(TAError::InvalidSize(String::from(
+                    "trades per candle cannot be 0",
+                )))
+            }
+            BarBoundary::Volume(volume) if !volume.is_finite() || volume <= 0.0 => {
+                return Err(TAError::InvalidSize(String::from(
+                    "volume per candle must be greater than 0",
+                )))
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            boundary,
+            current: None,
+            trades: 0,
+        })
+    }
+
+    /// When a candle is completed.
+    pub fn boundary(&self) -> BarBoundary {
+        self.boundary
+    }
+
+    /// Candle being built from the trades so far, `None` if there have been none.
+    pub fn current(&self) -> Option<Candle> {
+        self.current
+    }
+
+    /// Amount of trades in the candle being built.
+    pub fn trades(&self) -> usize {
+        self.trades
+    }
+
+    /// Adds a trade to the candle being built, returning the candle if the trade completed it.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - Price of the trade.
+    /// * `size` - Size of the trade, added to the volume.
+    pub fn push(&mut self, price: Num, size: Num) -> Option<Candle> {
+        if !price.is_finite() || !size.is_finite() || size < 0.0 {
+            return None;
+        }
+
+        let candle = self.current.get_or_insert(Candle::flat(price, 0.0));
+        candle.high = candle.high.max(price);
+        candle.low = candle.low.min(price);
+        candle.close = price;
+        candle.volume += size;
+        self.trades += 1;
+
+        let completed = match self.boundary {
+            BarBoundary::Trades(trades) => self.trades >= trades,
+            BarBoundary::Volume(volume) => candle.volume >= volume,
+        };
+
+        if completed {
+            self.flush()
+        } else {
+            None
+        }
+    }
+
+    /// Completes the candle being built regardless of the boundary, such as at the end of the
+    /// trades. `None` if there have been no trades since the last candle.
+    pub fn flush(&mut self) -> Option<Candle> {
+        self.trades = 0;
+        self.current.take()
+    }
+}
@@ -0,0 +1,219 @@
+//! CSV loading of candles, the Open, High, Low, Close, and Volume of each row.
+//!
+//! Columns are found by the names in the header (open, high, low, close, and volume) or by their
+//! positions. Rows that cannot be parsed return an error with their line number, blank lines are
+//! ignored. Requires the `csv` feature.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+use crate::error::TAError;
+use crate::{Candle, Num};
+
+/// Names of the columns in a header, in the order of a candle's values.
+const NAMES: [&str; 5] = ["open", "high", "low", "close", "volume"];
+
+/// If the first row of the CSV is a header.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CsvHeader {
+    /// First row is a header if any of its columns are not numbers.
+    #[default]
+    Detect,
+    /// First row is always a header.
+    Present,
+    /// First row is always a candle.
+    Absent,
+}
+
+/// Format of a CSV of candles, defaulting to comma separated columns found by the names in the
+/// header, or the first five columns without one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CsvFormat {
+    /// Separator between columns.
+    delimiter: char,
+    /// If the first row is a header.
+    header: CsvHeader,
+    /// Positions of the open, high, low, close, and volume columns, found from the header if
+    /// `None`.
+    columns: Option<[usize; 5]>,
+}
+
+impl Default for CsvFormat {
+    /// Comma separated columns, a detected header, and columns found by the header's names.
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            header: CsvHeader::Detect,
+            columns: None,
+        }
+    }
+}
+
+impl CsvFormat {
+    /// Changes the separator between columns from the default (`,`).
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Changes if the first row is a header from the default (Detect).
+    pub fn header(mut self, header: CsvHeader) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Changes the positions of the columns, starting at 0, from the default of finding them by
+    /// the names in the header.
+    ///
+    /// ## Arguments
+    ///
+    /// * `open` - Position of the opening value.
+    /// * `high` - Position of the highest value.
+    /// * `low` - Position of the lowest value.
+    /// * `close` - Position of the closing value.
+    /// * `volume` - Position of the total volume.
+    pub fn columns(
+        mut self,
+        open: usize,
+        high: usize,
+        low: usize,
+        close: usize,
+        volume: usize,
+    ) -> Self {
+        self.columns = Some([open, high, low, close, volume]);
+        self
+    }
+
+    /// Reads the candles from the file at the path.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - Path of the CSV file.
+    pub fn read_path<P: AsRef<Path>>(&self, path: P) -> Result<Vec<Candle>, TAError> {
+        let file = File::open(path.as_ref()).map_err(|e| {
+            TAError::InvalidInput(format!("cannot open {}, {}", path.as_ref().display(), e))
+        })?;
+
+        self.read(file)
+    }
+
+    /// Reads the candles from the reader, such as an open file or a string of CSV.
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - Source of the CSV.
+    pub fn read<R: Read>(&self, reader: R) -> Result<Vec<Candle>, TAError> {
+        let mut columns = self.columns;
+        let mut candles: Vec<Candle> = Vec::new();
+        let mut first = true;
+
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let number = index + 1;
+            let line = line.map_err(|e| TAError::InvalidRow(number, e.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line
+                .split(self.delimiter)
+                .map(|f| f.trim().trim_matches('"').trim())
+                .collect();
+
+            // The first row is checked for a header, mapping the columns by name.
+            if first {
+                first = false;
+                let is_header = match self.header {
+                    CsvHeader::Present => true,
+                    CsvHeader::Absent => false,
+                    CsvHeader::Detect => match columns {
+                        Some(positions) => positions
+                            .iter()
+                            .any(|p| fields.get(*p).is_some_and(|f| f.parse::<Num>().is_err())),
+                        None => fields.iter().any(|f| f.parse::<Num>().is_err()),
+                    },
+                };
+
+                if is_header {
+                    if columns.is_none() {
+                        columns = Some(Self::named(&fields, number)?);
+                    }
+                    continue;
+                }
+            }
+
+            let positions = columns.get_or_insert([0, 1, 2, 3, 4]);
+            candles.push(Self::parse(&fields, positions, number)?);
+        }
+
+        Ok(candles)
+    }
+
+    /// Positions of the columns by the names in the header, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - Names of the columns in the header.
+    /// * `number` - Line number of the header.
+    fn named(fields: &[&str], number: usize) -> Result<[usize; 5], TAError> {
+        let mut positions = [0; 5];
+        for (position, name) in positions.iter_mut().zip(NAMES) {
+            *position = fields
+                .iter()
+                .position(|f| f.eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    TAError::InvalidRow(number, format!("header is missing the {} column", name))
+                })?;
+        }
+
+        Ok(positions)
+    }
+
+    /// Candle of a row, validated the same as `Candle::new`.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - Columns of the row.
+    /// * `positions` - Positions of the open, high, low, close, and volume columns.
+    /// * `number` - Line number of the row.
+    fn parse(fields: &[&str], positions: &[usize; 5], number: usize) -> Result<Candle, TAError> {
+        let mut values: [Num; 5] = [0.0; 5];
+        for ((value, position), name) in values.iter_mut().zip(positions).zip(NAMES) {
+            let field = fields.get(*position).ok_or_else(|| {
+                TAError::InvalidRow(number, format!("missing the {} column", name))
+            })?;
+
+            *value = field.parse::<Num>().map_err(|_| {
+                TAError::InvalidRow(number, format!("{} of {:?} is not a number", name, field))
+            })?;
+        }
+
+        let [open, high, low, close, volume] = values;
+        Candle::new(open, high, low, close, volume).map_err(|e| match e {
+            TAError::InvalidInput(text) => TAError::InvalidRow(number, text),
+            e => e,
+        })
+    }
+}
+
+impl Candle {
+    /// Reads candles from the CSV file at the path, in the default `CsvFormat`. Requires the `csv`
+    /// feature.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - Path of the CSV file.
+    pub fn from_csv_path<P: AsRef<Path>>(path: P) -> Result<Vec<Candle>, TAError> {
+        CsvFormat::default().read_path(path)
+    }
+
+    /// Reads candles from the CSV of the reader, in the default `CsvFormat`. Requires the `csv`
+    /// feature.
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - Source of the CSV.
+    pub fn from_csv_reader<R: Read>(reader: R) -> Result<Vec<Candle>, TAError> {
+        CsvFormat::default().read(reader)
+    }
+}
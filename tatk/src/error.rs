@@ -1,17 +1,72 @@
 //! Errors that can occur while processing data.
-use std::fmt;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::Variants;
+use crate::Num;
+use core::error::Error;
+use core::fmt;
 
 /// Errors that can occur within the library.
 #[derive(Debug)]
 pub enum TAError {
     /// Invalid size for capacity, length, period, etc.
     InvalidSize(String),
-    /// Data provided is invalid.
-    InvalidData(String),
+    /// Not enough data provided to create the indicator.
+    InvalidData {
+        /// Name of the indicator or type being created.
+        indicator: &'static str,
+        /// Least amount of data required.
+        required: usize,
+        /// Amount of data provided.
+        received: usize,
+    },
     /// Indexes provided are not valid.
     InvalidIndex(usize, usize),
     /// Line length is not valid.
     InvalidLine(String),
+    /// Value provided cannot be used, such as NaN, infinite, or a price of 0.
+    InvalidInput(String),
+    /// State provided cannot restore the indicator, such as the state of another indicator.
+    InvalidState(String),
+    /// Row of a file cannot be parsed, the line number (starting at 1) and the reason.
+    InvalidRow(usize, String),
+}
+
+impl TAError {
+    /// Checks that every value is finite, neither NaN nor infinite.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Values to check.
+    pub(crate) fn check_finite<I>(values: I) -> Result<(), TAError>
+    where
+        I: IntoIterator<Item = Num>,
+    {
+        match values.into_iter().enumerate().find(|(_, v)| !v.is_finite()) {
+            Some((index, value)) => Err(TAError::InvalidInput(format!(
+                "value {} at index {} is not finite",
+                value, index
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that the periods per year used to annualize a value are finite and greater than 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `annualization` - Periods per year to check.
+    /// * `indicator` - Name of the indicator, used in the error.
+    pub(crate) fn check_annualization(annualization: Num, indicator: &str) -> Result<(), TAError> {
+        if annualization.is_finite() && annualization > 0.0 {
+            return Ok(());
+        }
+
+        Err(TAError::InvalidSize(format!(
+            "annualization must be finite and greater than 0 to calculate {}",
+            indicator
+        )))
+    }
 }
 
 impl fmt::Display for TAError {
@@ -20,8 +75,16 @@ impl fmt::Display for TAError {
             TAError::InvalidSize(text) => {
                 write!(f, "invalid size, {}", text)
             }
-            TAError::InvalidData(text) => {
-                write!(f, "invalid data, {}", text)
+            TAError::InvalidData {
+                indicator,
+                required,
+                received,
+            } => {
+                write!(
+                    f,
+                    "invalid data, not enough data to calculate {}, requires {} but received {}",
+                    indicator, required, received
+                )
             }
             TAError::InvalidIndex(start_idx, end_idx) => {
                 write!(
@@ -33,6 +96,50 @@ impl fmt::Display for TAError {
             TAError::InvalidLine(text) => {
                 write!(f, "invalid line, {}", text)
             }
+            TAError::InvalidInput(text) => {
+                write!(f, "invalid input, {}", text)
+            }
+            TAError::InvalidState(text) => {
+                write!(f, "invalid state, {}", text)
+            }
+            TAError::InvalidRow(line, text) => {
+                write!(f, "invalid row, line {}: {}", line, text)
+            }
+        }
+    }
+}
+
+impl Error for TAError {}
+
+/// How an indicator handles NaN or infinite values supplied with `next`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NonFinite {
+    /// Value is used as supplied, the indicator's value becomes NaN or infinite.
+    #[default]
+    Propagate,
+    /// Value is ignored and the previous value is returned.
+    Skip,
+    /// Panics in debug builds. In release builds the value is ignored, the same as `Skip`.
+    Panic,
+}
+
+impl Variants for NonFinite {
+    const ALL: &'static [Self] = &[NonFinite::Propagate, NonFinite::Skip, NonFinite::Panic];
+}
+
+impl NonFinite {
+    /// Checks if the values should be used by the indicator, panicking when required to.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Values supplied to the indicator.
+    pub(crate) fn accepts(self, values: &[Num]) -> bool {
+        if self == NonFinite::Propagate || values.iter().all(|v| v.is_finite()) {
+            return true;
+        } else if self == NonFinite::Panic && cfg!(debug_assertions) {
+            panic!("non-finite value supplied: {:?}", values);
         }
+
+        false
     }
 }
@@ -0,0 +1,270 @@
+//! Acceleration, Rate of Change (ROC) of a Rate of Change.
+//!
+//! Measures if momentum itself is speeding up or slowing down. As the first ROC approaches 0.0
+//! the acceleration grows rapidly, it is most useful while the momentum holds a direction.
+//!
+//! # Formula
+//!
+//! Acceleration = ROC(ROC(x, n), m)
+//!
+//! where:
+//!
+//! * `x` = current value (most recent)
+//! * `n` = period of the first (momentum) ROC.
+//! * `m` = period of the second (acceleration) ROC.
+
+use super::seed::{self, Reseed, Seed};
+use super::RateOfChange;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
+
+/// Acceleration, Rate of Change (ROC) of a Rate of Change.
+///
+/// Measures if momentum itself is speeding up or slowing down. As the first ROC approaches 0.0
+/// the acceleration grows rapidly, it is most useful while the momentum holds a direction.
+///
+/// # Formula
+///
+/// Acceleration = ROC(ROC(x, n), m)
+///
+/// where:
+///
+/// * `x` = current value (most recent)
+/// * `n` = period of the first (momentum) ROC.
+/// * `m` = period of the second (acceleration) ROC.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct Acceleration {
+    /// Size of the period (window) of the second ROC.
+    period: usize,
+    /// Acceleration's current value.
+    value: Num,
+    /// First order ROC of the values.
+    roc: RateOfChange,
+    /// Second order ROC, the ROC of the first ROC.
+    acceleration: RateOfChange,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl Acceleration {
+    /// Creates a new Acceleration with the supplied periods and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Both periods must be greater than 1.
+    /// * Data must have at least `roc_period + period + 1` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `roc_period` - Period of the first (momentum) ROC.
+    /// * `period` - Period of the second (acceleration) ROC.
+    /// * `data` - Array of values to create the Acceleration from.
+    pub fn new(roc_period: usize, period: usize, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate Acceleration.
+        if roc_period < 2 {
+            return Err(TAError::InvalidSize(String::from(
+                "roc period cannot be less than 2 to calculate acceleration",
+            )));
+        } else if period < 2 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 2 to calculate acceleration",
+            )));
+        } else if data.len() < roc_period + period + 1 {
+            // Make sure we have enough data for both ROCs.
+            return Err(TAError::InvalidData {
+                indicator: "acceleration",
+                required: roc_period + period + 1,
+                received: data.len(),
+            });
+        }
+
+        // First ROC needs `roc_period + 1` values, each value after produces another ROC.
+        let mut roc = RateOfChange::new(roc_period, &data[..roc_period + 1])?;
+        let mut rocs: Vec<Num> = vec![roc.value()];
+        for v in data[(roc_period + 1)..(roc_period + period + 1)].iter() {
+            rocs.push(roc.next(*v));
+        }
+
+        // Second ROC needs `period + 1` ROCs.
+        let mut acceleration = RateOfChange::new(period, &rocs)?;
+
+        // Calculate the remainder of the data.
+        for v in data[(roc_period + period + 1)..].iter() {
+            acceleration.next(roc.next(*v));
+        }
+
+        Ok(Self {
+            period,
+            value: acceleration.value(),
+            roc,
+            acceleration,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new Acceleration with the supplied periods, without initial data. The values are
+    /// supplied with `next` instead, the value is 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Both periods must be greater than 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `roc_period` - Period of the first (momentum) ROC.
+    /// * `period` - Period of the second (acceleration) ROC.
+    pub fn with_period(roc_period: usize, period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            roc_period,
+            period,
+            &seed::placeholder(1.0, roc_period + period + 1),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Current first order ROC (momentum) the acceleration is calculated from.
+    pub fn roc(&self) -> Num {
+        self.roc.value()
+    }
+
+    /// Period of the first order ROC (momentum).
+    pub fn roc_period(&self) -> usize {
+        self.roc.period()
+    }
+}
+
+impl Next<Num> for Acceleration {
+    /// Next value for the Acceleration.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new Acceleration.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        let roc = self.roc.next(value);
+        self.value = self.acceleration.next(roc);
+        self.value
+    }
+}
+
+impl<T> Next<T> for Acceleration
+where
+    T: AsValue,
+{
+    /// Next value for the Acceleration.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new Acceleration.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Stats for Acceleration {
+    /// Obtains the total sum of the buffer for Acceleration.
+    fn sum(&self) -> Num {
+        self.acceleration.sum()
+    }
+
+    /// Mean for the period of the Acceleration.
+    fn mean(&self) -> Num {
+        self.acceleration.mean()
+    }
+
+    /// Current variance for the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn variance(&self, is_sample: bool) -> Num {
+        self.acceleration.variance(is_sample)
+    }
+
+    /// Current standard deviation for the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn stdev(&self, is_sample: bool) -> Num {
+        self.acceleration.stdev(is_sample)
+    }
+}
+
+impl Reset for Acceleration {
+    /// Clears the Acceleration back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.roc.reset();
+        self.acceleration.reset();
+        self.seed.start();
+    }
+}
+
+impl Ready for Acceleration {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for Acceleration {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.roc.period() + self.period + 1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.roc.period(), self.period, data)
+    }
+}
+
+impl Snapshot for Acceleration {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Acceleration")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.roc)
+            .child(&self.acceleration)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Acceleration")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            roc: reader.child()?,
+            acceleration: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,431 @@
+//! Williams Alligator
+//!
+//! Three Smoothed Moving Averages (SMMA) of the median price (HL2), each displaced forward in time.
+//! Often the jaw is a period of 13 shifted 8 bars, teeth are a period of 8 shifted 5 bars, and
+//! lips are a period of 5 shifted 3 bars.
+//!
+//! # Formula
+//!
+//! Line = SMMA(HL2, n) displaced by `s` bars.
+//!
+//! where:
+//!
+//! * `HL2` = (high + low) / 2
+//! * `n` = period of the line.
+//! * `s` = shift of the line, the value shown is the SMMA from `s` bars ago.
+
+use super::seed::{self, Reseed, Seed};
+use super::SmoothedMovingAverage;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{High, Low, Next, Period, Ready, Reset, Snapshot};
+use crate::{Buffer, IndicatorState, Num, TAError};
+
+/// Used for conversions. Holds High (0) and Low (1) values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num);
+
+seed::impl_flatten!(Data(0, 1));
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+/// Single line of the Alligator, an SMMA and its recent history for the displacement.
+#[derive(Debug, Clone, PartialEq)]
+struct Line {
+    /// SMMA of the median price.
+    smma: SmoothedMovingAverage,
+    /// Last `shift + 1` SMMA values, the oldest is the displaced value.
+    history: Buffer,
+}
+
+impl Line {
+    /// Creates a new line from median prices.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Period of the SMMA.
+    /// * `shift` - Amount of bars to displace the line forward.
+    /// * `data` - Array of median prices to create the line from.
+    fn new(period: usize, shift: usize, data: &[Num]) -> Result<Self, TAError> {
+        let mut smma = SmoothedMovingAverage::new(period, &data[..period])?;
+        let mut history = Buffer::from_array(shift + 1, &[smma.value()])?;
+        for v in data[period..].iter() {
+            history.shift(smma.next(*v));
+        }
+
+        Ok(Self { smma, history })
+    }
+
+    /// Value of the line without displacement, the most recent SMMA.
+    fn value(&self) -> Num {
+        self.smma.value()
+    }
+
+    /// Value of the line displaced forward, the SMMA from `shift` bars ago.
+    fn displaced(&self) -> Num {
+        self.history.oldest()
+    }
+
+    /// Period of the SMMA and shift of the line, (`period`, `shift`).
+    fn setting(&self) -> (usize, usize) {
+        (self.smma.period(), self.history.capacity() - 1)
+    }
+
+    /// Adds a new median price to the line.
+    fn next(&mut self, value: Num) -> Num {
+        self.history.shift(self.smma.next(value));
+        self.displaced()
+    }
+
+    /// Clears the line back to having seen no data, displaced value being 0.
+    fn reset(&mut self) {
+        self.smma.reset();
+        self.history.reset(0.0);
+    }
+}
+
+/// Williams Alligator
+///
+/// Three Smoothed Moving Averages (SMMA) of the median price (HL2), each displaced forward in time.
+/// Often the jaw is a period of 13 shifted 8 bars, teeth are a period of 8 shifted 5 bars, and
+/// lips are a period of 5 shifted 3 bars.
+///
+/// # Formula
+///
+/// Line = SMMA(HL2, n) displaced by `s` bars.
+///
+/// where:
+///
+/// * `HL2` = (high + low) / 2
+/// * `n` = period of the line.
+/// * `s` = shift of the line, the value shown is the SMMA from `s` bars ago.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alligator {
+    /// Slowest line, the blue line.
+    jaw: Line,
+    /// Middle line, the red line.
+    teeth: Line,
+    /// Fastest line, the green line.
+    lips: Line,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
+}
+
+impl Alligator {
+    /// Creates a new Alligator with the supplied periods, shifts, and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * All periods must be greater than 0.
+    /// * Data must have at least `period + shift` elements for each line.
+    ///
+    /// ## Arguments
+    ///
+    /// * `jaw` - Period and shift of the jaw, (`period`, `shift`).
+    /// * `teeth` - Period and shift of the teeth, (`period`, `shift`).
+    /// * `lips` - Period and shift of the lips, (`period`, `shift`).
+    /// * `data` - Array of candles to create the Alligator from.
+    pub fn new<T>(
+        jaw: (usize, usize),
+        teeth: (usize, usize),
+        lips: (usize, usize),
+        data: &[T],
+    ) -> Result<Self, TAError>
+    where
+        T: High + Low,
+    {
+        // Check we can calculate the Alligator.
+        for (name, (period, shift)) in [("jaw", jaw), ("teeth", teeth), ("lips", lips)] {
+            if period < 1 {
+                return Err(TAError::InvalidSize(format!(
+                    "{} period cannot be less than 1 to calculate alligator",
+                    name
+                )));
+            } else if data.len() < period + shift {
+                // Make sure we have enough data to displace the line.
+                return Err(TAError::InvalidData {
+                    indicator: "alligator",
+                    required: period + shift,
+                    received: data.len(),
+                });
+            }
+        }
+
+        let data: Vec<Num> = data.iter().map(Self::median_price).collect();
+        Ok(Self {
+            jaw: Line::new(jaw.0, jaw.1, &data)?,
+            teeth: Line::new(teeth.0, teeth.1, &data)?,
+            lips: Line::new(lips.0, lips.1, &data)?,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new Alligator with the common settings, jaw (13, 8), teeth (8, 5), and lips
+    /// (5, 3).
+    ///
+    /// ### Requirements:
+    ///
+    /// * Data must have at least 21 elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - Array of candles to create the Alligator from.
+    pub fn with_defaults<T>(data: &[T]) -> Result<Self, TAError>
+    where
+        T: High + Low,
+    {
+        Self::new((13, 8), (8, 5), (5, 3), data)
+    }
+
+    /// Creates a new Alligator with the supplied periods and shifts, without initial data. The
+    /// candles are supplied with `next` instead, the lines are 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * All periods must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `jaw` - Period and shift of the jaw, (`period`, `shift`).
+    /// * `teeth` - Period and shift of the teeth, (`period`, `shift`).
+    /// * `lips` - Period and shift of the lips, (`period`, `shift`).
+    pub fn with_period(
+        jaw: (usize, usize),
+        teeth: (usize, usize),
+        lips: (usize, usize),
+    ) -> Result<Self, TAError> {
+        let required = Self::required_for(&[jaw, teeth, lips]);
+        seed::streaming(Self::new(
+            jaw,
+            teeth,
+            lips,
+            &seed::placeholder(Data(1.0, 1.0), required),
+        ))
+    }
+
+    /// Jaw (blue line) displaced forward by its shift.
+    pub fn jaw(&self) -> Num {
+        self.jaw.displaced()
+    }
+
+    /// Teeth (red line) displaced forward by its shift.
+    pub fn teeth(&self) -> Num {
+        self.teeth.displaced()
+    }
+
+    /// Lips (green line) displaced forward by its shift.
+    pub fn lips(&self) -> Num {
+        self.lips.displaced()
+    }
+
+    /// Period and shift of the jaw (0), teeth (1), and lips (2).
+    pub(crate) fn settings(&self) -> [(usize, usize); 3] {
+        [
+            self.jaw.setting(),
+            self.teeth.setting(),
+            self.lips.setting(),
+        ]
+    }
+
+    /// Amount of candles required to displace every line, the largest `period + shift`.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Period and shift of each line, (`period`, `shift`).
+    pub(crate) fn required_for(settings: &[(usize, usize)]) -> usize {
+        settings
+            .iter()
+            .map(|(period, shift)| period + shift)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Jaw (blue line) without displacement, the most recent SMMA.
+    pub fn jaw_undisplaced(&self) -> Num {
+        self.jaw.value()
+    }
+
+    /// Teeth (red line) without displacement, the most recent SMMA.
+    pub fn teeth_undisplaced(&self) -> Num {
+        self.teeth.value()
+    }
+
+    /// Lips (green line) without displacement, the most recent SMMA.
+    pub fn lips_undisplaced(&self) -> Num {
+        self.lips.value()
+    }
+
+    /// Alligator is sleeping when the displaced lines are intertwined, the distance between the
+    /// highest and lowest line is within the tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - Maximum distance between the lines, in the same units as the data.
+    pub fn is_sleeping(&self, tolerance: Num) -> bool {
+        let lines = [self.jaw(), self.teeth(), self.lips()];
+        let highest = lines.iter().cloned().fold(Num::MIN, Num::max);
+        let lowest = lines.iter().cloned().fold(Num::MAX, Num::min);
+        highest - lowest <= tolerance
+    }
+
+    /// Alligator is eating when the displaced lines are ordered, lips above teeth above jaw for
+    /// an uptrend or lips below teeth below jaw for a downtrend.
+    pub fn is_eating(&self) -> bool {
+        let (jaw, teeth, lips) = (self.jaw(), self.teeth(), self.lips());
+        (lips > teeth && teeth > jaw) || (lips < teeth && teeth < jaw)
+    }
+
+    /// Median price (HL2) of a candle.
+    fn median_price<T>(value: &T) -> Num
+    where
+        T: High + Low,
+    {
+        (value.high() + value.low()) / 2.0
+    }
+}
+
+impl Period for Alligator {
+    /// Period (window) for the jaw.
+    fn period(&self) -> usize {
+        self.jaw.smma.period()
+    }
+}
+
+impl<T> Next<&T> for Alligator
+where
+    T: High + Low,
+{
+    /// Displaced Jaw, Teeth, and Lips values.
+    type Output = (Num, Num, Num);
+
+    /// Supply an additional candle to recalculate the Alligator.
+    ///
+    /// # Returns
+    ///
+    /// * (`Jaw`, `Teeth`, `Lips`)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        if seed::collect(self, Data(value.high(), value.low())) {
+            return (self.jaw(), self.teeth(), self.lips());
+        }
+
+        let median = Self::median_price(value);
+        (
+            self.jaw.next(median),
+            self.teeth.next(median),
+            self.lips.next(median),
+        )
+    }
+}
+
+impl Next<(Num, Num)> for Alligator {
+    /// Displaced Jaw, Teeth, and Lips values.
+    type Output = (Num, Num, Num);
+
+    /// Supply an additional value to recalculate the Alligator.
+    ///
+    /// # Returns
+    ///
+    /// * (`Jaw`, `Teeth`, `Lips`)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    ///     * 0 = High
+    ///     * 1 = Low
+    fn next(&mut self, value: (Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1))
+    }
+}
+
+impl Reset for Alligator {
+    /// Clears the Alligator back to having seen no data.
+    fn reset(&mut self) {
+        self.jaw.reset();
+        self.teeth.reset();
+        self.lips.reset();
+        self.seed.start();
+    }
+}
+
+impl Ready for Alligator {
+    /// Checks if enough candles have been supplied for the lines to be complete, false while
+    /// collecting the initial candles after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of candles supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for Alligator {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        Self::required_for(&self.settings())
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        let [jaw, teeth, lips] = self.settings();
+        Self::new(jaw, teeth, lips, data)
+    }
+}
+
+impl Snapshot for Line {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("alligator line")
+            .child(&self.smma)
+            .child(&self.history)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "alligator line")?;
+        Ok(Self {
+            smma: reader.child()?,
+            history: reader.child()?,
+        })
+    }
+}
+
+impl Snapshot for Alligator {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Alligator")
+            .child(&self.jaw)
+            .child(&self.teeth)
+            .child(&self.lips)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Alligator")?;
+        Ok(Self {
+            jaw: reader.child()?,
+            teeth: reader.child()?,
+            lips: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
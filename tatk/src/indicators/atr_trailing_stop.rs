@@ -0,0 +1,361 @@
+//! Average True Range (ATR) Trailing Stop, a volatility based stop that only ratchets.
+//!
+//! While long the stop only rises, while short it only falls. When a candle closes through the
+//! stop the position flips and a new stop is seeded from the flip candle. Starts long.
+//!
+//! # Formula
+//!
+//! Long Stop = max(y, c - (m * ATR))
+//!
+//! Short Stop = min(y, c + (m * ATR))
+//!
+//! where:
+//!
+//! * `y` = prior stop.
+//! * `c` = close of the candle.
+//! * `m` = multiplier of the ATR.
+
+use super::seed::{self, Reseed, Seed};
+use super::AverageTrueRange;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{
+    Close, High, InternalValue, Low, Next, Period, Ready, Reset, Snapshot, Stats, Value,
+};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Used for conversions. Holds High (0), Low (1), and Close (2) values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num, Num);
+
+seed::impl_flatten!(Data(0, 1, 2));
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.2
+    }
+}
+
+/// Side of the market the stop is protecting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// Stop is below the price.
+    Long,
+    /// Stop is above the price.
+    Short,
+}
+
+impl Variants for Position {
+    const ALL: &'static [Self] = &[Position::Long, Position::Short];
+}
+
+/// Where a new stop is seeded from on the candle that flips the position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlipSeed {
+    /// Close of the flip candle.
+    Close,
+    /// Extreme of the flip candle, the high when flipping short and the low when flipping long.
+    Extreme,
+}
+
+impl Variants for FlipSeed {
+    const ALL: &'static [Self] = &[FlipSeed::Close, FlipSeed::Extreme];
+}
+
+/// Average True Range (ATR) Trailing Stop, a volatility based stop that only ratchets.
+///
+/// While long the stop only rises, while short it only falls. When a candle closes through the
+/// stop the position flips and a new stop is seeded from the flip candle. Starts long.
+///
+/// # Formula
+///
+/// Long Stop = max(y, c - (m * ATR))
+///
+/// Short Stop = min(y, c + (m * ATR))
+///
+/// where:
+///
+/// * `y` = prior stop.
+/// * `c` = close of the candle.
+/// * `m` = multiplier of the ATR.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct AtrTrailingStop {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Current stop.
+    value: Num,
+    /// Multiplier of the ATR.
+    multiplier: Num,
+    /// Where a new stop is seeded from when flipping.
+    flip_seed: FlipSeed,
+    /// ATR of the candles.
+    atr: AverageTrueRange,
+    /// Current position.
+    position: Position,
+    /// If the last candle flipped the position.
+    flipped: bool,
+    /// Holds `period` amount of generated stops.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
+}
+
+impl AtrTrailingStop {
+    /// Creates a new ATR Trailing Stop with the supplied period, multiplier, flip seed, and initial
+    /// data. The first stop is long, seeded from the close of the first candle with an ATR.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Multiplier must be greater than 0.
+    /// * Data must have at least `period + 1` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used for the ATR.
+    /// * `multiplier` - Multiplier of the ATR, distance of the stop from the price.
+    /// * `seed` - Where a new stop is seeded from when flipping.
+    /// * `data` - Array of candles to create the stop from.
+    pub fn new<T>(
+        period: usize,
+        multiplier: Num,
+        seed: FlipSeed,
+        data: &[T],
+    ) -> Result<Self, TAError>
+    where
+        T: High + Low + Close,
+    {
+        // Check we can calculate ATR Trailing Stop.
+        if multiplier <= 0.0 {
+            return Err(TAError::InvalidSize(String::from(
+                "multiplier must be greater than 0 to calculate atr trailing stop",
+            )));
+        }
+
+        // ATR validates the period and data.
+        let atr = AverageTrueRange::new(period, &data[..(period + 1).min(data.len())])?;
+
+        let value = data[period].close() - multiplier * atr.value();
+        let mut stop = Self {
+            period,
+            value,
+            multiplier,
+            flip_seed: seed,
+            atr,
+            position: Position::Long,
+            flipped: false,
+            buffer: Buffer::from_array(period, &[value])?,
+            seed: Seed::new(period + 1),
+        };
+
+        // Calculate the remainder of the stops.
+        for v in data[(period + 1)..].iter() {
+            stop.next(v);
+        }
+
+        Ok(stop)
+    }
+
+    /// Creates a new ATR Trailing Stop with the supplied period, multiplier, and flip seed, without
+    /// initial data. The candles are supplied with `next` instead, until ready the value is
+    /// calculated from the candles supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Multiplier must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used for the ATR.
+    /// * `multiplier` - Multiplier of the ATR, distance of the stop from the price.
+    /// * `seed` - Where a new stop is seeded from when flipping.
+    pub fn with_period(period: usize, multiplier: Num, seed: FlipSeed) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            multiplier,
+            seed,
+            &seed::placeholder(Data(1.0, 1.0, 1.0), period + 1),
+        ))
+    }
+
+    /// Current and most recent stop calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Current position the stop is protecting.
+    pub fn direction(&self) -> Position {
+        self.position
+    }
+
+    /// Checks if the last candle flipped the position.
+    pub fn flipped(&self) -> bool {
+        self.flipped
+    }
+
+    /// Current ATR used for the distance of the stop.
+    pub fn atr(&self) -> Num {
+        self.atr.value()
+    }
+}
+
+impl<T> Next<&T> for AtrTrailingStop
+where
+    T: High + Low + Close,
+{
+    /// Next value for the stop.
+    type Output = Num;
+
+    /// Supply an additional candle to recalculate the stop.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        if seed::collect(self, Data(value.high(), value.low(), value.close())) {
+            return self.value;
+        }
+
+        let distance = self.multiplier * self.atr.next((value.high(), value.low(), value.close()));
+        let close = value.close();
+
+        self.flipped = match self.position {
+            Position::Long => close < self.value,
+            Position::Short => close > self.value,
+        };
+
+        if self.flipped {
+            // Closed through the stop, seed a new stop on the other side.
+            let (position, stop) = match (self.position, self.flip_seed) {
+                (Position::Long, FlipSeed::Close) => (Position::Short, close + distance),
+                (Position::Long, FlipSeed::Extreme) => (Position::Short, value.high() + distance),
+                (Position::Short, FlipSeed::Close) => (Position::Long, close - distance),
+                (Position::Short, FlipSeed::Extreme) => (Position::Long, value.low() - distance),
+            };
+            self.position = position;
+            self.value = stop;
+        } else {
+            // Ratchet the stop towards the price.
+            self.value = match self.position {
+                Position::Long => self.value.max(close - distance),
+                Position::Short => self.value.min(close + distance),
+            };
+        }
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl Next<(Num, Num, Num)> for AtrTrailingStop {
+    /// Next value for the stop.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate the stop.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    ///     * 0 = High
+    ///     * 1 = Low
+    ///     * 2 = Close
+    fn next(&mut self, value: (Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2))
+    }
+}
+
+impl Reset for AtrTrailingStop {
+    /// Clears the ATR Trailing Stop back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.atr.reset();
+        self.position = Position::Long;
+        self.flipped = false;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for AtrTrailingStop {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for AtrTrailingStop {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period + 1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, self.multiplier, self.flip_seed, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len() - 1, self.multiplier, self.flip_seed, data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for AtrTrailingStop {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("ATR Trailing Stop")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.multiplier)
+            .variant(self.flip_seed)
+            .variant(self.position)
+            .flag(self.flipped)
+            .child(&self.atr)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "ATR Trailing Stop")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            multiplier: reader.scalar()?,
+            flip_seed: reader.variant()?,
+            position: reader.variant()?,
+            flipped: reader.flag()?,
+            atr: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -8,12 +8,38 @@
 //!
 //! * `TR` = true range
 //! * `n` = period
+//!
+//! The true ranges are smoothed with Wilder's smoothing, above, by default. They can instead be
+//! the simple or exponential average of the period.
 
+use super::seed::{self, Reseed, Seed};
 use super::true_range::TrueRangeData;
-use super::TrueRange;
-use crate::traits::{Close, High, InternalValue, Low, Next, Period, Stats};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+use super::{ExponentialMovingAverage, TrueRange};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{
+    CheckedNext, Close, High, InternalValue, Low, Next, Period, Ready, Reset, Snapshot, Stats,
+    Value,
+};
+use crate::{Buffer, IndicatorState, NonFinite, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Smoothing used for the true ranges of an ATR.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AtrSmoothing {
+    /// Wilder's smoothing, `(prior * (n - 1) + TR) / n`.
+    #[default]
+    Wilder,
+    /// Simple average of the period.
+    Sma,
+    /// Exponential average with a smoothing factor of `2 / (n + 1)`.
+    Ema,
+}
+
+impl Variants for AtrSmoothing {
+    const ALL: &'static [Self] = &[AtrSmoothing::Wilder, AtrSmoothing::Sma, AtrSmoothing::Ema];
+}
 
 /// Average True Range (ATR), for a `n` true ranges.
 ///
@@ -25,7 +51,10 @@ use tatk_derive::{InternalValue, Period};
 ///
 /// * `TR` = true range
 /// * `n` = period
-#[derive(Debug, Period, InternalValue)]
+///
+/// The true ranges are smoothed with Wilder's smoothing, above, by default. They can instead be
+/// the simple or exponential average of the period.
+#[derive(Debug, Clone, PartialEq, Period, InternalValue, Stats, Value)]
 pub struct AverageTrueRange {
     /// Size of the period (window) in which data is looked at.
     period: usize,
@@ -33,12 +62,20 @@ pub struct AverageTrueRange {
     value: Num,
     /// True Range used for calculations.
     true_range: TrueRange,
+    /// Smoothing used for the true ranges.
+    smoothing: AtrSmoothing,
+    /// EMA of the true ranges, only held for `AtrSmoothing::Ema`.
+    ema: Option<ExponentialMovingAverage>,
     /// Holds `period` amount of generated ATRs.
     buffer: Buffer,
+    /// How NaN or infinite values supplied with `next` are handled.
+    non_finite: NonFinite,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<TrueRangeData>,
 }
 
 impl AverageTrueRange {
-    /// Creates a new ATR with the supplied period and initial data.
+    /// Creates a new ATR with the supplied period and initial data, using Wilder's smoothing.
     ///
     /// ### Requirements:
     ///
@@ -50,49 +87,144 @@ impl AverageTrueRange {
     /// * `period` - Size of the period / window used.
     /// * `data` - Array of values to create the ATR from.
     pub fn new<T>(period: usize, data: &[T]) -> Result<Self, TAError>
+    where
+        T: High + Low + Close,
+    {
+        Self::with_smoothing(period, data, AtrSmoothing::Wilder)
+    }
+
+    /// Creates a new ATR with the supplied period, initial data, and smoothing of the true
+    /// ranges. Every smoothing starts from the mean of the first `period` true ranges.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period + 1` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the ATR from.
+    /// * `smoothing` - Smoothing used for the true ranges.
+    pub fn with_smoothing<T>(
+        period: usize,
+        data: &[T],
+        smoothing: AtrSmoothing,
+    ) -> Result<Self, TAError>
+    where
+        T: High + Low + Close,
+    {
+        Self::create(period, data, smoothing, false)
+    }
+
+    /// Creates a new ATR with the supplied period and initial data using Wilder's smoothing, the
+    /// first true range being the high - low of the first candle. This is the TA-Lib / Wilder
+    /// convention, allowing exactly `period` candles.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the ATR from.
+    pub fn new_from_first_bar<T>(period: usize, data: &[T]) -> Result<Self, TAError>
+    where
+        T: High + Low + Close,
+    {
+        Self::create(period, data, AtrSmoothing::Wilder, true)
+    }
+
+    /// Creates a new ATR, validating the period and data.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the ATR from.
+    /// * `smoothing` - Smoothing used for the true ranges.
+    /// * `first_bar` - If the first true range is the high - low of the first candle.
+    fn create<T>(
+        period: usize,
+        data: &[T],
+        smoothing: AtrSmoothing,
+        first_bar: bool,
+    ) -> Result<Self, TAError>
     where
         T: High + Low + Close,
     {
         // Check we can calculate ATR.
+        let required = TrueRange::required_for(period, first_bar);
         if period < 1 {
             return Err(TAError::InvalidSize(String::from(
                 "period cannot be less than 1 to calculate average true range",
             )));
-        } else if data.len() < period + 1 {
+        } else if data.len() < required {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data to calculate average true range",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "average true range",
+                required,
+                received: data.len(),
+            });
         }
 
+        // Make sure the data is usable.
+        TAError::check_finite(data.iter().flat_map(|v| [v.high(), v.low(), v.close()]))?;
+
         // Create the first `n` true ranges.
-        let mut tr = match TrueRange::new(period, &data[..(period + 1)]) {
-            Ok(v) => v,
-            Err(error) => return Err(error),
+        let tr = if first_bar {
+            TrueRange::new_from_first_bar(period, &data[..required])?
+        } else {
+            TrueRange::new(period, &data[..required])?
+        };
+        let ema = match smoothing {
+            AtrSmoothing::Ema => Some(ExponentialMovingAverage::new(period, tr.buffer().queue())?),
+            _ => None,
         };
 
         // Initial value.
-        let mut atr_value = tr.mean();
-
-        // Buffer will hold last `n` ATRs.
-        let mut buffer = match Buffer::from_array(period, &[atr_value]) {
-            Ok(v) => v,
-            Err(error) => return Err(error),
+        let value = tr.mean();
+        let mut atr = Self {
+            period,
+            value,
+            true_range: tr,
+            smoothing,
+            ema,
+            // Buffer will hold last `n` ATRs.
+            buffer: Buffer::from_array(period, &[value])?,
+            non_finite: NonFinite::default(),
+            seed: Seed::new(data.len()),
         };
 
         // Calculate the remainder of ATRs.
-        for value in data[(period + 1)..].iter() {
-            let tr_next = tr.next(value);
-            atr_value = Self::calculate(tr_next, period, atr_value);
-            buffer.shift(atr_value);
+        for value in data[required..].iter() {
+            atr.smooth(value);
         }
 
-        Ok(Self {
+        Ok(atr)
+    }
+
+    /// Creates a new ATR with the supplied period, without initial data. The candles are supplied
+    /// with `next` instead, until ready the value is calculated from the candles supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
             period,
-            true_range: tr,
-            value: atr_value,
-            buffer,
-        })
+            &seed::placeholder(TrueRangeData(1.0, 1.0, 1.0), period + 1),
+        ))
+    }
+
+    /// Creates a builder for an ATR, defaulting to a period of 14.
+    pub fn builder() -> AtrBuilder {
+        AtrBuilder::default()
     }
 
     /// Current and most recent value calculated.
@@ -100,11 +232,54 @@ impl AverageTrueRange {
         self.value
     }
 
+    /// ATR as a percentage of the close of the most recent candle, comparable across prices of
+    /// different sizes. 0 if the close is 0.
+    pub fn atr_percent(&self) -> Num {
+        let close = self.true_range.last_close();
+        if close == 0.0 {
+            return 0.0;
+        }
+
+        self.value / close * 100.0
+    }
+
+    /// Smoothing used for the true ranges.
+    pub fn smoothing(&self) -> AtrSmoothing {
+        self.smoothing
+    }
+
+    /// Changes how NaN or infinite values supplied with `next` are handled, the default being to
+    /// propagate them.
+    pub fn set_non_finite(&mut self, policy: NonFinite) {
+        self.non_finite = policy;
+    }
+
     /// Caclulates a new ATR, requring a prior close.
     fn calculate(tr_value: Num, period: usize, last_atr: Num) -> Num {
         let top = (last_atr * (period as Num - 1.0)) + tr_value;
         top / period as Num
     }
+
+    /// Adds a candle to the true ranges, smoothing them into a new ATR.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn smooth<T>(&mut self, value: &T) -> Num
+    where
+        T: High + Low + Close,
+    {
+        let tr_value = self.true_range.next(value);
+        self.value = match (self.smoothing, self.ema.as_mut()) {
+            (AtrSmoothing::Sma, _) => self.true_range.mean(),
+            (AtrSmoothing::Ema, Some(ema)) => ema.next(tr_value),
+            _ => Self::calculate(tr_value, self.period, self.value),
+        };
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
 }
 
 impl<T> Next<T> for AverageTrueRange
@@ -120,14 +295,22 @@ where
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: T) -> Self::Output {
-        let period = self.period();
-        let last = self.value();
-        let tr_value = self.true_range.next(&value);
-        self.value = Self::calculate(tr_value, period, last);
+        if !self
+            .non_finite
+            .accepts(&[value.high(), value.low(), value.close()])
+        {
+            self.seed.skip();
+            return self.value;
+        }
 
-        // Rotate the buffer.
-        self.buffer.shift(self.value());
-        self.value
+        if seed::collect(
+            self,
+            TrueRangeData(value.high(), value.low(), value.close()),
+        ) {
+            return self.value;
+        }
+
+        self.smooth(&value)
     }
 }
 
@@ -144,42 +327,175 @@ impl Next<(Num, Num, Num)> for AverageTrueRange {
     ///     * 1 = Low
     ///     * 2 = Close
     fn next(&mut self, value: (Num, Num, Num)) -> Self::Output {
-        let v = TrueRangeData {
-            0: value.0, // High
-            1: value.1, // Low
-            2: value.2, // Close
-        };
+        let v = TrueRangeData(value.0, value.1, value.2);
 
         self.next(v)
     }
 }
 
-impl Stats for AverageTrueRange {
-    /// Obtains the total sum of the buffer for ATR.
-    fn sum(&self) -> Num {
-        self.buffer.sum()
+impl<T> CheckedNext<T> for AverageTrueRange
+where
+    T: High + Low + Close,
+{
+    /// Supply an additional value to recalculate a new ATR, rejecting NaN or infinite values.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn checked_next(&mut self, value: T) -> Result<Self::Output, TAError> {
+        TAError::check_finite([value.high(), value.low(), value.close()])?;
+        Ok(self.next(value))
+    }
+}
+
+impl Reset for AverageTrueRange {
+    /// Clears the ATR back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.true_range.reset();
+        if let Some(ema) = self.ema.as_mut() {
+            ema.reset();
+        }
+        self.buffer.clear();
+        self.seed.start();
     }
+}
 
-    /// Mean for the period of the ATR.
-    fn mean(&self) -> Num {
-        self.buffer.mean()
+impl Ready for AverageTrueRange {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting() && self.true_range.is_ready()
     }
 
-    /// Current variance for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn variance(&self, is_sample: bool) -> Num {
-        self.buffer.variance(is_sample)
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for AverageTrueRange {
+    type Input = TrueRangeData;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        TrueRange::required_for(self.period, self.true_range.is_first_bar())
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        // Keep the policy that was set.
+        let first_bar = self.true_range.is_first_bar();
+        let mut atr = Self::create(self.period, data, self.smoothing, first_bar)?;
+        atr.non_finite = self.non_finite;
+        Ok(atr)
     }
 
-    /// Current standard deviation for the period.
+    fn partial(&mut self, data: &[Self::Input]) {
+        let first_bar = self.true_range.is_first_bar();
+        let period = data.len() + 1 - TrueRange::required_for(1, first_bar);
+        if let Ok(partial) = Self::create(period, data, self.smoothing, first_bar) {
+            self.value = partial.value;
+        }
+    }
+}
+
+/// Builder for an ATR, defaulting to a period of 14.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtrBuilder {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Smoothing used for the true ranges.
+    smoothing: AtrSmoothing,
+    /// If the first true range is the high - low of the first candle.
+    first_bar: bool,
+}
+
+impl Default for AtrBuilder {
+    /// Common period (14) and Wilder's smoothing, requiring a prior close for the first true
+    /// range.
+    fn default() -> Self {
+        Self {
+            period: 14,
+            smoothing: AtrSmoothing::Wilder,
+            first_bar: false,
+        }
+    }
+}
+
+impl AtrBuilder {
+    /// Changes the period from the default (14).
+    pub fn period(mut self, period: usize) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Changes the smoothing of the true ranges from the default (Wilder).
+    pub fn smoothing(mut self, smoothing: AtrSmoothing) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Changes if the first true range is the high - low of the first candle, requiring only
+    /// `period` candles, from the default (false).
+    pub fn seed_first_bar(mut self, first_bar: bool) -> Self {
+        self.first_bar = first_bar;
+        self
+    }
+
+    /// Creates the ATR from the initial candles, validated the same as `new`.
     ///
-    /// # Arguments
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period + 1` elements, or `period` if seeded from the first bar.
+    ///
+    /// ## Arguments
     ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn stdev(&self, is_sample: bool) -> Num {
-        self.buffer.stdev(is_sample)
+    /// * `data` - Array of candles to create the ATR from.
+    pub fn build<T>(self, data: &[T]) -> Result<AverageTrueRange, TAError>
+    where
+        T: High + Low + Close,
+    {
+        AverageTrueRange::create(self.period, data, self.smoothing, self.first_bar)
+    }
+}
+
+impl Snapshot for AverageTrueRange {
+    fn snapshot(&self) -> IndicatorState {
+        let mut state = IndicatorState::new("ATR")
+            .count(self.period)
+            .scalar(self.value)
+            .variant(self.smoothing)
+            .variant(self.non_finite)
+            .flag(self.ema.is_some())
+            .child(&self.true_range)
+            .child(&self.buffer)
+            .child(&self.seed);
+
+        if let Some(ema) = self.ema.as_ref() {
+            state = state.child(ema);
+        }
+
+        state
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "ATR")?;
+        let (period, value) = (reader.count()?, reader.scalar()?);
+        let (smoothing, non_finite) = (reader.variant()?, reader.variant()?);
+        let has_ema = reader.flag()?;
+        Ok(Self {
+            period,
+            value,
+            smoothing,
+            non_finite,
+            true_range: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+            ema: if has_ema { Some(reader.child()?) } else { None },
+        })
     }
 }
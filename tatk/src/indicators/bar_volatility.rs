@@ -0,0 +1,191 @@
+//! Shared machinery for volatility estimators built from a term for each bar (candle).
+//!
+//! The variance of a period is the mean of the terms within the period multiplied by a scale,
+//! the volatility is the square root of the variance then annualized.
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::Snapshot;
+use crate::{Buffer, IndicatorState, Num, TAError};
+
+/// Rolling buffer of per-bar terms used by the range-based volatility estimators.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BarVolatility {
+    /// Multiplier of the mean term to obtain the variance.
+    scale: Num,
+    /// Periods per year used to annualize.
+    annualization: Num,
+    /// Term for each bar within the period.
+    terms: Buffer,
+    /// If the last bar was skipped for being invalid.
+    skipped: bool,
+    /// Current annualized volatility.
+    value: Num,
+    /// Holds `period` amount of generated volatilities.
+    buffer: Buffer,
+}
+
+impl BarVolatility {
+    /// Creates the estimator from the terms of each bar, none being an invalid bar.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Annualization must be finite and greater than 0, 1.0 for none.
+    /// * Terms must have at least `period` elements.
+    /// * All terms must be valid (some).
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` - Name of the estimator, used for errors.
+    /// * `period` - Size of the period / window used.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    /// * `scale` - Multiplier of the mean term to obtain the variance.
+    /// * `terms` - Term for each bar.
+    pub(crate) fn new(
+        name: &'static str,
+        period: usize,
+        annualization: Num,
+        scale: Num,
+        terms: &[Option<Num>],
+    ) -> Result<Self, TAError> {
+        // Check we can calculate the estimator.
+        if period < 1 {
+            return Err(TAError::InvalidSize(format!(
+                "period cannot be less than 1 to calculate {}",
+                name
+            )));
+        }
+
+        TAError::check_annualization(annualization, name)?;
+        if terms.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: name,
+                required: period,
+                received: terms.len(),
+            });
+        }
+
+        let terms: Vec<Num> = match terms.iter().cloned().collect::<Option<Vec<Num>>>() {
+            Some(terms) => terms,
+            None => {
+                return Err(TAError::InvalidInput(format!(
+                    "candles must have positive prices with a low no greater than the high to calculate {}",
+                    name
+                )))
+            }
+        };
+
+        // Seed with the first period of bars.
+        let seed = Buffer::from_array(period, &terms[..period])?;
+        let value = Self::calculate(&seed, scale) * annualization.sqrt();
+        let mut volatility = Self {
+            scale,
+            annualization,
+            terms: seed,
+            skipped: false,
+            value,
+            buffer: Buffer::from_array(period, &[value])?,
+        };
+
+        // Calculate the remainder of the volatilities.
+        for term in terms[period..].iter() {
+            volatility.next(Some(*term));
+        }
+
+        Ok(volatility)
+    }
+
+    /// Current annualized volatility.
+    pub(crate) fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Volatility within the period, before annualization.
+    pub(crate) fn period_volatility(&self) -> Num {
+        Self::calculate(&self.terms, self.scale)
+    }
+
+    /// Periods per year used to annualize.
+    pub(crate) fn annualization(&self) -> Num {
+        self.annualization
+    }
+
+    /// Checks if the last bar was skipped for being invalid.
+    pub(crate) fn is_skipped(&self) -> bool {
+        self.skipped
+    }
+
+    /// Holds `period` amount of generated volatilities.
+    pub(crate) fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Clears the terms and volatilities, the value is 0 until the estimator is recreated.
+    pub(crate) fn clear(&mut self) {
+        self.terms.clear();
+        self.skipped = false;
+        self.value = 0.0;
+        self.buffer.clear();
+    }
+
+    /// Adds the term of a new bar, an invalid bar (none) is skipped and the current value is
+    /// returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `term` - Term of the new bar.
+    pub(crate) fn next(&mut self, term: Option<Num>) -> Num {
+        let Some(term) = term else {
+            self.skipped = true;
+            return self.value;
+        };
+
+        self.skipped = false;
+        self.terms.shift(term);
+        self.value = self.period_volatility() * self.annualization.sqrt();
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+
+    /// Calculates the volatility, before annualization, from the terms of a period. Negative
+    /// variances, possible from some estimators, are treated as 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - Term for each bar within the period.
+    /// * `scale` - Multiplier of the mean term to obtain the variance.
+    fn calculate(terms: &Buffer, scale: Num) -> Num {
+        (terms.sum() / terms.capacity() as Num * scale)
+            .max(0.0)
+            .sqrt()
+    }
+}
+
+impl Snapshot for BarVolatility {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("bar volatility")
+            .scalar(self.scale)
+            .scalar(self.annualization)
+            .flag(self.skipped)
+            .scalar(self.value)
+            .child(&self.terms)
+            .child(&self.buffer)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "bar volatility")?;
+        Ok(Self {
+            scale: reader.scalar()?,
+            annualization: reader.scalar()?,
+            skipped: reader.flag()?,
+            value: reader.scalar()?,
+            terms: reader.child()?,
+            buffer: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,403 @@
+//! Batch calculation of an indicator, the output for every value of the data at once.
+//!
+//! Each output is aligned with the value that produced it, `output[i]` being the indicator after
+//! `data[i]` was supplied. Outputs from before the indicator is ready are either skipped or NaN.
+
+use super::{
+    Acceleration, Alligator, AtrTrailingStop, AverageTrueRange, BandsOutput, Beta, BollingerBands,
+    ChannelWidth, CumulativeReturn, DoubleExponentialMovingAverage, ExponentialMovingAverage,
+    FlipSeed, GarmanKlassVolatility, GatorBar, GatorOscillator, GaussianCenter,
+    GaussianMovingAverage, HistoricalVolatility, HoltSmoothing, KalmanFilter, KalmanModel,
+    KeltnerChannels, Lag, LinearRegression, LinearRegressionChannel, MacdOutput, McGinleyDynamic,
+    Median, MedianAbsoluteDeviation, MidPoint, MidPrice, MinMaxScaler, Moments,
+    MovingAverageConvergenceDivergence, OnBalanceVolume, ParkinsonVolatility, RateOfChange,
+    RelativeStrengthIndex, RogersSatchellVolatility, RollingExtrema, RollingSum, SavitzkyGolay,
+    SharpeRatio, SimpleMovingAverage, SmoothedMovingAverage, SmoothedRateOfChange, SortinoRatio,
+    Squeeze, SqueezeOutput, StandardDeviation, TrueRange, TypicalPrice, Variance,
+    VolumeWeightedAveragePrice, WeightedClose, WeightedMovingAverage, YangZhangVolatility,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::traits::{Close, High, Low, Next, Open, Ready, Volume};
+use crate::{Num, TAError};
+
+/// Outputs of an indicator produced before it is ready.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Warmup {
+    /// Replaced with NaN, keeping the outputs aligned with the data.
+    #[default]
+    Nan,
+    /// Left out, the first output being the first value the indicator is ready.
+    Skip,
+}
+
+/// Supplies every value of the data to an indicator, returning the output for each. Use an
+/// indicator created without initial data for the outputs to be aligned with the data.
+///
+/// # Arguments
+///
+/// * `indicator` - Indicator the data is supplied to.
+/// * `data` - Values or candles to supply, oldest to newest.
+/// * `warmup` - Outputs produced before the indicator is ready.
+pub fn compute<I, T>(indicator: I, data: impl IntoIterator<Item = T>, warmup: Warmup) -> Vec<Num>
+where
+    I: Next<T, Output = Num> + Ready,
+{
+    outputs(indicator, data, warmup, Num::NAN)
+}
+
+/// Supplies every value of the data to an indicator, returning the output for each. Outputs
+/// produced before the indicator is ready are replaced with `nan` or left out.
+///
+/// # Arguments
+///
+/// * `indicator` - Indicator the data is supplied to.
+/// * `data` - Values or candles to supply, oldest to newest.
+/// * `warmup` - Outputs produced before the indicator is ready.
+/// * `nan` - Output used in place of those produced before the indicator is ready.
+fn outputs<I, T>(
+    mut indicator: I,
+    data: impl IntoIterator<Item = T>,
+    warmup: Warmup,
+    nan: I::Output,
+) -> Vec<I::Output>
+where
+    I: Next<T> + Ready,
+    I::Output: Clone,
+{
+    let data = data.into_iter();
+    let mut outputs: Vec<I::Output> = Vec::with_capacity(data.size_hint().0);
+    for value in data {
+        let output = indicator.next(value);
+        if indicator.is_ready() {
+            outputs.push(output);
+        } else if warmup == Warmup::Nan {
+            outputs.push(nan.clone());
+        }
+    }
+
+    outputs
+}
+
+/// Output of an indicator before it is ready, NaN for values and lines.
+macro_rules! nan_output {
+    () => {
+        Num::NAN
+    };
+    ($nan:expr) => {
+        $nan
+    };
+}
+
+/// Type of the outputs of an indicator, `Num` unless the indicator produces several lines.
+macro_rules! output_type {
+    () => {
+        Num
+    };
+    ($output:ty) => {
+        $output
+    };
+}
+
+/// Implements `compute` for indicators that consume single values, created from a period or the
+/// settings listed. Indicators producing several lines list their output and its value before
+/// ready.
+macro_rules! impl_compute {
+    ($(
+        $indicator:ty
+        $(, ($($arg:ident: $ty:ty => $doc:literal),+))?
+        $(=> $output:ty = $nan:expr)?
+    );+ $(;)?) => {
+        $(
+            impl $indicator {
+                impl_compute!(
+                    @compute ($($($arg: $ty => $doc),+)?)
+                    $(=> $output = $nan)?
+                );
+            }
+        )+
+    };
+    (@compute () $(=> $output:ty = $nan:expr)?) => {
+        impl_compute!(
+            @compute (period: usize => "Size of the period / window used.")
+            $(=> $output = $nan)?
+        );
+    };
+    (@compute ($($arg:ident: $ty:ty => $doc:literal),+) $(=> $output:ty = $nan:expr)?) => {
+        /// Calculates the indicator for every value of the data, NaN until ready. Each output
+        /// is aligned with the value that produced it.
+        ///
+        /// # Arguments
+        ///
+        $(#[doc = concat!("* `", stringify!($arg), "` - ", $doc)])+
+        /// * `data` - Array of values to calculate, oldest to newest.
+        pub fn compute(
+            $($arg: $ty,)+
+            data: &[Num],
+        ) -> Result<Vec<output_type!($($output)?)>, TAError> {
+            Ok(outputs(
+                Self::with_period($($arg),+)?,
+                data.iter().copied(),
+                Warmup::Nan,
+                nan_output!($($nan)?),
+            ))
+        }
+    };
+}
+
+/// Implements `compute` for indicators that consume candles, created from a period or the
+/// settings listed. Indicators producing several lines list their output and its value before
+/// ready.
+macro_rules! impl_candle_compute {
+    ($(
+        $indicator:ty => $($bound:path)|+
+        $(, ($($arg:ident: $ty:ty => $doc:literal),+))?
+        $(=> $output:ty = $nan:expr)?
+    );+ $(;)?) => {
+        $(
+            impl $indicator {
+                impl_candle_compute!(
+                    @compute [$($bound)|+] ($($($arg: $ty => $doc),+)?)
+                    $(=> $output = $nan)?
+                );
+            }
+        )+
+    };
+    (@compute [$($bound:path)|+] () $(=> $output:ty = $nan:expr)?) => {
+        impl_candle_compute!(
+            @compute [$($bound)|+] (period: usize => "Size of the period / window used.")
+            $(=> $output = $nan)?
+        );
+    };
+    (
+        @compute [$($bound:path)|+] ($($arg:ident: $ty:ty => $doc:literal),+)
+        $(=> $output:ty = $nan:expr)?
+    ) => {
+        /// Calculates the indicator for every candle of the data, NaN until ready. Each output
+        /// is aligned with the candle that produced it.
+        ///
+        /// # Arguments
+        ///
+        $(#[doc = concat!("* `", stringify!($arg), "` - ", $doc)])+
+        /// * `data` - Array of candles to calculate, oldest to newest.
+        pub fn compute<T>(
+            $($arg: $ty,)+
+            data: &[T],
+        ) -> Result<Vec<output_type!($($output)?)>, TAError>
+        where
+            T: $($bound +)+,
+        {
+            Ok(outputs(
+                Self::with_period($($arg),+)?,
+                data.iter(),
+                Warmup::Nan,
+                nan_output!($($nan)?),
+            ))
+        }
+    };
+}
+
+/// Lines of an indicator that are each NaN.
+const NAN_BANDS: BandsOutput = BandsOutput {
+    lower: Num::NAN,
+    middle: Num::NAN,
+    upper: Num::NAN,
+};
+
+impl_compute!(
+    Acceleration, (
+        roc_period: usize => "Period of the first (momentum) ROC.",
+        period: usize => "Period of the second (acceleration) ROC."
+    );
+    BollingerBands<SimpleMovingAverage>, (
+        period: usize => "Size of the period / window used.",
+        distance: Num => "Distance of the bands (in standard deviations) from the SMA."
+    ) => BandsOutput = NAN_BANDS;
+    DoubleExponentialMovingAverage;
+    ExponentialMovingAverage;
+    GaussianMovingAverage, (
+        period: usize => "Size of the period / window used.",
+        sigma: Num => "Width of the curve in values.",
+        center: GaussianCenter => "Position the curve is centered on."
+    );
+    HistoricalVolatility, (
+        period: usize => "Size of the period / window used.",
+        annualization: Num => "Periods per year, such as 252 for daily data."
+    );
+    HoltSmoothing, (
+        period: usize => "Amount of levels kept for statistics.",
+        alpha: Num => "Smoothing of the level.",
+        beta: Num => "Smoothing of the trend."
+    );
+    KalmanFilter, (
+        period: usize => "Amount of estimates kept for statistics.",
+        model: KalmanModel => "Model of how the true value moves.",
+        process_noise: Num => "Expected movement of the true value between values.",
+        measurement_noise: Num => "Expected noise of the values supplied."
+    );
+    Lag;
+    LinearRegression;
+    LinearRegressionChannel, (
+        period: usize => "Size of the period / window used.",
+        width: ChannelWidth => "Distance of the bands from the regression line."
+    ) => (Num, Num, Num) = (Num::NAN, Num::NAN, Num::NAN);
+    McGinleyDynamic, (
+        period: usize => "Size of the period / window used.",
+        k: Num => "Constant adjusting the speed of the line."
+    );
+    Median;
+    MedianAbsoluteDeviation, (
+        period: usize => "Size of the period / window used.",
+        is_scaled: bool => "Scales the deviation to be comparable to a standard deviation."
+    );
+    MidPoint;
+    MinMaxScaler;
+    Moments => (Num, Num) = (Num::NAN, Num::NAN);
+    MovingAverageConvergenceDivergence, (
+        short: usize => "Period of the short line.",
+        long: usize => "Period of the long line.",
+        signal: usize => "Period of the signal line."
+    ) => MacdOutput = MacdOutput {
+        macd: Num::NAN,
+        signal: Num::NAN,
+        histogram: Num::NAN,
+    };
+    RateOfChange;
+    RelativeStrengthIndex;
+    RollingExtrema => (Num, Num) = (Num::NAN, Num::NAN);
+    RollingSum;
+    SavitzkyGolay, (
+        period: usize => "Size of the period / window used.",
+        degree: usize => "Degree of the polynomial fitted."
+    );
+    SharpeRatio, (
+        period: usize => "Size of the period / window used.",
+        risk_free: Num => "Risk-free rate per period.",
+        annualization: Num => "Periods per year, such as 252 for daily data.",
+        is_prices: bool => "Data is prices rather than returns."
+    );
+    SimpleMovingAverage;
+    SmoothedMovingAverage;
+    SmoothedRateOfChange, (
+        ema_period: usize => "Period of the EMA smoothing the values.",
+        period: usize => "Period of the ROC of the EMA."
+    );
+    SortinoRatio, (
+        period: usize => "Size of the period / window used.",
+        target: Num => "Minimum acceptable return per period.",
+        annualization: Num => "Periods per year, such as 252 for daily data.",
+        is_prices: bool => "Data is prices rather than returns."
+    );
+    StandardDeviation, (
+        period: usize => "Size of the period / window used.",
+        is_sample: bool => "If the data is a Sample or Population."
+    );
+    Variance, (
+        period: usize => "Size of the period / window used.",
+        is_sample: bool => "If the data is a Sample or Population."
+    );
+    WeightedMovingAverage;
+);
+
+impl_candle_compute!(
+    Alligator => High | Low, (
+        jaw: (usize, usize) => "Period and shift of the jaw, (`period`, `shift`).",
+        teeth: (usize, usize) => "Period and shift of the teeth, (`period`, `shift`).",
+        lips: (usize, usize) => "Period and shift of the lips, (`period`, `shift`)."
+    ) => (Num, Num, Num) = (Num::NAN, Num::NAN, Num::NAN);
+    AtrTrailingStop => High | Low | Close, (
+        period: usize => "Size of the period / window used for the ATR.",
+        multiplier: Num => "Multiplier of the ATR, distance of the stop from the close.",
+        seed: FlipSeed => "Position the stop starts in."
+    );
+    AverageTrueRange => High | Low | Close;
+    GarmanKlassVolatility => Open | High | Low | Close, (
+        period: usize => "Size of the period / window used.",
+        annualization: Num => "Periods per year, such as 252 for daily data."
+    );
+    GatorOscillator => High | Low, (
+        jaw: (usize, usize) => "Period and shift of the jaw, (`period`, `shift`).",
+        teeth: (usize, usize) => "Period and shift of the teeth, (`period`, `shift`).",
+        lips: (usize, usize) => "Period and shift of the lips, (`period`, `shift`)."
+    ) => GatorBar = GatorBar {
+        upper: Num::NAN,
+        lower: Num::NAN,
+        is_upper_expanding: false,
+        is_lower_expanding: false,
+    };
+    KeltnerChannels => High | Low | Close, (
+        period: usize => "Size of the period / window used for the EMA and ATR.",
+        multiplier: Num => "Multiplier of the ATR, distance of the bands from the EMA."
+    ) => BandsOutput = NAN_BANDS;
+    MidPrice => High | Low;
+    OnBalanceVolume => Close | Volume;
+    ParkinsonVolatility => High | Low, (
+        period: usize => "Size of the period / window used.",
+        annualization: Num => "Periods per year, such as 252 for daily data."
+    );
+    RogersSatchellVolatility => Open | High | Low | Close, (
+        period: usize => "Size of the period / window used.",
+        annualization: Num => "Periods per year, such as 252 for daily data."
+    );
+    Squeeze => High | Low | Close, (
+        period: usize => "Size of the period / window used.",
+        distance: Num => "Distance of the Bollinger Bands (in standard deviations).",
+        multiplier: Num => "Multiplier of the ATR for the Keltner Channels."
+    ) => SqueezeOutput = SqueezeOutput {
+        is_squeezed: false,
+        fired: false,
+        momentum: Num::NAN,
+    };
+    TrueRange => High | Low | Close;
+    TypicalPrice => High | Low | Close;
+    WeightedClose => High | Low | Close;
+    YangZhangVolatility => Open | High | Low | Close, (
+        period: usize => "Size of the period / window used.",
+        annualization: Num => "Periods per year, such as 252 for daily data."
+    );
+);
+
+impl Beta {
+    /// Calculates the Beta for every pair of returns of the data, NaN until ready. Each output is
+    /// aligned with the pair that produced it.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of returns, (`asset`, `benchmark`), oldest to newest.
+    pub fn compute(period: usize, data: &[(Num, Num)]) -> Result<Vec<Num>, TAError> {
+        Ok(compute(
+            Self::with_period(period)?,
+            data.iter().copied(),
+            Warmup::Nan,
+        ))
+    }
+}
+
+impl CumulativeReturn {
+    /// Calculates the equity compounded by every return of the data.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Amount of equity values to keep.
+    /// * `base` - Starting equity, such as 1.0 or 100.0.
+    /// * `data` - Array of returns to compound, oldest to newest.
+    pub fn compute(period: usize, base: Num, data: &[Num]) -> Result<Vec<Num>, TAError> {
+        let mut indicator = Self::new(period, base, &[])?;
+        Ok(data.iter().map(|value| indicator.next(*value)).collect())
+    }
+}
+
+impl VolumeWeightedAveragePrice {
+    /// Calculates the VWAP for every candle of the data, the average since the first candle.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Array of candles to calculate, oldest to newest.
+    pub fn compute<T>(data: &[T]) -> Vec<Num>
+    where
+        T: High + Low + Close + Volume,
+    {
+        compute(Self::new(), data.iter(), Warmup::Nan)
+    }
+}
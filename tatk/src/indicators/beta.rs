@@ -0,0 +1,314 @@
+//! Beta (β), volatility of an asset relative to a benchmark.
+//!
+//! # Formula
+//!
+//! β = Cov(x, y) / Var(y)
+//!
+//! where:
+//!
+//! * `x` = returns of the asset over the period.
+//! * `y` = returns of the benchmark over the period.
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{InternalValue, Next, Period, Ready, Reset, Snapshot, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
+
+/// Method used to convert prices into returns.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ReturnKind {
+    /// Simple (percentage) return, `(x - y) / y`.
+    Simple,
+    /// Logarithmic return, `ln(x / y)`.
+    Log,
+}
+
+impl Variants for ReturnKind {
+    const ALL: &'static [Self] = &[ReturnKind::Simple, ReturnKind::Log];
+}
+
+impl ReturnKind {
+    /// Calculates the return between two prices.
+    ///
+    /// # Arguments
+    ///
+    /// * `last` - Prior price.
+    /// * `value` - Current price.
+    pub fn calculate(&self, last: Num, value: Num) -> Num {
+        match self {
+            ReturnKind::Simple => (value - last) / last,
+            ReturnKind::Log => (value / last).ln(),
+        }
+    }
+}
+
+/// Beta (β), volatility of an asset relative to a benchmark.
+///
+/// If the benchmark has no variance within the period, beta is undefined and the previous value
+/// is kept (`0.0` if there has never been a defined value). The same applies to the correlation.
+///
+/// # Formula
+///
+/// β = Cov(x, y) / Var(y)
+///
+/// where:
+///
+/// * `x` = returns of the asset over the period.
+/// * `y` = returns of the benchmark over the period.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct Beta {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Beta's current value.
+    value: Num,
+    /// Correlation between the asset and benchmark.
+    correlation: Num,
+    /// Returns for the asset.
+    asset: Buffer,
+    /// Returns for the benchmark.
+    benchmark: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<(Num, Num)>,
+}
+
+impl Beta {
+    /// Creates a new Beta with the supplied period and initial paired returns.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of returns, (`asset`, `benchmark`), to create the Beta from.
+    pub fn new(period: usize, data: &[(Num, Num)]) -> Result<Self, TAError> {
+        // Check we can calculate Beta.
+        if period < 2 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 2 to calculate beta",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "beta",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        let asset: Vec<Num> = data.iter().map(|d| d.0).collect();
+        let benchmark: Vec<Num> = data.iter().map(|d| d.1).collect();
+
+        let mut beta = Self {
+            period,
+            value: 0.0,
+            correlation: 0.0,
+            asset: Buffer::from_array(period, &asset)?,
+            benchmark: Buffer::from_array(period, &benchmark)?,
+            seed: Seed::new(data.len()),
+        };
+
+        beta.calculate();
+        Ok(beta)
+    }
+
+    /// Creates a new Beta with the supplied period, without initial data. The paired returns are
+    /// supplied with `next` instead, until ready the value is calculated from the paired returns
+    /// supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder((1.0, 1.0), period)))
+    }
+
+    /// Creates a new Beta with the supplied period and prices, converting the prices to returns.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Asset and benchmark must have the same amount of prices.
+    /// * Prices must have at least `period + 1` elements.
+    /// * All prices must be finite and greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `asset` - Array of prices for the asset.
+    /// * `benchmark` - Array of prices for the benchmark.
+    /// * `kind` - Method used to convert the prices into returns.
+    pub fn from_prices(
+        period: usize,
+        asset: &[Num],
+        benchmark: &[Num],
+        kind: ReturnKind,
+    ) -> Result<Self, TAError> {
+        if asset.len() != benchmark.len() {
+            return Err(TAError::InvalidInput(String::from(
+                "asset and benchmark must have the same amount of prices",
+            )));
+        }
+
+        // Returns of a price that is not positive are not finite.
+        for (name, prices) in [("asset", asset), ("benchmark", benchmark)] {
+            if let Some((index, price)) = prices
+                .iter()
+                .enumerate()
+                .find(|(_, p)| !(p.is_finite() && **p > 0.0))
+            {
+                return Err(TAError::InvalidInput(format!(
+                    "{} price {} at index {} must be finite and greater than 0 to calculate beta",
+                    name, price, index
+                )));
+            }
+        }
+
+        let returns: Vec<(Num, Num)> = asset
+            .windows(2)
+            .zip(benchmark.windows(2))
+            .map(|(a, b)| (kind.calculate(a[0], a[1]), kind.calculate(b[0], b[1])))
+            .collect();
+
+        Self::new(period, &returns)
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Correlation (Pearson) between the asset and benchmark returns for the period.
+    pub fn correlation(&self) -> Num {
+        self.correlation
+    }
+
+    /// Calculates the beta and correlation for the current period, keeping the prior values if
+    /// either is undefined.
+    fn calculate(&mut self) {
+        let mean_a = self.asset.mean();
+        let mean_b = self.benchmark.mean();
+
+        // Covariance and variances for the period.
+        let mut cov: Num = 0.0;
+        let mut var_a: Num = 0.0;
+        let mut var_b: Num = 0.0;
+        for (a, b) in self.asset.queue().iter().zip(self.benchmark.queue()) {
+            cov += (a - mean_a) * (b - mean_b);
+            var_a += (a - mean_a).powi(2);
+            var_b += (b - mean_b).powi(2);
+        }
+
+        if var_b != 0.0 {
+            self.value = cov / var_b;
+
+            if var_a != 0.0 {
+                self.correlation = cov / (var_a * var_b).sqrt();
+            }
+        }
+    }
+}
+
+impl Next<(Num, Num)> for Beta {
+    /// Next value for the Beta.
+    type Output = Num;
+
+    /// Supply an additional pair of returns to recalculate a new Beta.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New returns to add to the period.
+    ///     * 0 = Asset
+    ///     * 1 = Benchmark
+    fn next(&mut self, value: (Num, Num)) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        // Rotate the buffers.
+        self.asset.shift(value.0);
+        self.benchmark.shift(value.1);
+
+        self.calculate();
+        self.value
+    }
+}
+
+impl Reset for Beta {
+    /// Clears the Beta back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.correlation = 0.0;
+        self.asset.clear();
+        self.benchmark.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for Beta {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for Beta {
+    type Input = (Num, Num);
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for Beta {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Beta")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.correlation)
+            .child(&self.asset)
+            .child(&self.benchmark)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Beta")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            correlation: reader.scalar()?,
+            asset: reader.child()?,
+            benchmark: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
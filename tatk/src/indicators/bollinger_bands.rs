@@ -11,10 +11,34 @@
 //! * `SMA` is the moving average of a period.
 //! * `σ` is the standard deviation of the period.
 //! * `d` is the distance from the SMA to calculate.
+//!
+//! Bandwidth = (BBands_upper - BBands_lower) / SMA
+//!
+//! %B = (price - BBands_lower) / (BBands_upper - BBands_lower)
+//!
+//! The recent bands are held in `Buffer`s by default, any `Window` can be used instead such as a
+//! `StaticBuffer` to avoid heap allocations.
 
 use super::SimpleMovingAverage;
-use crate::traits::{InternalValue, Next, Period, Stats};
-use crate::{Num, TAError};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    AsValue, InternalValue, Next, Period, Ready, Reset, SignalSource, Snapshot, Stats, Value,
+    Window,
+};
+use crate::{Buffer, IndicatorState, Num, Signal, TAError};
+
+/// Values of Bollinger Bands for a single value supplied.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BandsOutput {
+    /// Lower band, the line minus the distance in standard deviations.
+    pub lower: Num,
+    /// Middle line, the moving average.
+    pub middle: Num,
+    /// Upper band, the line plus the distance in standard deviations.
+    pub upper: Num,
+}
 
 /// Bollinger Bands (BBands). More recent data is weighted heavier than older data.
 ///
@@ -29,8 +53,15 @@ use crate::{Num, TAError};
 /// * `SMA` is the moving average of a period.
 /// * `σ` is the standard deviation of the period.
 /// * `d` is the distance from the SMA to calculate.
-#[derive(Debug)]
-pub struct BollingerBands<L>
+///
+/// Bandwidth = (BBands_upper - BBands_lower) / SMA
+///
+/// %B = (price - BBands_lower) / (BBands_upper - BBands_lower)
+///
+/// The recent bands are held in `Buffer`s by default, any `Window` can be used instead such as a
+/// `StaticBuffer` to avoid heap allocations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BollingerBands<L, W = Buffer>
 where
     L: InternalValue + Period + Stats,
 {
@@ -44,6 +75,14 @@ where
     lower: Num,
     /// Upper bound for the Bollinger Bands.
     upper: Num,
+    /// Last value supplied, compared against the bands for a signal.
+    last: Option<Num>,
+    /// Holds `period` amount of lower bands.
+    lowers: W,
+    /// Holds `period` amount of upper bands.
+    uppers: W,
+    /// Holds `period` amount of bandwidths.
+    bandwidths: W,
 }
 
 impl BollingerBands<SimpleMovingAverage> {
@@ -61,23 +100,57 @@ impl BollingerBands<SimpleMovingAverage> {
     /// * `distance` - Distance the bands (in standard deviations) from the SMA. default 2.0
     pub fn new(period: usize, data: &[Num], distance: Num) -> Result<Self, TAError> {
         // SMA used for the Bollinger Band.
-        let sma = match SimpleMovingAverage::new(period, data) {
-            Ok(v) => v,
-            Err(error) => return Err(error),
-        };
+        let sma = SimpleMovingAverage::new(period, data)?;
 
-        let distance = distance.abs();
-        let stdev = sma.stdev(true);
-        let lower = sma.value() - (stdev * distance);
-        let upper = sma.value() + (stdev * distance);
+        let mut bbands = Self::with_line(sma, distance)?;
+        bbands.last = data.last().copied();
+        Ok(bbands)
+    }
 
-        Ok(Self {
-            period,
-            line: sma,
-            distance,
-            lower,
-            upper,
-        })
+    /// Creates a new Bollinger Band with the supplied period and distance, without initial data.
+    /// The values are supplied with `next` instead, the bands are unknown (NaN) until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `distance` - Distance the bands (in standard deviations) from the SMA. default 2.0
+    pub fn with_period(period: usize, distance: Num) -> Result<Self, TAError> {
+        Self::with_line(SimpleMovingAverage::with_period(period)?, distance)
+    }
+
+    /// Creates a builder for Bollinger Bands, defaulting to a period of 20 with the bands a
+    /// distance of 2.0 standard deviations from the SMA.
+    pub fn builder() -> BollingerBandsBuilder {
+        BollingerBandsBuilder::default()
+    }
+}
+
+impl<W> BollingerBands<SimpleMovingAverage<W>, W>
+where
+    W: Window,
+{
+    /// Creates a new Bollinger Band with the supplied period and initial data, the SMA and recent
+    /// bands held in the window `W`.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    /// * Period must be a capacity the window supports, `N` for a `StaticBuffer<N>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the BBands from.
+    /// * `distance` - Distance the bands (in standard deviations) from the SMA. default 2.0
+    pub fn with_window(period: usize, data: &[Num], distance: Num) -> Result<Self, TAError> {
+        let mut bbands = Self::create(SimpleMovingAverage::with_window(period, data)?, distance)?;
+        bbands.last = data.last().copied();
+        Ok(bbands)
     }
 }
 
@@ -85,24 +158,46 @@ impl<L> BollingerBands<L>
 where
     L: InternalValue + Period + Stats,
 {
-    /// Creates Bollinger Bands using an alternative line, such as an EMA.
+    /// Creates Bollinger Bands using an alternative line, such as an EMA. There is no last
+    /// value until a value is supplied with `next`.
     ///
     /// # Arguments
     ///
     /// * `line` - `Line` to use as the middle value.
     /// * `distance` - Distance the bands (in standard deviations) from the SMA. default 2.0
     pub fn with_line(line: L, distance: Num) -> Result<BollingerBands<L>, TAError> {
+        Self::create(line, distance)
+    }
+}
+
+impl<L, W> BollingerBands<L, W>
+where
+    L: InternalValue + Period + Stats,
+    W: Window,
+{
+    /// Creates Bollinger Bands around the line, holding the recent bands in the window `W`.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - `Line` to use as the middle value.
+    /// * `distance` - Distance the bands (in standard deviations) from the line.
+    fn create(line: L, distance: Num) -> Result<Self, TAError> {
         let distance = distance.abs();
         let stdev = line.stdev(true);
         let lower = line.internal_value() - (stdev * distance);
         let upper = line.internal_value() + (stdev * distance);
+        let bandwidth = Self::calculate_bandwidth(lower, line.internal_value(), upper);
 
         Ok(Self {
             period: line.period(),
+            lowers: W::from_array(line.period(), &[lower])?,
+            uppers: W::from_array(line.period(), &[upper])?,
+            bandwidths: W::from_array(line.period(), &[bandwidth])?,
             line,
             distance,
             lower,
             upper,
+            last: None,
         })
     }
 
@@ -125,11 +220,81 @@ where
     pub fn upper(&self) -> Num {
         self.upper
     }
+
+    /// Last value supplied, `None` if a value has not been supplied.
+    pub fn last(&self) -> Option<Num> {
+        self.last
+    }
+
+    /// Recent lower bands, up to `period` of them from Oldest -> Newest.
+    pub fn lower_history(&self) -> &[Num] {
+        self.lowers.queue()
+    }
+
+    /// Recent upper bands, up to `period` of them from Oldest -> Newest.
+    pub fn upper_history(&self) -> &[Num] {
+        self.uppers.queue()
+    }
+
+    /// Recent bandwidths, up to `period` of them from Oldest -> Newest.
+    pub fn bandwidth_history(&self) -> &[Num] {
+        self.bandwidths.queue()
+    }
+
+    /// Width of the bands relative to the middle line, (upper - lower) / middle. 0 if the middle
+    /// line is 0.
+    pub fn bandwidth(&self) -> Num {
+        Self::calculate_bandwidth(self.lower, self.value(), self.upper)
+    }
+
+    /// Position of a price relative to the bands, 0 at the lower band and 1 at the upper band.
+    /// Prices outside of the bands are below 0 or above 1. 0.5 if the bands are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - Price to compare to the bands, often the last value supplied.
+    pub fn percent_b(&self, price: Num) -> Num {
+        let width = self.upper - self.lower;
+        if width == 0.0 {
+            return 0.5;
+        }
+
+        (price - self.lower) / width
+    }
+
+    /// Checks if the bands are in a squeeze, the current bandwidth being the lowest of the last
+    /// `lookback` bandwidths. The lookback is limited to the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `lookback` - Amount of recent bandwidths to compare to, including the current.
+    pub fn is_squeeze(&self, lookback: usize) -> bool {
+        let bandwidths = self.bandwidths.queue();
+        let recent = &bandwidths[bandwidths.len().saturating_sub(lookback)..];
+        let lowest = recent.iter().copied().fold(Num::INFINITY, Num::min);
+        !recent.is_empty() && self.bandwidth() <= lowest
+    }
+
+    /// Calculates the bandwidth, 0 if the middle line is 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `lower` - Lower band.
+    /// * `middle` - Middle line.
+    /// * `upper` - Upper band.
+    fn calculate_bandwidth(lower: Num, middle: Num, upper: Num) -> Num {
+        if middle == 0.0 {
+            return 0.0;
+        }
+
+        (upper - lower) / middle
+    }
 }
 
-impl<L> Period for BollingerBands<L>
+impl<L, W> Period for BollingerBands<L, W>
 where
     L: InternalValue + Period + Stats,
+    W: Window,
 {
     /// Period (window) for the samples.
     fn period(&self) -> usize {
@@ -137,26 +302,248 @@ where
     }
 }
 
-impl<L> Next<Num> for BollingerBands<L>
+impl<L, W> InternalValue for BollingerBands<L, W>
+where
+    L: InternalValue + Period + Stats,
+    W: Window,
+{
+    /// Current value of the middle line.
+    fn internal_value(&self) -> Num {
+        self.value()
+    }
+}
+
+impl<L, W> Value for BollingerBands<L, W>
+where
+    L: InternalValue + Period + Stats,
+    W: Window,
+{
+    /// Current value of the middle line.
+    fn value(&self) -> Num {
+        self.line.internal_value()
+    }
+}
+
+impl<L, W> Next<Num> for BollingerBands<L, W>
 where
     L: InternalValue + Period + Stats + Next<Num>,
+    W: Window,
 {
-    /// Lower, Signal, Upper,
-    type Output = (Num, <L as Next<Num>>::Output, Num);
+    /// Lower, Middle, and Upper values.
+    type Output = BandsOutput;
 
-    /// Supply an additional value to recalculate a new Bollinger Band, this returns the current
-    /// SMA. Obtain the Upper and Lower bounds with `.upper()` and `.lower()`.
+    /// Supply an additional value to recalculate a new Bollinger Band.
     ///
     /// # Arguments
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
         // Progress the SMA by a value.
-        let value = self.line.next(value);
+        self.last = Some(value);
+        self.line.next(value);
 
         let stdev = self.line.stdev(true);
         self.lower = self.value() - (stdev * self.distance());
         self.upper = self.value() + (stdev * self.distance());
-        (self.lower, value, self.upper)
+        self.lowers.shift(self.lower);
+        self.uppers.shift(self.upper);
+        self.bandwidths.shift(self.bandwidth());
+        BandsOutput {
+            lower: self.lower,
+            middle: self.value(),
+            upper: self.upper,
+        }
+    }
+}
+
+impl<L, W, T> Next<T> for BollingerBands<L, W>
+where
+    L: InternalValue + Period + Stats + Next<Num>,
+    W: Window,
+    T: AsValue,
+{
+    /// Lower, Middle, and Upper values.
+    type Output = BandsOutput;
+
+    /// Supply an additional value to recalculate a new Bollinger Band.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl<L, W> Stats for BollingerBands<L, W>
+where
+    L: InternalValue + Period + Stats,
+    W: Window,
+{
+    /// Obtains the total sum of the buffer for the middle line.
+    fn sum(&self) -> Num {
+        self.line.sum()
+    }
+
+    /// Mean for the period of the middle line.
+    fn mean(&self) -> Num {
+        self.line.mean()
+    }
+
+    /// Current variance for the period of the middle line.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn variance(&self, is_sample: bool) -> Num {
+        self.line.variance(is_sample)
+    }
+
+    /// Current standard deviation for the period of the middle line.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn stdev(&self, is_sample: bool) -> Num {
+        self.line.stdev(is_sample)
+    }
+}
+
+impl<L, W> SignalSource for BollingerBands<L, W>
+where
+    L: InternalValue + Period + Stats,
+    W: Window,
+{
+    /// Buy when the last value is below the lower band and sell when it is above the upper band,
+    /// holding while exactly at or between the bands or before a value is supplied.
+    fn signal(&self) -> Signal {
+        match self.last {
+            Some(last) if last < self.lower => Signal::Buy,
+            Some(last) if last > self.upper => Signal::Sell,
+            _ => Signal::Hold,
+        }
+    }
+}
+
+impl<L, W> Reset for BollingerBands<L, W>
+where
+    L: InternalValue + Period + Stats + Reset,
+    W: Window,
+{
+    /// Clears the Bollinger Bands back to having seen no data. The bands are unknown (NaN) until
+    /// the line has been recalculated.
+    fn reset(&mut self) {
+        self.line.reset();
+        self.lower = 0.0;
+        self.upper = 0.0;
+        self.last = None;
+        self.lowers.clear();
+        self.uppers.clear();
+        self.bandwidths.clear();
+    }
+}
+
+impl<L, W> Ready for BollingerBands<L, W>
+where
+    L: InternalValue + Period + Stats + Ready,
+    W: Window,
+{
+    /// Checks if enough values have been supplied for the line and bands to be complete.
+    fn is_ready(&self) -> bool {
+        self.line.is_ready()
+    }
+
+    /// Amount of values supplied to the line since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.line.samples_seen()
+    }
+}
+
+/// Builder for Bollinger Bands, defaulting to a period of 20 with the bands a distance of 2.0
+/// standard deviations from the SMA.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BollingerBandsBuilder {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Distance the Standard Deviation must be for the lower and upper bands.
+    distance: Num,
+}
+
+impl Default for BollingerBandsBuilder {
+    /// Common period (20) and distance (2.0).
+    fn default() -> Self {
+        Self {
+            period: 20,
+            distance: 2.0,
+        }
+    }
+}
+
+impl BollingerBandsBuilder {
+    /// Changes the period from the default (20).
+    pub fn period(mut self, period: usize) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Changes the distance of the bands, in standard deviations, from the default (2.0).
+    pub fn distance(mut self, distance: Num) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    /// Creates the Bollinger Bands from the initial data, validated the same as `new`.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Distance must be a number.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - Array of values to create the BBands from.
+    pub fn build(self, data: &[Num]) -> Result<BollingerBands<SimpleMovingAverage>, TAError> {
+        if self.distance.is_nan() {
+            return Err(TAError::InvalidSize(String::from(
+                "distance must be a number to calculate bollinger bands",
+            )));
+        }
+
+        BollingerBands::new(self.period, data, self.distance)
+    }
+}
+
+impl<L, W> Snapshot for BollingerBands<L, W>
+where
+    L: InternalValue + Period + Stats + Snapshot,
+    W: Window + Snapshot,
+{
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("BBands")
+            .count(self.period)
+            .scalar(self.distance)
+            .scalar(self.lower)
+            .scalar(self.upper)
+            .optional(self.last)
+            .child(&self.line)
+            .child(&self.lowers)
+            .child(&self.uppers)
+            .child(&self.bandwidths)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "BBands")?;
+        Ok(Self {
+            period: reader.count()?,
+            distance: reader.scalar()?,
+            lower: reader.scalar()?,
+            upper: reader.scalar()?,
+            last: reader.optional()?,
+            line: reader.child()?,
+            lowers: reader.child()?,
+            uppers: reader.child()?,
+            bandwidths: reader.child()?,
+        })
     }
 }
@@ -0,0 +1,364 @@
+//! Candle Pattern, classifies the shape of single candles.
+//!
+//! Compares the body and wicks of a candle to its range. A candle can match several patterns, a
+//! candle without a range is only a doji.
+//!
+//! # Patterns
+//!
+//! * Doji: body at most `doji_body` of the range.
+//! * Hammer: small body with a long lower wick and a short upper wick.
+//! * Inverted Hammer: small bullish body with a long upper wick and a short lower wick.
+//! * Shooting Star: small bearish body with a long upper wick and a short lower wick.
+//! * Spinning Top: small body with both wicks longer than the body.
+//! * Marubozu: body with both wicks at most `marubozu_wick` of the range.
+//!
+//! where:
+//!
+//! * Body = |c - o|
+//! * Range = h - l
+//! * Upper Wick = h - max(o, c)
+//! * Lower Wick = min(o, c) - l
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{Close, High, Low, Next, Open, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+
+/// Used for conversions. Holds Open (0), High (1), Low (2), and Close (3) values.
+#[derive(Copy, Clone, PartialEq)]
+struct Data(Num, Num, Num, Num);
+
+// Opening value.
+impl Open for Data {
+    fn open(&self) -> Num {
+        self.0
+    }
+}
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.1
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.2
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.3
+    }
+}
+
+/// Pattern matched by one or more candles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternKind {
+    /// Open and close are nearly equal.
+    Doji,
+    /// Long lower wick below a small body.
+    Hammer,
+    /// Long upper wick above a small bullish body.
+    InvertedHammer,
+    /// Long upper wick above a small bearish body.
+    ShootingStar,
+    /// Small body between two longer wicks.
+    SpinningTop,
+    /// Body with little to no wicks.
+    Marubozu,
+    /// Bullish body engulfing the prior bearish body.
+    BullishEngulfing,
+    /// Bearish body engulfing the prior bullish body.
+    BearishEngulfing,
+    /// Small bullish body within the prior bearish body.
+    BullishHarami,
+    /// Small bearish body within the prior bullish body.
+    BearishHarami,
+    /// Doji within the prior body.
+    HaramiCross,
+    /// Opens below the prior bearish close and closes above the middle of its body.
+    PiercingLine,
+    /// Opens above the prior bullish close and closes below the middle of its body.
+    DarkCloudCover,
+}
+
+impl Variants for PatternKind {
+    const ALL: &'static [Self] = &[
+        PatternKind::Doji,
+        PatternKind::Hammer,
+        PatternKind::InvertedHammer,
+        PatternKind::ShootingStar,
+        PatternKind::SpinningTop,
+        PatternKind::Marubozu,
+        PatternKind::BullishEngulfing,
+        PatternKind::BearishEngulfing,
+        PatternKind::BullishHarami,
+        PatternKind::BearishHarami,
+        PatternKind::HaramiCross,
+        PatternKind::PiercingLine,
+        PatternKind::DarkCloudCover,
+    ];
+}
+
+/// Ratios used to classify the candles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PatternConfig {
+    /// Largest body, as a fraction of the range, to be a doji.
+    pub doji_body: Num,
+    /// Largest body, as a fraction of the range, for a hammer, star, or spinning top.
+    pub small_body: Num,
+    /// Smallest long wick, as a multiple of the body, for a hammer or star.
+    pub long_wick: Num,
+    /// Largest short wick, as a fraction of the range, for a hammer or star.
+    pub short_wick: Num,
+    /// Largest wicks, as a fraction of the range, to be a marubozu.
+    pub marubozu_wick: Num,
+}
+
+impl Default for PatternConfig {
+    /// Commonly used ratios.
+    fn default() -> Self {
+        Self {
+            doji_body: 0.1,
+            small_body: 0.3,
+            long_wick: 2.0,
+            short_wick: 0.1,
+            marubozu_wick: 0.05,
+        }
+    }
+}
+
+impl PatternConfig {
+    /// Checks that the fractions are between 0 and 1 and the long wick multiple is greater than 0.
+    pub fn validate(&self) -> Result<(), TAError> {
+        let fractions = [
+            self.doji_body,
+            self.small_body,
+            self.short_wick,
+            self.marubozu_wick,
+        ];
+
+        if fractions.iter().any(|f| !(0.0..=1.0).contains(f)) {
+            return Err(TAError::InvalidSize(String::from(
+                "ratios of the range must be between 0 and 1 to classify candles",
+            )));
+        } else if self.long_wick.is_nan() || self.long_wick <= 0.0 {
+            return Err(TAError::InvalidSize(String::from(
+                "long wick must be greater than 0 to classify candles",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Candle Pattern, classifies the shape of single candles.
+///
+/// Compares the body and wicks of a candle to its range. A candle can match several patterns, a
+/// candle without a range is only a doji.
+///
+/// # Patterns
+///
+/// * Doji: body at most `doji_body` of the range.
+/// * Hammer: small body with a long lower wick and a short upper wick.
+/// * Inverted Hammer: small bullish body with a long upper wick and a short lower wick.
+/// * Shooting Star: small bearish body with a long upper wick and a short lower wick.
+/// * Spinning Top: small body with both wicks longer than the body.
+/// * Marubozu: body with both wicks at most `marubozu_wick` of the range.
+///
+/// where:
+///
+/// * Body = |c - o|
+/// * Range = h - l
+/// * Upper Wick = h - max(o, c)
+/// * Lower Wick = min(o, c) - l
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandlePattern {
+    /// Ratios used to classify the candles.
+    config: PatternConfig,
+    /// Patterns matched by the last candle.
+    patterns: Vec<PatternKind>,
+}
+
+impl CandlePattern {
+    /// Creates a new Candle Pattern with the supplied ratios.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Fractions of the range must be between 0 and 1.
+    /// * Long wick multiple must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `config` - Ratios used to classify the candles.
+    pub fn new(config: PatternConfig) -> Result<Self, TAError> {
+        config.validate()?;
+        Ok(Self {
+            config,
+            patterns: vec![],
+        })
+    }
+
+    /// Classifies the shape of a single candle.
+    ///
+    /// # Arguments
+    ///
+    /// * `candle` - Candle to classify.
+    /// * `config` - Ratios used to classify the candle.
+    pub fn classify<T>(candle: &T, config: &PatternConfig) -> Vec<PatternKind>
+    where
+        T: Open + High + Low + Close,
+    {
+        let (open, close) = (candle.open(), candle.close());
+        let range = candle.high() - candle.low();
+        if range <= 0.0 {
+            // No range to compare against, open and close are equal.
+            return vec![PatternKind::Doji];
+        }
+
+        let body = (close - open).abs();
+        let upper = candle.high() - open.max(close);
+        let lower = open.min(close) - candle.low();
+
+        let mut patterns: Vec<PatternKind> = vec![];
+        let is_doji = body <= config.doji_body * range;
+        if is_doji {
+            patterns.push(PatternKind::Doji);
+        }
+
+        if body <= config.small_body * range {
+            if lower >= config.long_wick * body && upper <= config.short_wick * range {
+                patterns.push(PatternKind::Hammer);
+            } else if upper >= config.long_wick * body && lower <= config.short_wick * range {
+                if close >= open {
+                    patterns.push(PatternKind::InvertedHammer);
+                } else {
+                    patterns.push(PatternKind::ShootingStar);
+                }
+            } else if !is_doji && upper > body && lower > body {
+                patterns.push(PatternKind::SpinningTop);
+            }
+        }
+
+        if !is_doji
+            && upper <= config.marubozu_wick * range
+            && lower <= config.marubozu_wick * range
+        {
+            patterns.push(PatternKind::Marubozu);
+        }
+
+        patterns
+    }
+
+    /// Ratios used to classify the candles.
+    pub fn config(&self) -> &PatternConfig {
+        &self.config
+    }
+
+    /// Patterns matched by the last candle.
+    pub fn patterns(&self) -> &[PatternKind] {
+        &self.patterns
+    }
+
+    /// Checks if the last candle matched the pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Pattern to check for.
+    pub fn is(&self, kind: PatternKind) -> bool {
+        self.patterns.contains(&kind)
+    }
+}
+
+impl<T> Next<&T> for CandlePattern
+where
+    T: Open + High + Low + Close,
+{
+    /// Patterns matched by the candle.
+    type Output = Vec<PatternKind>;
+
+    /// Supply an additional candle to classify.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to classify.
+    fn next(&mut self, value: &T) -> Self::Output {
+        self.patterns = Self::classify(value, &self.config);
+        self.patterns.clone()
+    }
+}
+
+impl Next<(Num, Num, Num, Num)> for CandlePattern {
+    /// Patterns matched by the candle.
+    type Output = Vec<PatternKind>;
+
+    /// Supply an additional value to classify.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to classify.
+    ///     * 0 = Open
+    ///     * 1 = High
+    ///     * 2 = Low
+    ///     * 3 = Close
+    fn next(&mut self, value: (Num, Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2, value.3))
+    }
+}
+
+impl Reset for CandlePattern {
+    /// Clears the patterns found.
+    fn reset(&mut self) {
+        self.patterns.clear();
+    }
+}
+
+impl Snapshot for PatternConfig {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Pattern Config")
+            .scalar(self.doji_body)
+            .scalar(self.small_body)
+            .scalar(self.long_wick)
+            .scalar(self.short_wick)
+            .scalar(self.marubozu_wick)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Pattern Config")?;
+        Ok(Self {
+            doji_body: reader.scalar()?,
+            small_body: reader.scalar()?,
+            long_wick: reader.scalar()?,
+            short_wick: reader.scalar()?,
+            marubozu_wick: reader.scalar()?,
+        })
+    }
+}
+
+impl Snapshot for CandlePattern {
+    fn snapshot(&self) -> IndicatorState {
+        let state = IndicatorState::new("Candle Pattern")
+            .count(self.patterns.len())
+            .child(&self.config);
+        self.patterns
+            .iter()
+            .fold(state, |state, pattern| state.variant(*pattern))
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Candle Pattern")?;
+        let len = reader.count()?;
+        Ok(Self {
+            config: reader.child()?,
+            patterns: (0..len)
+                .map(|_| reader.variant())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
@@ -0,0 +1,209 @@
+//! Chain, the output of one indicator supplied as the input of another.
+//!
+//! Outputs of the first stage are only supplied to the second stage once the first stage is
+//! ready, such as an EMA of the RSI starting from the first complete RSI.
+
+use crate::snapshot::StateReader;
+use crate::traits::{InternalValue, Next, Ready, Reset, Snapshot, Value};
+use crate::{IndicatorState, Num, TAError};
+
+/// Chain, the output of one indicator supplied as the input of another.
+///
+/// Outputs of the `first` stage are only supplied to the `second` stage once the `first` stage is
+/// ready. The value of the chain is the value of the `second` stage, chains can be chained again
+/// to add more stages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chain<A, B> {
+    /// Stage supplied with the data.
+    first: A,
+    /// Stage supplied with the outputs of the first stage.
+    second: B,
+}
+
+impl<A, B> Chain<A, B>
+where
+    A: Ready,
+    B: Next<Num> + InternalValue + Ready,
+{
+    /// Creates a new Chain from the stages supplied and initial data. The data is supplied to the
+    /// `first` stage and its outputs to the `second` stage, stages are best created without data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Data must be enough for both stages to be ready.
+    ///
+    /// ## Arguments
+    ///
+    /// * `first` - Stage supplied with the data.
+    /// * `second` - Stage supplied with the outputs of the `first` stage.
+    /// * `data` - Values or candles to create the Chain from, oldest to newest.
+    pub fn new<T>(first: A, second: B, data: impl IntoIterator<Item = T>) -> Result<Self, TAError>
+    where
+        A: Next<T, Output = Num>,
+        T: Clone,
+    {
+        let mut chain = Self::with_stages(first, second);
+        let mut last: Option<T> = None;
+        let mut received: usize = 0;
+        for value in data {
+            chain.next(value.clone());
+            last = Some(value);
+            received += 1;
+        }
+
+        if chain.is_ready() {
+            return Ok(chain);
+        }
+
+        // Repeat the last value to find how many values the stages required.
+        let mut required = received + 1;
+        if let Some(last) = last {
+            chain.next(last.clone());
+            while !chain.is_ready() {
+                chain.next(last.clone());
+                required += 1;
+            }
+        }
+
+        Err(TAError::InvalidData {
+            indicator: "chain",
+            required,
+            received,
+        })
+    }
+
+    /// Creates a new Chain from the stages supplied, without initial data. The values are
+    /// supplied with `next` instead, the value is that of the `second` stage until ready.
+    ///
+    /// ## Arguments
+    ///
+    /// * `first` - Stage supplied with the data.
+    /// * `second` - Stage supplied with the outputs of the `first` stage.
+    pub fn with_stages(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Current and most recent value calculated, the value of the `second` stage.
+    pub fn value(&self) -> Num {
+        self.second.internal_value()
+    }
+
+    /// Stage supplied with the data.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Stage supplied with the outputs of the `first` stage.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A, B, T> Next<T> for Chain<A, B>
+where
+    A: Next<T, Output = Num> + Ready,
+    B: Next<Num> + InternalValue + Ready,
+{
+    /// Value of the `second` stage.
+    type Output = Num;
+
+    /// Supply an additional value to the `first` stage, its output is supplied to the `second`
+    /// stage once the `first` stage is ready.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        let output = self.first.next(value);
+        if self.first.is_ready() {
+            self.second.next(output);
+        }
+
+        self.value()
+    }
+}
+
+impl<A, B> InternalValue for Chain<A, B>
+where
+    B: InternalValue,
+{
+    fn internal_value(&self) -> Num {
+        self.second.internal_value()
+    }
+}
+
+impl<A, B> Value for Chain<A, B>
+where
+    B: InternalValue,
+{
+    /// Current and most recent value calculated, the value of the `second` stage.
+    fn value(&self) -> Num {
+        self.second.internal_value()
+    }
+}
+
+impl<A, B> Reset for Chain<A, B>
+where
+    A: Reset,
+    B: Reset,
+{
+    /// Clears both stages back to having seen no data.
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+impl<A, B> Ready for Chain<A, B>
+where
+    A: Ready,
+    B: Ready,
+{
+    /// Checks if both stages are ready.
+    fn is_ready(&self) -> bool {
+        self.first.is_ready() && self.second.is_ready()
+    }
+
+    /// Amount of values supplied to the `first` stage.
+    fn samples_seen(&self) -> usize {
+        self.first.samples_seen()
+    }
+}
+
+/// Chains indicators together, `rsi.then(ema)` being an EMA of the RSI.
+pub trait Pipeline: Ready + Sized {
+    /// Supplies the outputs of this indicator to another once ready.
+    ///
+    /// # Arguments
+    ///
+    /// * `next` - Stage supplied with the outputs of this indicator.
+    fn then<B>(self, next: B) -> Chain<Self, B>
+    where
+        B: Next<Num> + InternalValue + Ready,
+    {
+        Chain::with_stages(self, next)
+    }
+}
+
+impl<A> Pipeline for A where A: Ready {}
+
+impl<A, B> Snapshot for Chain<A, B>
+where
+    A: Snapshot,
+    B: Snapshot,
+{
+    /// State of both stages.
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Chain")
+            .child(&self.first)
+            .child(&self.second)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Chain")?;
+        Ok(Self {
+            first: reader.child()?,
+            second: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,213 @@
+//! Indicator Config, the settings of an indicator kept apart from the indicator itself.
+//!
+//! Configs are plain values that can be stored and compared, written as text such as
+//! `rsi(14, 20, 80)` and parsed back, and built into the indicator they describe.
+
+use core::fmt;
+use core::str::FromStr;
+
+use super::{
+    BollingerBandsBuilder, ExponentialMovingAverage, MacdBuilder, RsiBuilder, SimpleMovingAverage,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::traits::Indicator;
+use crate::{Num, TAError};
+
+/// Settings of an indicator, built into the indicator with `build`.
+///
+/// Written as text in the form `name(settings, ...)`, the settings in the order of the fields:
+///
+/// * `sma(20)`
+/// * `ema(20)`
+/// * `rsi(14, 20, 80)`
+/// * `macd(12, 26, 9)`
+/// * `bbands(20, 2)`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndicatorConfig {
+    /// Simple Moving Average (SMA).
+    Sma {
+        /// Size of the period (window) in which data is looked at.
+        period: usize,
+    },
+    /// Exponential Moving Average (EMA).
+    Ema {
+        /// Size of the period (window) in which data is looked at.
+        period: usize,
+    },
+    /// Relative Strength Index (RSI).
+    Rsi {
+        /// Size of the period (window) in which data is looked at.
+        period: usize,
+        /// Oversold threshold.
+        oversold: Num,
+        /// Overbought threshold.
+        overbought: Num,
+    },
+    /// Moving Average Convergence and Divergence (MACD).
+    Macd {
+        /// Period of the short line.
+        short: usize,
+        /// Period of the long line.
+        long: usize,
+        /// Period of the signal line.
+        signal: usize,
+    },
+    /// Bollinger Bands (BBands).
+    BBands {
+        /// Size of the period (window) in which data is looked at.
+        period: usize,
+        /// Distance the Standard Deviation must be for the lower and upper bands.
+        distance: Num,
+    },
+}
+
+impl IndicatorConfig {
+    /// Name of the config as written in text.
+    pub fn name(&self) -> &'static str {
+        match self {
+            IndicatorConfig::Sma { .. } => "sma",
+            IndicatorConfig::Ema { .. } => "ema",
+            IndicatorConfig::Rsi { .. } => "rsi",
+            IndicatorConfig::Macd { .. } => "macd",
+            IndicatorConfig::BBands { .. } => "bbands",
+        }
+    }
+
+    /// Creates the indicator described from the initial data, validated the same as its `new`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - Array of values to create the indicator from.
+    pub fn build(&self, data: &[Num]) -> Result<Box<dyn Indicator>, TAError> {
+        Ok(match *self {
+            IndicatorConfig::Sma { period } => Box::new(SimpleMovingAverage::new(period, data)?),
+            IndicatorConfig::Ema { period } => {
+                Box::new(ExponentialMovingAverage::new(period, data)?)
+            }
+            IndicatorConfig::Rsi {
+                period,
+                oversold,
+                overbought,
+            } => Box::new(
+                RsiBuilder::default()
+                    .period(period)
+                    .oversold(oversold)
+                    .overbought(overbought)
+                    .build(data)?,
+            ),
+            IndicatorConfig::Macd {
+                short,
+                long,
+                signal,
+            } => Box::new(
+                MacdBuilder::default()
+                    .short(short)
+                    .long(long)
+                    .signal(signal)
+                    .build(data)?,
+            ),
+            IndicatorConfig::BBands { period, distance } => Box::new(
+                BollingerBandsBuilder::default()
+                    .period(period)
+                    .distance(distance)
+                    .build(data)?,
+            ),
+        })
+    }
+}
+
+impl fmt::Display for IndicatorConfig {
+    /// Writes the config as text, such as `rsi(14, 20, 80)`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}(", self.name())?;
+        match self {
+            IndicatorConfig::Sma { period } | IndicatorConfig::Ema { period } => {
+                write!(f, "{}", period)?
+            }
+            IndicatorConfig::Rsi {
+                period,
+                oversold,
+                overbought,
+            } => write!(f, "{}, {}, {}", period, oversold, overbought)?,
+            IndicatorConfig::Macd {
+                short,
+                long,
+                signal,
+            } => write!(f, "{}, {}, {}", short, long, signal)?,
+            IndicatorConfig::BBands { period, distance } => write!(f, "{}, {}", period, distance)?,
+        }
+        write!(f, ")")
+    }
+}
+
+impl FromStr for IndicatorConfig {
+    type Err = TAError;
+
+    /// Parses a config written as text, such as `rsi(14, 20, 80)`. Names are not case sensitive.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - Config as text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| TAError::InvalidInput(format!("config {:?} {}", s, reason));
+
+        let (name, rest) = s
+            .trim()
+            .split_once('(')
+            .ok_or_else(|| invalid("is missing its settings"))?;
+        let settings: Vec<&str> = rest
+            .strip_suffix(')')
+            .ok_or_else(|| invalid("is missing a closing parenthesis"))?
+            .split(',')
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .collect();
+
+        let name = name.trim().to_ascii_lowercase();
+        let expected = match name.as_str() {
+            "sma" | "ema" => 1,
+            "bbands" => 2,
+            "rsi" | "macd" => 3,
+            _ => return Err(invalid("is not a known indicator")),
+        };
+
+        if settings.len() != expected {
+            return Err(invalid(&format!(
+                "requires {} settings but received {}",
+                expected,
+                settings.len()
+            )));
+        }
+
+        let count = |index: usize| {
+            settings[index]
+                .parse::<usize>()
+                .map_err(|_| invalid(&format!("setting {} is not a count", settings[index])))
+        };
+        let number = |index: usize| {
+            settings[index]
+                .parse::<Num>()
+                .map_err(|_| invalid(&format!("setting {} is not a number", settings[index])))
+        };
+
+        Ok(match name.as_str() {
+            "sma" => IndicatorConfig::Sma { period: count(0)? },
+            "ema" => IndicatorConfig::Ema { period: count(0)? },
+            "rsi" => IndicatorConfig::Rsi {
+                period: count(0)?,
+                oversold: number(1)?,
+                overbought: number(2)?,
+            },
+            "macd" => IndicatorConfig::Macd {
+                short: count(0)?,
+                long: count(1)?,
+                signal: count(2)?,
+            },
+            _ => IndicatorConfig::BBands {
+                period: count(0)?,
+                distance: number(1)?,
+            },
+        })
+    }
+}
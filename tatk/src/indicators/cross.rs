@@ -4,15 +4,42 @@
 //!
 //! Golden Cross: `short_line` (reactive) crosses above `long_line` (historic).
 
-use crate::traits::{InternalValue, Next};
-use crate::Num;
+use super::Tolerance;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{AsValue, InternalValue, Next, Ready, Reset, SignalSource, Snapshot};
+use crate::{IndicatorState, Num, Signal, TAError};
+
+/// Direction the lines crossed in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrossEvent {
+    /// Lines did not cross.
+    #[default]
+    None,
+    /// `short_line` crossed above the `long_line`.
+    Golden,
+    /// `short_line` crossed below the `long_line`.
+    Death,
+}
+
+impl Variants for CrossEvent {
+    const ALL: &'static [Self] = &[CrossEvent::None, CrossEvent::Golden, CrossEvent::Death];
+}
 
 /// Cross, used to check if lines cross.
 ///
 /// Death Cross: `short_line` (reactive) crosses below `long_line` (historic).
 ///
 /// Golden Cross: `short_line` (reactive) crosses above `long_line` (historic).
-#[derive(Debug)]
+///
+/// Lines that are exactly equal have not crossed, the `short_line` stays on the side it was
+/// last on. Touching the `long_line` then retreating is not a cross, a cross is only reported
+/// once the `short_line` is strictly on the other side.
+///
+/// Whipsaws around the crossing point can be filtered with a minimum separation, the
+/// `short_line` must be beyond the `long_line` by the separation to change sides, and with
+/// confirmation bars, the lines must stay crossed for that many values before the cross is
+/// reported. Both are off by default.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Cross<L>
 where
     L: InternalValue,
@@ -21,8 +48,22 @@ where
     short_line: L,
     // Longer line (longer period)
     long_line: L,
-    // Holds if the lines crossed or not.
-    crossed: bool,
+    // Side the short line was last strictly on, true if above. None while only equal.
+    side: Option<bool>,
+    // Side of the last reported cross, or the first side taken.
+    is_above: Option<bool>,
+    // Distance the short line must be beyond the long line to change sides.
+    separation: Tolerance,
+    // Values the lines must stay crossed for before the cross is reported.
+    confirmation: usize,
+    // Consecutive values the lines have been crossed without being reported.
+    pending: usize,
+    // Direction the lines crossed in for the most recent value.
+    event: CrossEvent,
+    // Direction of the most recent cross.
+    last_cross: CrossEvent,
+    // Values supplied since the most recent cross.
+    bars_since_cross: Option<usize>,
 }
 
 impl<L> Cross<L>
@@ -40,42 +81,269 @@ where
     /// * `short_line` - Shorter or more reactive line.
     /// * `long_line` - Longer or more historic line.
     pub fn new(short_line: L, long_line: L) -> Self {
+        let separation = Tolerance::Absolute(0.0);
+        let side = Self::side(&short_line, &long_line, separation, None);
         Self {
             short_line,
             long_line,
-            crossed: false,
+            side,
+            is_above: side,
+            separation,
+            confirmation: 1,
+            pending: 0,
+            event: CrossEvent::None,
+            last_cross: CrossEvent::None,
+            bars_since_cross: None,
         }
     }
 
+    /// Changes the distance the `short_line` must be beyond the `long_line` to change sides, the
+    /// default being 0. A percent is of the `long_line`.
+    ///
+    /// # Arguments
+    ///
+    /// * `separation` - Minimum distance between the lines, negative distances are made positive.
+    pub fn set_separation(&mut self, separation: Tolerance) {
+        self.separation = match separation {
+            Tolerance::Absolute(d) => Tolerance::Absolute(d.abs()),
+            Tolerance::Percent(p) => Tolerance::Percent(p.abs()),
+        };
+    }
+
+    /// Changes the amount of values the lines must stay crossed for before the cross is
+    /// reported, the default being 1 to report on the value that crossed. The cross is reported
+    /// on the last value confirming it.
+    ///
+    /// # Arguments
+    ///
+    /// * `bars` - Values the lines must stay crossed for, 0 is treated as 1.
+    pub fn set_confirmation(&mut self, bars: usize) {
+        self.confirmation = bars.max(1);
+    }
+
     /// Checks if the `short_line` and `long_line` crossed.
     pub fn crossed(&self) -> bool {
-        self.crossed
+        self.event != CrossEvent::None
+    }
+
+    /// Direction the lines crossed in for the most recent value, `None` if they did not cross.
+    pub fn event(&self) -> CrossEvent {
+        self.event
     }
 
     /// True if the lines have crossed and the `short_line` is above the `long_line`.
     pub fn is_golden(&self) -> bool {
-        self.crossed() && self.short_line.internal_value() > self.long_line.internal_value()
+        self.event == CrossEvent::Golden
     }
 
     /// True if the lines have crossed and the `short_line` is below the `long_line`.
     pub fn is_death(&self) -> bool {
-        self.crossed() && self.short_line.internal_value() < self.long_line.internal_value()
+        self.event == CrossEvent::Death
     }
 
-    /// Supply an additional value to recalculate a cross.
+    /// Direction of the most recent cross, `None` if the lines have not crossed.
+    pub fn last_cross(&self) -> CrossEvent {
+        self.last_cross
+    }
+
+    /// Values supplied since the most recent cross, 0 being the value that crossed. `None` if the
+    /// lines have not crossed.
+    pub fn bars_since_cross(&self) -> Option<usize> {
+        self.bars_since_cross
+    }
+
+    /// Supply an additional value to recalculate a cross, returning if the lines crossed.
     ///
     /// # Arguments
     ///
     /// * `value` - New value to add to period.
-    pub fn next(&mut self, value: Num) -> bool {
-        let was_below: bool = self.short_line.internal_value() < self.long_line.internal_value();
+    #[deprecated(note = "use `next`, it returns the direction of the cross")]
+    pub fn next_crossed(&mut self, value: Num) -> bool {
+        Next::next(self, value) != CrossEvent::None
+    }
+
+    /// Side the `short_line` is on, the prior side is kept while the lines are within the
+    /// separation of each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `short_line` - Shorter or more reactive line.
+    /// * `long_line` - Longer or more historic line.
+    /// * `separation` - Distance the `short_line` must be beyond the `long_line`.
+    /// * `prior` - Side the `short_line` was last on.
+    fn side(
+        short_line: &L,
+        long_line: &L,
+        separation: Tolerance,
+        prior: Option<bool>,
+    ) -> Option<bool> {
+        let (short, long) = (short_line.internal_value(), long_line.internal_value());
+        let distance = separation.distance(long);
+        if short > long + distance {
+            Some(true)
+        } else if short < long - distance {
+            Some(false)
+        } else {
+            prior
+        }
+    }
+}
 
+impl<L> Next<Num> for Cross<L>
+where
+    L: InternalValue + Next<Num>,
+{
+    /// Direction the lines crossed in.
+    type Output = CrossEvent;
+
+    /// Supply an additional value to recalculate a cross.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
         // Progress both lines.
         self.short_line.next(value);
         self.long_line.next(value);
-        let current: bool = self.short_line.internal_value() < self.long_line.internal_value();
 
-        self.crossed = was_below != current;
-        self.crossed()
+        self.side = Self::side(
+            &self.short_line,
+            &self.long_line,
+            self.separation,
+            self.side,
+        );
+
+        // Count the values crossed until confirmed, the first side taken is not a cross.
+        self.event = CrossEvent::None;
+        match (self.is_above, self.side) {
+            (None, side) => self.is_above = side,
+            (Some(was_above), Some(is_above)) if was_above != is_above => {
+                self.pending += 1;
+                if self.pending >= self.confirmation {
+                    self.is_above = self.side;
+                    self.pending = 0;
+                    self.event = if is_above {
+                        CrossEvent::Golden
+                    } else {
+                        CrossEvent::Death
+                    };
+                }
+            }
+            _ => self.pending = 0,
+        }
+
+        if self.crossed() {
+            self.last_cross = self.event;
+            self.bars_since_cross = Some(0);
+        } else if let Some(bars) = self.bars_since_cross.as_mut() {
+            *bars += 1;
+        }
+
+        self.event
+    }
+}
+
+impl<L, T> Next<T> for Cross<L>
+where
+    L: InternalValue + Next<Num>,
+    T: AsValue,
+{
+    /// Direction the lines crossed in.
+    type Output = CrossEvent;
+
+    /// Supply an additional value to recalculate a cross.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl<L> SignalSource for Cross<L>
+where
+    L: InternalValue + Next<Num>,
+{
+    /// Buy on a golden cross and sell on a death cross, holding while the lines have not crossed.
+    fn signal(&self) -> Signal {
+        if self.is_golden() {
+            Signal::Buy
+        } else if self.is_death() {
+            Signal::Sell
+        } else {
+            Signal::Hold
+        }
+    }
+}
+
+impl<L> Reset for Cross<L>
+where
+    L: InternalValue + Reset,
+{
+    /// Clears both lines back to having seen no data.
+    fn reset(&mut self) {
+        self.short_line.reset();
+        self.long_line.reset();
+        self.side = None;
+        self.is_above = None;
+        self.pending = 0;
+        self.event = CrossEvent::None;
+        self.last_cross = CrossEvent::None;
+        self.bars_since_cross = None;
+    }
+}
+
+impl<L> Ready for Cross<L>
+where
+    L: InternalValue + Ready,
+{
+    /// Checks if both lines are ready.
+    fn is_ready(&self) -> bool {
+        self.short_line.is_ready() && self.long_line.is_ready()
+    }
+
+    /// Amount of values supplied to the line that has seen the fewest.
+    fn samples_seen(&self) -> usize {
+        self.short_line
+            .samples_seen()
+            .min(self.long_line.samples_seen())
+    }
+}
+
+impl<L> Snapshot for Cross<L>
+where
+    L: InternalValue + Snapshot,
+{
+    fn snapshot(&self) -> IndicatorState {
+        let (separation, distance) = self.separation.to_state();
+        IndicatorState::new("Cross")
+            .optional_flag(self.side)
+            .optional_flag(self.is_above)
+            .count(separation)
+            .scalar(distance)
+            .count(self.confirmation)
+            .count(self.pending)
+            .variant(self.event)
+            .variant(self.last_cross)
+            .optional_count(self.bars_since_cross)
+            .child(&self.short_line)
+            .child(&self.long_line)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Cross")?;
+        Ok(Self {
+            side: reader.optional_flag()?,
+            is_above: reader.optional_flag()?,
+            separation: Tolerance::from_state(reader.count()?, reader.scalar()?)?,
+            confirmation: reader.count()?,
+            pending: reader.count()?,
+            event: reader.variant()?,
+            last_cross: reader.variant()?,
+            bars_since_cross: reader.optional_count()?,
+            short_line: reader.child()?,
+            long_line: reader.child()?,
+        })
     }
 }
@@ -0,0 +1,222 @@
+//! Cumulative Return, compounds returns into a running equity value.
+//!
+//! Starts at a base value, such as 1.0 or 100.0, that each return is compounded onto. Created from
+//! prices, the prices are converted into simple returns first.
+//!
+//! # Formula
+//!
+//! E = y * (1 + r)
+//!
+//! where:
+//!
+//! * `y` = prior equity, starting with the base.
+//! * `r` = current return (most recent)
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Reset, Snapshot, Stats, Value};
+use crate::transforms::{ReturnKind, Returns};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Cumulative Return, compounds returns into a running equity value.
+///
+/// Starts at a base value, such as 1.0 or 100.0, that each return is compounded onto. Created from
+/// prices, the prices are converted into simple returns first.
+///
+/// # Formula
+///
+/// E = y * (1 + r)
+///
+/// where:
+///
+/// * `y` = prior equity, starting with the base.
+/// * `r` = current return (most recent)
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct CumulativeReturn {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Current equity.
+    value: Num,
+    /// Starting equity.
+    base: Num,
+    /// Converts prices into returns, if created from prices.
+    prices: Option<Returns>,
+    /// Holds `period` amount of equity values.
+    buffer: Buffer,
+}
+
+impl CumulativeReturn {
+    /// Creates a new Cumulative Return with the supplied period, base, and initial returns.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Base must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Amount of equity values to keep.
+    /// * `base` - Starting equity, such as 1.0 or 100.0.
+    /// * `data` - Array of returns to compound, can be empty.
+    pub fn new(period: usize, base: Num, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate Cumulative Return.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate cumulative return",
+            )));
+        } else if base.is_nan() || base <= 0.0 {
+            return Err(TAError::InvalidSize(String::from(
+                "base must be greater than 0 to calculate cumulative return",
+            )));
+        }
+
+        let mut cr = Self {
+            period,
+            value: base,
+            base,
+            prices: None,
+            buffer: Buffer::from_array(period, &[base])?,
+        };
+
+        // Compound the returns.
+        for r in data.iter() {
+            cr.add(*r);
+        }
+
+        Ok(cr)
+    }
+
+    /// Creates a new Cumulative Return from prices, converted into simple returns. The next
+    /// values are also expected to be prices.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Base must be greater than 0.
+    /// * Prices must not be 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Amount of equity values to keep.
+    /// * `base` - Starting equity, such as 1.0 or 100.0.
+    /// * `data` - Array of prices to compound the returns of, can be empty.
+    pub fn from_prices(period: usize, base: Num, data: &[Num]) -> Result<Self, TAError> {
+        let mut cr = Self::new(period, base, &Returns::compute(ReturnKind::Simple, data)?)?;
+
+        // Keep the last price for the next return.
+        let mut prices = Returns::new(ReturnKind::Simple);
+        if let Some(last) = data.last() {
+            prices.next(*last)?;
+        }
+
+        cr.prices = Some(prices);
+        Ok(cr)
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Starting equity.
+    pub fn base(&self) -> Num {
+        self.base
+    }
+
+    /// Total return since the start, 0.5 being a 50% gain.
+    pub fn total_return(&self) -> Num {
+        self.value / self.base - 1.0
+    }
+
+    /// Equity values within the period, oldest to newest.
+    pub fn equity(&self) -> &[Num] {
+        self.buffer.queue()
+    }
+
+    /// Compounds a return onto the equity.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Return to compound.
+    fn add(&mut self, value: Num) -> Num {
+        self.value *= 1.0 + value;
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl Next<Num> for CumulativeReturn {
+    /// Next value for the Cumulative Return.
+    type Output = Num;
+
+    /// Supply an additional return, or price if created from prices, to compound onto the equity.
+    /// Prices that are 0 are ignored and the current value is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New return or price to compound.
+    fn next(&mut self, value: Num) -> Self::Output {
+        let value = match self.prices.as_mut().map(|p| p.next(value)) {
+            None => value,
+            Some(Ok(Some(r))) => r,
+            Some(_) => return self.value,
+        };
+
+        self.add(value)
+    }
+}
+
+impl<T> Next<T> for CumulativeReturn
+where
+    T: AsValue,
+{
+    /// Next value for the Cumulative Return.
+    type Output = Num;
+
+    /// Supply an additional return, or price if created from prices, to compound onto the equity.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New return or price to compound.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for CumulativeReturn {
+    /// Resets the equity to the base, clearing the equity values. If created from prices, the
+    /// next price only provides the prior price.
+    fn reset(&mut self) {
+        self.value = self.base;
+        self.buffer.reset(self.base);
+        if let Some(prices) = self.prices.as_mut() {
+            prices.reset();
+        }
+    }
+}
+
+impl Snapshot for CumulativeReturn {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Cumulative Return")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.base)
+            .optional_child(self.prices.as_ref())
+            .child(&self.buffer)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Cumulative Return")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            base: reader.scalar()?,
+            prices: reader.optional_child()?,
+            buffer: reader.child()?,
+        })
+    }
+}
@@ -12,10 +12,14 @@
 //! * `y` = \[EMA(EMA(n))\] EMA of EMA(n)
 //! * `n` = period
 
-use super::ExponentialMovingAverage;
-use crate::traits::{AsValue, InternalValue, Next, Period, Stats};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+use super::seed::{self, Reseed, Seed};
+use super::{EmaSeed, ExponentialMovingAverage};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
 
 /// Double Exponential Moving Average (DEMA)
 ///
@@ -30,7 +34,7 @@ use tatk_derive::{InternalValue, Period};
 /// * `x` = \[EMA(n)\] Current EMA of period `n`
 /// * `y` = \[EMA(EMA(n))\] EMA of EMA(n)
 /// * `n` = period
-#[derive(Debug, InternalValue, Period)]
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
 pub struct DoubleExponentialMovingAverage {
     /// Size of the period (window) in which data is looked at.
     period: usize,
@@ -42,6 +46,8 @@ pub struct DoubleExponentialMovingAverage {
     ema_ema_n: ExponentialMovingAverage,
     /// Holds `period` amount of generated DEMAs.
     buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
 }
 
 impl DoubleExponentialMovingAverage {
@@ -57,6 +63,24 @@ impl DoubleExponentialMovingAverage {
     /// * `period` - Size of the period / window used.
     /// * `data` - Array of values to create the DEMA from.
     pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::with_seed(period, data, EmaSeed::default())
+    }
+
+    /// Creates a new Double Exponential Moving Average with the supplied period, initial data,
+    /// and starting EMA of both EMA(n) and EMA(EMA(n)).
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `(period * 2) - 1` elements.
+    /// * A custom starting EMA must be finite.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the DEMA from.
+    /// * `seed` - Starting EMA, the SMA of the first period by default.
+    pub fn with_seed(period: usize, data: &[Num], seed: EmaSeed) -> Result<Self, TAError> {
         // Check we can calculate Double Exponential Moving Average.
         if period < 1 {
             return Err(TAError::InvalidSize(String::from(
@@ -64,16 +88,15 @@ impl DoubleExponentialMovingAverage {
             )));
         } else if data.len() < (period * 2) - 1 {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data for period provided",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "double exponential moving average",
+                required: (period * 2) - 1,
+                received: data.len(),
+            });
         }
 
         // Build EMA(n) from first 'n' samples (period amount).
-        let mut ema_n = match ExponentialMovingAverage::new(period, &data[..period]) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        let mut ema_n = ExponentialMovingAverage::with_seed(period, &data[..period], seed)?;
 
         // n EMA(n), build it manually because we need to catch the output.
         let mut n_ema_n: Vec<Num> = vec![ema_n.value()];
@@ -82,17 +105,11 @@ impl DoubleExponentialMovingAverage {
         }
 
         // EMA of EMA(n)
-        let mut ema_ema_n = match ExponentialMovingAverage::new(period, &n_ema_n) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        let mut ema_ema_n = ExponentialMovingAverage::with_seed(period, &n_ema_n, seed)?;
 
         // Buffer will old processed DEMAs
         let mut value = (2.0 * ema_n.value()) - ema_ema_n.value();
-        let mut buffer = match Buffer::from_array(period, &[value]) {
-            Ok(v) => v,
-            Err(error) => return Err(error),
-        };
+        let mut buffer = Buffer::from_array(period, &[value])?;
 
         // Calculate the remainder data points.
         for v in data[((period * 2) - 1)..].iter() {
@@ -109,13 +126,37 @@ impl DoubleExponentialMovingAverage {
             ema_n,
             ema_ema_n,
             buffer,
+            seed: Seed::new(data.len()),
         })
     }
 
+    /// Creates a new Double Exponential Moving Average with the supplied period, without initial
+    /// data. The values are supplied with `next` instead, until ready the value is calculated from
+    /// the values supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            &seed::placeholder(1.0, (period * 2).saturating_sub(1)),
+        ))
+    }
+
     /// Current and most recent value calculated.
     pub fn value(&self) -> Num {
         self.value
     }
+
+    /// Starting EMA of both EMA(n) and EMA(EMA(n)).
+    pub fn seeding(&self) -> EmaSeed {
+        self.ema_n.seeding()
+    }
 }
 
 impl Next<Num> for DoubleExponentialMovingAverage {
@@ -128,6 +169,10 @@ impl Next<Num> for DoubleExponentialMovingAverage {
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
         let ema: Num = self.ema_n.next(value);
 
         // Calculate the new DEMA.
@@ -154,32 +199,72 @@ where
     }
 }
 
-impl Stats for DoubleExponentialMovingAverage {
-    /// Obtains the total sum of the buffer for DEMA.
-    fn sum(&self) -> Num {
-        self.buffer.sum()
+impl Reset for DoubleExponentialMovingAverage {
+    /// Clears the DEMA back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.ema_n.reset();
+        self.ema_ema_n.reset();
+        self.buffer.clear();
+        self.seed.start();
     }
+}
 
-    /// Mean for the period of the EMA.
-    fn mean(&self) -> Num {
-        self.buffer.mean()
+impl Ready for DoubleExponentialMovingAverage {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
     }
 
-    /// Current variance for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn variance(&self, is_sample: bool) -> Num {
-        self.buffer.variance(is_sample)
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
     }
+}
 
-    /// Current standard deviation for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn stdev(&self, is_sample: bool) -> Num {
-        self.buffer.stdev(is_sample)
+impl Reseed for DoubleExponentialMovingAverage {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        (self.period * 2) - 1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::with_seed(self.period, data, self.seeding())
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len().div_ceil(2), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for DoubleExponentialMovingAverage {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("DEMA")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.ema_n)
+            .child(&self.ema_ema_n)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "DEMA")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            ema_n: reader.child()?,
+            ema_ema_n: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
     }
 }
@@ -0,0 +1,149 @@
+//! Implementations of `Indicator` and `CandleIndicator`, allowing indicators to be used as trait
+//! objects.
+
+use super::{
+    Acceleration, AtrTrailingStop, AverageTrueRange, BollingerBands, CumulativeReturn,
+    DoubleExponentialMovingAverage, ExponentialMovingAverage, GarmanKlassVolatility,
+    GaussianMovingAverage, HistoricalVolatility, HoltSmoothing, KalmanFilter, KeltnerChannels, Lag,
+    LinearRegression, LinearRegressionChannel, McGinleyDynamic, Median, MedianAbsoluteDeviation,
+    MidPoint, MidPrice, MinMaxScaler, Moments, MovingAverageConvergenceDivergence, OnBalanceVolume,
+    ParkinsonVolatility, RateOfChange, RelativeStrengthIndex, RogersSatchellVolatility, RollingSum,
+    SavitzkyGolay, SharpeRatio, SimpleMovingAverage, SmoothedMovingAverage, SmoothedRateOfChange,
+    SortinoRatio, Squeeze, StandardDeviation, TrueRange, TypicalPrice, Variance, WeightedClose,
+    WeightedMovingAverage, YangZhangVolatility,
+};
+use crate::traits::{CandleIndicator, Close, High, Indicator, Low, Next, Ohlcv, Open, Volume};
+use crate::Num;
+
+/// Candle supplied as a trait object, forwarding to the candle it holds.
+#[derive(Clone, Copy)]
+struct DynCandle<'a>(&'a dyn Ohlcv);
+
+impl Open for DynCandle<'_> {
+    fn open(&self) -> Num {
+        self.0.open()
+    }
+}
+
+impl High for DynCandle<'_> {
+    fn high(&self) -> Num {
+        self.0.high()
+    }
+}
+
+impl Low for DynCandle<'_> {
+    fn low(&self) -> Num {
+        self.0.low()
+    }
+}
+
+impl Close for DynCandle<'_> {
+    fn close(&self) -> Num {
+        self.0.close()
+    }
+}
+
+impl Volume for DynCandle<'_> {
+    fn volume(&self) -> Num {
+        self.0.volume()
+    }
+}
+
+/// Implements `Indicator` for indicators that consume a single value.
+macro_rules! impl_indicator {
+    ($($indicator:ty => $name:literal),+ $(,)?) => {
+        $(
+            impl Indicator for $indicator {
+                fn update(&mut self, value: Num) {
+                    Next::<Num>::next(self, value);
+                }
+
+                fn value(&self) -> Num {
+                    <$indicator>::value(self)
+                }
+
+                fn name(&self) -> &'static str {
+                    $name
+                }
+            }
+        )+
+    };
+}
+
+/// Implements `CandleIndicator` for indicators that consume candles, either by reference (`ref`)
+/// or by value (`value`).
+macro_rules! impl_candle_indicator {
+    (@next ref, $indicator:ident, $candle:ident) => {
+        $indicator.next(&DynCandle($candle))
+    };
+    (@next value, $indicator:ident, $candle:ident) => {
+        $indicator.next(DynCandle($candle))
+    };
+    ($($indicator:ty => $name:literal, $by:ident);+ $(;)?) => {
+        $(
+            impl CandleIndicator for $indicator {
+                fn update(&mut self, candle: &dyn Ohlcv) {
+                    impl_candle_indicator!(@next $by, self, candle);
+                }
+
+                fn value(&self) -> Num {
+                    <$indicator>::value(self)
+                }
+
+                fn name(&self) -> &'static str {
+                    $name
+                }
+            }
+        )+
+    };
+}
+
+impl_indicator!(
+    Acceleration => "Acceleration",
+    BollingerBands<SimpleMovingAverage> => "BBands",
+    CumulativeReturn => "Cumulative Return",
+    DoubleExponentialMovingAverage => "DEMA",
+    ExponentialMovingAverage => "EMA",
+    GaussianMovingAverage => "GMA",
+    HistoricalVolatility => "HV",
+    HoltSmoothing => "Holt Smoothing",
+    KalmanFilter => "Kalman Filter",
+    Lag => "Lag",
+    LinearRegression => "LineReg",
+    LinearRegressionChannel => "LRC",
+    McGinleyDynamic => "MD",
+    Median => "Median",
+    MedianAbsoluteDeviation => "MAD",
+    MidPoint => "MidPoint",
+    MinMaxScaler => "Min-Max Scaler",
+    Moments => "Moments",
+    MovingAverageConvergenceDivergence => "MACD",
+    RateOfChange => "ROC",
+    RelativeStrengthIndex => "RSI",
+    RollingSum => "Rolling Sum",
+    SavitzkyGolay => "Savitzky-Golay Filter",
+    SharpeRatio => "Sharpe Ratio",
+    SimpleMovingAverage => "SMA",
+    SmoothedMovingAverage => "SMMA",
+    SmoothedRateOfChange => "SROC",
+    SortinoRatio => "Sortino Ratio",
+    StandardDeviation => "STDEV",
+    Variance => "Variance",
+    WeightedMovingAverage => "WMA",
+);
+
+impl_candle_indicator!(
+    AtrTrailingStop => "ATR Trailing Stop", ref;
+    AverageTrueRange => "ATR", value;
+    GarmanKlassVolatility => "Garman-Klass Volatility", ref;
+    KeltnerChannels => "KC", ref;
+    MidPrice => "MidPrice", ref;
+    OnBalanceVolume => "OBV", value;
+    ParkinsonVolatility => "Parkinson Volatility", ref;
+    RogersSatchellVolatility => "Rogers-Satchell Volatility", ref;
+    Squeeze => "Squeeze", ref;
+    TrueRange => "TR", ref;
+    TypicalPrice => "Typical Price", ref;
+    WeightedClose => "Weighted Close", ref;
+    YangZhangVolatility => "Yang-Zhang Volatility", ref;
+);
@@ -8,12 +8,33 @@
 //!
 //! * `x` = current value (most recent)
 //! * `y` = last EMA
-//! * `k` = 2 * (n + 1)
+//! * `k` = 2 / (n + 1), or the alpha supplied
 //! * `n` = period
+//!
+//! The period's EMAs are held in a `Buffer` by default, any `Window` can be used instead such as a
+//! `StaticBuffer` to avoid heap allocations.
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    AsValue, CheckedNext, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value, Window,
+};
+use crate::{Buffer, IndicatorState, NonFinite, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
 
-use crate::traits::{AsValue, InternalValue, Next, Period, Stats};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+/// Starting EMA that the remainder of the initial data is smoothed from.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum EmaSeed {
+    /// SMA of the first `period` values.
+    #[default]
+    SmaOfFirstPeriod,
+    /// First value, every value after it is smoothed.
+    FirstValue,
+    /// Supplied value, every value is smoothed.
+    Custom(Num),
+}
 
 /// Exponential Moving Average (EMA). More recent data is weighted heavier than older data.
 ///
@@ -24,18 +45,53 @@ use tatk_derive::{InternalValue, Period};
 /// where:
 /// * `x` = current value (most recent)
 /// * `y` = last EMA
-/// * `k` = 2 * (n + 1)
+/// * `k` = 2 / (n + 1), or the alpha supplied
 /// * `n` = period
-#[derive(Debug, InternalValue, Period)]
-pub struct ExponentialMovingAverage {
+///
+/// The period's EMAs are held in a `Buffer` by default, any `Window` can be used instead such as a
+/// `StaticBuffer` to avoid heap allocations.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct ExponentialMovingAverage<B = Buffer> {
     /// Size of the period (window) in which data is looked at.
     period: usize,
     /// Current value for the EMA.
     value: Num,
     /// Holds `period` amount of generated EMAs.
-    buffer: Buffer,
+    buffer: B,
     /// Smoothing value.
     k: Num,
+    /// Starting EMA of the initial data.
+    seeding: EmaSeed,
+    /// How NaN or infinite values supplied with `next` are handled.
+    non_finite: NonFinite,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl EmaSeed {
+    /// Position of the variant and the custom seed, held in a state.
+    pub(crate) fn to_state(self) -> (usize, Num) {
+        match self {
+            EmaSeed::SmaOfFirstPeriod => (0, 0.0),
+            EmaSeed::FirstValue => (1, 0.0),
+            EmaSeed::Custom(value) => (2, value),
+        }
+    }
+
+    /// Creates the seeding from the position of the variant and the custom seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the variant.
+    /// * `value` - Custom seed, ignored by the other variants.
+    pub(crate) fn from_state(index: usize, value: Num) -> Result<Self, TAError> {
+        match index {
+            0 => Ok(EmaSeed::SmaOfFirstPeriod),
+            1 => Ok(EmaSeed::FirstValue),
+            2 => Ok(EmaSeed::Custom(value)),
+            _ => Err(TAError::InvalidState(String::from("unknown ema seeding"))),
+        }
+    }
 }
 
 impl ExponentialMovingAverage {
@@ -51,6 +107,97 @@ impl ExponentialMovingAverage {
     /// * `period` - Size of the period / window used.
     /// * `data` - Array of values to create the EMA from.
     pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::create(period, data, 2.0 / (period + 1) as Num, EmaSeed::default())
+    }
+
+    /// Creates a new EMA with the supplied period, initial data, and starting EMA. Each starting
+    /// EMA gives different early values, converging as more values are supplied.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    /// * A custom starting EMA must be finite.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the EMA from.
+    /// * `seed` - Starting EMA, the SMA of the first period by default.
+    pub fn with_seed(period: usize, data: &[Num], seed: EmaSeed) -> Result<Self, TAError> {
+        Self::create(period, data, 2.0 / (period + 1) as Num, seed)
+    }
+
+    /// Creates a new EMA with the supplied period, initial data, and smoothing factor. The period
+    /// is still the values required to seed the EMA and held by the buffer, such as an alpha of
+    /// `1 / period` for Wilder's smoothing.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    /// * Alpha must be greater than 0 and at most 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the EMA from.
+    /// * `alpha` - Smoothing factor, the weight of the most recent value.
+    pub fn with_alpha(period: usize, data: &[Num], alpha: Num) -> Result<Self, TAError> {
+        if alpha.is_nan() || alpha <= 0.0 || alpha > 1.0 {
+            return Err(TAError::InvalidInput(String::from(
+                "alpha must be greater than 0 and at most 1 to calculate exponential moving average",
+            )));
+        }
+
+        Self::create(period, data, alpha, EmaSeed::default())
+    }
+
+    /// Creates a new EMA with the supplied period, without initial data. The values are supplied
+    /// with `next` instead, until ready the value is calculated from the values supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period)))
+    }
+}
+
+impl<B> ExponentialMovingAverage<B>
+where
+    B: Window,
+{
+    /// Creates a new EMA with the supplied period and initial data, holding its EMAs in the window
+    /// `B`.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    /// * Period must be a capacity the window supports, `N` for a `StaticBuffer<N>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the EMA from.
+    pub fn with_window(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::create(period, data, 2.0 / (period + 1) as Num, EmaSeed::default())
+    }
+
+    /// Creates a new EMA with the supplied period, initial data, and smoothing factor.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the EMA from.
+    /// * `k` - Smoothing factor.
+    /// * `seeding` - Starting EMA of the initial data.
+    fn create(period: usize, data: &[Num], k: Num, seeding: EmaSeed) -> Result<Self, TAError> {
         // Check we can calculate EMA.
         if period < 1 {
             return Err(TAError::InvalidSize(String::from(
@@ -58,28 +205,34 @@ impl ExponentialMovingAverage {
             )));
         } else if data.len() < period {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data for period provided",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "exponential moving average",
+                required: period,
+                received: data.len(),
+            });
         }
 
-        // Temporary buffer to get seed SMA for EMA.
-        let mut last_ema = match Buffer::from_array(period, &data[..period]) {
-            Ok(v) => v.mean(),
-            Err(error) => return Err(error),
-        };
+        // Make sure the data is usable.
+        TAError::check_finite(data.iter().copied())?;
 
-        // Buffer will hold last `period` EMAs.
-        let mut buffer = match Buffer::from_array(period, &[last_ema]) {
-            Ok(v) => v,
-            Err(error) => return Err(error),
+        // Starting EMA and the data remaining to smooth from it.
+        let (mut last_ema, remainder) = match seeding {
+            EmaSeed::SmaOfFirstPeriod => (
+                B::from_array(period, &data[..period])?.mean(),
+                &data[period..],
+            ),
+            EmaSeed::FirstValue => (data[0], &data[1..]),
+            EmaSeed::Custom(start) => {
+                TAError::check_finite([start])?;
+                (Self::calculate(&k, &start, &data[0]), &data[1..])
+            }
         };
 
-        // Smoothing factor.
-        let k: Num = 2.0 / (period + 1) as Num;
+        // Buffer will hold last `period` EMAs.
+        let mut buffer = B::from_array(period, &[last_ema])?;
 
         // Calculate the remainder of the datas EMA, using the prior EMA.
-        for value in data[period..].iter() {
+        for value in remainder.iter() {
             last_ema = Self::calculate(&k, &last_ema, value);
             buffer.shift(last_ema);
         }
@@ -89,6 +242,9 @@ impl ExponentialMovingAverage {
             value: last_ema,
             buffer,
             k,
+            seeding,
+            non_finite: NonFinite::default(),
+            seed: Seed::new(data.len()),
         })
     }
 
@@ -97,9 +253,20 @@ impl ExponentialMovingAverage {
         self.value
     }
 
-    /// Smoothing factor.
-    fn k(&self) -> &Num {
-        &self.k
+    /// Changes how NaN or infinite values supplied with `next` are handled, the default being to
+    /// propagate them.
+    pub fn set_non_finite(&mut self, policy: NonFinite) {
+        self.non_finite = policy;
+    }
+
+    /// Smoothing factor, the weight of the most recent value.
+    pub fn alpha(&self) -> Num {
+        self.k
+    }
+
+    /// Starting EMA of the initial data.
+    pub fn seeding(&self) -> EmaSeed {
+        self.seeding
     }
 
     /// Calculates an EMA with newly provided data and the last EMA.
@@ -114,7 +281,10 @@ impl ExponentialMovingAverage {
     }
 }
 
-impl Next<Num> for ExponentialMovingAverage {
+impl<B> Next<Num> for ExponentialMovingAverage<B>
+where
+    B: Window,
+{
     /// Next value for the EMA.
     type Output = Num;
 
@@ -124,16 +294,26 @@ impl Next<Num> for ExponentialMovingAverage {
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
+        if !self.non_finite.accepts(&[value]) {
+            self.seed.skip();
+            return self.value;
+        }
+
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
         // Get the next EMA value.
-        self.value = Self::calculate(self.k(), &self.value(), &value);
+        self.value = Self::calculate(&self.k, &self.value(), &value);
         self.buffer.shift(self.value());
         self.value
     }
 }
 
-impl<T> Next<T> for ExponentialMovingAverage
+impl<T, B> Next<T> for ExponentialMovingAverage<B>
 where
     T: AsValue,
+    B: Window,
 {
     /// Next value for the EMA.
     type Output = Num;
@@ -148,18 +328,96 @@ where
     }
 }
 
-impl Stats for ExponentialMovingAverage {
-    /// Obtains the total sum of the buffer for EMA.
+impl<B> CheckedNext<Num> for ExponentialMovingAverage<B>
+where
+    B: Window,
+{
+    /// Supply an additional value to recalculate a new EMA, rejecting NaN or infinite values.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn checked_next(&mut self, value: Num) -> Result<Self::Output, TAError> {
+        TAError::check_finite([value])?;
+        Ok(self.next(value))
+    }
+}
+
+impl<B> Reset for ExponentialMovingAverage<B>
+where
+    B: Window,
+{
+    /// Clears the EMA back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl<B> Ready for ExponentialMovingAverage<B>
+where
+    B: Window,
+{
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl<B> Reseed for ExponentialMovingAverage<B>
+where
+    B: Window,
+{
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        // Keep the smoothing and policy that was set.
+        let mut ema = Self::create(self.period, data, self.k, self.seeding)?;
+        ema.non_finite = self.non_finite;
+        Ok(ema)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        // Same smoothing and seeding as the full EMA, the window `B` may not support a smaller
+        // capacity.
+        if let Ok(partial) =
+            ExponentialMovingAverage::<Buffer>::create(data.len(), data, self.k, self.seeding)
+        {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl<B> Stats for ExponentialMovingAverage<B>
+where
+    B: Window,
+{
+    /// Sum of the period's EMAs.
     fn sum(&self) -> Num {
         self.buffer.sum()
     }
 
-    /// Mean for the period of the EMA.
+    /// Mean of the period's EMAs.
     fn mean(&self) -> Num {
         self.buffer.mean()
     }
 
-    /// Current variance for the period.
+    /// Variance of the period's EMAs.
     ///
     /// # Arguments
     ///
@@ -168,7 +426,7 @@ impl Stats for ExponentialMovingAverage {
         self.buffer.variance(is_sample)
     }
 
-    /// Current standard deviation for the period.
+    /// Standard deviation of the period's EMAs.
     ///
     /// # Arguments
     ///
@@ -177,3 +435,34 @@ impl Stats for ExponentialMovingAverage {
         self.buffer.stdev(is_sample)
     }
 }
+
+impl<B> Snapshot for ExponentialMovingAverage<B>
+where
+    B: Window + Snapshot,
+{
+    fn snapshot(&self) -> IndicatorState {
+        let (seeding, custom) = self.seeding.to_state();
+        IndicatorState::new("EMA")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.k)
+            .count(seeding)
+            .scalar(custom)
+            .variant(self.non_finite)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "EMA")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            k: reader.scalar()?,
+            seeding: EmaSeed::from_state(reader.count()?, reader.scalar()?)?,
+            non_finite: reader.variant()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
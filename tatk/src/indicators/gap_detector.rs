@@ -0,0 +1,313 @@
+//! Gap Detector, finds price gaps between consecutive candles and tracks them until filled.
+//!
+//! A gap occurs when a candle opens away from the prior close. A full gap opens beyond the prior
+//! high (up) or low (down), a partial gap opens within the prior range. A gap is filled once a
+//! later candle trades back to the prior close.
+//!
+//! # Formula
+//!
+//! Size = |o - p|
+//!
+//! Percent = (Size / p) * 100
+//!
+//! where:
+//!
+//! * `o` = open of the candle.
+//! * `p` = close of the prior candle.
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{Close, High, Low, Next, Open, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+
+/// Used for conversions. Holds Open (0), High (1), Low (2), and Close (3) values.
+#[derive(Copy, Clone, PartialEq)]
+struct Data(Num, Num, Num, Num);
+
+// Opening value.
+impl Open for Data {
+    fn open(&self) -> Num {
+        self.0
+    }
+}
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.1
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.2
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.3
+    }
+}
+
+/// Direction the price gapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapDirection {
+    /// Opened above the prior close.
+    Up,
+    /// Opened below the prior close.
+    Down,
+}
+
+impl Variants for GapDirection {
+    const ALL: &'static [Self] = &[GapDirection::Up, GapDirection::Down];
+}
+
+/// Gap between two consecutive candles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gap {
+    /// Direction the price gapped.
+    pub direction: GapDirection,
+    /// Absolute size of the gap, |o - p|.
+    pub size: Num,
+    /// Size of the gap as a percent of the prior close.
+    pub percent: Num,
+    /// Opened beyond the prior high or low instead of within the prior range.
+    pub is_full: bool,
+    /// Close of the prior candle, price needs to return here to fill the gap.
+    pub prior_close: Num,
+    /// Open of the candle that gapped.
+    pub open: Num,
+}
+
+impl Gap {
+    /// Checks if a candle trades back to the prior close, filling the gap.
+    ///
+    /// # Arguments
+    ///
+    /// * `candle` - Candle to check against the gap.
+    pub fn is_filled_by<T>(&self, candle: &T) -> bool
+    where
+        T: High + Low,
+    {
+        match self.direction {
+            GapDirection::Up => candle.low() <= self.prior_close,
+            GapDirection::Down => candle.high() >= self.prior_close,
+        }
+    }
+}
+
+/// Gap Detector, finds price gaps between consecutive candles and tracks them until filled.
+///
+/// A gap occurs when a candle opens away from the prior close. A full gap opens beyond the prior
+/// high (up) or low (down), a partial gap opens within the prior range. A gap is filled once a
+/// later candle trades back to the prior close.
+///
+/// # Formula
+///
+/// Size = |o - p|
+///
+/// Percent = (Size / p) * 100
+///
+/// where:
+///
+/// * `o` = open of the candle.
+/// * `p` = close of the prior candle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GapDetector {
+    /// Minimum percent size for a gap to be detected.
+    threshold: Num,
+    /// Close, high, and low of the prior candle.
+    prior: Option<(Num, Num, Num)>,
+    /// Gaps that have not been filled, oldest to newest.
+    gaps: Vec<Gap>,
+}
+
+impl GapDetector {
+    /// Creates a new Gap Detector with the supplied threshold.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Threshold must be 0 or greater.
+    ///
+    /// ## Arguments
+    ///
+    /// * `threshold` - Minimum percent size of a gap, 0.5 is 0.5% of the prior close.
+    pub fn new(threshold: Num) -> Result<Self, TAError> {
+        // Check we can detect gaps.
+        if threshold.is_nan() || threshold < 0.0 {
+            return Err(TAError::InvalidSize(String::from(
+                "threshold cannot be less than 0 to detect gaps",
+            )));
+        }
+
+        Ok(Self {
+            threshold,
+            prior: None,
+            gaps: vec![],
+        })
+    }
+
+    /// Minimum percent size for a gap to be detected.
+    pub fn threshold(&self) -> Num {
+        self.threshold
+    }
+
+    /// Gaps that have not been filled yet, oldest to newest.
+    pub fn open_gaps(&self) -> &[Gap] {
+        &self.gaps
+    }
+
+    /// Removes and returns the open gaps that are filled by the candle.
+    ///
+    /// # Arguments
+    ///
+    /// * `candle` - Candle to check the open gaps against.
+    pub fn check_filled<T>(&mut self, candle: &T) -> Vec<Gap>
+    where
+        T: High + Low,
+    {
+        let (filled, open): (Vec<Gap>, Vec<Gap>) =
+            self.gaps.iter().partition(|g| g.is_filled_by(candle));
+        self.gaps = open;
+        filled
+    }
+}
+
+impl<T> Next<&T> for GapDetector
+where
+    T: Open + High + Low + Close,
+{
+    /// Gap created by the candle, if any.
+    type Output = Option<Gap>;
+
+    /// Supply an additional candle to check for a gap from the prior candle. Open gaps are
+    /// checked for being filled first. A new gap is only tracked as open if the candle did not
+    /// already fill it.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to check.
+    fn next(&mut self, value: &T) -> Self::Output {
+        self.check_filled(value);
+
+        let prior = self
+            .prior
+            .replace((value.close(), value.high(), value.low()));
+        let (close, high, low) = prior?;
+        if close <= 0.0 {
+            return None;
+        }
+
+        let open = value.open();
+        let (direction, is_full) = if open > close {
+            (GapDirection::Up, open > high)
+        } else if open < close {
+            (GapDirection::Down, open < low)
+        } else {
+            return None;
+        };
+
+        let size = (open - close).abs();
+        let percent = size / close * 100.0;
+        if percent < self.threshold {
+            return None;
+        }
+
+        let gap = Gap {
+            direction,
+            size,
+            percent,
+            is_full,
+            prior_close: close,
+            open,
+        };
+
+        if !gap.is_filled_by(value) {
+            self.gaps.push(gap);
+        }
+
+        Some(gap)
+    }
+}
+
+impl Next<(Num, Num, Num, Num)> for GapDetector {
+    /// Gap created by the candle, if any.
+    type Output = Option<Gap>;
+
+    /// Supply an additional value to check for a gap from the prior candle.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to check.
+    ///     * 0 = Open
+    ///     * 1 = High
+    ///     * 2 = Low
+    ///     * 3 = Close
+    fn next(&mut self, value: (Num, Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2, value.3))
+    }
+}
+
+impl Reset for GapDetector {
+    /// Clears the prior candle and the gaps found.
+    fn reset(&mut self) {
+        self.prior = None;
+        self.gaps.clear();
+    }
+}
+
+impl Snapshot for GapDetector {
+    fn snapshot(&self) -> IndicatorState {
+        let prior = self.prior.unwrap_or_default();
+        let mut gaps: Vec<Num> = Vec::with_capacity(self.gaps.len() * 4);
+        let mut state = IndicatorState::new("Gap Detector")
+            .scalar(self.threshold)
+            .flag(self.prior.is_some())
+            .scalar(prior.0)
+            .scalar(prior.1)
+            .scalar(prior.2);
+
+        // Numbers of each gap are held in a buffer, the direction and fill as counts.
+        for gap in self.gaps.iter() {
+            gaps.extend([gap.size, gap.percent, gap.prior_close, gap.open]);
+            state = state.variant(gap.direction).flag(gap.is_full);
+        }
+
+        state.buffer(&gaps)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Gap Detector")?;
+        let threshold = reader.scalar()?;
+        let is_prior = reader.flag()?;
+        let prior = (reader.scalar()?, reader.scalar()?, reader.scalar()?);
+        let values = reader.buffer()?;
+        if values.len() % 4 != 0 {
+            return Err(TAError::InvalidState(String::from(
+                "gap detector holds a partial gap",
+            )));
+        }
+
+        let mut gaps: Vec<Gap> = Vec::with_capacity(values.len() / 4);
+        for gap in values.chunks(4) {
+            gaps.push(Gap {
+                direction: reader.variant()?,
+                size: gap[0],
+                percent: gap[1],
+                is_full: reader.flag()?,
+                prior_close: gap[2],
+                open: gap[3],
+            });
+        }
+
+        Ok(Self {
+            threshold,
+            prior: is_prior.then_some(prior),
+            gaps,
+        })
+    }
+}
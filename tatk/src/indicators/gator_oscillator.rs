@@ -0,0 +1,327 @@
+//! Gator Oscillator
+//!
+//! Histograms of the distance between the lines of the Williams Alligator, showing when the
+//! Alligator is sleeping (contracting) or eating (expanding).
+//!
+//! # Formula
+//!
+//! Upper = |x - y|
+//!
+//! Lower = -|y - z|
+//!
+//! where:
+//!
+//! * `x` = displaced jaw of the Alligator.
+//! * `y` = displaced teeth of the Alligator.
+//! * `z` = displaced lips of the Alligator.
+
+use super::seed::{self, Reseed, Seed};
+use super::Alligator;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{High, Low, Next, Period, Ready, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+
+/// Used for conversions. Holds High (0) and Low (1) values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num);
+
+seed::impl_flatten!(Data(0, 1));
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+/// Single bar of the Gator Oscillator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GatorBar {
+    /// Upper histogram, distance between the jaw and teeth.
+    pub upper: Num,
+    /// Lower histogram, negative distance between the teeth and lips.
+    pub lower: Num,
+    /// Upper histogram grew compared to the prior bar.
+    pub is_upper_expanding: bool,
+    /// Lower histogram grew compared to the prior bar.
+    pub is_lower_expanding: bool,
+}
+
+/// Gator Oscillator
+///
+/// Histograms of the distance between the lines of the Williams Alligator, showing when the
+/// Alligator is sleeping (contracting) or eating (expanding).
+///
+/// # Formula
+///
+/// Upper = |x - y|
+///
+/// Lower = -|y - z|
+///
+/// where:
+///
+/// * `x` = displaced jaw of the Alligator.
+/// * `y` = displaced teeth of the Alligator.
+/// * `z` = displaced lips of the Alligator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GatorOscillator {
+    /// Alligator the histograms are created from.
+    alligator: Alligator,
+    /// Current bar of the oscillator.
+    bar: GatorBar,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
+}
+
+impl GatorOscillator {
+    /// Creates a new Gator Oscillator with the supplied periods, shifts, and initial data. The
+    /// expanding flags are only set once there is more data than the Alligator requires.
+    ///
+    /// ### Requirements:
+    ///
+    /// * All periods must be greater than 0.
+    /// * Data must have at least `period + shift` elements for each line.
+    ///
+    /// ## Arguments
+    ///
+    /// * `jaw` - Period and shift of the jaw, (`period`, `shift`).
+    /// * `teeth` - Period and shift of the teeth, (`period`, `shift`).
+    /// * `lips` - Period and shift of the lips, (`period`, `shift`).
+    /// * `data` - Array of candles to create the Gator Oscillator from.
+    pub fn new<T>(
+        jaw: (usize, usize),
+        teeth: (usize, usize),
+        lips: (usize, usize),
+        data: &[T],
+    ) -> Result<Self, TAError>
+    where
+        T: High + Low,
+    {
+        // Seed with the least amount of data the Alligator needs, validated by the Alligator.
+        let required = Alligator::required_for(&[jaw, teeth, lips]).min(data.len());
+        let alligator = Alligator::new(jaw, teeth, lips, &data[..required])?;
+
+        let (upper, lower) = Self::calculate(&alligator);
+        let mut gator = Self {
+            alligator,
+            bar: GatorBar {
+                upper,
+                lower,
+                is_upper_expanding: false,
+                is_lower_expanding: false,
+            },
+            seed: Seed::new(required),
+        };
+
+        // Calculate the remainder of the bars.
+        for v in data[required..].iter() {
+            gator.next(v);
+        }
+
+        Ok(gator)
+    }
+
+    /// Creates a new Gator Oscillator with the common Alligator settings, jaw (13, 8), teeth
+    /// (8, 5), and lips (5, 3).
+    ///
+    /// ### Requirements:
+    ///
+    /// * Data must have at least 21 elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - Array of candles to create the Gator Oscillator from.
+    pub fn with_defaults<T>(data: &[T]) -> Result<Self, TAError>
+    where
+        T: High + Low,
+    {
+        Self::new((13, 8), (8, 5), (5, 3), data)
+    }
+
+    /// Creates a new Gator Oscillator with the supplied periods and shifts, without initial data.
+    /// The candles are supplied with `next` instead, the bars are 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * All periods must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `jaw` - Period and shift of the jaw, (`period`, `shift`).
+    /// * `teeth` - Period and shift of the teeth, (`period`, `shift`).
+    /// * `lips` - Period and shift of the lips, (`period`, `shift`).
+    pub fn with_period(
+        jaw: (usize, usize),
+        teeth: (usize, usize),
+        lips: (usize, usize),
+    ) -> Result<Self, TAError> {
+        let required = Alligator::required_for(&[jaw, teeth, lips]);
+        seed::streaming(Self::new(
+            jaw,
+            teeth,
+            lips,
+            &seed::placeholder(Data(1.0, 1.0), required),
+        ))
+    }
+
+    /// Current bar of the oscillator.
+    pub fn value(&self) -> GatorBar {
+        self.bar
+    }
+
+    /// Upper histogram, distance between the jaw and teeth.
+    pub fn upper(&self) -> Num {
+        self.bar.upper
+    }
+
+    /// Lower histogram, negative distance between the teeth and lips.
+    pub fn lower(&self) -> Num {
+        self.bar.lower
+    }
+
+    /// Alligator the histograms are created from.
+    pub fn alligator(&self) -> &Alligator {
+        &self.alligator
+    }
+
+    /// Calculates the upper and lower histograms from the Alligator.
+    fn calculate(alligator: &Alligator) -> (Num, Num) {
+        (
+            (alligator.jaw() - alligator.teeth()).abs(),
+            -(alligator.teeth() - alligator.lips()).abs(),
+        )
+    }
+}
+
+impl Period for GatorOscillator {
+    /// Period (window) for the jaw.
+    fn period(&self) -> usize {
+        self.alligator.period()
+    }
+}
+
+impl<T> Next<&T> for GatorOscillator
+where
+    T: High + Low,
+{
+    /// Upper and Lower histograms with their expanding flags.
+    type Output = GatorBar;
+
+    /// Supply an additional candle to recalculate the Gator Oscillator.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        if seed::collect(self, Data(value.high(), value.low())) {
+            return self.bar;
+        }
+
+        self.alligator.next(value);
+
+        let (upper, lower) = Self::calculate(&self.alligator);
+        self.bar = GatorBar {
+            upper,
+            lower,
+            is_upper_expanding: upper > self.bar.upper,
+            is_lower_expanding: lower.abs() > self.bar.lower.abs(),
+        };
+
+        self.bar
+    }
+}
+
+impl Next<(Num, Num)> for GatorOscillator {
+    /// Upper and Lower histograms with their expanding flags.
+    type Output = GatorBar;
+
+    /// Supply an additional value to recalculate the Gator Oscillator.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    ///     * 0 = High
+    ///     * 1 = Low
+    fn next(&mut self, value: (Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1))
+    }
+}
+
+impl Reset for GatorOscillator {
+    /// Clears the Gator Oscillator back to having seen no data.
+    fn reset(&mut self) {
+        self.alligator.reset();
+        self.bar = GatorBar {
+            upper: 0.0,
+            lower: 0.0,
+            is_upper_expanding: false,
+            is_lower_expanding: false,
+        };
+        self.seed.start();
+    }
+}
+
+impl Ready for GatorOscillator {
+    /// Checks if enough candles have been supplied for the bars to be complete, false while
+    /// collecting the initial candles after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of candles supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for GatorOscillator {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.alligator.required()
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        let [jaw, teeth, lips] = self.alligator.settings();
+        Self::new(jaw, teeth, lips, data)
+    }
+}
+
+impl Snapshot for GatorOscillator {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Gator Oscillator")
+            .scalar(self.bar.upper)
+            .scalar(self.bar.lower)
+            .flag(self.bar.is_upper_expanding)
+            .flag(self.bar.is_lower_expanding)
+            .child(&self.alligator)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Gator Oscillator")?;
+        Ok(Self {
+            bar: GatorBar {
+                upper: reader.scalar()?,
+                lower: reader.scalar()?,
+                is_upper_expanding: reader.flag()?,
+                is_lower_expanding: reader.flag()?,
+            },
+            alligator: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
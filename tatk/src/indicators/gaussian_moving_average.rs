@@ -0,0 +1,311 @@
+//! Gaussian Moving Average (GMA), weighted average of a period using a Gaussian curve.
+//!
+//! Weights follow a Gaussian curve centered on the newest value, or the middle of the period,
+//! and are normalized to sum to 1. Sigma is the width of the curve in values, a smaller sigma
+//! favours values closer to the center. Compared to the SMA the weights taper off smoothly,
+//! reducing the noise that passes through for the same lag.
+//!
+//! # Formula
+//!
+//! GMA = w\[0\] * x\[t - n + 1\] + ... + w\[n - 1\] * x\[t\]
+//!
+//! w\[i\] = exp(-(i - c)² / (2 * σ²)) / W
+//!
+//! where:
+//!
+//! * `x[t]` = current value (most recent)
+//! * `c` = center of the curve, `n - 1` for the newest value or `(n - 1) / 2` for the middle.
+//! * `σ` = sigma, width of the curve.
+//! * `W` = sum of the unnormalized weights.
+//! * `n` = period
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Position within the period the Gaussian curve is centered on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GaussianCenter {
+    /// Centered on the newest value, the most weight is on the newest value.
+    Newest,
+    /// Centered on the middle of the period, a symmetric window.
+    Middle,
+}
+
+impl Variants for GaussianCenter {
+    const ALL: &'static [Self] = &[GaussianCenter::Newest, GaussianCenter::Middle];
+}
+
+/// Gaussian Moving Average (GMA), weighted average of a period using a Gaussian curve.
+///
+/// Weights follow a Gaussian curve centered on the newest value, or the middle of the period,
+/// and are normalized to sum to 1. Sigma is the width of the curve in values, a smaller sigma
+/// favours values closer to the center. Compared to the SMA the weights taper off smoothly,
+/// reducing the noise that passes through for the same lag.
+///
+/// # Formula
+///
+/// GMA = w\[0\] * x\[t - n + 1\] + ... + w\[n - 1\] * x\[t\]
+///
+/// w\[i\] = exp(-(i - c)² / (2 * σ²)) / W
+///
+/// where:
+///
+/// * `x[t]` = current value (most recent)
+/// * `c` = center of the curve, `n - 1` for the newest value or `(n - 1) / 2` for the middle.
+/// * `σ` = sigma, width of the curve.
+/// * `W` = sum of the unnormalized weights.
+/// * `n` = period
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct GaussianMovingAverage {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// GMA's current value.
+    value: Num,
+    /// Width of the curve.
+    sigma: Num,
+    /// Position the curve is centered on.
+    center: GaussianCenter,
+    /// Normalized weights, oldest to newest.
+    weights: Vec<Num>,
+    /// Holds all of the current period's values.
+    values: Buffer,
+    /// Holds `period` amount of generated GMAs.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl GaussianMovingAverage {
+    /// Creates a new GMA with the supplied period, sigma, center, and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Sigma must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `sigma` - Width of the curve in values.
+    /// * `center` - Position the curve is centered on.
+    /// * `data` - Array of values to create the GMA from.
+    pub fn new(
+        period: usize,
+        sigma: Num,
+        center: GaussianCenter,
+        data: &[Num],
+    ) -> Result<Self, TAError> {
+        // Check we can calculate GMA.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate gaussian moving average",
+            )));
+        } else if sigma.is_nan() || sigma <= 0.0 {
+            return Err(TAError::InvalidSize(String::from(
+                "sigma must be greater than 0 to calculate gaussian moving average",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "gaussian moving average",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Gaussian curve, normalized to sum to 1.
+        let c = match center {
+            GaussianCenter::Newest => (period - 1) as Num,
+            GaussianCenter::Middle => (period - 1) as Num / 2.0,
+        };
+        let mut weights: Vec<Num> = (0..period)
+            .map(|i| (-(i as Num - c).powi(2) / (2.0 * sigma * sigma)).exp())
+            .collect();
+        let total: Num = weights.iter().sum();
+        weights.iter_mut().for_each(|w| *w /= total);
+
+        // Build the buffer containing the `period` of values.
+        let values = Buffer::from_array(period, &data[..period])?;
+        let value = values.weighted_sum(&weights);
+
+        let mut gma = Self {
+            period,
+            value,
+            sigma,
+            center,
+            weights,
+            values,
+            buffer: Buffer::from_array(period, &[value])?,
+            seed: Seed::new(period),
+        };
+
+        // Calculate the remainder of the GMAs.
+        for v in data[period..].iter() {
+            gma.next(*v);
+        }
+
+        Ok(gma)
+    }
+
+    /// Creates a new GMA with the supplied period, sigma, and center, without initial data. The
+    /// values are supplied with `next` instead, until ready the value is calculated from the values
+    /// supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Sigma must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `sigma` - Width of the curve in values.
+    /// * `center` - Position the curve is centered on.
+    pub fn with_period(period: usize, sigma: Num, center: GaussianCenter) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            sigma,
+            center,
+            &seed::placeholder(1.0, period),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Width of the curve in values.
+    pub fn sigma(&self) -> Num {
+        self.sigma
+    }
+
+    /// Position the curve is centered on.
+    pub fn center(&self) -> GaussianCenter {
+        self.center
+    }
+
+    /// Normalized weights, oldest to newest.
+    pub fn weights(&self) -> &[Num] {
+        &self.weights
+    }
+}
+
+impl Next<Num> for GaussianMovingAverage {
+    /// Next value for the GMA.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new GMA.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.values.shift(value);
+        self.value = self.values.weighted_sum(&self.weights);
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl<T> Next<T> for GaussianMovingAverage
+where
+    T: AsValue,
+{
+    /// Next value for the GMA.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new GMA.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for GaussianMovingAverage {
+    /// Clears the GMA back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.values.clear();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for GaussianMovingAverage {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for GaussianMovingAverage {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, self.sigma, self.center, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), self.sigma, self.center, data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for GaussianMovingAverage {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("GMA")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.sigma)
+            .variant(self.center)
+            .buffer(&self.weights)
+            .child(&self.values)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "GMA")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            sigma: reader.scalar()?,
+            center: reader.variant()?,
+            weights: reader.buffer()?.to_vec(),
+            values: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
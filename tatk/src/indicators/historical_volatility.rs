@@ -0,0 +1,277 @@
+//! Historical Volatility (HV), annualized close-to-close volatility.
+//!
+//! Sample standard deviation of the log returns within a period, scaled to a yearly figure.
+//! Common annualization values are 252 (daily, trading days), 365 (daily, calendar days), and
+//! 252 * 390 (minutes of a trading day).
+//!
+//! # Formula
+//!
+//! HV = SD(ln(x / y)) * sqrt(a)
+//!
+//! where:
+//!
+//! * `x` = current price (most recent)
+//! * `y` = prior price.
+//! * `a` = periods per year (annualization).
+
+use super::seed::{self, Reseed, Seed};
+use super::ReturnKind;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Historical Volatility (HV), annualized close-to-close volatility.
+///
+/// Sample standard deviation of the log returns within a period, scaled to a yearly figure.
+/// Common annualization values are 252 (daily, trading days), 365 (daily, calendar days), and
+/// 252 * 390 (minutes of a trading day).
+///
+/// # Formula
+///
+/// HV = SD(ln(x / y)) * sqrt(a)
+///
+/// where:
+///
+/// * `x` = current price (most recent)
+/// * `y` = prior price.
+/// * `a` = periods per year (annualization).
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct HistoricalVolatility {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// HV's current value.
+    value: Num,
+    /// Periods per year used to annualize.
+    annualization: Num,
+    /// Last price received.
+    last: Num,
+    /// Log returns within the period.
+    returns: Buffer,
+    /// Holds `period` amount of generated HVs.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl HistoricalVolatility {
+    /// Creates a new HV with the supplied period, annualization, and initial prices.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Annualization must be finite and greater than 0.
+    /// * Data must have at least `period + 1` elements.
+    /// * All prices must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    /// * `data` - Array of prices to create the HV from.
+    pub fn new(period: usize, annualization: Num, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate Historical Volatility.
+        if period < 2 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 2 to calculate historical volatility",
+            )));
+        }
+
+        TAError::check_annualization(annualization, "historical volatility")?;
+        if data.len() < period + 1 {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "historical volatility",
+                required: period + 1,
+                received: data.len(),
+            });
+        } else if data.iter().any(|v| *v <= 0.0) {
+            return Err(TAError::InvalidInput(String::from(
+                "prices must be greater than 0 to calculate historical volatility",
+            )));
+        }
+
+        // Log returns of the first period, used as the seed value.
+        let returns: Vec<Num> = data[..=period]
+            .windows(2)
+            .map(|w| ReturnKind::Log.calculate(w[0], w[1]))
+            .collect();
+        let returns = Buffer::from_array(period, &returns)?;
+        let value = returns.stdev(true) * annualization.sqrt();
+
+        let mut hv = Self {
+            period,
+            value,
+            annualization,
+            last: data[period],
+            returns,
+            buffer: Buffer::from_array(period, &[value])?,
+            seed: Seed::new(period + 1),
+        };
+
+        // Calculate the remainder of the HVs.
+        for v in data[(period + 1)..].iter() {
+            hv.next(*v);
+        }
+
+        Ok(hv)
+    }
+
+    /// Creates a new HV with the supplied period and annualization, without initial data. The
+    /// prices are supplied with `next` instead, until ready the value is calculated from the prices
+    /// supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Annualization must be finite and greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    pub fn with_period(period: usize, annualization: Num) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            annualization,
+            &seed::placeholder(1.0, period + 1),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Standard deviation of the log returns within the period, before annualization.
+    pub fn period_volatility(&self) -> Num {
+        self.returns.stdev(true)
+    }
+
+    /// Periods per year used to annualize.
+    pub fn annualization(&self) -> Num {
+        self.annualization
+    }
+}
+
+impl Next<Num> for HistoricalVolatility {
+    /// Next value for the HV.
+    type Output = Num;
+
+    /// Supply an additional price to recalculate a new HV. Prices that are 0 or lower have no log
+    /// return, they are ignored and the current value is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New price to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if value <= 0.0 {
+            self.seed.skip();
+            return self.value;
+        }
+
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.returns
+            .shift(ReturnKind::Log.calculate(self.last, value));
+        self.last = value;
+        self.value = self.period_volatility() * self.annualization.sqrt();
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl<T> Next<T> for HistoricalVolatility
+where
+    T: AsValue,
+{
+    /// Next value for the HV.
+    type Output = Num;
+
+    /// Supply an additional price to recalculate a new HV.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New price to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for HistoricalVolatility {
+    /// Clears the HV back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.last = 0.0;
+        self.returns.clear();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for HistoricalVolatility {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for HistoricalVolatility {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period + 1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, self.annualization, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len() - 1, self.annualization, data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for HistoricalVolatility {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("HV")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.annualization)
+            .scalar(self.last)
+            .child(&self.returns)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "HV")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            annualization: reader.scalar()?,
+            last: reader.scalar()?,
+            returns: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,292 @@
+//! Holt Smoothing, double exponential smoothing of a level and a trend.
+//!
+//! The level is smoothed towards the newest value and the trend towards the change in the level,
+//! allowing values to be forecast with the trend. Higher alpha and beta react faster to changes
+//! of the level and trend. The level and trend are seeded from the first two values.
+//!
+//! # Formula
+//!
+//! L = α * x + (1 - α) * (y + z)
+//!
+//! T = β * (L - y) + (1 - β) * z
+//!
+//! Forecast = L + h * T
+//!
+//! where:
+//!
+//! * `x` = current value (most recent)
+//! * `y` = prior level
+//! * `z` = prior trend
+//! * `α` = alpha, smoothing of the level.
+//! * `β` = beta, smoothing of the trend.
+//! * `h` = values ahead to forecast.
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Holt Smoothing, double exponential smoothing of a level and a trend.
+///
+/// The level is smoothed towards the newest value and the trend towards the change in the level,
+/// allowing values to be forecast with the trend. Higher alpha and beta react faster to changes
+/// of the level and trend. The level and trend are seeded from the first two values.
+///
+/// # Formula
+///
+/// L = α * x + (1 - α) * (y + z)
+///
+/// T = β * (L - y) + (1 - β) * z
+///
+/// Forecast = L + h * T
+///
+/// where:
+///
+/// * `x` = current value (most recent)
+/// * `y` = prior level
+/// * `z` = prior trend
+/// * `α` = alpha, smoothing of the level.
+/// * `β` = beta, smoothing of the trend.
+/// * `h` = values ahead to forecast.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct HoltSmoothing {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Current level.
+    value: Num,
+    /// Current trend.
+    trend: Num,
+    /// Smoothing of the level.
+    alpha: Num,
+    /// Smoothing of the trend.
+    beta: Num,
+    /// Holds `period` amount of generated levels.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl HoltSmoothing {
+    /// Creates a new Holt Smoothing with the supplied period, smoothing, and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Alpha and beta must be greater than 0 and less than or equal to 1.
+    /// * Data must have at least `period` elements and at least 2 elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Amount of levels kept for statistics.
+    /// * `alpha` - Smoothing of the level.
+    /// * `beta` - Smoothing of the trend.
+    /// * `data` - Array of values to create the Holt Smoothing from.
+    pub fn new(period: usize, alpha: Num, beta: Num, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate Holt Smoothing.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate holt smoothing",
+            )));
+        } else if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(TAError::InvalidSize(String::from(
+                "alpha must be greater than 0 and at most 1 to calculate holt smoothing",
+            )));
+        } else if !(beta > 0.0 && beta <= 1.0) {
+            return Err(TAError::InvalidSize(String::from(
+                "beta must be greater than 0 and at most 1 to calculate holt smoothing",
+            )));
+        } else if data.len() < period || data.len() < 2 {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "holt smoothing",
+                required: period.max(2),
+                received: data.len(),
+            });
+        }
+
+        // Seed the level and trend with the first two values.
+        let mut holt = Self {
+            period,
+            value: data[1],
+            trend: data[1] - data[0],
+            alpha,
+            beta,
+            buffer: Buffer::from_array(period, &[data[0], data[1]])?,
+            seed: Seed::new(2),
+        };
+
+        // Calculate the remainder of the levels.
+        for v in data[2..].iter() {
+            holt.next(*v);
+        }
+
+        Ok(holt)
+    }
+
+    /// Creates a new Holt Smoothing with the supplied period and smoothing, without initial data.
+    /// The values are supplied with `next` instead, until ready the value is calculated from the
+    /// values supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Alpha and beta must be greater than 0 and less than or equal to 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Amount of levels kept for statistics.
+    /// * `alpha` - Smoothing of the level.
+    /// * `beta` - Smoothing of the trend.
+    pub fn with_period(period: usize, alpha: Num, beta: Num) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            alpha,
+            beta,
+            &seed::placeholder(1.0, period.max(2)),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Current trend, the smoothed change of the level per value.
+    pub fn trend(&self) -> Num {
+        self.trend
+    }
+
+    /// Smoothing of the level.
+    pub fn alpha(&self) -> Num {
+        self.alpha
+    }
+
+    /// Smoothing of the trend.
+    pub fn beta(&self) -> Num {
+        self.beta
+    }
+
+    /// Predicts (forecasts) a future value `distance` away from the current.
+    ///
+    /// # Arguments
+    ///
+    /// * `distance` - How far in the future to predict.
+    pub fn forecast(&self, distance: usize) -> Num {
+        self.value + self.trend * distance as Num
+    }
+}
+
+impl Next<Num> for HoltSmoothing {
+    /// Next value for the Holt Smoothing.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate the level and trend.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        let last = self.value;
+        self.value = self.alpha * value + (1.0 - self.alpha) * (last + self.trend);
+        self.trend = self.beta * (self.value - last) + (1.0 - self.beta) * self.trend;
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl<T> Next<T> for HoltSmoothing
+where
+    T: AsValue,
+{
+    /// Next value for the Holt Smoothing.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate the level and trend.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for HoltSmoothing {
+    /// Clears the Holt Smoothing back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.trend = 0.0;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for HoltSmoothing {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for HoltSmoothing {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period.max(2)
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, self.alpha, self.beta, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), self.alpha, self.beta, data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for HoltSmoothing {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Holt Smoothing")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.trend)
+            .scalar(self.alpha)
+            .scalar(self.beta)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Holt Smoothing")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            trend: reader.scalar()?,
+            alpha: reader.scalar()?,
+            beta: reader.scalar()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,273 @@
+//! Indicator Set, a collection of named indicators supplied with the same candles.
+//!
+//! Indicators consuming candles are supplied the candle, indicators consuming single values are
+//! supplied a price from the candle, the close by default. The value of each indicator is `None`
+//! until it is ready.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::traits::{CandleIndicator, Indicator, Next, Ohlcv, Ready, Reset};
+use crate::{Num, TAError};
+
+/// Price taken from a candle for the indicators consuming single values.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriceSource {
+    /// Opening value.
+    Open,
+    /// Highest value.
+    High,
+    /// Lowest value.
+    Low,
+    /// Closing value.
+    #[default]
+    Close,
+    /// Average of the high and low.
+    Hl2,
+    /// Average of the high, low, and close.
+    Hlc3,
+    /// Average of the open, high, low, and close.
+    Ohlc4,
+}
+
+impl PriceSource {
+    /// Price of the candle.
+    ///
+    /// # Arguments
+    ///
+    /// * `candle` - Candle to take the price from.
+    pub fn price<T: Ohlcv + ?Sized>(self, candle: &T) -> Num {
+        match self {
+            PriceSource::Open => candle.open(),
+            PriceSource::High => candle.high(),
+            PriceSource::Low => candle.low(),
+            PriceSource::Close => candle.close(),
+            PriceSource::Hl2 => (candle.high() + candle.low()) / 2.0,
+            PriceSource::Hlc3 => (candle.high() + candle.low() + candle.close()) / 3.0,
+            PriceSource::Ohlc4 => {
+                (candle.open() + candle.high() + candle.low() + candle.close()) / 4.0
+            }
+        }
+    }
+}
+
+/// Indicator consuming single values held by the set.
+trait ValueMember: Indicator + Ready + Reset {}
+
+impl<T> ValueMember for T where T: Indicator + Ready + Reset {}
+
+/// Indicator consuming candles held by the set.
+trait CandleMember: CandleIndicator + Ready + Reset {}
+
+impl<T> CandleMember for T where T: CandleIndicator + Ready + Reset {}
+
+/// Indicator held by the set, by what it consumes.
+enum Member {
+    /// Supplied the price of the candle.
+    Value(Box<dyn ValueMember>),
+    /// Supplied the candle.
+    Candle(Box<dyn CandleMember>),
+}
+
+impl Member {
+    /// Current value, `None` until ready.
+    fn value(&self) -> Option<Num> {
+        match self {
+            Member::Value(indicator) => indicator.is_ready().then(|| indicator.value()),
+            Member::Candle(indicator) => indicator.is_ready().then(|| indicator.value()),
+        }
+    }
+}
+
+/// Indicator Set, a collection of named indicators supplied with the same candles.
+///
+/// Indicators consuming candles are supplied the candle, indicators consuming single values are
+/// supplied a price from the candle, the close by default. The value of each indicator is `None`
+/// until it is ready. Indicators are best created without data, so they are all supplied the
+/// same candles.
+pub struct IndicatorSet {
+    /// Price supplied to the indicators consuming single values.
+    source: PriceSource,
+    /// Names and indicators, in the order they were added.
+    members: Vec<(&'static str, Member)>,
+    /// Amount of candles supplied since created or reset.
+    seen: usize,
+}
+
+impl IndicatorSet {
+    /// Creates a new empty Indicator Set, supplying the price from the source to the indicators
+    /// consuming single values.
+    ///
+    /// ## Arguments
+    ///
+    /// * `source` - Price supplied to the indicators consuming single values. default `Close`
+    pub fn new(source: PriceSource) -> Self {
+        Self {
+            source,
+            members: Vec::new(),
+            seen: 0,
+        }
+    }
+
+    /// Adds an indicator consuming single values, supplied the price of every candle.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Name must not already be in the set.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` - Name the value of the indicator is found under.
+    /// * `indicator` - Indicator to add.
+    pub fn add<I>(&mut self, name: &'static str, indicator: I) -> Result<&mut Self, TAError>
+    where
+        I: Indicator + Ready + Reset + 'static,
+    {
+        self.insert(name, Member::Value(Box::new(indicator)))
+    }
+
+    /// Adds an indicator consuming candles, supplied every candle.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Name must not already be in the set.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` - Name the value of the indicator is found under.
+    /// * `indicator` - Indicator to add.
+    pub fn add_candle<I>(&mut self, name: &'static str, indicator: I) -> Result<&mut Self, TAError>
+    where
+        I: CandleIndicator + Ready + Reset + 'static,
+    {
+        self.insert(name, Member::Candle(Box::new(indicator)))
+    }
+
+    /// Price supplied to the indicators consuming single values.
+    pub fn source(&self) -> PriceSource {
+        self.source
+    }
+
+    /// Names of the indicators, in the order they were added.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.members.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// Amount of indicators in the set.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Checks if the set has no indicators.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Current value of the named indicator, `None` if it is not ready or not in the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the indicator.
+    pub fn value(&self, name: &str) -> Option<Num> {
+        self.members
+            .iter()
+            .find(|(n, _)| *n == name)
+            .and_then(|(_, member)| member.value())
+    }
+
+    /// Current values of every indicator by name, `None` for those not ready.
+    pub fn values(&self) -> HashMap<&'static str, Option<Num>> {
+        self.members
+            .iter()
+            .map(|(name, member)| (*name, member.value()))
+            .collect()
+    }
+
+    /// Adds an indicator if the name is not already in the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name the value of the indicator is found under.
+    /// * `member` - Indicator to add.
+    fn insert(&mut self, name: &'static str, member: Member) -> Result<&mut Self, TAError> {
+        if self.members.iter().any(|(n, _)| *n == name) {
+            return Err(TAError::InvalidInput(format!(
+                "indicator named {} is already in the set",
+                name
+            )));
+        }
+
+        self.members.push((name, member));
+        Ok(self)
+    }
+}
+
+impl Default for IndicatorSet {
+    /// Creates a new empty Indicator Set supplying the close.
+    fn default() -> Self {
+        Self::new(PriceSource::default())
+    }
+}
+
+impl fmt::Debug for IndicatorSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IndicatorSet")
+            .field("source", &self.source)
+            .field("names", &self.names())
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl<T> Next<&T> for IndicatorSet
+where
+    T: Ohlcv,
+{
+    /// Values of every indicator by name, `None` for those not ready.
+    type Output = HashMap<&'static str, Option<Num>>;
+
+    /// Supply an additional candle to every indicator.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        self.seen += 1;
+        let price = self.source.price(value);
+        for (_, member) in self.members.iter_mut() {
+            match member {
+                Member::Value(indicator) => indicator.update(price),
+                Member::Candle(indicator) => indicator.update(value),
+            }
+        }
+
+        self.values()
+    }
+}
+
+impl Reset for IndicatorSet {
+    /// Clears every indicator back to having seen no data.
+    fn reset(&mut self) {
+        self.seen = 0;
+        for (_, member) in self.members.iter_mut() {
+            match member {
+                Member::Value(indicator) => indicator.reset(),
+                Member::Candle(indicator) => indicator.reset(),
+            }
+        }
+    }
+}
+
+impl Ready for IndicatorSet {
+    /// Checks if every indicator is ready.
+    fn is_ready(&self) -> bool {
+        self.members
+            .iter()
+            .all(|(_, member)| member.value().is_some())
+    }
+
+    /// Amount of candles supplied since created or reset.
+    fn samples_seen(&self) -> usize {
+        self.seen
+    }
+}
@@ -0,0 +1,214 @@
+//! Inside Bar, finds candles contained within the prior candle and tracks the breakout.
+//!
+//! An inside bar has a range within the prior candle (the mother bar), an outside bar has a range
+//! covering the prior candle. After an inside bar the range of the mother bar is tracked until a
+//! candle closes beyond it. Consecutive inside bars keep the original mother bar.
+
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{Close, High, Low, Next, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+
+/// Used for conversions. Holds High (0), Low (1), and Close (2) values.
+#[derive(Copy, Clone, PartialEq)]
+struct Data(Num, Num, Num);
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.2
+    }
+}
+
+/// Relation of a candle to the prior candle or the mother bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarSignal {
+    /// Nothing of interest.
+    None,
+    /// Range is within the prior candle.
+    Inside,
+    /// Range covers the prior candle.
+    Outside,
+    /// Closed above the mother bar.
+    BreakoutUp,
+    /// Closed below the mother bar.
+    BreakoutDown,
+}
+
+impl Variants for BarSignal {
+    const ALL: &'static [Self] = &[
+        BarSignal::None,
+        BarSignal::Inside,
+        BarSignal::Outside,
+        BarSignal::BreakoutUp,
+        BarSignal::BreakoutDown,
+    ];
+}
+
+/// Inside Bar, finds candles contained within the prior candle and tracks the breakout.
+///
+/// An inside bar has a range within the prior candle (the mother bar), an outside bar has a range
+/// covering the prior candle. After an inside bar the range of the mother bar is tracked until a
+/// candle closes beyond it. Consecutive inside bars keep the original mother bar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsideBar {
+    /// High and low of the prior candle.
+    prior: Option<(Num, Num)>,
+    /// High and low of the mother bar being tracked.
+    mother: Option<(Num, Num)>,
+    /// Signal of the last candle.
+    signal: BarSignal,
+}
+
+impl Default for InsideBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InsideBar {
+    /// Creates a new Inside Bar without any prior candle.
+    pub fn new() -> Self {
+        Self {
+            prior: None,
+            mother: None,
+            signal: BarSignal::None,
+        }
+    }
+
+    /// Signal of the last candle.
+    pub fn signal(&self) -> BarSignal {
+        self.signal
+    }
+
+    /// High (0) and low (1) of the mother bar waiting for a breakout.
+    pub fn mother_bar(&self) -> Option<(Num, Num)> {
+        self.mother
+    }
+
+    /// Relation of a candle to the prior candle, breakouts are not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `prior` - High (0) and low (1) of the prior candle.
+    /// * `high` - High of the candle.
+    /// * `low` - Low of the candle.
+    fn relation(prior: (Num, Num), high: Num, low: Num) -> BarSignal {
+        let (p_high, p_low) = prior;
+        if high == p_high && low == p_low {
+            BarSignal::None
+        } else if high <= p_high && low >= p_low {
+            BarSignal::Inside
+        } else if high >= p_high && low <= p_low {
+            BarSignal::Outside
+        } else {
+            BarSignal::None
+        }
+    }
+}
+
+impl<T> Next<&T> for InsideBar
+where
+    T: High + Low + Close,
+{
+    /// Signal of the candle.
+    type Output = BarSignal;
+
+    /// Supply an additional candle to compare against the prior candle and mother bar. A close
+    /// beyond the mother bar is a breakout and stops tracking it.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to check.
+    fn next(&mut self, value: &T) -> Self::Output {
+        let (high, low, close) = (value.high(), value.low(), value.close());
+        let prior = self.prior.replace((high, low));
+
+        // Closing beyond the mother bar ends the tracking.
+        self.signal = match self.mother {
+            Some((m_high, _)) if close > m_high => BarSignal::BreakoutUp,
+            Some((_, m_low)) if close < m_low => BarSignal::BreakoutDown,
+            _ => match prior {
+                Some(p) => Self::relation(p, high, low),
+                None => BarSignal::None,
+            },
+        };
+
+        match self.signal {
+            BarSignal::BreakoutUp | BarSignal::BreakoutDown => self.mother = None,
+            BarSignal::Inside if self.mother.is_none() => self.mother = prior,
+            _ => (),
+        }
+
+        self.signal
+    }
+}
+
+impl Next<(Num, Num, Num)> for InsideBar {
+    /// Signal of the candle.
+    type Output = BarSignal;
+
+    /// Supply an additional value to compare against the prior candle and mother bar.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to check.
+    ///     * 0 = High
+    ///     * 1 = Low
+    ///     * 2 = Close
+    fn next(&mut self, value: (Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2))
+    }
+}
+
+impl Reset for InsideBar {
+    /// Clears the prior and mother candles back to having seen no data.
+    fn reset(&mut self) {
+        self.prior = None;
+        self.mother = None;
+        self.signal = BarSignal::None;
+    }
+}
+
+impl Snapshot for InsideBar {
+    fn snapshot(&self) -> IndicatorState {
+        let (prior, mother) = (
+            self.prior.unwrap_or_default(),
+            self.mother.unwrap_or_default(),
+        );
+        IndicatorState::new("Inside Bar")
+            .flag(self.prior.is_some())
+            .scalar(prior.0)
+            .scalar(prior.1)
+            .flag(self.mother.is_some())
+            .scalar(mother.0)
+            .scalar(mother.1)
+            .variant(self.signal)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Inside Bar")?;
+        let is_prior = reader.flag()?;
+        let prior = (reader.scalar()?, reader.scalar()?);
+        let is_mother = reader.flag()?;
+        let mother = (reader.scalar()?, reader.scalar()?);
+        Ok(Self {
+            prior: is_prior.then_some(prior),
+            mother: is_mother.then_some(mother),
+            signal: reader.variant()?,
+        })
+    }
+}
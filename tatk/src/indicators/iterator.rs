@@ -0,0 +1,195 @@
+//! Iterator adapters supplying each item of an iterator to an indicator.
+//!
+//! Outputs are produced lazily as the iterator is advanced, `None` until the indicator is ready.
+//! The warm-up is skipped by flattening the outputs.
+
+use super::{
+    AverageTrueRange, DoubleExponentialMovingAverage, ExponentialMovingAverage, OnBalanceVolume,
+    RateOfChange, RelativeStrengthIndex, SimpleMovingAverage, SmoothedMovingAverage, TrueRange,
+    WeightedMovingAverage,
+};
+use crate::traits::{Close, High, Low, Next, Ready, Volume};
+use crate::{Num, TAError};
+
+/// Iterator supplying each item to an indicator, producing its output once ready.
+#[derive(Debug, Clone)]
+pub struct Apply<It, I> {
+    /// Items supplied to the indicator.
+    iter: It,
+    /// Indicator the items are supplied to.
+    indicator: I,
+}
+
+impl<It, I> Apply<It, I> {
+    /// Indicator the items are supplied to.
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+
+    /// Stops supplying items, returning the indicator.
+    pub fn into_indicator(self) -> I {
+        self.indicator
+    }
+}
+
+impl<It, I> Iterator for Apply<It, I>
+where
+    It: Iterator,
+    I: Next<It::Item> + Ready,
+{
+    /// Output of the indicator, `None` until ready.
+    type Item = Option<I::Output>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let output = self.indicator.next(self.iter.next()?);
+        Some(self.indicator.is_ready().then_some(output))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Extension of iterators to supply their items to an indicator, such as
+/// `prices.iter().copied().sma(20)` or `candles.iter().atr(14)`. Each output is `None` until the
+/// indicator is ready, use `flatten` to skip them.
+pub trait IndicatorIteratorExt: Iterator + Sized {
+    /// Supplies each item to the indicator. Use an indicator created without initial data for the
+    /// outputs to be aligned with the items.
+    ///
+    /// # Arguments
+    ///
+    /// * `indicator` - Indicator the items are supplied to.
+    fn apply<I>(self, indicator: I) -> Apply<Self, I>
+    where
+        I: Next<Self::Item> + Ready,
+    {
+        Apply {
+            iter: self,
+            indicator,
+        }
+    }
+
+    /// Simple Moving Average (SMA) of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn sma(self, period: usize) -> Result<Apply<Self, SimpleMovingAverage>, TAError>
+    where
+        Self: Iterator<Item = Num>,
+    {
+        Ok(self.apply(SimpleMovingAverage::with_period(period)?))
+    }
+
+    /// Exponential Moving Average (EMA) of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn ema(self, period: usize) -> Result<Apply<Self, ExponentialMovingAverage>, TAError>
+    where
+        Self: Iterator<Item = Num>,
+    {
+        Ok(self.apply(ExponentialMovingAverage::with_period(period)?))
+    }
+
+    /// Double Exponential Moving Average (DEMA) of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn dema(self, period: usize) -> Result<Apply<Self, DoubleExponentialMovingAverage>, TAError>
+    where
+        Self: Iterator<Item = Num>,
+    {
+        Ok(self.apply(DoubleExponentialMovingAverage::with_period(period)?))
+    }
+
+    /// Smoothed Moving Average (SMMA) of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn smma(self, period: usize) -> Result<Apply<Self, SmoothedMovingAverage>, TAError>
+    where
+        Self: Iterator<Item = Num>,
+    {
+        Ok(self.apply(SmoothedMovingAverage::with_period(period)?))
+    }
+
+    /// Weighted Moving Average (WMA) of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn wma(self, period: usize) -> Result<Apply<Self, WeightedMovingAverage>, TAError>
+    where
+        Self: Iterator<Item = Num>,
+    {
+        Ok(self.apply(WeightedMovingAverage::with_period(period)?))
+    }
+
+    /// Relative Strength Index (RSI) of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn rsi(self, period: usize) -> Result<Apply<Self, RelativeStrengthIndex>, TAError>
+    where
+        Self: Iterator<Item = Num>,
+    {
+        Ok(self.apply(RelativeStrengthIndex::with_period(period)?))
+    }
+
+    /// Rate of Change (ROC) of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn roc(self, period: usize) -> Result<Apply<Self, RateOfChange>, TAError>
+    where
+        Self: Iterator<Item = Num>,
+    {
+        Ok(self.apply(RateOfChange::with_period(period)?))
+    }
+
+    /// True Range (TR) of the candles.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn tr<'a, T>(self, period: usize) -> Result<Apply<Self, TrueRange>, TAError>
+    where
+        Self: Iterator<Item = &'a T>,
+        T: High + Low + Close + 'a,
+    {
+        Ok(self.apply(TrueRange::with_period(period)?))
+    }
+
+    /// Average True Range (ATR) of the candles.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn atr(self, period: usize) -> Result<Apply<Self, AverageTrueRange>, TAError>
+    where
+        Self::Item: High + Low + Close,
+    {
+        Ok(self.apply(AverageTrueRange::with_period(period)?))
+    }
+
+    /// On-Balance Volume (OBV) of the candles.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn obv(self, period: usize) -> Result<Apply<Self, OnBalanceVolume>, TAError>
+    where
+        Self::Item: Close + Volume,
+    {
+        Ok(self.apply(OnBalanceVolume::with_period(period)?))
+    }
+}
+
+impl<It> IndicatorIteratorExt for It where It: Iterator {}
@@ -0,0 +1,364 @@
+//! Kalman Filter, a one-dimensional smoother estimating the underlying value of a noisy line.
+//!
+//! Each value is predicted from the prior estimate then corrected by the new measurement,
+//! weighing the two by their uncertainty. Process noise is how much the true value is expected
+//! to move between values, measurement noise is how noisy the values supplied are. A lower
+//! process noise relative to the measurement noise produces a smoother line.
+//!
+//! The level model assumes the true value is constant between values. The velocity model also
+//! estimates the rate of change, tracking trends with less lag.
+//!
+//! # Formula
+//!
+//! K = P / (P + R)
+//!
+//! x = x + K * (z - x)
+//!
+//! where:
+//!
+//! * `x` = predicted estimate, prior estimate for level or prior estimate plus velocity.
+//! * `z` = current value (most recent)
+//! * `P` = predicted error covariance, prior covariance plus the process noise.
+//! * `R` = measurement noise.
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Model of how the true value moves between values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KalmanModel {
+    /// True value is constant, only noise changes it.
+    Level,
+    /// True value moves at a velocity that is also estimated.
+    Velocity,
+}
+
+impl Variants for KalmanModel {
+    const ALL: &'static [Self] = &[KalmanModel::Level, KalmanModel::Velocity];
+}
+
+/// Kalman Filter, a one-dimensional smoother estimating the underlying value of a noisy line.
+///
+/// Each value is predicted from the prior estimate then corrected by the new measurement,
+/// weighing the two by their uncertainty. Process noise is how much the true value is expected
+/// to move between values, measurement noise is how noisy the values supplied are. A lower
+/// process noise relative to the measurement noise produces a smoother line.
+///
+/// The level model assumes the true value is constant between values. The velocity model also
+/// estimates the rate of change, tracking trends with less lag.
+///
+/// # Formula
+///
+/// K = P / (P + R)
+///
+/// x = x + K * (z - x)
+///
+/// where:
+///
+/// * `x` = predicted estimate, prior estimate for level or prior estimate plus velocity.
+/// * `z` = current value (most recent)
+/// * `P` = predicted error covariance, prior covariance plus the process noise.
+/// * `R` = measurement noise.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct KalmanFilter {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Current estimate.
+    value: Num,
+    /// Model of how the true value moves.
+    model: KalmanModel,
+    /// Expected movement of the true value between values.
+    process_noise: Num,
+    /// Expected noise of the values supplied.
+    measurement_noise: Num,
+    /// Estimated velocity, always 0 for the level model.
+    velocity: Num,
+    /// Error covariance of the estimate (0), between the estimate and velocity (1), and of the
+    /// velocity (2).
+    covariance: (Num, Num, Num),
+    /// Holds `period` amount of generated estimates.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl KalmanFilter {
+    /// Creates a new Kalman Filter with the supplied period, model, noise, and initial data. The
+    /// first value is used as the initial estimate.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Process and measurement noise must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Amount of estimates kept for statistics.
+    /// * `model` - Model of how the true value moves.
+    /// * `process_noise` - Expected movement of the true value between values.
+    /// * `measurement_noise` - Expected noise of the values supplied.
+    /// * `data` - Array of values to create the Kalman Filter from.
+    pub fn new(
+        period: usize,
+        model: KalmanModel,
+        process_noise: Num,
+        measurement_noise: Num,
+        data: &[Num],
+    ) -> Result<Self, TAError> {
+        // Check we can calculate Kalman Filter.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate kalman filter",
+            )));
+        } else if process_noise.is_nan() || process_noise <= 0.0 {
+            return Err(TAError::InvalidSize(String::from(
+                "process noise must be greater than 0 to calculate kalman filter",
+            )));
+        } else if measurement_noise.is_nan() || measurement_noise <= 0.0 {
+            return Err(TAError::InvalidSize(String::from(
+                "measurement noise must be greater than 0 to calculate kalman filter",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "kalman filter",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Initial estimate is as uncertain as a measurement.
+        let covariance = match model {
+            KalmanModel::Level => (measurement_noise, 0.0, 0.0),
+            KalmanModel::Velocity => (measurement_noise, 0.0, measurement_noise),
+        };
+
+        let mut kf = Self {
+            period,
+            value: data[0],
+            model,
+            process_noise,
+            measurement_noise,
+            velocity: 0.0,
+            covariance,
+            buffer: Buffer::from_array(period, &[data[0]])?,
+            seed: Seed::new(1),
+        };
+
+        // Calculate the remainder of the estimates.
+        for v in data[1..].iter() {
+            kf.next(*v);
+        }
+
+        Ok(kf)
+    }
+
+    /// Creates a new Kalman Filter with the supplied period, model, and noise, without initial
+    /// data. The values are supplied with `next` instead, the value is 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Process and measurement noise must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Amount of estimates kept for statistics.
+    /// * `model` - Model of how the true value moves.
+    /// * `process_noise` - Expected movement of the true value between values.
+    /// * `measurement_noise` - Expected noise of the values supplied.
+    pub fn with_period(
+        period: usize,
+        model: KalmanModel,
+        process_noise: Num,
+        measurement_noise: Num,
+    ) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            model,
+            process_noise,
+            measurement_noise,
+            &seed::placeholder(1.0, period),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Model of how the true value moves.
+    pub fn model(&self) -> KalmanModel {
+        self.model
+    }
+
+    /// Estimated velocity, the change per value. Always 0 for the level model.
+    pub fn velocity(&self) -> Num {
+        self.velocity
+    }
+
+    /// Error covariance of the current estimate, lower is more certain.
+    pub fn covariance(&self) -> Num {
+        self.covariance.0
+    }
+
+    /// Predicts the estimate and covariance, then corrects them with the measurement.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Measurement to correct the prediction with.
+    fn update(&mut self, value: Num) {
+        let (p00, p01, p11) = self.covariance;
+        let q = self.process_noise;
+
+        match self.model {
+            KalmanModel::Level => {
+                // Predict.
+                let p = p00 + q;
+
+                // Correct.
+                let gain = p / (p + self.measurement_noise);
+                self.value += gain * (value - self.value);
+                self.covariance = ((1.0 - gain) * p, 0.0, 0.0);
+            }
+            KalmanModel::Velocity => {
+                // Predict, process noise is applied as a random acceleration.
+                let x = self.value + self.velocity;
+                let p00 = p00 + 2.0 * p01 + p11 + q / 3.0;
+                let p01 = p01 + p11 + q / 2.0;
+                let p11 = p11 + q;
+
+                // Correct.
+                let s = p00 + self.measurement_noise;
+                let (k0, k1) = (p00 / s, p01 / s);
+                let residual = value - x;
+                self.value = x + k0 * residual;
+                self.velocity += k1 * residual;
+                self.covariance = ((1.0 - k0) * p00, (1.0 - k0) * p01, p11 - k1 * p01);
+            }
+        }
+    }
+}
+
+impl Next<Num> for KalmanFilter {
+    /// Next value for the Kalman Filter.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate the filtered estimate.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.update(value);
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl<T> Next<T> for KalmanFilter
+where
+    T: AsValue,
+{
+    /// Next value for the Kalman Filter.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate the filtered estimate.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for KalmanFilter {
+    /// Clears the Kalman Filter back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.velocity = 0.0;
+        self.covariance = (0.0, 0.0, 0.0);
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for KalmanFilter {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for KalmanFilter {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(
+            self.period,
+            self.model,
+            self.process_noise,
+            self.measurement_noise,
+            data,
+        )
+    }
+}
+
+impl Snapshot for KalmanFilter {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Kalman Filter")
+            .count(self.period)
+            .scalar(self.value)
+            .variant(self.model)
+            .scalar(self.process_noise)
+            .scalar(self.measurement_noise)
+            .scalar(self.velocity)
+            .scalar(self.covariance.0)
+            .scalar(self.covariance.1)
+            .scalar(self.covariance.2)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Kalman Filter")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            model: reader.variant()?,
+            process_noise: reader.scalar()?,
+            measurement_noise: reader.scalar()?,
+            velocity: reader.scalar()?,
+            covariance: (reader.scalar()?, reader.scalar()?, reader.scalar()?),
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,295 @@
+//! Keltner Channels (KC), bands around an EMA a multiple of the Average True Range (ATR) away.
+//!
+//! # Formula
+//!
+//! KC_lower = EMA - (m * ATR)
+//!
+//! KC_upper = EMA + (m * ATR)
+//!
+//! where:
+//!
+//! * `EMA` = exponential moving average of the closes for a period.
+//! * `ATR` = average true range for the same period.
+//! * `m` = multiplier of the ATR, the distance of the bands from the EMA.
+
+use super::seed::{self, Reseed, Seed};
+use super::{AverageTrueRange, BandsOutput, ExponentialMovingAverage};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{Close, High, InternalValue, Low, Next, Period, Ready, Reset, Snapshot, Value};
+use crate::{IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
+
+/// Used for conversions. Holds High (0), Low (1), and Close (2) values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num, Num);
+
+seed::impl_flatten!(Data(0, 1, 2));
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.2
+    }
+}
+
+/// Keltner Channels (KC), bands around an EMA a multiple of the Average True Range (ATR) away.
+///
+/// # Formula
+///
+/// KC_lower = EMA - (m * ATR)
+///
+/// KC_upper = EMA + (m * ATR)
+///
+/// where:
+///
+/// * `EMA` = exponential moving average of the closes for a period.
+/// * `ATR` = average true range for the same period.
+/// * `m` = multiplier of the ATR, the distance of the bands from the EMA.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct KeltnerChannels {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Current middle line, the EMA.
+    value: Num,
+    /// Multiplier of the ATR.
+    multiplier: Num,
+    /// EMA of the closes, the middle line.
+    ema: ExponentialMovingAverage,
+    /// ATR of the candles, the distance of the bands.
+    atr: AverageTrueRange,
+    /// Lower band.
+    lower: Num,
+    /// Upper band.
+    upper: Num,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
+}
+
+impl KeltnerChannels {
+    /// Creates new Keltner Channels with the supplied period, multiplier, and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Multiplier must be greater than 0.
+    /// * Data must have at least `period + 1` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used for the EMA and ATR.
+    /// * `multiplier` - Multiplier of the ATR, distance of the bands from the EMA. default 2.0
+    /// * `data` - Array of candles to create the Keltner Channels from.
+    pub fn new<T>(period: usize, multiplier: Num, data: &[T]) -> Result<Self, TAError>
+    where
+        T: High + Low + Close,
+    {
+        // Check we can calculate Keltner Channels.
+        if multiplier.is_nan() || multiplier <= 0.0 {
+            return Err(TAError::InvalidSize(String::from(
+                "multiplier must be greater than 0 to calculate keltner channels",
+            )));
+        }
+
+        // ATR validates the period and data.
+        let atr = AverageTrueRange::new(period, data)?;
+        let closes: Vec<Num> = data.iter().map(|v| v.close()).collect();
+        let ema = ExponentialMovingAverage::new(period, &closes)?;
+
+        let (lower, upper) = Self::calculate(ema.value(), atr.value(), multiplier);
+        Ok(Self {
+            period,
+            value: ema.value(),
+            multiplier,
+            ema,
+            atr,
+            lower,
+            upper,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates new Keltner Channels with the supplied period and multiplier, without initial data.
+    /// The candles are supplied with `next` instead, the bands are 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Multiplier must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used for the EMA and ATR.
+    /// * `multiplier` - Multiplier of the ATR, distance of the bands from the EMA. default 2.0
+    pub fn with_period(period: usize, multiplier: Num) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            multiplier,
+            &seed::placeholder(Data(1.0, 1.0, 1.0), period + 1),
+        ))
+    }
+
+    /// Current and most recent middle line, the EMA.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Multiplier of the ATR, distance of the bands from the EMA.
+    pub fn multiplier(&self) -> Num {
+        self.multiplier
+    }
+
+    /// Lower band of the channels.
+    pub fn lower(&self) -> Num {
+        self.lower
+    }
+
+    /// Upper band of the channels.
+    pub fn upper(&self) -> Num {
+        self.upper
+    }
+
+    /// Current ATR used for the distance of the bands.
+    pub fn atr(&self) -> Num {
+        self.atr.value()
+    }
+
+    /// Calculates the lower (0) and upper (1) bands.
+    ///
+    /// # Arguments
+    ///
+    /// * `middle` - Middle line, the EMA.
+    /// * `atr` - Current ATR.
+    /// * `multiplier` - Multiplier of the ATR.
+    fn calculate(middle: Num, atr: Num, multiplier: Num) -> (Num, Num) {
+        (middle - multiplier * atr, middle + multiplier * atr)
+    }
+}
+
+impl<T> Next<&T> for KeltnerChannels
+where
+    T: High + Low + Close,
+{
+    /// Lower, Middle, and Upper values.
+    type Output = BandsOutput;
+
+    /// Supply an additional candle to recalculate the Keltner Channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        if !seed::collect(self, Data(value.high(), value.low(), value.close())) {
+            self.value = self.ema.next(value.close());
+            let atr = self.atr.next((value.high(), value.low(), value.close()));
+            (self.lower, self.upper) = Self::calculate(self.value, atr, self.multiplier);
+        }
+
+        BandsOutput {
+            lower: self.lower,
+            middle: self.value,
+            upper: self.upper,
+        }
+    }
+}
+
+impl Next<(Num, Num, Num)> for KeltnerChannels {
+    /// Lower, Middle, and Upper values.
+    type Output = BandsOutput;
+
+    /// Supply an additional value to recalculate the Keltner Channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    ///     * 0 = High
+    ///     * 1 = Low
+    ///     * 2 = Close
+    fn next(&mut self, value: (Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2))
+    }
+}
+
+impl Reset for KeltnerChannels {
+    /// Clears the Keltner Channels back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.lower = 0.0;
+        self.upper = 0.0;
+        self.ema.reset();
+        self.atr.reset();
+        self.seed.start();
+    }
+}
+
+impl Ready for KeltnerChannels {
+    /// Checks if enough candles have been supplied for the bands to be complete, false while
+    /// collecting the initial candles after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of candles supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for KeltnerChannels {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period + 1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, self.multiplier, data)
+    }
+}
+
+impl Snapshot for KeltnerChannels {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("KC")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.multiplier)
+            .scalar(self.lower)
+            .scalar(self.upper)
+            .child(&self.ema)
+            .child(&self.atr)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "KC")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            multiplier: reader.scalar()?,
+            lower: reader.scalar()?,
+            upper: reader.scalar()?,
+            ema: reader.child()?,
+            atr: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
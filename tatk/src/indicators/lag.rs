@@ -0,0 +1,197 @@
+//! Lag, the value from `n` values ago.
+//!
+//! Until `n + 1` values have been supplied the oldest available value is used, `is_ready` reports
+//! when the value is truly `n` values old. Useful to displace other lines or to compare a value
+//! against its own past.
+//!
+//! # Formula
+//!
+//! Lag = x[t - n]
+//!
+//! where:
+//!
+//! * `x[t]` = current value (most recent)
+//! * `n` = period
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
+
+/// Lag, the value from `n` values ago.
+///
+/// Until `n + 1` values have been supplied the oldest available value is used, `is_ready` reports
+/// when the value is truly `n` values old. Useful to displace other lines or to compare a value
+/// against its own past.
+///
+/// # Formula
+///
+/// Lag = x[t - n]
+///
+/// where:
+///
+/// * `x[t]` = current value (most recent)
+/// * `n` = period
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct Lag {
+    /// Amount of values to lag by.
+    period: usize,
+    /// Value from `period` values ago.
+    value: Num,
+    /// Holds the current value and the `period` values before it.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl Lag {
+    /// Creates a new Lag with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least 1 element, `period + 1` to be ready.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Amount of values to lag by.
+    /// * `data` - Array of values to create the Lag from.
+    pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate Lag.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate lag",
+            )));
+        } else if data.is_empty() {
+            return Err(TAError::InvalidData {
+                indicator: "lag",
+                required: 1,
+                received: 0,
+            });
+        }
+
+        let buffer = Buffer::from_array(period + 1, data)?;
+        Ok(Self {
+            period,
+            value: buffer.oldest(),
+            buffer,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new Lag with the supplied period, without initial data. The values are supplied
+    /// with `next` instead, the value is 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Amount of values to lag by.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, 1)))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+}
+
+impl Next<Num> for Lag {
+    /// Next value for the Lag.
+    type Output = Num;
+
+    /// Supply an additional value, obtaining the value from `period` values ago.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.buffer.shift(value);
+        self.value = self.buffer.oldest();
+        self.value
+    }
+}
+
+impl<T> Next<T> for Lag
+where
+    T: AsValue,
+{
+    /// Next value for the Lag.
+    type Output = Num;
+
+    /// Supply an additional value, obtaining the value from `period` values ago.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for Lag {
+    /// Clears the Lag back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for Lag {
+    /// Checks if `period + 1` values have been supplied, the value is then `period` values old.
+    fn is_ready(&self) -> bool {
+        self.buffer.is_ready()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for Lag {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+}
+
+impl Snapshot for Lag {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Lag")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Lag")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
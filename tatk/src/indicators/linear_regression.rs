@@ -1,15 +1,21 @@
 //! Linear Regression (LR / LineReg), creates a best fit line.
 //!
-//! Creates a line that best fits a period of data using the least squares approach.
+//! Creates a line that best fits a period of data using the least squares approach. The sums are
+//! kept incrementally and recalculated from the values once every period to prevent drift.
 
-use crate::traits::{AsValue, InternalValue, Next, Period, Stats};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
 
 /// Linear Regression (LR / LineReg), creates a best fit line.
 ///
-/// Creates a line that best fits a period of data using the least squares approach.
-#[derive(Debug, InternalValue, Period)]
+/// Creates a line that best fits a period of data using the least squares approach. The sums are
+/// kept incrementally and recalculated from the values once every period to prevent drift.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
 pub struct LinearRegression {
     /// Size of the period (window) in which data is looked at.
     period: usize,
@@ -23,10 +29,22 @@ pub struct LinearRegression {
     sum_x: Num,
     /// Sum of the X, squared.
     sum_x_sq: Num,
+    /// Sum of the X multiplied by the Y, X being 1 for the oldest value.
+    sum_xy: Num,
+    /// Values added since the sum of the XY was last recalculated.
+    shifts: usize,
     /// Intercept of the line.
     intercept: Num,
     /// Slope of the line.
     slope: Num,
+    /// Observed minus fitted values for the current period, oldest to newest.
+    residuals: Vec<Num>,
+    /// Sum of Squares Residual for the current period.
+    ssr: Num,
+    /// Sum of Squares Total for the current period.
+    sst: Num,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
 }
 
 impl LinearRegression {
@@ -49,9 +67,11 @@ impl LinearRegression {
             )));
         } else if data.len() < period {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data for period provided",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "linear regression",
+                required: period,
+                received: data.len(),
+            });
         }
 
         // Constants
@@ -59,20 +79,14 @@ impl LinearRegression {
         let sum_x_sq: Num = (period * (period + 1) * (2 * period + 1)) as Num / 6.0;
 
         // Build the buffer containing the `period` of y values.
-        let mut values: Buffer = match Buffer::from_array(period, &data[..period]) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        let mut values: Buffer = Buffer::from_array(period, &data[..period])?;
 
         // Calculate the first value to seed the buffer.
         let (mut intercept, mut slope) = Self::calculate(period, &values, sum_x, sum_x_sq);
         let mut value: Num = intercept + (slope * period as Num);
 
         // Build the buffer to hold old best fit values.
-        let mut buffer: Buffer = match Buffer::from_array(period, &[value]) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        let mut buffer: Buffer = Buffer::from_array(period, &[value])?;
 
         // Calculate the remaining best fit values.
         for y in data[period..].iter() {
@@ -84,16 +98,40 @@ impl LinearRegression {
             buffer.shift(value);
         }
 
-        Ok(Self {
+        let mut lr = Self {
             period,
             value,
+            sum_xy: Self::sum_xy(&values),
             values,
             buffer,
             sum_x,
             sum_x_sq,
+            shifts: 0,
             intercept,
             slope,
-        })
+            residuals: Vec::with_capacity(period),
+            ssr: 0.0,
+            sst: 0.0,
+            seed: Seed::new(data.len()),
+        };
+
+        lr.fit();
+        Ok(lr)
+    }
+
+    /// Creates a new Linear Regression line with the supplied period, without initial data. The
+    /// values are supplied with `next` instead, until ready the value is calculated from the values
+    /// supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period)))
     }
 
     /// Current and most recent value calculated.
@@ -110,18 +148,53 @@ impl LinearRegression {
     /// * `sum_x` - Constant used, represents the sum of the time portion.
     /// * `sum_x_sq` - Constant used, represents the square of the sum of the time portion.
     fn calculate(period: usize, values: &Buffer, sum_x: Num, sum_x_sq: Num) -> (Num, Num) {
-        let sum_y: Num = values.sum();
-        let sum_xy: Num = (1..=period)
-            .zip(values.queue().iter().take(period))
+        Self::line(period, values.sum(), Self::sum_xy(values), sum_x, sum_x_sq)
+    }
+
+    /// Sum of the X multiplied by the Y for the values, X being 1 for the oldest value.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Last `period` of values to fit a line to.
+    fn sum_xy(values: &Buffer) -> Num {
+        (1..=values.capacity())
+            .zip(values.queue().iter())
             .map(|(x, y)| x as Num * y)
-            .sum();
+            .sum()
+    }
 
+    /// Calculates the intercept and slope for the line from the sums of the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `sum_y` - Sum of the values.
+    /// * `sum_xy` - Sum of the time portion multiplied by the values.
+    /// * `sum_x` - Constant used, represents the sum of the time portion.
+    /// * `sum_x_sq` - Constant used, represents the square of the sum of the time portion.
+    fn line(period: usize, sum_y: Num, sum_xy: Num, sum_x: Num, sum_x_sq: Num) -> (Num, Num) {
         // Calculate intercept and slope.
         let period_as: Num = period as Num;
         let slope = (period_as * sum_xy - sum_x * sum_y) / (period_as * sum_x_sq - sum_x * sum_x);
         let intercept = (sum_y - slope * sum_x) / period_as;
 
-        return (intercept, slope);
+        (intercept, slope)
+    }
+
+    /// Calculates the residuals and sums of squares for the current line, cached until the next
+    /// value is supplied.
+    fn fit(&mut self) {
+        let mean_y: Num = self.values.mean();
+        self.residuals.clear();
+        self.ssr = 0.0;
+        self.sst = 0.0;
+
+        for (i, y) in self.values.queue().iter().enumerate() {
+            let residual: Num = y - (self.intercept + self.slope * (i + 1) as Num);
+            self.sst += (y - mean_y).powi(2);
+            self.ssr += residual.powi(2);
+            self.residuals.push(residual);
+        }
     }
 
     /// Predicted value of the dependent variable when all independent variables are set to zero.
@@ -134,22 +207,66 @@ impl LinearRegression {
         self.slope
     }
 
+    /// Angle of the line in degrees, positive when rising. The slope is divided by the scale to
+    /// normalize the price units, such as a tick size or an ATR, before the angle is taken.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - Price units per unit of the angle, `None` or 0 uses the slope unchanged.
+    pub fn slope_degrees(&self, scale: Option<Num>) -> Num {
+        match scale {
+            Some(scale) if scale != 0.0 => (self.slope / scale).atan().to_degrees(),
+            _ => self.slope.atan().to_degrees(),
+        }
+    }
+
+    /// Slope as a percent of the mean of the period, the percent change per value. 0 if the mean
+    /// is 0.
+    pub fn slope_pct(&self) -> Num {
+        let mean = self.values.mean();
+        if mean == 0.0 {
+            return 0.0;
+        }
+
+        self.slope / mean * 100.0
+    }
+
     /// Percentage of variance in the dependent variable that can be explained by the independent variable.
     pub fn r_sq(&self) -> Num {
-        let mean_y: Num = self.values.mean();
-        let predicted_y: Vec<Num> = (1..=self.period())
-            .map(|i| self.intercept() + self.slope() * i as Num)
-            .collect();
+        1.0 - (self.ssr / self.sst)
+    }
 
-        // Sum of Squares Total (sst) and Sum of Squares Residual (ssr).
-        let mut sst: Num = 0.0;
-        let mut ssr: Num = 0.0;
-        for (i, y) in self.values.queue().iter().enumerate() {
-            sst += (y - mean_y).powi(2);
-            ssr += (y - predicted_y[i]).powi(2);
+    /// Observed minus fitted values for the current period, oldest to newest.
+    pub fn residuals(&self) -> Vec<Num> {
+        self.residuals.clone()
+    }
+
+    /// Residuals for the current period without copying them, oldest to newest.
+    pub(crate) fn residual_values(&self) -> &[Num] {
+        &self.residuals
+    }
+
+    /// Sum of Squares Residual for the current period.
+    pub(crate) fn ssr(&self) -> Num {
+        self.ssr
+    }
+
+    /// Mean Squared Error of the residuals, the Sum of Squares Residual divided by the period.
+    pub fn mse(&self) -> Num {
+        self.ssr / self.residuals.len() as Num
+    }
+
+    /// Standard error of the estimate, the typical distance of a value from the line. Two values
+    /// always fit the line exactly, a period of 2 has a standard error of 0.
+    ///
+    /// Standard Error = sqrt(SSR / (n - 2))
+    pub fn standard_error(&self) -> Num {
+        let n = self.residuals.len();
+        if n <= 2 {
+            return 0.0;
         }
 
-        1.0 - (ssr / sst)
+        (self.ssr / (n - 2) as Num).sqrt()
     }
 
     /// Gets the standard deviation for the current line.
@@ -180,12 +297,32 @@ impl Next<Num> for LinearRegression {
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
-        // Rotate the buffer.
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        // Rotate the buffer, every prior value moves one closer to the oldest.
+        let sum_y: Num = self.values.sum();
         self.values.shift(value);
+        self.shifts += 1;
+
+        // Recalculate from the values once per period to remove accumulated error.
+        if self.shifts >= self.period {
+            self.shifts = 0;
+            self.sum_xy = Self::sum_xy(&self.values);
+        } else {
+            self.sum_xy += self.period as Num * value - sum_y;
+        }
 
         // Get the intercept and slope.
-        (self.intercept, self.slope) =
-            Self::calculate(self.period(), &self.values, self.sum_x, self.sum_x_sq);
+        (self.intercept, self.slope) = Self::line(
+            self.period(),
+            self.values.sum(),
+            self.sum_xy,
+            self.sum_x,
+            self.sum_x_sq,
+        );
+        self.fit();
 
         // Calculate the current value.
         self.value = self.intercept() + (self.slope() * self.period() as Num);
@@ -212,32 +349,94 @@ where
     }
 }
 
-impl Stats for LinearRegression {
-    /// Obtains the total sum of the buffer for LR.
-    fn sum(&self) -> Num {
-        self.buffer.sum()
+impl Reset for LinearRegression {
+    /// Clears the LR back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.intercept = 0.0;
+        self.slope = 0.0;
+        self.sum_xy = 0.0;
+        self.shifts = 0;
+        self.residuals.clear();
+        self.ssr = 0.0;
+        self.sst = 0.0;
+        self.values.clear();
+        self.buffer.clear();
+        self.seed.start();
     }
+}
 
-    /// Mean for the period of the LR.
-    fn mean(&self) -> Num {
-        self.buffer.mean()
+impl Ready for LinearRegression {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
     }
 
-    /// Current variance for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn variance(&self, is_sample: bool) -> Num {
-        self.buffer.variance(is_sample)
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
     }
+}
 
-    /// Current standard deviation for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn stdev(&self, is_sample: bool) -> Num {
-        self.buffer.stdev(is_sample)
+impl Reseed for LinearRegression {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for LinearRegression {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("LR")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.sum_x)
+            .scalar(self.sum_x_sq)
+            .scalar(self.sum_xy)
+            .count(self.shifts)
+            .scalar(self.intercept)
+            .scalar(self.slope)
+            .scalar(self.ssr)
+            .scalar(self.sst)
+            .buffer(&self.residuals)
+            .child(&self.values)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "LR")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            sum_x: reader.scalar()?,
+            sum_x_sq: reader.scalar()?,
+            sum_xy: reader.scalar()?,
+            shifts: reader.count()?,
+            intercept: reader.scalar()?,
+            slope: reader.scalar()?,
+            ssr: reader.scalar()?,
+            sst: reader.scalar()?,
+            residuals: reader.buffer()?.to_vec(),
+            values: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
     }
 }
@@ -0,0 +1,309 @@
+//! Linear Regression Channel (LRC), a best fit line with parallel bands.
+//!
+//! # Formula
+//!
+//! LRC_lower = LR - d
+//!
+//! LRC_upper = LR + d
+//!
+//! where:
+//!
+//! * `LR` is the current value (endpoint) of the linear regression line.
+//! * `d` is either `k` standard deviations of the residuals or the largest residual.
+
+use super::seed::{self, Reseed, Seed};
+use super::LinearRegression;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Value};
+use crate::{IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
+
+/// Distance the bands of a Linear Regression Channel are offset from the line.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ChannelWidth {
+    /// Bands are `k` (sample) standard deviations of the residuals away from the line.
+    Deviation(Num),
+    /// Bands are the largest residual within the period away from the line, containing every
+    /// value within the period.
+    MaxResidual,
+}
+
+impl ChannelWidth {
+    /// Position of the variant and the deviations, held in a state.
+    pub(crate) fn to_state(self) -> (usize, Num) {
+        match self {
+            ChannelWidth::Deviation(k) => (0, k),
+            ChannelWidth::MaxResidual => (1, 0.0),
+        }
+    }
+
+    /// Creates the width from the position of the variant and the deviations.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the variant.
+    /// * `k` - Deviations, ignored by the other variants.
+    pub(crate) fn from_state(index: usize, k: Num) -> Result<Self, TAError> {
+        match index {
+            0 => Ok(ChannelWidth::Deviation(k)),
+            1 => Ok(ChannelWidth::MaxResidual),
+            _ => Err(TAError::InvalidState(String::from("unknown channel width"))),
+        }
+    }
+}
+
+/// Linear Regression Channel (LRC), a best fit line with parallel bands.
+///
+/// # Formula
+///
+/// LRC_lower = LR - d
+///
+/// LRC_upper = LR + d
+///
+/// where:
+///
+/// * `LR` is the current value (endpoint) of the linear regression line.
+/// * `d` is either `k` standard deviations of the residuals or the largest residual.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct LinearRegressionChannel {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// LRC's current value, the endpoint of the line.
+    value: Num,
+    /// Line the channel is built around.
+    line: LinearRegression,
+    /// Distance the bands are from the line.
+    width: ChannelWidth,
+    /// Lower bound for the channel.
+    lower: Num,
+    /// Upper bound for the channel.
+    upper: Num,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl LinearRegressionChannel {
+    /// Creates a new Linear Regression Channel with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the LRC from.
+    /// * `width` - Distance of the bands from the line.
+    pub fn new(period: usize, data: &[Num], width: ChannelWidth) -> Result<Self, TAError> {
+        let line = LinearRegression::new(period, data)?;
+
+        // Distance from the line is always positive.
+        let width = match width {
+            ChannelWidth::Deviation(k) => ChannelWidth::Deviation(k.abs()),
+            ChannelWidth::MaxResidual => ChannelWidth::MaxResidual,
+        };
+
+        let value = line.value();
+        let offset = Self::calculate(&line, width);
+
+        Ok(Self {
+            period,
+            value,
+            line,
+            width,
+            lower: value - offset,
+            upper: value + offset,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new Linear Regression Channel with the supplied period and width, without initial
+    /// data. The values are supplied with `next` instead, the value is 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `width` - Distance of the bands from the line.
+    pub fn with_period(period: usize, width: ChannelWidth) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period), width))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Distance the bands are from the line.
+    pub fn width(&self) -> ChannelWidth {
+        self.width
+    }
+
+    /// Lower bound for the channel.
+    pub fn lower(&self) -> Num {
+        self.lower
+    }
+
+    /// Upper bound for the channel.
+    pub fn upper(&self) -> Num {
+        self.upper
+    }
+
+    /// Current endpoints of the channel.
+    ///
+    /// # Returns
+    ///
+    /// * (`Lower`, `Value`, `Upper`)
+    pub fn channel(&self) -> (Num, Num, Num) {
+        (self.lower(), self.value(), self.upper())
+    }
+
+    /// Predicted value of the dependent variable when all independent variables are set to zero.
+    pub fn intercept(&self) -> Num {
+        self.line.intercept()
+    }
+
+    /// Coefficient associated with the independent variable.
+    pub fn slope(&self) -> Num {
+        self.line.slope()
+    }
+
+    /// Calculates the distance of the bands from the line.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - Line the channel is built around.
+    /// * `width` - Method used to determine the distance.
+    fn calculate(line: &LinearRegression, width: ChannelWidth) -> Num {
+        // Distance of each value within the period from the line.
+        let residuals = line.residual_values();
+
+        match width {
+            ChannelWidth::Deviation(k) => (line.ssr() / (residuals.len() - 1) as Num).sqrt() * k,
+            ChannelWidth::MaxResidual => residuals.iter().fold(0.0, |max, r| max.max(r.abs())),
+        }
+    }
+}
+
+impl Next<Num> for LinearRegressionChannel {
+    /// Lower, Value, Upper.
+    type Output = (Num, Num, Num);
+
+    /// Supply an additional value to recalculate a new LRC.
+    ///
+    /// # Returns
+    ///
+    /// * (`Lower`, `Value`, `Upper`)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.channel();
+        }
+
+        // Progress the line by a value.
+        self.value = self.line.next(value);
+
+        let offset = Self::calculate(&self.line, self.width());
+        self.lower = self.value() - offset;
+        self.upper = self.value() + offset;
+        self.channel()
+    }
+}
+
+impl<T> Next<T> for LinearRegressionChannel
+where
+    T: AsValue,
+{
+    /// Lower, Value, Upper.
+    type Output = (Num, Num, Num);
+
+    /// Supply an additional value to recalculate a new LRC.
+    ///
+    /// # Returns
+    ///
+    /// * (`Lower`, `Value`, `Upper`)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for LinearRegressionChannel {
+    /// Clears the LRC back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.line.reset();
+        self.lower = 0.0;
+        self.upper = 0.0;
+        self.seed.start();
+    }
+}
+
+impl Ready for LinearRegressionChannel {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for LinearRegressionChannel {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data, self.width)
+    }
+}
+
+impl Snapshot for LinearRegressionChannel {
+    fn snapshot(&self) -> IndicatorState {
+        let (width, deviations) = self.width.to_state();
+        IndicatorState::new("LRC")
+            .count(self.period)
+            .scalar(self.value)
+            .count(width)
+            .scalar(deviations)
+            .scalar(self.lower)
+            .scalar(self.upper)
+            .child(&self.line)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "LRC")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            width: ChannelWidth::from_state(reader.count()?, reader.scalar()?)?,
+            lower: reader.scalar()?,
+            upper: reader.scalar()?,
+            line: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
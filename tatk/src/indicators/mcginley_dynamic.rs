@@ -10,9 +10,13 @@
 //! * `k` = modifies the period, normally 0.6
 //! * `n` = period
 
-use crate::traits::{AsValue, InternalValue, Next, Period, Stats};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
 
 /// McGinley Dynamic (MD)
 ///
@@ -25,7 +29,7 @@ use tatk_derive::{InternalValue, Period};
 /// * `x` = current close (most recent)
 /// * `k` = modifies the period, normally 0.6
 /// * `n` = period
-#[derive(Debug, InternalValue, Period)]
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
 pub struct McGinleyDynamic {
     /// Size of the period (window) in which data is looked at.
     period: usize,
@@ -35,6 +39,8 @@ pub struct McGinleyDynamic {
     value: Num,
     /// Holds all of the current period's values.
     buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
 }
 
 impl McGinleyDynamic {
@@ -58,19 +64,18 @@ impl McGinleyDynamic {
             )));
         } else if data.len() < period + 1 {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data for period provided",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "mcginley dynamic",
+                required: period + 1,
+                received: data.len(),
+            });
         }
 
         // First MD value.
         let mut last_md = data[0];
 
         // Buffer will hold last `period` MDs.
-        let mut buffer = match Buffer::from_array(period, &[last_md]) {
-            Ok(v) => v,
-            Err(error) => return Err(error),
-        };
+        let mut buffer = Buffer::from_array(period, &[last_md])?;
 
         // Calculate the remainder of the data set.
         for v in data[1..].iter() {
@@ -83,9 +88,26 @@ impl McGinleyDynamic {
             k,
             value: last_md,
             buffer,
+            seed: Seed::new(data.len()),
         })
     }
 
+    /// Creates a new McGinley Dynamic with the supplied period, without initial data. The values
+    /// are supplied with `next` instead, until ready the value is calculated from the values
+    /// supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `k` - Constant used to modify selected period. Default: 0.6
+    pub fn with_period(period: usize, k: Num) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period + 1), k))
+    }
+
     /// Current and most recent value calculated.
     pub fn value(&self) -> Num {
         self.value
@@ -116,6 +138,10 @@ impl Next<Num> for McGinleyDynamic {
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
         self.value = Self::calculate(self.k, self.value(), value, self.period());
 
         // Rotate the buffer.
@@ -141,32 +167,68 @@ where
     }
 }
 
-impl Stats for McGinleyDynamic {
-    /// Obtains the total sum of the buffer for MD.
-    fn sum(&self) -> Num {
-        self.buffer.sum()
+impl Reset for McGinleyDynamic {
+    /// Clears the MD back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.buffer.clear();
+        self.seed.start();
     }
+}
 
-    /// Mean for the period of the MD.
-    fn mean(&self) -> Num {
-        self.buffer.mean()
+impl Ready for McGinleyDynamic {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
     }
 
-    /// Current variance for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn variance(&self, is_sample: bool) -> Num {
-        self.buffer.variance(is_sample)
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
     }
+}
 
-    /// Current standard deviation for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn stdev(&self, is_sample: bool) -> Num {
-        self.buffer.stdev(is_sample)
+impl Reseed for McGinleyDynamic {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period + 1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data, self.k)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len() - 1, data, self.k) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for McGinleyDynamic {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("MD")
+            .count(self.period)
+            .scalar(self.k)
+            .scalar(self.value)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "MD")?;
+        Ok(Self {
+            period: reader.count()?,
+            k: reader.scalar()?,
+            value: reader.scalar()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
     }
 }
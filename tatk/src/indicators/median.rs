@@ -0,0 +1,227 @@
+//! Median, the middle value within a period.
+//!
+//! Robust alternative to the Simple Moving Average (SMA) that is not pulled by spikes in the data.
+//! For an even period, the median is the mean of the two middle values.
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Median, the middle value within a period.
+///
+/// Robust alternative to the Simple Moving Average (SMA) that is not pulled by spikes in the data.
+/// For an even period, the median is the mean of the two middle values.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct Median {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Median's current value.
+    value: Num,
+    /// Values within the current period, in the order received.
+    values: Buffer,
+    /// Values within the current period, lowest to highest.
+    sorted: Vec<Num>,
+    /// Holds `period` amount of generated Medians.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl Median {
+    /// Creates a new Median with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the Median from.
+    pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate Median.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate median",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "median",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Seed with the first period of values.
+        let values = Buffer::from_array(period, &data[..period])?;
+        let mut sorted = values.queue().to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let value = Buffer::sorted_median(&sorted);
+        let buffer = Buffer::from_array(period, &[value])?;
+
+        let mut median = Self {
+            period,
+            value,
+            values,
+            sorted,
+            buffer,
+            seed: Seed::new(period),
+        };
+
+        // Calculate the remainder of the Medians.
+        for v in data[period..].iter() {
+            median.next(*v);
+        }
+
+        Ok(median)
+    }
+
+    /// Creates a new Median with the supplied period, without initial data. The values are supplied
+    /// with `next` instead, until ready the value is calculated from the values supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period)))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Values within the current period, lowest to highest.
+    pub(crate) fn sorted(&self) -> &[Num] {
+        &self.sorted
+    }
+}
+
+impl Next<Num> for Median {
+    /// Next value for the Median.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new Median.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        // Remove the oldest value from the sorted values, it always exists.
+        let oldest = self.values.shift(value);
+        let idx = self
+            .sorted
+            .partition_point(|v| v.total_cmp(&oldest).is_lt());
+        self.sorted.remove(idx);
+
+        // Insert the newest value, keeping the order.
+        let idx = self.sorted.partition_point(|v| v.total_cmp(&value).is_lt());
+        self.sorted.insert(idx, value);
+
+        self.value = Buffer::sorted_median(&self.sorted);
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl<T> Next<T> for Median
+where
+    T: AsValue,
+{
+    /// Next value for the Median.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new Median.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for Median {
+    /// Clears the Median back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.values.clear();
+        self.sorted.clear();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for Median {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for Median {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for Median {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Median")
+            .count(self.period)
+            .scalar(self.value)
+            .buffer(&self.sorted)
+            .child(&self.values)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Median")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            sorted: reader.buffer()?.to_vec(),
+            values: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,281 @@
+//! Median Absolute Deviation (MAD)
+//!
+//! Robust measure of dispersion, the median distance of each value from the median of the period.
+//!
+//! # Formula
+//!
+//! MAD = c * median(|x - m|)
+//!
+//! where:
+//!
+//! * `x` is the current value in a set.
+//! * `m` is the median of the set.
+//! * `c` is the scale, 1.4826 for consistency with the standard deviation of normal data, or 1.0.
+
+use super::seed::{self, Reseed, Seed};
+use super::Median;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Scale that makes the MAD a consistent estimator of the standard deviation for normal data.
+const NORMAL_SCALE: Num = 1.4826;
+
+/// Median Absolute Deviation (MAD)
+///
+/// Robust measure of dispersion, the median distance of each value from the median of the period.
+///
+/// # Formula
+///
+/// MAD = c * median(|x - m|)
+///
+/// where:
+///
+/// * `x` is the current value in a set.
+/// * `m` is the median of the set.
+/// * `c` is the scale, 1.4826 for consistency with the standard deviation of normal data, or 1.0.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct MedianAbsoluteDeviation {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// MAD's current value.
+    value: Num,
+    /// Median of the values within the period.
+    median: Median,
+    /// Scales the MAD to be consistent with the standard deviation.
+    is_scaled: bool,
+    /// Holds `period` amount of generated MADs.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl MedianAbsoluteDeviation {
+    /// Creates a new MAD with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the MAD from.
+    /// * `is_scaled` - Scale by 1.4826 to be consistent with the standard deviation of normal data.
+    pub fn new(period: usize, data: &[Num], is_scaled: bool) -> Result<Self, TAError> {
+        // Check we can calculate Median Absolute Deviation.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate median absolute deviation",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "median absolute deviation",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Seed with the first period of values.
+        let median = Median::new(period, &data[..period])?;
+        let value = Self::calculate(&median, is_scaled);
+        let buffer = Buffer::from_array(period, &[value])?;
+
+        let mut mad = Self {
+            period,
+            value,
+            median,
+            is_scaled,
+            buffer,
+            seed: Seed::new(period),
+        };
+
+        // Calculate the remainder of the MADs.
+        for v in data[period..].iter() {
+            mad.next(*v);
+        }
+
+        Ok(mad)
+    }
+
+    /// Creates a new MAD with the supplied period, without initial data. The values are supplied
+    /// with `next` instead, until ready the value is calculated from the values supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `is_scaled` - Scale by 1.4826 to be consistent with the standard deviation of normal data.
+    pub fn with_period(period: usize, is_scaled: bool) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            &seed::placeholder(1.0, period),
+            is_scaled,
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Median of the values within the period.
+    pub fn median(&self) -> Num {
+        self.median.value()
+    }
+
+    /// Indicates if the MAD is scaled to be consistent with the standard deviation.
+    pub fn is_scaled(&self) -> bool {
+        self.is_scaled
+    }
+
+    /// Robust alternative to the z-score, the distance of a value from the median in MADs. If the
+    /// MAD is 0.0 (constant data) then 0.0 is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to score.
+    pub fn robust_zscore(&self, value: Num) -> Num {
+        if self.value() == 0.0 {
+            return 0.0;
+        }
+
+        (value - self.median()) / self.value()
+    }
+
+    /// Calculates the MAD from the current median and its values.
+    ///
+    /// # Arguments
+    ///
+    /// * `median` - Median holding the values of the period.
+    /// * `is_scaled` - Scale by 1.4826 to be consistent with the standard deviation.
+    fn calculate(median: &Median, is_scaled: bool) -> Num {
+        let mid = median.value();
+        let mut deviations: Vec<Num> = median.sorted().iter().map(|v| (v - mid).abs()).collect();
+        deviations.sort_by(|a, b| a.total_cmp(b));
+
+        let mad = Buffer::sorted_median(&deviations);
+        if is_scaled {
+            mad * NORMAL_SCALE
+        } else {
+            mad
+        }
+    }
+}
+
+impl Next<Num> for MedianAbsoluteDeviation {
+    /// Next value for the MAD.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new MAD.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.median.next(value);
+        self.value = Self::calculate(&self.median, self.is_scaled());
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl<T> Next<T> for MedianAbsoluteDeviation
+where
+    T: AsValue,
+{
+    /// Next value for the MAD.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new MAD.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for MedianAbsoluteDeviation {
+    /// Clears the MAD back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.median.reset();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for MedianAbsoluteDeviation {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for MedianAbsoluteDeviation {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data, self.is_scaled)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data, self.is_scaled) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for MedianAbsoluteDeviation {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("MAD")
+            .count(self.period)
+            .scalar(self.value)
+            .flag(self.is_scaled)
+            .child(&self.median)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "MAD")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            is_scaled: reader.flag()?,
+            median: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
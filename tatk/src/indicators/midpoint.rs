@@ -0,0 +1,226 @@
+//! MidPoint, middle of the highest and lowest values within a period.
+//!
+//! # Formula
+//!
+//! MidPoint = (x + y) / 2
+//!
+//! where:
+//!
+//! * `x` = highest value within the period.
+//! * `y` = lowest value within the period.
+
+use super::seed::{self, Reseed, Seed};
+use super::RollingExtrema;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// MidPoint, middle of the highest and lowest values within a period.
+///
+/// # Formula
+///
+/// MidPoint = (x + y) / 2
+///
+/// where:
+///
+/// * `x` = highest value within the period.
+/// * `y` = lowest value within the period.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct MidPoint {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// MidPoint's current value.
+    value: Num,
+    /// Highest and lowest values within the period.
+    extrema: RollingExtrema,
+    /// Holds `period` amount of generated MidPoints.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl MidPoint {
+    /// Creates a new MidPoint with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the MidPoint from.
+    pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate MidPoint.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate midpoint",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "midpoint",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Extrema of the first period, used as the seed value.
+        let mut extrema = RollingExtrema::new(period, &data[..period])?;
+        let mut value = Self::calculate(&extrema);
+
+        // Buffer will hold last `period` MidPoints.
+        let mut buffer = Buffer::from_array(period, &[value])?;
+
+        // Calculate the remainder of the MidPoints.
+        for v in data[period..].iter() {
+            extrema.next(*v);
+            value = Self::calculate(&extrema);
+            buffer.shift(value);
+        }
+
+        Ok(Self {
+            period,
+            value,
+            extrema,
+            buffer,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new MidPoint with the supplied period, without initial data. The values are
+    /// supplied with `next` instead, until ready the value is calculated from the values supplied
+    /// so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period)))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Calculates the MidPoint from the current extrema.
+    fn calculate(extrema: &RollingExtrema) -> Num {
+        (extrema.max() + extrema.min()) / 2.0
+    }
+}
+
+impl Next<Num> for MidPoint {
+    /// Next value for the MidPoint.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new MidPoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.extrema.next(value);
+        self.value = Self::calculate(&self.extrema);
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl<T> Next<T> for MidPoint
+where
+    T: AsValue,
+{
+    /// Next value for the MidPoint.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new MidPoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for MidPoint {
+    /// Clears the MidPoint back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.extrema.reset();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for MidPoint {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for MidPoint {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for MidPoint {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("MidPoint")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.extrema)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "MidPoint")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            extrema: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
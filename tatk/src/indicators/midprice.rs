@@ -0,0 +1,254 @@
+//! MidPrice, middle of the highest high and lowest low within a period.
+//!
+//! # Formula
+//!
+//! MidPrice = (x + y) / 2
+//!
+//! where:
+//!
+//! * `x` = highest high within the period.
+//! * `y` = lowest low within the period.
+
+use super::seed::{self, Reseed, Seed};
+use super::RollingExtrema;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{High, InternalValue, Low, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Used for conversions. Holds High (0) and Low (1) values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num);
+
+seed::impl_flatten!(Data(0, 1));
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+/// MidPrice, middle of the highest high and lowest low within a period.
+///
+/// # Formula
+///
+/// MidPrice = (x + y) / 2
+///
+/// where:
+///
+/// * `x` = highest high within the period.
+/// * `y` = lowest low within the period.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct MidPrice {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// MidPrice's current value.
+    value: Num,
+    /// Highest high and lowest low within the period.
+    extrema: RollingExtrema,
+    /// Holds `period` amount of generated MidPrices.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
+}
+
+impl MidPrice {
+    /// Creates a new MidPrice with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of candles to create the MidPrice from.
+    pub fn new<T>(period: usize, data: &[T]) -> Result<Self, TAError>
+    where
+        T: High + Low,
+    {
+        // Check we can calculate MidPrice.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate midprice",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "midprice",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Extrema of the first period, used as the seed value.
+        let mut extrema = RollingExtrema::from_candles(period, &data[..period])?;
+        let mut value = Self::calculate(&extrema);
+
+        // Buffer will hold last `period` MidPrices.
+        let mut buffer = Buffer::from_array(period, &[value])?;
+
+        // Calculate the remainder of the MidPrices.
+        for v in data[period..].iter() {
+            extrema.next(v);
+            value = Self::calculate(&extrema);
+            buffer.shift(value);
+        }
+
+        Ok(Self {
+            period,
+            value,
+            extrema,
+            buffer,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new MidPrice with the supplied period, without initial data. The candles are
+    /// supplied with `next` instead, until ready the value is calculated from the candles supplied
+    /// so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            &seed::placeholder(Data(1.0, 1.0), period),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Calculates the MidPrice from the current extrema.
+    fn calculate(extrema: &RollingExtrema) -> Num {
+        (extrema.max() + extrema.min()) / 2.0
+    }
+}
+
+impl<T> Next<&T> for MidPrice
+where
+    T: High + Low,
+{
+    /// Next value for the MidPrice.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new MidPrice.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        if seed::collect(self, Data(value.high(), value.low())) {
+            return self.value;
+        }
+
+        self.extrema.next(value);
+        self.value = Self::calculate(&self.extrema);
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl Next<(Num, Num)> for MidPrice {
+    /// Next value for the MidPrice.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new MidPrice.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    ///     * 0 = High
+    ///     * 1 = Low
+    fn next(&mut self, value: (Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1))
+    }
+}
+
+impl Reset for MidPrice {
+    /// Clears the MidPrice back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.extrema.reset();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for MidPrice {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for MidPrice {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for MidPrice {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("MidPrice")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.extrema)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "MidPrice")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            extrema: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
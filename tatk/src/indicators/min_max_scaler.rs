@@ -0,0 +1,294 @@
+//! Min-Max Scaler, maps the newest value into a range relative to the lowest and highest values
+//! within a period.
+//!
+//! The output range defaults to 0 to 1. A period where every value is the same has no range and
+//! produces the middle of the output range.
+//!
+//! # Formula
+//!
+//! Scaled = a + (x - y) / (z - y) * (b - a)
+//!
+//! where:
+//!
+//! * `x` = current value (most recent)
+//! * `y` = lowest value within the period.
+//! * `z` = highest value within the period.
+//! * `a` = lower bound of the output range.
+//! * `b` = upper bound of the output range.
+
+use super::seed::{self, Reseed, Seed};
+use super::RollingExtrema;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Min-Max Scaler, maps the newest value into a range relative to the lowest and highest values
+/// within a period.
+///
+/// The output range defaults to 0 to 1. A period where every value is the same has no range and
+/// produces the middle of the output range.
+///
+/// # Formula
+///
+/// Scaled = a + (x - y) / (z - y) * (b - a)
+///
+/// where:
+///
+/// * `x` = current value (most recent)
+/// * `y` = lowest value within the period.
+/// * `z` = highest value within the period.
+/// * `a` = lower bound of the output range.
+/// * `b` = upper bound of the output range.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct MinMaxScaler {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Scaled value of the newest value.
+    value: Num,
+    /// Lower bound of the output range.
+    lower: Num,
+    /// Upper bound of the output range.
+    upper: Num,
+    /// Highest and lowest values within the period.
+    extrema: RollingExtrema,
+    /// Holds `period` amount of generated scaled values.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl MinMaxScaler {
+    /// Creates a new Min-Max Scaler with the supplied period and initial data, scaling into 0 to 1.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the Min-Max Scaler from.
+    pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::with_range(period, 0.0, 1.0, data)
+    }
+
+    /// Creates a new Min-Max Scaler with the supplied period, without initial data. The values are
+    /// supplied with `next` instead, until ready the value is calculated from the values supplied
+    /// so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period)))
+    }
+
+    /// Creates a new Min-Max Scaler with the supplied period, output range, and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Lower bound must be less than the upper bound.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `lower` - Lower bound of the output range.
+    /// * `upper` - Upper bound of the output range.
+    /// * `data` - Array of values to create the Min-Max Scaler from.
+    pub fn with_range(
+        period: usize,
+        lower: Num,
+        upper: Num,
+        data: &[Num],
+    ) -> Result<Self, TAError> {
+        // Check we can calculate Min-Max Scaler.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate min-max scaler",
+            )));
+        } else if lower.is_nan() || upper.is_nan() || lower >= upper {
+            return Err(TAError::InvalidSize(String::from(
+                "lower bound must be less than upper bound to calculate min-max scaler",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "min-max scaler",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Extrema of the first period, used as the seed value.
+        let mut extrema = RollingExtrema::new(period, &data[..period])?;
+        let mut value = Self::calculate(lower, upper, &extrema, data[period - 1]);
+
+        // Buffer will hold last `period` scaled values.
+        let mut buffer = Buffer::from_array(period, &[value])?;
+
+        // Calculate the remainder of the scaled values.
+        for v in data[period..].iter() {
+            extrema.next(*v);
+            value = Self::calculate(lower, upper, &extrema, *v);
+            buffer.shift(value);
+        }
+
+        Ok(Self {
+            period,
+            value,
+            lower,
+            upper,
+            extrema,
+            buffer,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Lower (0) and upper (1) bounds of the output range.
+    pub fn range(&self) -> (Num, Num) {
+        (self.lower, self.upper)
+    }
+
+    /// Scales the value into the output range using the current extrema.
+    ///
+    /// # Arguments
+    ///
+    /// * `lower` - Lower bound of the output range.
+    /// * `upper` - Upper bound of the output range.
+    /// * `extrema` - Highest and lowest values within the period.
+    /// * `value` - Value to scale.
+    fn calculate(lower: Num, upper: Num, extrema: &RollingExtrema, value: Num) -> Num {
+        let range = extrema.max() - extrema.min();
+        if range == 0.0 {
+            return (lower + upper) / 2.0;
+        }
+
+        lower + (value - extrema.min()) / range * (upper - lower)
+    }
+}
+
+impl Next<Num> for MinMaxScaler {
+    /// Next value for the Min-Max Scaler.
+    type Output = Num;
+
+    /// Supply an additional value to scale into the output range.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.extrema.next(value);
+        self.value = Self::calculate(self.lower, self.upper, &self.extrema, value);
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl<T> Next<T> for MinMaxScaler
+where
+    T: AsValue,
+{
+    /// Next value for the Min-Max Scaler.
+    type Output = Num;
+
+    /// Supply an additional value to scale into the output range.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for MinMaxScaler {
+    /// Clears the Min-Max Scaler back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.extrema.reset();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for MinMaxScaler {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for MinMaxScaler {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::with_range(self.period, self.lower, self.upper, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::with_range(data.len(), self.lower, self.upper, data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for MinMaxScaler {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Min-Max Scaler")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.lower)
+            .scalar(self.upper)
+            .child(&self.extrema)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Min-Max Scaler")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            lower: reader.scalar()?,
+            upper: reader.scalar()?,
+            extrema: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
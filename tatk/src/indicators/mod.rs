@@ -1,32 +1,145 @@
 //! Indicators generated from samples used for signals.
+mod acceleration;
+mod alligator;
+mod atr_trailing_stop;
 mod average_true_range;
+mod bar_volatility;
+mod batch;
+mod beta;
 mod bollinger_bands;
+mod candle_pattern;
+mod chain;
+mod config;
 mod cross;
+mod cumulative_return;
 mod double_exponential_moving_average;
+mod dynamic;
 mod exponential_moving_average;
+mod gap_detector;
+mod garman_klass_volatility;
+mod gator_oscillator;
+mod gaussian_moving_average;
+mod historical_volatility;
+mod holt_smoothing;
+#[cfg(feature = "std")]
+mod indicator_set;
+mod inside_bar;
+mod iterator;
+mod kalman_filter;
+mod keltner_channels;
+mod lag;
 mod linear_regression;
+mod linear_regression_channel;
 mod mcginley_dynamic;
+mod median;
+mod median_absolute_deviation;
+mod midpoint;
+mod midprice;
+mod min_max_scaler;
+mod moments;
+mod moving_average;
 mod moving_average_convergence_divergence;
 mod on_balance_volume;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod parkinson_volatility;
 mod rate_of_change;
 mod relative_strength_index;
+mod rogers_satchell_volatility;
+mod rolling_extrema;
+mod rolling_returns;
+mod rolling_sum;
+mod rsi_divergence;
+mod savitzky_golay;
+mod seed;
+mod sharpe_ratio;
 mod simple_moving_average;
+mod smoothed_moving_average;
+mod smoothed_rate_of_change;
+mod sortino_ratio;
+mod squeeze;
 mod standard_deviation;
+mod support_resistance;
+mod swing_points;
+mod timed;
 mod true_range;
+mod two_candle_patterns;
+mod typical_price;
 mod variance;
+mod volume_profile;
+mod volume_weighted_average_price;
+mod weighted_close;
+mod weighted_moving_average;
+mod yang_zhang_volatility;
 
-pub use average_true_range::AverageTrueRange;
-pub use bollinger_bands::BollingerBands;
-pub use cross::Cross;
+pub use acceleration::Acceleration;
+pub use alligator::Alligator;
+pub use atr_trailing_stop::{AtrTrailingStop, FlipSeed, Position};
+pub use average_true_range::{AtrBuilder, AtrSmoothing, AverageTrueRange};
+pub use batch::{compute, Warmup};
+pub use beta::{Beta, ReturnKind};
+pub use bollinger_bands::{BandsOutput, BollingerBands, BollingerBandsBuilder};
+pub use candle_pattern::{CandlePattern, PatternConfig, PatternKind};
+pub use chain::{Chain, Pipeline};
+pub use config::IndicatorConfig;
+pub use cross::{Cross, CrossEvent};
+pub use cumulative_return::CumulativeReturn;
 pub use double_exponential_moving_average::DoubleExponentialMovingAverage;
-pub use exponential_moving_average::ExponentialMovingAverage;
+pub use exponential_moving_average::{EmaSeed, ExponentialMovingAverage};
+pub use gap_detector::{Gap, GapDetector, GapDirection};
+pub use garman_klass_volatility::GarmanKlassVolatility;
+pub use gator_oscillator::{GatorBar, GatorOscillator};
+pub use gaussian_moving_average::{GaussianCenter, GaussianMovingAverage};
+pub use historical_volatility::HistoricalVolatility;
+pub use holt_smoothing::HoltSmoothing;
+#[cfg(feature = "std")]
+pub use indicator_set::{IndicatorSet, PriceSource};
+pub use inside_bar::{BarSignal, InsideBar};
+pub use iterator::{Apply, IndicatorIteratorExt};
+pub use kalman_filter::{KalmanFilter, KalmanModel};
+pub use keltner_channels::KeltnerChannels;
+pub use lag::Lag;
 pub use linear_regression::LinearRegression;
+pub use linear_regression_channel::{ChannelWidth, LinearRegressionChannel};
 pub use mcginley_dynamic::McGinleyDynamic;
-pub use moving_average_convergence_divergence::MovingAverageConvergenceDivergence;
+pub use median::Median;
+pub use median_absolute_deviation::MedianAbsoluteDeviation;
+pub use midpoint::MidPoint;
+pub use midprice::MidPrice;
+pub use min_max_scaler::MinMaxScaler;
+pub use moments::Moments;
+pub use moving_average::MaKind;
+pub use moving_average_convergence_divergence::{
+    MacdBuilder, MacdOutput, MovingAverageConvergenceDivergence,
+};
 pub use on_balance_volume::OnBalanceVolume;
+#[cfg(feature = "parallel")]
+pub use parallel::{compute_many, compute_per_symbol};
+pub use parkinson_volatility::ParkinsonVolatility;
 pub use rate_of_change::RateOfChange;
-pub use relative_strength_index::RelativeStrengthIndex;
+pub use relative_strength_index::{RelativeStrengthIndex, RsiBuilder, RsiSmoothing};
+pub use rogers_satchell_volatility::RogersSatchellVolatility;
+pub(crate) use rolling_extrema::MonotonicQueue;
+pub use rolling_extrema::RollingExtrema;
+pub use rolling_sum::RollingSum;
+pub use rsi_divergence::{DivergenceEvent, RsiDivergence};
+pub use savitzky_golay::SavitzkyGolay;
+pub use sharpe_ratio::SharpeRatio;
 pub use simple_moving_average::SimpleMovingAverage;
+pub use smoothed_moving_average::SmoothedMovingAverage;
+pub use smoothed_rate_of_change::SmoothedRateOfChange;
+pub use sortino_ratio::SortinoRatio;
+pub use squeeze::{Squeeze, SqueezeOutput};
 pub use standard_deviation::StandardDeviation;
+pub use support_resistance::{Level, SupportResistance, Tolerance};
+pub use swing_points::{MarketStructure, Swing, SwingKind, SwingPoints};
+pub use timed::{GapPolicy, Session, Timed};
 pub use true_range::TrueRange;
+pub use two_candle_patterns::TwoCandlePatterns;
+pub use typical_price::TypicalPrice;
 pub use variance::Variance;
+pub use volume_profile::{Bin, Binning, Distribution, VolumeProfile};
+pub use volume_weighted_average_price::VolumeWeightedAveragePrice;
+pub use weighted_close::WeightedClose;
+pub use weighted_moving_average::WeightedMovingAverage;
+pub use yang_zhang_volatility::YangZhangVolatility;
@@ -0,0 +1,262 @@
+//! Moments, rolling skewness and excess kurtosis of a period.
+//!
+//! Skewness measures the asymmetry of the values around the mean, negative when the tail of
+//! lower values is longer. Excess kurtosis measures how heavy the tails are compared to a normal
+//! distribution, a normal distribution being 0. Both are the population (biased) estimates, a
+//! period where every value is the same produces 0 for both.
+//!
+//! # Formula
+//!
+//! Skewness = m3 / m2^(3/2)
+//!
+//! Kurtosis = m4 / m2² - 3
+//!
+//! where:
+//!
+//! * `mk` = mean of the values' distance from the mean to the power of `k`.
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Moments, rolling skewness and excess kurtosis of a period.
+///
+/// Skewness measures the asymmetry of the values around the mean, negative when the tail of
+/// lower values is longer. Excess kurtosis measures how heavy the tails are compared to a normal
+/// distribution, a normal distribution being 0. Both are the population (biased) estimates, a
+/// period where every value is the same produces 0 for both.
+///
+/// # Formula
+///
+/// Skewness = m3 / m2^(3/2)
+///
+/// Kurtosis = m4 / m2² - 3
+///
+/// where:
+///
+/// * `mk` = mean of the values' distance from the mean to the power of `k`.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct Moments {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Current skewness.
+    value: Num,
+    /// Current excess kurtosis.
+    kurtosis: Num,
+    /// Holds all of the current period's values.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl Moments {
+    /// Creates a new Moments with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the Moments from.
+    pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate Moments.
+        if period < 2 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 2 to calculate moments",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "moments",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        let buffer = Buffer::from_array(period, data)?;
+        let (value, kurtosis) = Self::calculate(&buffer);
+        Ok(Self {
+            period,
+            value,
+            kurtosis,
+            buffer,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new Moments with the supplied period, without initial data. The values are
+    /// supplied with `next` instead, until ready the value is calculated from the values supplied
+    /// so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period)))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Skewness of the period, the asymmetry around the mean.
+    pub fn skewness(&self) -> Num {
+        self.value
+    }
+
+    /// Excess kurtosis of the period, heaviness of the tails compared to a normal distribution.
+    pub fn kurtosis(&self) -> Num {
+        self.kurtosis
+    }
+
+    /// Calculates the skewness (0) and excess kurtosis (1) in a single pass of the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Values of the period.
+    fn calculate(buffer: &Buffer) -> (Num, Num) {
+        let mean = buffer.mean();
+        let (mut m2, mut m3, mut m4) = (0.0, 0.0, 0.0);
+        for v in buffer.queue().iter() {
+            let d = v - mean;
+            let d2 = d * d;
+            m2 += d2;
+            m3 += d2 * d;
+            m4 += d2 * d2;
+        }
+
+        if m2 == 0.0 {
+            return (0.0, 0.0);
+        }
+
+        let n = buffer.queue().len() as Num;
+        let (m2, m3, m4) = (m2 / n, m3 / n, m4 / n);
+        (m3 / (m2 * m2.sqrt()), m4 / (m2 * m2) - 3.0)
+    }
+}
+
+impl Next<Num> for Moments {
+    /// Skewness and excess kurtosis.
+    type Output = (Num, Num);
+
+    /// Supply an additional value to recalculate the skewness and excess kurtosis.
+    ///
+    /// # Returns
+    ///
+    /// * (`Skewness`, `Kurtosis`)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return (self.value, self.kurtosis);
+        }
+
+        // Rotate the buffer.
+        self.buffer.shift(value);
+        (self.value, self.kurtosis) = Self::calculate(&self.buffer);
+        (self.value, self.kurtosis)
+    }
+}
+
+impl<T> Next<T> for Moments
+where
+    T: AsValue,
+{
+    /// Skewness and excess kurtosis.
+    type Output = (Num, Num);
+
+    /// Supply an additional value to recalculate the skewness and excess kurtosis.
+    ///
+    /// # Returns
+    ///
+    /// * (`Skewness`, `Kurtosis`)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for Moments {
+    /// Clears the Moments back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.kurtosis = 0.0;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for Moments {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for Moments {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+            self.kurtosis = partial.kurtosis;
+        }
+    }
+}
+
+impl Snapshot for Moments {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Moments")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.kurtosis)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Moments")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            kurtosis: reader.scalar()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
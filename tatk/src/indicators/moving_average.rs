@@ -0,0 +1,161 @@
+//! Selectable moving average used by indicators that smooth a line with a configurable kind.
+
+use super::{
+    EmaSeed, ExponentialMovingAverage, SimpleMovingAverage, SmoothedMovingAverage,
+    WeightedMovingAverage,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::traits::{Next, Period, Ready, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+
+/// Kind of moving average used to smooth a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaKind {
+    /// Simple Moving Average (SMA)
+    Sma,
+    /// Exponential Moving Average (EMA)
+    Ema,
+    /// Weighted Moving Average (WMA)
+    Wma,
+    /// Smoothed Moving Average (SMMA), Wilder's smoothing.
+    Smma,
+}
+
+/// Moving average of the selected kind.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum MovingAverage {
+    /// Simple Moving Average (SMA)
+    Sma(SimpleMovingAverage),
+    /// Exponential Moving Average (EMA)
+    Ema(ExponentialMovingAverage),
+    /// Weighted Moving Average (WMA)
+    Wma(WeightedMovingAverage),
+    /// Smoothed Moving Average (SMMA)
+    Smma(SmoothedMovingAverage),
+}
+
+impl MovingAverage {
+    /// Creates a new moving average of the kind with the supplied period and initial data.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Kind of moving average.
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the moving average from.
+    pub(crate) fn new(kind: MaKind, period: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::with_seed(kind, period, data, EmaSeed::default())
+    }
+
+    /// Creates a new moving average of the kind with the supplied period and initial data, an EMA
+    /// starting from the supplied seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Kind of moving average.
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the moving average from.
+    /// * `seed` - Starting EMA, unused by the other kinds.
+    pub(crate) fn with_seed(
+        kind: MaKind,
+        period: usize,
+        data: &[Num],
+        seed: EmaSeed,
+    ) -> Result<Self, TAError> {
+        Ok(match kind {
+            MaKind::Sma => Self::Sma(SimpleMovingAverage::new(period, data)?),
+            MaKind::Ema => Self::Ema(ExponentialMovingAverage::with_seed(period, data, seed)?),
+            MaKind::Wma => Self::Wma(WeightedMovingAverage::new(period, data)?),
+            MaKind::Smma => Self::Smma(SmoothedMovingAverage::new(period, data)?),
+        })
+    }
+
+    /// Kind of the moving average.
+    pub(crate) fn kind(&self) -> MaKind {
+        match self {
+            Self::Sma(_) => MaKind::Sma,
+            Self::Ema(_) => MaKind::Ema,
+            Self::Wma(_) => MaKind::Wma,
+            Self::Smma(_) => MaKind::Smma,
+        }
+    }
+
+    /// Size of the period (window) in which data is looked at.
+    pub(crate) fn period(&self) -> usize {
+        match self {
+            Self::Sma(ma) => ma.period(),
+            Self::Ema(ma) => ma.period(),
+            Self::Wma(ma) => ma.period(),
+            Self::Smma(ma) => ma.period(),
+        }
+    }
+
+    /// Current and most recent value calculated.
+    pub(crate) fn value(&self) -> Num {
+        match self {
+            Self::Sma(ma) => ma.value(),
+            Self::Ema(ma) => ma.value(),
+            Self::Wma(ma) => ma.value(),
+            Self::Smma(ma) => ma.value(),
+        }
+    }
+
+    /// Checks if enough values have been supplied for the value to be complete.
+    pub(crate) fn is_ready(&self) -> bool {
+        match self {
+            Self::Sma(ma) => ma.is_ready(),
+            Self::Ema(ma) => ma.is_ready(),
+            Self::Wma(ma) => ma.is_ready(),
+            Self::Smma(ma) => ma.is_ready(),
+        }
+    }
+
+    /// Supply an additional value to recalculate the moving average.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    pub(crate) fn next(&mut self, value: Num) -> Num {
+        match self {
+            Self::Sma(ma) => ma.next(value),
+            Self::Ema(ma) => ma.next(value),
+            Self::Wma(ma) => ma.next(value),
+            Self::Smma(ma) => ma.next(value),
+        }
+    }
+
+    /// Clears the moving average back to having seen no data.
+    pub(crate) fn reset(&mut self) {
+        match self {
+            Self::Sma(ma) => ma.reset(),
+            Self::Ema(ma) => ma.reset(),
+            Self::Wma(ma) => ma.reset(),
+            Self::Smma(ma) => ma.reset(),
+        }
+    }
+}
+
+impl Snapshot for MovingAverage {
+    /// State of the moving average held, named by its kind.
+    fn snapshot(&self) -> IndicatorState {
+        match self {
+            MovingAverage::Sma(ma) => ma.snapshot(),
+            MovingAverage::Ema(ma) => ma.snapshot(),
+            MovingAverage::Wma(ma) => ma.snapshot(),
+            MovingAverage::Smma(ma) => ma.snapshot(),
+        }
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        match state.name.as_str() {
+            "SMA" => Ok(MovingAverage::Sma(Snapshot::restore(state)?)),
+            "EMA" => Ok(MovingAverage::Ema(Snapshot::restore(state)?)),
+            "WMA" => Ok(MovingAverage::Wma(Snapshot::restore(state)?)),
+            "SMMA" => Ok(MovingAverage::Smma(Snapshot::restore(state)?)),
+            name => Err(TAError::InvalidState(format!(
+                "state of {} cannot restore a moving average",
+                name
+            ))),
+        }
+    }
+}
@@ -6,15 +6,38 @@
 //!
 //! MACD = x - y
 //!
+//! Histogram = MACD - SIGNAL_EMA
+//!
 //! where:
 //!
 //! * `x` = Short EMA of period `n`
 //! * `y` = Long EMA of period `n`
+//!
+//! The short, long, and signal lines are EMAs by default, each can instead be another kind of
+//! moving average such as an SMA, WMA, or SMMA.
 
-use super::ExponentialMovingAverage;
-use crate::traits::{AsValue, InternalValue, Next, Period};
-use crate::{Num, TAError};
-use tatk_derive::InternalValue;
+use super::moving_average::MovingAverage;
+use super::seed::{self, Reseed, Seed};
+use super::{CrossEvent, EmaSeed, MaKind};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    AsValue, InternalValue, Next, Period, Ready, Reset, SignalSource, Snapshot, Stats, Value,
+};
+use crate::{Buffer, IndicatorState, Num, Signal, TAError};
+use tatk_derive::{InternalValue, Stats, Value};
+
+/// Values of a MACD for a single value supplied.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MacdOutput {
+    /// MACD, the short line minus the long line.
+    pub macd: Num,
+    /// Signal line, moving average of the MACD.
+    pub signal: Num,
+    /// Histogram, the MACD minus the signal.
+    pub histogram: Num,
+}
 
 /// Moving Average Convergence and Divergence (MACD)
 ///
@@ -24,22 +47,35 @@ use tatk_derive::InternalValue;
 ///
 /// MACD = x - y
 ///
+/// Histogram = MACD - SIGNAL_EMA
+///
 /// where:
 ///
 /// * `x` = Short EMA of period `n`
 /// * `y` = Long EMA of period `n`
-#[derive(Debug, InternalValue)]
+///
+/// The short, long, and signal lines are EMAs by default, each can instead be another kind of
+/// moving average such as an SMA, WMA, or SMMA.
+#[derive(Debug, Clone, PartialEq, InternalValue, Stats, Value)]
 pub struct MovingAverageConvergenceDivergence {
     /// MACD's current value.
     value: Num,
-    /// Short EMA
-    ema_short: ExponentialMovingAverage,
-    /// Long EMA
-    ema_long: ExponentialMovingAverage,
-    /// Signal Line, EMA of MACD values.
-    ema_signal: ExponentialMovingAverage,
-    /// If the MACD crossed the signal.
-    crossed: bool,
+    /// Short line, an EMA by default.
+    short_line: MovingAverage,
+    /// Long line, an EMA by default.
+    long_line: MovingAverage,
+    /// Signal line, moving average of MACD values.
+    signal_line: MovingAverage,
+    /// Holds `signal` amount of histogram values.
+    buffer: Buffer,
+    /// If the MACD was last strictly above the signal, `None` while they have only been equal.
+    side: Option<bool>,
+    /// Direction the MACD crossed the signal in for the most recent value.
+    crossed: CrossEvent,
+    /// Starting EMA of the lines that are EMAs.
+    ema_seed: EmaSeed,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
 }
 
 impl MovingAverageConvergenceDivergence {
@@ -50,8 +86,7 @@ impl MovingAverageConvergenceDivergence {
     ///
     /// * Short, Signal, and Long must greater than 0.
     /// * Short must be smaller than Long.
-    /// * Data must have at least `signal` elements.
-    /// * Data must have at least `long` elements.
+    /// * Data must have at least `long + signal - 1` elements.
     ///
     /// ## Arguments
     ///
@@ -60,6 +95,49 @@ impl MovingAverageConvergenceDivergence {
     /// * `signal` - Period of the signal EMA.
     /// * `data` - Array of values to create the MACD from.
     pub fn new(short: usize, long: usize, signal: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::with_kind(short, long, signal, MaKind::Ema, data)
+    }
+
+    /// Creates a new MACD with the short, long, and signal lines all of the supplied kind of
+    /// moving average. Use the builder for lines of different kinds.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Short, Signal, and Long must greater than 0.
+    /// * Short must be smaller than Long.
+    /// * Data must have at least `long + signal - 1` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `short` - Period of the short line.
+    /// * `long` - Period of the long line.
+    /// * `signal` - Period of the signal line.
+    /// * `kind` - Kind of moving average for every line.
+    /// * `data` - Array of values to create the MACD from.
+    pub fn with_kind(
+        short: usize,
+        long: usize,
+        signal: usize,
+        kind: MaKind,
+        data: &[Num],
+    ) -> Result<Self, TAError> {
+        Self::with_kinds((short, long, signal), [kind; 3], EmaSeed::default(), data)
+    }
+
+    /// Creates a new MACD with each line its own kind of moving average.
+    ///
+    /// # Arguments
+    ///
+    /// * `periods` - Periods of the short, long, and signal lines.
+    /// * `kinds` - Kinds of the short, long, and signal lines.
+    /// * `ema_seed` - Starting EMA of the lines that are EMAs.
+    /// * `data` - Array of values to create the MACD from.
+    fn with_kinds(
+        (short, long, signal): (usize, usize, usize),
+        [short_kind, long_kind, signal_kind]: [MaKind; 3],
+        ema_seed: EmaSeed,
+        data: &[Num],
+    ) -> Result<Self, TAError> {
         if short < 1 {
             return Err(TAError::InvalidSize(String::from(
                 "short cannot be less than 1 to calculate moving average convergence and divergence",
@@ -72,54 +150,82 @@ impl MovingAverageConvergenceDivergence {
             return Err(TAError::InvalidSize(String::from(
                 "larger long period required to calculate moving average convergence and divergence",
             )));
-        } else if data.len() < signal {
-            return Err(TAError::InvalidSize(String::from(
-                "not enough data to calculate signal for moving average convergence and divergence",
-            )));
-        } else if data.len() < long {
-            return Err(TAError::InvalidSize(String::from(
-                "not enough data to calculate long for moving average convergence and divergence",
-            )));
+        } else if data.len() < long + signal - 1 {
+            // Make sure there is enough data for the signal line.
+            return Err(TAError::InvalidData {
+                indicator: "moving average convergence and divergence",
+                required: long + signal - 1,
+                received: data.len(),
+            });
         }
 
-        // Build short EMA up to the long.
-        let mut ema_short = match ExponentialMovingAverage::new(short, &data[..long]) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        // Build short line up to the long.
+        let mut short_line = MovingAverage::with_seed(short_kind, short, &data[..long], ema_seed)?;
 
-        // Build long EMA.
-        let mut ema_long = match ExponentialMovingAverage::new(long, &data[..long]) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        // Build long line.
+        let mut long_line = MovingAverage::with_seed(long_kind, long, &data[..long], ema_seed)?;
 
         // Add the first value.
-        let mut signals: Vec<Num> = vec![ema_short.value() - ema_long.value()];
+        let mut signals: Vec<Num> = vec![short_line.value() - long_line.value()];
 
         // Process the remainder of the data, building a signal line.
         for v in data[long..].iter() {
-            let short_value = ema_short.next(*v);
-            let long_value = ema_long.next(*v);
+            let short_value = short_line.next(*v);
+            let long_value = long_line.next(*v);
 
             signals.push(short_value - long_value);
         }
 
-        // Build signal EMA of MACDs.
-        let ema_signal = match ExponentialMovingAverage::new(signal, &signals) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        // Build signal line of MACDs, keeping the histogram of each.
+        let mut signal_line =
+            MovingAverage::with_seed(signal_kind, signal, &signals[..signal], ema_seed)?;
+        let mut buffer = Buffer::from_array(signal, &[signals[signal - 1] - signal_line.value()])?;
+        for v in signals[signal..].iter() {
+            buffer.shift(v - signal_line.next(*v));
+        }
 
+        let value = short_line.value() - long_line.value();
         Ok(Self {
-            value: ema_short.value() - ema_long.value(),
-            ema_short,
-            ema_long,
-            ema_signal,
-            crossed: false,
+            side: Self::side(value, signal_line.value(), None),
+            value,
+            short_line,
+            long_line,
+            signal_line,
+            buffer,
+            crossed: CrossEvent::None,
+            ema_seed,
+            seed: Seed::new(data.len()),
         })
     }
 
+    /// Creates a new MACD with the supplied periods, without initial data. The values are supplied
+    /// with `next` instead, the value is 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Short, Signal, and Long must greater than 0.
+    /// * Short must be smaller than Long.
+    ///
+    /// ## Arguments
+    ///
+    /// * `short` - Period of the short EMA.
+    /// * `long` - Period of the long EMA.
+    /// * `signal` - Period of the signal EMA.
+    pub fn with_period(short: usize, long: usize, signal: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            short,
+            long,
+            signal,
+            &seed::placeholder(1.0, (long + signal).saturating_sub(1)),
+        ))
+    }
+
+    /// Creates a builder for a MACD, defaulting to the common short (12), long (26), and signal
+    /// (9) periods.
+    pub fn builder() -> MacdBuilder {
+        MacdBuilder::default()
+    }
+
     /// Current and most recent value calculated.
     pub fn value(&self) -> Num {
         self.value
@@ -127,12 +233,72 @@ impl MovingAverageConvergenceDivergence {
 
     /// Current and most recent signal value calculated.
     pub fn signal_value(&self) -> Num {
-        self.ema_signal.value()
+        self.signal_line.value()
+    }
+
+    /// Current and most recent histogram value calculated, the value minus the signal.
+    pub fn histogram(&self) -> Num {
+        self.value - self.signal_value()
+    }
+
+    /// Kinds of moving average for the short (0), long (1), and signal (2) lines.
+    pub fn kinds(&self) -> [MaKind; 3] {
+        [
+            self.short_line.kind(),
+            self.long_line.kind(),
+            self.signal_line.kind(),
+        ]
+    }
+
+    /// Current and most recent value of the short line.
+    pub fn short_value(&self) -> Num {
+        self.short_line.value()
     }
 
-    /// Check if the value crossed the signal.
+    /// Current and most recent value of the long line.
+    pub fn long_value(&self) -> Num {
+        self.long_line.value()
+    }
+
+    /// Side the value is on compared to the signal, the prior side is kept while they are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - MACD value.
+    /// * `signal` - Signal value.
+    /// * `prior` - Side the value was last on, true if above.
+    fn side(value: Num, signal: Num, prior: Option<bool>) -> Option<bool> {
+        if value > signal {
+            Some(true)
+        } else if value < signal {
+            Some(false)
+        } else {
+            prior
+        }
+    }
+
+    /// Values of the MACD, signal, and histogram.
+    fn output(&self) -> MacdOutput {
+        MacdOutput {
+            macd: self.value,
+            signal: self.signal_value(),
+            histogram: self.histogram(),
+        }
+    }
+
+    /// Check if the value crossed the signal, in either direction.
     pub fn crossed(&self) -> bool {
-        self.crossed
+        self.crossed != CrossEvent::None
+    }
+
+    /// Check if the value crossed above the signal.
+    pub fn crossed_above(&self) -> bool {
+        self.crossed == CrossEvent::Golden
+    }
+
+    /// Check if the value crossed below the signal.
+    pub fn crossed_below(&self) -> bool {
+        self.crossed == CrossEvent::Death
     }
 
     /// Returns true if the value is above the signal.
@@ -149,43 +315,42 @@ impl MovingAverageConvergenceDivergence {
 impl Period for MovingAverageConvergenceDivergence {
     /// Period (window) for the signal.
     fn period(&self) -> usize {
-        self.ema_signal.period()
+        self.signal_line.period()
     }
 }
 
 impl Next<Num> for MovingAverageConvergenceDivergence {
-    /// Signal, Short, and Long values,
-    type Output = (Num, Num, Num);
+    /// MACD, Signal, and Histogram values.
+    type Output = MacdOutput;
 
     /// Supply an additional value to recalculate a new MACD.
     ///
-    /// # Returns
-    ///
-    /// * (`Signal`, `Short`, `Long`)
-    ///
     /// # Arguments
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
-        let was_below: bool = self.is_below();
+        if seed::collect(self, value) {
+            return self.output();
+        }
 
-        let short_value = self.ema_short.next(value);
-        let long_value = self.ema_long.next(value);
+        let short_value = self.short_line.next(value);
+        let long_value = self.long_line.next(value);
 
         // Calculate the new MACD and signal.
         self.value = short_value - long_value;
-        self.ema_signal.next(self.value());
+        self.signal_line.next(self.value());
+        self.buffer.shift(self.histogram());
 
-        // Update if it crossed the signal or not.
-        if was_below && self.is_below() {
-            self.crossed = false;
-        } else if !was_below && self.is_above() {
-            self.crossed = false;
-        } else {
-            self.crossed = true;
-        }
+        // Compare the side before and after, equal values are not a cross.
+        let was_above = self.side;
+        self.side = Self::side(self.value, self.signal_value(), was_above);
+        self.crossed = match (was_above, self.side) {
+            (Some(false), Some(true)) => CrossEvent::Golden,
+            (Some(true), Some(false)) => CrossEvent::Death,
+            _ => CrossEvent::None,
+        };
 
-        (self.value, short_value, long_value)
+        self.output()
     }
 }
 
@@ -193,15 +358,11 @@ impl<T> Next<T> for MovingAverageConvergenceDivergence
 where
     T: AsValue,
 {
-    /// Signal, Short, and Long values,
-    type Output = (Num, Num, Num);
+    /// MACD, Signal, and Histogram values.
+    type Output = MacdOutput;
 
     /// Supply an additional value to recalculate a new MACD.
     ///
-    /// # Returns
-    ///
-    /// * (`Signal`, `Short`, `Long`)
-    ///
     /// # Arguments
     ///
     /// * `value` - New value to add to period.
@@ -209,3 +370,203 @@ where
         self.next(value.as_value())
     }
 }
+
+impl SignalSource for MovingAverageConvergenceDivergence {
+    /// Buy when the value crosses above the signal and sell when it crosses below, holding
+    /// otherwise including while the value is equal to the signal.
+    fn signal(&self) -> Signal {
+        if self.crossed_above() {
+            Signal::Buy
+        } else if self.crossed_below() {
+            Signal::Sell
+        } else {
+            Signal::Hold
+        }
+    }
+}
+
+impl Reset for MovingAverageConvergenceDivergence {
+    /// Clears the MACD back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.short_line.reset();
+        self.long_line.reset();
+        self.signal_line.reset();
+        self.buffer.clear();
+        self.side = None;
+        self.crossed = CrossEvent::None;
+        self.seed.start();
+    }
+}
+
+impl Ready for MovingAverageConvergenceDivergence {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+            && self.short_line.is_ready()
+            && self.long_line.is_ready()
+            && self.signal_line.is_ready()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for MovingAverageConvergenceDivergence {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.long_line.period() + self.signal_line.period() - 1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::with_kinds(
+            (
+                self.short_line.period(),
+                self.long_line.period(),
+                self.signal_line.period(),
+            ),
+            self.kinds(),
+            self.ema_seed,
+            data,
+        )
+    }
+}
+
+/// Builder for a MACD, defaulting to the common short (12), long (26), and signal (9) periods.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MacdBuilder {
+    /// Period of the short line.
+    short: usize,
+    /// Period of the long line.
+    long: usize,
+    /// Period of the signal line.
+    signal: usize,
+    /// Kinds of moving average for the short, long, and signal lines.
+    kinds: [MaKind; 3],
+    /// Starting EMA of the lines that are EMAs.
+    ema_seed: EmaSeed,
+}
+
+impl Default for MacdBuilder {
+    /// Common periods, short (12), long (26), and signal (9).
+    fn default() -> Self {
+        Self {
+            short: 12,
+            long: 26,
+            signal: 9,
+            kinds: [MaKind::Ema; 3],
+            ema_seed: EmaSeed::default(),
+        }
+    }
+}
+
+impl MacdBuilder {
+    /// Changes the period of the short line from the default (12).
+    pub fn short(mut self, period: usize) -> Self {
+        self.short = period;
+        self
+    }
+
+    /// Changes the period of the long line from the default (26).
+    pub fn long(mut self, period: usize) -> Self {
+        self.long = period;
+        self
+    }
+
+    /// Changes the period of the signal line from the default (9).
+    pub fn signal(mut self, period: usize) -> Self {
+        self.signal = period;
+        self
+    }
+
+    /// Changes the kind of moving average for every line from the default (EMA).
+    pub fn kind(mut self, kind: MaKind) -> Self {
+        self.kinds = [kind; 3];
+        self
+    }
+
+    /// Changes the kind of moving average for the short line from the default (EMA).
+    pub fn short_kind(mut self, kind: MaKind) -> Self {
+        self.kinds[0] = kind;
+        self
+    }
+
+    /// Changes the kind of moving average for the long line from the default (EMA).
+    pub fn long_kind(mut self, kind: MaKind) -> Self {
+        self.kinds[1] = kind;
+        self
+    }
+
+    /// Changes the kind of moving average for the signal line from the default (EMA).
+    pub fn signal_kind(mut self, kind: MaKind) -> Self {
+        self.kinds[2] = kind;
+        self
+    }
+
+    /// Changes the starting EMA of the lines that are EMAs from the default (SMA of the first
+    /// period).
+    pub fn ema_seed(mut self, seed: EmaSeed) -> Self {
+        self.ema_seed = seed;
+        self
+    }
+
+    /// Creates the MACD from the initial data, validated the same as `new`.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Short, Signal, and Long must greater than 0.
+    /// * Short must be smaller than Long.
+    /// * Data must have at least `long + signal - 1` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - Array of values to create the MACD from.
+    pub fn build(self, data: &[Num]) -> Result<MovingAverageConvergenceDivergence, TAError> {
+        MovingAverageConvergenceDivergence::with_kinds(
+            (self.short, self.long, self.signal),
+            self.kinds,
+            self.ema_seed,
+            data,
+        )
+    }
+}
+
+impl Snapshot for MovingAverageConvergenceDivergence {
+    fn snapshot(&self) -> IndicatorState {
+        let (ema_seed, custom) = self.ema_seed.to_state();
+        IndicatorState::new("MACD")
+            .scalar(self.value)
+            .optional_flag(self.side)
+            .variant(self.crossed)
+            .count(ema_seed)
+            .scalar(custom)
+            .child(&self.short_line)
+            .child(&self.long_line)
+            .child(&self.signal_line)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "MACD")?;
+        Ok(Self {
+            value: reader.scalar()?,
+            side: reader.optional_flag()?,
+            crossed: reader.variant()?,
+            ema_seed: EmaSeed::from_state(reader.count()?, reader.scalar()?)?,
+            short_line: reader.child()?,
+            long_line: reader.child()?,
+            signal_line: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -11,14 +11,26 @@
 //! * `x` = current close (most recent)
 //! * `y` = last close
 //! * `z` = current volume
+//!
+//! An optional signal line, a moving average of the OBV, confirms the trend of the volume flow.
 
-use crate::traits::{Close, InternalValue, Next, Period, Stats, Volume};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+use super::moving_average::MovingAverage;
+use super::seed::{self, Reseed, Seed};
+use super::MaKind;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    Close, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value, Volume,
+};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
 
 /// Used for conversions. Holds Close (0), and Volume (1) values.
-#[derive(Copy, Clone)]
-struct Data(Num, Num);
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num);
+
+seed::impl_flatten!(Data(0, 1));
 
 // Closing value.
 impl Close for Data {
@@ -47,7 +59,9 @@ impl Volume for Data {
 /// * `x` = current close (most recent)
 /// * `y` = last close
 /// * `z` = current volume
-#[derive(Debug, InternalValue, Period)]
+///
+/// An optional signal line, a moving average of the OBV, confirms the trend of the volume flow.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
 pub struct OnBalanceVolume {
     /// Size of the period (window) in which data is looked at.
     period: usize,
@@ -57,6 +71,12 @@ pub struct OnBalanceVolume {
     last_close: Num,
     /// Holds all of the current period's values.
     buffer: Buffer,
+    /// Moving average of the OBV, if created with a signal.
+    signal: Option<MovingAverage>,
+    /// If the OBV crossed the signal with the last value.
+    crossed: bool,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
 }
 
 impl OnBalanceVolume {
@@ -82,19 +102,18 @@ impl OnBalanceVolume {
             )));
         } else if data.len() < period {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data for history period provided",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "on-balance volume",
+                required: period,
+                received: data.len(),
+            });
         }
 
         let mut last_close = data[0].close();
         let mut last_obv = 0.0;
 
         // Build the buffer from the data provided.
-        let mut buffer: Buffer = match Buffer::from_array(period, &[last_obv]) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        let mut buffer: Buffer = Buffer::from_array(period, &[last_obv])?;
 
         // Calculate the remaining values.
         for v in data[1..].iter() {
@@ -108,14 +127,116 @@ impl OnBalanceVolume {
             last_close,
             value: last_obv,
             buffer,
+            signal: None,
+            crossed: false,
+            seed: Seed::new(data.len()),
         })
     }
 
+    /// Creates a new On-Balance Volume with the supplied period, without initial data. The candles
+    /// are supplied with `next` instead, until ready the value is calculated from the candles
+    /// supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - History of values to keep.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            &seed::placeholder(Data(1.0, 1.0), period),
+        ))
+    }
+
+    /// Creates a new On-Balance Volume with a signal line, a moving average of the OBV.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Signal period must be greater than 0.
+    /// * Data must have at least `period` and `signal_period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - History of values to keep.
+    /// * `signal_period` - Size of the period / window used for the signal line.
+    /// * `kind` - Kind of moving average used for the signal line.
+    /// * `data` - Array of values to create the OBV from.
+    pub fn with_signal<T>(
+        period: usize,
+        signal_period: usize,
+        kind: MaKind,
+        data: &[T],
+    ) -> Result<Self, TAError>
+    where
+        T: Close + Volume,
+    {
+        // Check we can calculate the signal line.
+        let mut obv = Self::new(period, data)?;
+        if signal_period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "signal period cannot be less than 1 to calculate on-balance volume",
+            )));
+        } else if data.len() < signal_period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "on-balance volume",
+                required: signal_period,
+                received: data.len(),
+            });
+        }
+
+        // OBV for each of the values, seeding the signal line.
+        let mut series: Vec<Num> = Vec::with_capacity(data.len());
+        series.push(0.0);
+        for w in data.windows(2) {
+            series.push(Self::calculate(
+                series[series.len() - 1],
+                &w[1],
+                w[0].close(),
+            ));
+        }
+
+        let mut signal = MovingAverage::new(kind, signal_period, &series[..signal_period])?;
+        let mut is_above = series[signal_period - 1] > signal.value();
+        for v in series[signal_period..].iter() {
+            let was_above = is_above;
+            is_above = *v > signal.next(*v);
+            obv.crossed = was_above != is_above;
+        }
+
+        obv.signal = Some(signal);
+        Ok(obv)
+    }
+
     /// Current and most recent value calculated.
     pub fn value(&self) -> Num {
         self.value
     }
 
+    /// Current value of the signal line, if created with a signal.
+    pub fn signal(&self) -> Option<Num> {
+        self.signal.as_ref().map(|s| s.value())
+    }
+
+    /// Kind of moving average used for the signal line, if created with a signal.
+    pub fn signal_kind(&self) -> Option<MaKind> {
+        self.signal.as_ref().map(|s| s.kind())
+    }
+
+    /// Checks if the OBV is above the signal line, false without a signal.
+    pub fn is_above_signal(&self) -> bool {
+        self.signal().is_some_and(|s| self.value > s)
+    }
+
+    /// Checks if the OBV crossed the signal line with the last value, false without a signal.
+    pub fn crossed(&self) -> bool {
+        self.crossed
+    }
+
     /// Calculates the On-Balance Value.
     ///
     /// # Arguments
@@ -153,9 +274,20 @@ where
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: T) -> Self::Output {
+        if seed::collect(self, Data(value.close(), value.volume())) {
+            return self.value;
+        }
+
+        let was_above = self.is_above_signal();
         self.value = Self::calculate(self.value(), &value, self.last_close);
         self.last_close = value.close();
 
+        // Progress the signal line.
+        if let Some(signal) = self.signal.as_mut() {
+            signal.next(self.value);
+            self.crossed = was_above != self.is_above_signal();
+        }
+
         // Rotate the buffer.
         self.buffer.shift(self.value());
         self.value
@@ -174,41 +306,89 @@ impl Next<(Num, Num)> for OnBalanceVolume {
     ///     * 0 = Close
     ///     * 1 = Volume
     fn next(&mut self, value: (Num, Num)) -> Self::Output {
-        let v = Data {
-            0: value.0,
-            1: value.1,
-        };
+        let v = Data(value.0, value.1);
 
         self.next(v)
     }
 }
 
-impl Stats for OnBalanceVolume {
-    /// Obtains the total sum of the buffer for OBV.
-    fn sum(&self) -> Num {
-        self.buffer.sum()
+impl Reset for OnBalanceVolume {
+    /// Clears the OBV back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.last_close = 0.0;
+        self.buffer.clear();
+        if let Some(signal) = self.signal.as_mut() {
+            signal.reset();
+        }
+        self.crossed = false;
+        self.seed.start();
     }
+}
 
-    /// Mean for the period of the OBV.
-    fn mean(&self) -> Num {
-        self.buffer.mean()
+impl Ready for OnBalanceVolume {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
     }
 
-    /// Current variance for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn variance(&self, is_sample: bool) -> Num {
-        self.buffer.variance(is_sample)
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
     }
+}
 
-    /// Current standard deviation for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn stdev(&self, is_sample: bool) -> Num {
-        self.buffer.stdev(is_sample)
+impl Reseed for OnBalanceVolume {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        match self.signal.as_ref() {
+            Some(signal) => self.period.max(signal.period()),
+            None => self.period,
+        }
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        match self.signal.as_ref() {
+            Some(signal) => Self::with_signal(self.period, signal.period(), signal.kind(), data),
+            None => Self::new(self.period, data),
+        }
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for OnBalanceVolume {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("OBV")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.last_close)
+            .flag(self.crossed)
+            .child(&self.buffer)
+            .optional_child(self.signal.as_ref())
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "OBV")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            last_close: reader.scalar()?,
+            crossed: reader.flag()?,
+            buffer: reader.child()?,
+            signal: reader.optional_child()?,
+            seed: reader.child()?,
+        })
     }
 }
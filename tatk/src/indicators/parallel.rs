@@ -0,0 +1,96 @@
+//! Parallel batch calculation, many configurations or series calculated across threads.
+//!
+//! The indicators themselves are single-threaded, each configuration or series is calculated on
+//! one of the threads available. Results are returned in the same order or under the same key as
+//! the input, an error creating one indicator does not stop the others.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::panic;
+use std::thread;
+
+use super::batch::{compute, Warmup};
+use crate::traits::{Next, Ready};
+use crate::{Num, TAError};
+
+/// Splits the items across the threads available, returning the result of each in order.
+///
+/// # Arguments
+///
+/// * `items` - Items to process.
+/// * `work` - Processes a single item.
+fn fan_out<T, R, F>(items: &[T], work: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let size = items.len().div_ceil(threads).max(1);
+    let work = &work;
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(work).collect::<Vec<R>>()))
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    })
+}
+
+/// Calculates an indicator for every configuration over the same data, each in parallel. The
+/// outputs of each are the same as `compute`, in the same order as the configurations.
+///
+/// # Arguments
+///
+/// * `configs` - Configurations to create the indicators from, such as periods.
+/// * `data` - Values to supply to every indicator, oldest to newest.
+/// * `warmup` - Outputs produced before each indicator is ready.
+/// * `build` - Creates an indicator without initial data from a configuration.
+pub fn compute_many<C, I, F>(
+    configs: &[C],
+    data: &[Num],
+    warmup: Warmup,
+    build: F,
+) -> Vec<Result<Vec<Num>, TAError>>
+where
+    C: Sync,
+    I: Next<Num, Output = Num> + Ready,
+    F: Fn(&C) -> Result<I, TAError> + Sync,
+{
+    fan_out(configs, |config| {
+        Ok(compute(build(config)?, data.iter().copied(), warmup))
+    })
+}
+
+/// Calculates an indicator for the candles of every symbol, each in parallel. The outputs of
+/// each are the same as `compute`, under the same symbol.
+///
+/// # Arguments
+///
+/// * `map` - Candles of each symbol, oldest to newest.
+/// * `warmup` - Outputs produced before each indicator is ready.
+/// * `build` - Creates an indicator without initial data for a symbol.
+pub fn compute_per_symbol<K, T, I, F>(
+    map: &HashMap<K, Vec<T>>,
+    warmup: Warmup,
+    build: F,
+) -> HashMap<K, Result<Vec<Num>, TAError>>
+where
+    K: Eq + Hash + Clone + Send + Sync,
+    T: Sync,
+    I: for<'a> Next<&'a T, Output = Num> + Ready,
+    F: Fn(&K) -> Result<I, TAError> + Sync,
+{
+    let entries: Vec<(&K, &Vec<T>)> = map.iter().collect();
+    fan_out(&entries, |(symbol, candles)| {
+        let outputs = build(symbol).map(|indicator| compute(indicator, candles.iter(), warmup));
+        ((*symbol).clone(), outputs)
+    })
+    .into_iter()
+    .collect()
+}
@@ -9,9 +9,13 @@
 //! * `x` = current value (most recent)
 //! * `y` = value `n` periods prior.
 
-use crate::traits::{AsValue, InternalValue, Next, Period, Stats};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
 
 /// Rate of Change (ROC), Measures percentage change in value.
 ///
@@ -23,7 +27,7 @@ use tatk_derive::{InternalValue, Period};
 ///
 /// * `x` = current value (most recent)
 /// * `y` = value `n` periods prior.
-#[derive(Debug, InternalValue, Period)]
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
 pub struct RateOfChange {
     /// Size of the period (window) in which data is looked at.
     period: usize,
@@ -33,6 +37,8 @@ pub struct RateOfChange {
     values: Buffer,
     /// Holds all of the current period's values.
     buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
 }
 
 impl RateOfChange {
@@ -55,16 +61,15 @@ impl RateOfChange {
             )));
         } else if data.len() < period + 1 {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data for period provided",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "rate of change",
+                required: period + 1,
+                received: data.len(),
+            });
         }
 
         // Stores previous closes / data points.
-        let mut values: Buffer = match Buffer::from_array(period, &data[..period]) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        let mut values: Buffer = Buffer::from_array(period, &data[..period])?;
 
         // Create the first value.
         let recent_value = data[period];
@@ -72,10 +77,7 @@ impl RateOfChange {
         values.shift(recent_value);
 
         // Build the buffer from the data provided.
-        let mut buffer: Buffer = match Buffer::from_array(period, &[last_roc]) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        let mut buffer: Buffer = Buffer::from_array(period, &[last_roc])?;
 
         // Process the remaining values.
         for v in data[period + 1..].iter() {
@@ -89,9 +91,25 @@ impl RateOfChange {
             value: last_roc,
             values,
             buffer,
+            seed: Seed::new(data.len()),
         })
     }
 
+    /// Creates a new Rate of Change with the supplied period, without initial data. The values are
+    /// supplied with `next` instead, until ready the value is calculated from the values supplied
+    /// so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period + 1)))
+    }
+
     /// Current and most recent value calculated.
     pub fn value(&self) -> Num {
         self.value
@@ -118,6 +136,10 @@ impl Next<Num> for RateOfChange {
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
         self.value = Self::calculate(&value, self.values.oldest());
 
         self.buffer.shift(self.value);
@@ -143,32 +165,69 @@ where
     }
 }
 
-impl Stats for RateOfChange {
-    /// Obtains the total sum of the buffer for ROC.
-    fn sum(&self) -> Num {
-        self.buffer.sum()
+impl Reset for RateOfChange {
+    /// Clears the ROC back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.values.clear();
+        self.buffer.clear();
+        self.seed.start();
     }
+}
 
-    /// Mean for the period of the ROC.
-    fn mean(&self) -> Num {
-        self.buffer.mean()
+impl Ready for RateOfChange {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
     }
 
-    /// Current variance for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn variance(&self, is_sample: bool) -> Num {
-        self.buffer.variance(is_sample)
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for RateOfChange {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
     }
 
-    /// Current standard deviation for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn stdev(&self, is_sample: bool) -> Num {
-        self.buffer.stdev(is_sample)
+    fn required(&self) -> usize {
+        self.period + 1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len() - 1, data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for RateOfChange {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("ROC")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.values)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "ROC")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            values: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
     }
 }
@@ -13,10 +13,37 @@
 //! * `z` = Period - 1.
 //! * `x1` = Most recent gain.
 //! * `y1` = Most recent loss.
+//!
+//! The averages are smoothed with Wilder's smoothing, above, by default. Cutler's RSI instead
+//! uses the simple average of the gains and losses for the period, so it does not depend on
+//! values older than the period.
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{
+    AsValue, CheckedNext, InternalValue, Next, Period, Ready, Reset, SignalSource, Snapshot, Stats,
+    Value,
+};
+use crate::{Buffer, IndicatorState, NonFinite, Num, Signal, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Smoothing used for the average gain and loss of a RSI.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RsiSmoothing {
+    /// Wilder's smoothing, `(prior * (n - 1) + x) / n`.
+    #[default]
+    Wilder,
+    /// Simple average of the period, Cutler's RSI.
+    Sma,
+    /// Exponential average with a smoothing factor of `2 / (n + 1)`.
+    Ema,
+}
 
-use crate::traits::{AsValue, InternalValue, Next, Period, Stats};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+impl Variants for RsiSmoothing {
+    const ALL: &'static [Self] = &[RsiSmoothing::Wilder, RsiSmoothing::Sma, RsiSmoothing::Ema];
+}
 
 /// Relative Strength Index (RSI)
 ///
@@ -33,7 +60,11 @@ use tatk_derive::{InternalValue, Period};
 /// * `z` = Period - 1.
 /// * `x1` = Most recent gain.
 /// * `y1` = Most recent loss.
-#[derive(Debug, InternalValue, Period)]
+///
+/// The averages are smoothed with Wilder's smoothing, above, by default. Cutler's RSI instead
+/// uses the simple average of the gains and losses for the period, so it does not depend on
+/// values older than the period.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
 pub struct RelativeStrengthIndex {
     /// Size of the period (window) in which data is looked at.
     period: usize,
@@ -45,16 +76,28 @@ pub struct RelativeStrengthIndex {
     loss_avg: Num,
     /// Last value processed.
     last_data_value: Num,
+    /// Smoothing used for the averages.
+    smoothing: RsiSmoothing,
+    /// Holds `period` amount of gains.
+    gains: Buffer,
+    /// Holds `period` amount of losses.
+    losses: Buffer,
     /// Oversold threshold.
     oversold: Num,
     /// Overbought threshold.
     overbought: Num,
+    /// RSI prior to the current value, compared to the thresholds for a signal.
+    prior: Option<Num>,
     /// Holds `period` amount of generated EMAs.
     buffer: Buffer,
+    /// How NaN or infinite values supplied with `next` are handled.
+    non_finite: NonFinite,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
 }
 
 impl RelativeStrengthIndex {
-    /// Creates a new RSI with the supplied period and initial data.
+    /// Creates a new RSI with the supplied period and initial data, using Wilder's smoothing.
     ///
     /// ### Requirements:
     ///
@@ -66,6 +109,27 @@ impl RelativeStrengthIndex {
     /// * `period` - Size of the period / window used.
     /// * `data` - Array of values to create the RSI from.
     pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::with_smoothing(period, data, RsiSmoothing::Wilder)
+    }
+
+    /// Creates a new RSI with the supplied period, initial data, and smoothing of the averages.
+    /// `RsiSmoothing::Sma` is Cutler's RSI.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Data must have at least `period + 1` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the RSI from.
+    /// * `smoothing` - Smoothing used for the average gain and loss.
+    pub fn with_smoothing(
+        period: usize,
+        data: &[Num],
+        smoothing: RsiSmoothing,
+    ) -> Result<Self, TAError> {
         // Check we can calculate Relative Strength Index.
         if period < 2 {
             return Err(TAError::InvalidSize(String::from(
@@ -73,68 +137,111 @@ impl RelativeStrengthIndex {
             )));
         } else if data.len() < period + 1 {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data for period",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "relative strength index",
+                required: period + 1,
+                received: data.len(),
+            });
         }
 
-        let mut gains: Num = 0.0;
-        let mut losses: Num = 0.0;
-        let mut last_data_value: &Num = &data[0];
+        // Make sure the data is usable.
+        TAError::check_finite(data.iter().copied())?;
 
         // Generates the gains / losses for the first period of values. Unique and uses all gains /
         // losses for the first period as a seed value.
-        for value in data[1..=period].iter() {
-            let change = value - last_data_value;
-            last_data_value = value;
-            if change > 0.0 {
-                gains = gains + change;
-            } else {
-                losses = losses + change.abs();
-            }
-        }
+        let (gains, losses): (Vec<Num>, Vec<Num>) = data[..=period]
+            .windows(2)
+            .map(|w| Self::change(w[0], w[1]))
+            .unzip();
+        let gains = Buffer::from_array(period, &gains)?;
+        let losses = Buffer::from_array(period, &losses)?;
 
         // These values will be updated by calculate, used to calculate period + 1.
-        let mut last_gain: Num = 0.0;
-        let mut last_loss: Num = 0.0;
-        let mut value = Self::calculate(period, &mut last_gain, &mut last_loss, gains, losses);
-
-        // Buffer will old processed RSIs
-        let mut buffer = match Buffer::from_array(period, &[value]) {
-            Ok(v) => v,
-            Err(error) => return Err(error),
+        let mut gain_avg: Num = 0.0;
+        let mut loss_avg: Num = 0.0;
+        let value = Self::calculate(
+            period,
+            &mut gain_avg,
+            &mut loss_avg,
+            gains.sum(),
+            losses.sum(),
+        );
+
+        let mut rsi = Self {
+            period,
+            value,
+            gain_avg,
+            loss_avg,
+            last_data_value: data[period],
+            smoothing,
+            gains,
+            losses,
+            oversold: 20.0,
+            overbought: 80.0,
+            prior: None,
+            // Buffer will hold processed RSIs.
+            buffer: Buffer::from_array(period, &[value])?,
+            non_finite: NonFinite::default(),
+            seed: Seed::new(data.len()),
         };
 
         // Calculate remaining values. This uses the average + next value. It's a slightly
         // different calculation than the initial seed value for the RSI.
-        if period < data.len() {
-            for v in &data[(period + 1)..] {
-                let change = v - last_data_value;
-                let mut gain = 0.0;
-                let mut loss = 0.0;
-
-                if change > 0.0 {
-                    gain = change;
-                } else {
-                    loss = change.abs();
-                }
-
-                value = Self::calculate(period, &mut last_gain, &mut last_loss, gain, loss);
-                buffer.shift(value);
-                last_data_value = v;
-            }
+        for v in data[(period + 1)..].iter() {
+            rsi.smooth(*v);
+            rsi.buffer.shift(rsi.value);
         }
 
-        Ok(Self {
-            period,
-            value,
-            gain_avg: last_gain,
-            loss_avg: last_loss,
-            last_data_value: last_data_value.clone(),
-            oversold: 20.0,
-            overbought: 80.0,
-            buffer,
-        })
+        Ok(rsi)
+    }
+
+    /// Creates a new RSI with the supplied period, initial data, and thresholds, using Wilder's
+    /// smoothing.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Thresholds must be between 0 and 100, oversold being less than overbought.
+    /// * Data must have at least `period + 1` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the RSI from.
+    /// * `oversold` - Oversold threshold, commonly 30.0.
+    /// * `overbought` - Overbought threshold, commonly 70.0.
+    pub fn with_thresholds(
+        period: usize,
+        data: &[Num],
+        oversold: Num,
+        overbought: Num,
+    ) -> Result<Self, TAError> {
+        Self::check_thresholds(oversold, overbought)?;
+
+        let mut rsi = Self::new(period, data)?;
+        rsi.set_oversold(oversold);
+        rsi.set_overbought(overbought);
+        Ok(rsi)
+    }
+
+    /// Creates a new RSI with the supplied period, without initial data. The values are supplied
+    /// with `next` instead, until ready the value is calculated from the values supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period + 1)))
+    }
+
+    /// Creates a builder for a RSI, defaulting to a period of 14 with an oversold threshold of
+    /// 20.0 and overbought threshold of 80.0.
+    pub fn builder() -> RsiBuilder {
+        RsiBuilder::default()
     }
 
     /// Current and most recent value calculated.
@@ -142,6 +249,17 @@ impl RelativeStrengthIndex {
         self.value
     }
 
+    /// Smoothing used for the average gain and loss.
+    pub fn smoothing(&self) -> RsiSmoothing {
+        self.smoothing
+    }
+
+    /// Changes how NaN or infinite values supplied with `next` are handled, the default being to
+    /// propagate them.
+    pub fn set_non_finite(&mut self, policy: NonFinite) {
+        self.non_finite = policy;
+    }
+
     /// Changes the Oversold Threshold from the default (20.0)
     pub fn set_oversold(&mut self, oversold_value: Num) {
         self.oversold = oversold_value;
@@ -162,9 +280,90 @@ impl RelativeStrengthIndex {
         self.value() > self.overbought
     }
 
-    /// Last value the RSI processed.
-    fn last_data_value(&self) -> Num {
-        self.last_data_value
+    /// Validates the thresholds are between 0 and 100 with oversold below overbought.
+    ///
+    /// # Arguments
+    ///
+    /// * `oversold` - Oversold threshold.
+    /// * `overbought` - Overbought threshold.
+    fn check_thresholds(oversold: Num, overbought: Num) -> Result<(), TAError> {
+        let range = 0.0..=100.0;
+        if !range.contains(&oversold) || !range.contains(&overbought) || oversold >= overbought {
+            return Err(TAError::InvalidSize(String::from(
+                "thresholds must be between 0 and 100 with oversold below overbought",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Gain (0) and loss (1) from one value to the next, both >= 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `last` - Prior value.
+    /// * `value` - Most recent value.
+    fn change(last: Num, value: Num) -> (Num, Num) {
+        let change = value - last;
+        if change > 0.0 {
+            (change, 0.0)
+        } else {
+            (0.0, change.abs())
+        }
+    }
+
+    /// Updates the averages with a new value using the smoothing, recalculating the RSI.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn smooth(&mut self, value: Num) -> Num {
+        let (gain, loss) = Self::change(self.last_data_value, value);
+        self.last_data_value = value;
+        self.prior = Some(self.value);
+        self.gains.shift(gain);
+        self.losses.shift(loss);
+
+        let period = self.period as Num;
+        self.value = match self.smoothing {
+            RsiSmoothing::Wilder => Self::calculate(
+                self.period,
+                &mut self.gain_avg,
+                &mut self.loss_avg,
+                gain,
+                loss,
+            ),
+            RsiSmoothing::Sma => {
+                self.gain_avg = self.gains.mean();
+                self.loss_avg = self.losses.mean();
+                Self::index(self.gain_avg, self.loss_avg)
+            }
+            RsiSmoothing::Ema => {
+                let k = 2.0 / (period + 1.0);
+                self.gain_avg += (gain - self.gain_avg) * k;
+                self.loss_avg += (loss - self.loss_avg) * k;
+                Self::index(self.gain_avg, self.loss_avg)
+            }
+        };
+
+        self.value
+    }
+
+    /// RSI from the average gain and loss. Without losses the RSI is 100, without gains it is 0,
+    /// and without either (flat data) it is 50.
+    ///
+    /// # Arguments
+    ///
+    /// * `gain_avg` - Average gain (>= 0).
+    /// * `loss_avg` - Average loss (>= 0).
+    fn index(gain_avg: Num, loss_avg: Num) -> Num {
+        if loss_avg == 0.0 {
+            return if gain_avg > 0.0 { 100.0 } else { 50.0 };
+        } else if gain_avg == 0.0 {
+            return 0.0;
+        }
+
+        100.0 - (100.0 / (1.0 + (gain_avg / loss_avg)))
     }
 
     /// Calculates a RSI with the given data between two indexes.
@@ -189,7 +388,7 @@ impl RelativeStrengthIndex {
         *gain_avg = (*gain_avg * period_value + gain) / period as Num;
         *loss_avg = (*loss_avg * period_value + loss) / period as Num;
 
-        100.0 - (100.0 / (1.0 + (*gain_avg / *loss_avg)))
+        Self::index(*gain_avg, *loss_avg)
     }
 }
 
@@ -203,26 +402,19 @@ impl Next<Num> for RelativeStrengthIndex {
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
-        let mut gain = 0.0;
-        let mut loss = 0.0;
-        let change = value - self.last_data_value();
+        if !self.non_finite.accepts(&[value]) {
+            // The value is unchanged, so the skipped bar does not repeat the last signal.
+            self.prior = self.prior.map(|_| self.value);
+            self.seed.skip();
+            return self.value;
+        }
 
-        if change > 0.0 {
-            gain = change;
-        } else {
-            loss = change.abs();
+        if seed::collect(self, value) {
+            return self.value;
         }
 
         // Calculate the new RSI.
-        self.last_data_value = value;
-        self.value = Self::calculate(
-            self.period(),
-            &mut self.gain_avg,
-            &mut self.loss_avg,
-            gain,
-            loss,
-        );
-        self.value
+        self.smooth(value)
     }
 }
 
@@ -243,32 +435,200 @@ where
     }
 }
 
-impl Stats for RelativeStrengthIndex {
-    /// Obtains the total sum of the buffer for RSI.
-    fn sum(&self) -> Num {
-        self.buffer.sum()
+impl CheckedNext<Num> for RelativeStrengthIndex {
+    /// Supply an additional value to recalculate a new RSI, rejecting NaN or infinite values.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn checked_next(&mut self, value: Num) -> Result<Self::Output, TAError> {
+        TAError::check_finite([value])?;
+        Ok(self.next(value))
+    }
+}
+
+impl SignalSource for RelativeStrengthIndex {
+    /// Buy on the value leaving oversold upwards and sell on the value leaving overbought
+    /// downwards, holding otherwise. Exactly at a threshold is not within it.
+    fn signal(&self) -> Signal {
+        let Some(prior) = self.prior else {
+            return Signal::Hold;
+        };
+
+        if prior < self.oversold && !self.is_oversold() {
+            Signal::Buy
+        } else if prior > self.overbought && !self.is_overbought() {
+            Signal::Sell
+        } else {
+            Signal::Hold
+        }
     }
+}
 
-    /// Mean for the period of the EMA.
-    fn mean(&self) -> Num {
-        self.buffer.mean()
+impl Reset for RelativeStrengthIndex {
+    /// Clears the RSI back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.gain_avg = 0.0;
+        self.loss_avg = 0.0;
+        self.last_data_value = 0.0;
+        self.prior = None;
+        self.gains.clear();
+        self.losses.clear();
+        self.buffer.clear();
+        self.seed.start();
     }
+}
 
-    /// Current variance for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn variance(&self, is_sample: bool) -> Num {
-        self.buffer.variance(is_sample)
+impl Ready for RelativeStrengthIndex {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
     }
+}
+
+impl Reseed for RelativeStrengthIndex {
+    type Input = Num;
 
-    /// Current standard deviation for the period.
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period + 1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        // Keep the thresholds that were set.
+        let mut rsi = Self::with_smoothing(self.period, data, self.smoothing)?;
+        rsi.oversold = self.oversold;
+        rsi.overbought = self.overbought;
+        rsi.non_finite = self.non_finite;
+        Ok(rsi)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::with_smoothing(data.len() - 1, data, self.smoothing) {
+            self.value = partial.value;
+        }
+    }
+}
+
+/// Builder for a RSI, defaulting to a period of 14 with an oversold threshold of 20.0 and
+/// overbought threshold of 80.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RsiBuilder {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Oversold threshold.
+    oversold: Num,
+    /// Overbought threshold.
+    overbought: Num,
+    /// Smoothing used for the averages.
+    smoothing: RsiSmoothing,
+}
+
+impl Default for RsiBuilder {
+    /// Common period (14), thresholds, oversold (20.0) and overbought (80.0), and Wilder's
+    /// smoothing.
+    fn default() -> Self {
+        Self {
+            period: 14,
+            oversold: 20.0,
+            overbought: 80.0,
+            smoothing: RsiSmoothing::Wilder,
+        }
+    }
+}
+
+impl RsiBuilder {
+    /// Changes the period from the default (14).
+    pub fn period(mut self, period: usize) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Changes the oversold threshold from the default (20.0).
+    pub fn oversold(mut self, oversold: Num) -> Self {
+        self.oversold = oversold;
+        self
+    }
+
+    /// Changes the overbought threshold from the default (80.0).
+    pub fn overbought(mut self, overbought: Num) -> Self {
+        self.overbought = overbought;
+        self
+    }
+
+    /// Changes the smoothing of the averages from the default (Wilder).
+    pub fn smoothing(mut self, smoothing: RsiSmoothing) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Creates the RSI from the initial data, validated the same as `new`.
     ///
-    /// # Arguments
+    /// ### Requirements:
     ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn stdev(&self, is_sample: bool) -> Num {
-        self.buffer.stdev(is_sample)
+    /// * Period must be greater than 1.
+    /// * Thresholds must be between 0 and 100, oversold being less than overbought.
+    /// * Data must have at least `period + 1` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - Array of values to create the RSI from.
+    pub fn build(self, data: &[Num]) -> Result<RelativeStrengthIndex, TAError> {
+        RelativeStrengthIndex::check_thresholds(self.oversold, self.overbought)?;
+
+        let mut rsi = RelativeStrengthIndex::with_smoothing(self.period, data, self.smoothing)?;
+        rsi.set_oversold(self.oversold);
+        rsi.set_overbought(self.overbought);
+        Ok(rsi)
+    }
+}
+
+impl Snapshot for RelativeStrengthIndex {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("RSI")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.gain_avg)
+            .scalar(self.loss_avg)
+            .scalar(self.last_data_value)
+            .variant(self.smoothing)
+            .scalar(self.oversold)
+            .scalar(self.overbought)
+            .optional(self.prior)
+            .variant(self.non_finite)
+            .child(&self.gains)
+            .child(&self.losses)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "RSI")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            gain_avg: reader.scalar()?,
+            loss_avg: reader.scalar()?,
+            last_data_value: reader.scalar()?,
+            smoothing: reader.variant()?,
+            oversold: reader.scalar()?,
+            overbought: reader.scalar()?,
+            prior: reader.optional()?,
+            non_finite: reader.variant()?,
+            gains: reader.child()?,
+            losses: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
     }
 }
@@ -0,0 +1,328 @@
+//! Rogers-Satchell Volatility, open-high-low-close volatility estimator.
+//!
+//! Unlike the Parkinson and Garman-Klass estimators, it remains accurate for data with a non-zero
+//! drift (trending). Assumes no gaps between candles.
+//!
+//! # Formula
+//!
+//! RSV = sqrt((1 / n) * Σ [ln(h / c) * ln(h / o) + ln(l / c) * ln(l / o)]) * sqrt(a)
+//!
+//! where:
+//!
+//! * `o` = open of a candle.
+//! * `h` = high of a candle.
+//! * `l` = low of a candle.
+//! * `c` = close of a candle.
+//! * `n` = period
+//! * `a` = periods per year (annualization), 1.0 for none.
+
+use super::bar_volatility::BarVolatility;
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    Close, High, InternalValue, Low, Next, Open, Period, Ready, Reset, Snapshot, Stats, Value,
+};
+use crate::{IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
+
+/// Used for conversions. Holds Open (0), High (1), Low (2), and Close (3) values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num, Num, Num);
+
+seed::impl_flatten!(Data(0, 1, 2, 3));
+
+// Opening value.
+impl Open for Data {
+    fn open(&self) -> Num {
+        self.0
+    }
+}
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.1
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.2
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.3
+    }
+}
+
+/// Rogers-Satchell Volatility, open-high-low-close volatility estimator.
+///
+/// Unlike the Parkinson and Garman-Klass estimators, it remains accurate for data with a non-zero
+/// drift (trending). Assumes no gaps between candles.
+///
+/// # Formula
+///
+/// RSV = sqrt((1 / n) * Σ [ln(h / c) * ln(h / o) + ln(l / c) * ln(l / o)]) * sqrt(a)
+///
+/// where:
+///
+/// * `o` = open of a candle.
+/// * `h` = high of a candle.
+/// * `l` = low of a candle.
+/// * `c` = close of a candle.
+/// * `n` = period
+/// * `a` = periods per year (annualization), 1.0 for none.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct RogersSatchellVolatility {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Rogers-Satchell Volatility's current value.
+    value: Num,
+    /// ln(h / c) * ln(h / o) + ln(l / c) * ln(l / o) for each candle within the period.
+    terms: BarVolatility,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
+}
+
+impl RogersSatchellVolatility {
+    /// Creates a new Rogers-Satchell Volatility with the supplied period, annualization, and initial
+    /// candles.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Annualization must be finite and greater than 0, 1.0 for none.
+    /// * Data must have at least `period` elements.
+    /// * All prices must be greater than 0 and lows no greater than their high.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    /// * `data` - Array of candles to create the volatility from.
+    pub fn new<T>(period: usize, annualization: Num, data: &[T]) -> Result<Self, TAError>
+    where
+        T: Open + High + Low + Close,
+    {
+        let terms: Vec<Option<Num>> = data.iter().map(Self::term).collect();
+        let terms = BarVolatility::new(
+            "rogers-satchell volatility",
+            period,
+            annualization,
+            1.0,
+            &terms,
+        )?;
+
+        Ok(Self {
+            period,
+            value: terms.value(),
+            terms,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new Rogers-Satchell Volatility with the supplied period and annualization, without
+    /// initial data. The candles are supplied with `next` instead, until ready the value is
+    /// calculated from the candles supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Annualization must be finite and greater than 0, 1.0 for none.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    pub fn with_period(period: usize, annualization: Num) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            annualization,
+            &seed::placeholder(Data(1.0, 1.0, 1.0, 1.0), period),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Volatility within the period, before annualization.
+    pub fn period_volatility(&self) -> Num {
+        self.terms.period_volatility()
+    }
+
+    /// Periods per year used to annualize.
+    pub fn annualization(&self) -> Num {
+        self.terms.annualization()
+    }
+
+    /// Checks if the last candle was skipped for having a price of 0 or lower, or a high less than
+    /// the low.
+    pub fn is_skipped(&self) -> bool {
+        self.terms.is_skipped()
+    }
+
+    /// Rogers-Satchell term of a candle, none if the candle is invalid.
+    pub(crate) fn term<T>(candle: &T) -> Option<Num>
+    where
+        T: Open + High + Low + Close,
+    {
+        let (open, high, low, close) = (candle.open(), candle.high(), candle.low(), candle.close());
+        if open <= 0.0 || close <= 0.0 || low <= 0.0 || high < low {
+            return None;
+        }
+
+        let upper = (high / close).ln() * (high / open).ln();
+        let lower = (low / close).ln() * (low / open).ln();
+        Some(upper + lower)
+    }
+}
+
+impl<T> Next<&T> for RogersSatchellVolatility
+where
+    T: Open + High + Low + Close,
+{
+    /// Next value for the Rogers-Satchell Volatility.
+    type Output = Num;
+
+    /// Supply an additional candle to recalculate a new volatility. Invalid candles are skipped,
+    /// the current value is returned and `is_skipped` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        // Invalid candles are skipped rather than collected.
+        let term = Self::term(value);
+        if term.is_none() {
+            self.seed.skip();
+        } else if seed::collect(
+            self,
+            Data(value.open(), value.high(), value.low(), value.close()),
+        ) {
+            return self.value;
+        }
+
+        self.value = self.terms.next(term);
+        self.value
+    }
+}
+
+impl Next<(Num, Num, Num, Num)> for RogersSatchellVolatility {
+    /// Next value for the Rogers-Satchell Volatility.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    ///     * 0 = Open
+    ///     * 1 = High
+    ///     * 2 = Low
+    ///     * 3 = Close
+    fn next(&mut self, value: (Num, Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2, value.3))
+    }
+}
+
+impl Stats for RogersSatchellVolatility {
+    /// Obtains the total sum of the buffer for Rogers-Satchell Volatility.
+    fn sum(&self) -> Num {
+        self.terms.buffer().sum()
+    }
+
+    /// Mean for the period of the Rogers-Satchell Volatility.
+    fn mean(&self) -> Num {
+        self.terms.buffer().mean()
+    }
+
+    /// Current variance for the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn variance(&self, is_sample: bool) -> Num {
+        self.terms.buffer().variance(is_sample)
+    }
+
+    /// Current standard deviation for the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn stdev(&self, is_sample: bool) -> Num {
+        self.terms.buffer().stdev(is_sample)
+    }
+}
+
+impl Reset for RogersSatchellVolatility {
+    /// Clears the Rogers-Satchell Volatility back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.terms.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for RogersSatchellVolatility {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for RogersSatchellVolatility {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, self.terms.annualization(), data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), self.terms.annualization(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for RogersSatchellVolatility {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Rogers-Satchell Volatility")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.terms)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Rogers-Satchell Volatility")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            terms: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
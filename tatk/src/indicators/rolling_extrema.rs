@@ -0,0 +1,327 @@
+//! Rolling Extrema, highest high and lowest low within a period.
+//!
+//! Uses monotonic queues so each new value is processed in amortized O(1) time instead of scanning
+//! the entire period.
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{High, Low, Next, Period, Ready, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+use alloc::collections::VecDeque;
+use tatk_derive::Period;
+
+/// Queue that holds only the values that can still become the extreme (maximum or minimum) of a
+/// rolling period. The front of the queue is always the current extreme.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MonotonicQueue {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Tracks the maximum if true, otherwise the minimum.
+    is_max: bool,
+    /// Candidates for the extreme, (index, value). Oldest -> Newest.
+    data: VecDeque<(usize, Num)>,
+}
+
+impl MonotonicQueue {
+    /// Creates a new empty queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `is_max` - Tracks the maximum if true, otherwise the minimum.
+    pub(crate) fn new(period: usize, is_max: bool) -> Self {
+        Self {
+            period,
+            is_max,
+            data: VecDeque::with_capacity(period),
+        }
+    }
+
+    /// Adds a new value, removing candidates that can no longer be the extreme.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the value within the entire series.
+    /// * `value` - Value to add.
+    pub(crate) fn push(&mut self, index: usize, value: Num) {
+        // Newer values that match or beat older ones will outlive them.
+        while let Some(&(_, last)) = self.data.back() {
+            if (self.is_max && last <= value) || (!self.is_max && last >= value) {
+                self.data.pop_back();
+            } else {
+                break;
+            }
+        }
+        self.data.push_back((index, value));
+
+        // Remove the extreme if it has fallen out of the period.
+        while let Some(&(first, _)) = self.data.front() {
+            if first + self.period <= index {
+                self.data.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Current extreme of the period.
+    pub(crate) fn value(&self) -> Num {
+        self.data.front().map(|v| v.1).unwrap_or(Num::NAN)
+    }
+
+    /// Index of the current extreme within the entire series.
+    pub(crate) fn index(&self) -> usize {
+        self.data.front().map(|v| v.0).unwrap_or(0)
+    }
+
+    /// Removes all of the candidates.
+    pub(crate) fn clear(&mut self) {
+        self.data.clear();
+    }
+}
+
+/// Rolling Extrema, highest high and lowest low within a period.
+///
+/// Uses monotonic queues so each new value is processed in amortized O(1) time instead of scanning
+/// the entire period.
+#[derive(Debug, Clone, PartialEq, Period)]
+pub struct RollingExtrema {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Total amount of values processed.
+    count: usize,
+    /// Candidates for the highest value.
+    highs: MonotonicQueue,
+    /// Candidates for the lowest value.
+    lows: MonotonicQueue,
+}
+
+impl RollingExtrema {
+    /// Creates a new Rolling Extrema with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the extrema from.
+    pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        let mut extrema = Self::empty(period, data.len())?;
+        for value in data.iter() {
+            extrema.push(*value, *value);
+        }
+
+        Ok(extrema)
+    }
+
+    /// Creates a new Rolling Extrema with the supplied period and initial candles, tracking the
+    /// highest high and lowest low.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of candles to create the extrema from.
+    pub fn from_candles<T>(period: usize, data: &[T]) -> Result<Self, TAError>
+    where
+        T: High + Low,
+    {
+        let mut extrema = Self::empty(period, data.len())?;
+        for value in data.iter() {
+            extrema.push(value.high(), value.low());
+        }
+
+        Ok(extrema)
+    }
+
+    /// Creates a new Rolling Extrema with the supplied period, without initial data. The values
+    /// are supplied with `next` instead, until ready the extrema are of the values supplied so
+    /// far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        // No data to check, only the period.
+        Self::empty(period, period)
+    }
+
+    /// Validates the period and amount of data, creating an extrema with no values.
+    fn empty(period: usize, len: usize) -> Result<Self, TAError> {
+        // Check we can calculate Rolling Extrema.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate rolling extrema",
+            )));
+        } else if len < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "rolling extrema",
+                required: period,
+                received: len,
+            });
+        }
+
+        Ok(Self {
+            period,
+            count: 0,
+            highs: MonotonicQueue::new(period, true),
+            lows: MonotonicQueue::new(period, false),
+        })
+    }
+
+    /// Highest value within the period.
+    pub fn max(&self) -> Num {
+        self.highs.value()
+    }
+
+    /// Lowest value within the period.
+    pub fn min(&self) -> Num {
+        self.lows.value()
+    }
+
+    /// Amount of values (bars) since the most recent occurrence of the highest value, 0 being
+    /// the newest value.
+    pub fn max_index_age(&self) -> usize {
+        self.count.saturating_sub(1) - self.highs.index()
+    }
+
+    /// Amount of values (bars) since the most recent occurrence of the lowest value, 0 being the
+    /// newest value.
+    pub fn min_index_age(&self) -> usize {
+        self.count.saturating_sub(1) - self.lows.index()
+    }
+
+    /// Adds a new high and low to the queues.
+    fn push(&mut self, high: Num, low: Num) {
+        self.highs.push(self.count, high);
+        self.lows.push(self.count, low);
+        self.count += 1;
+    }
+}
+
+impl Next<Num> for RollingExtrema {
+    /// Lowest and Highest values.
+    type Output = (Num, Num);
+
+    /// Supply an additional value to recalculate the extrema.
+    ///
+    /// # Returns
+    ///
+    /// * (`Min`, `Max`)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        self.push(value, value);
+        (self.min(), self.max())
+    }
+}
+
+impl<T> Next<&T> for RollingExtrema
+where
+    T: High + Low,
+{
+    /// Lowest low and Highest high.
+    type Output = (Num, Num);
+
+    /// Supply an additional candle to recalculate the extrema.
+    ///
+    /// # Returns
+    ///
+    /// * (`Min`, `Max`)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        self.push(value.high(), value.low());
+        (self.min(), self.max())
+    }
+}
+
+impl Reset for RollingExtrema {
+    /// Clears the Rolling Extrema back to having seen no data. Extrema are tracked from the first
+    /// value supplied afterwards, being NaN until then.
+    fn reset(&mut self) {
+        self.count = 0;
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
+impl Ready for RollingExtrema {
+    /// Checks if a full period of values has been supplied.
+    fn is_ready(&self) -> bool {
+        self.count >= self.period
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.count
+    }
+}
+
+impl Snapshot for MonotonicQueue {
+    fn snapshot(&self) -> IndicatorState {
+        let values: Vec<Num> = self.data.iter().map(|v| v.1).collect();
+        let state = IndicatorState::new("monotonic queue")
+            .count(self.period)
+            .flag(self.is_max)
+            .buffer(&values);
+
+        // Position of each candidate within the series, one count for each value.
+        self.data
+            .iter()
+            .fold(state, |state, (index, _)| state.count(*index))
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "monotonic queue")?;
+        let (period, is_max) = (reader.count()?, reader.flag()?);
+        let values = reader.buffer()?;
+        let mut data = VecDeque::with_capacity(period.max(values.len()));
+        for value in values.iter() {
+            data.push_back((reader.count()?, *value));
+        }
+
+        Ok(Self {
+            period,
+            is_max,
+            data,
+        })
+    }
+}
+
+impl Snapshot for RollingExtrema {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Rolling Extrema")
+            .count(self.period)
+            .count(self.count)
+            .child(&self.highs)
+            .child(&self.lows)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Rolling Extrema")?;
+        Ok(Self {
+            period: reader.count()?,
+            count: reader.count()?,
+            highs: reader.child()?,
+            lows: reader.child()?,
+        })
+    }
+}
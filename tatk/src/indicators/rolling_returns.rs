@@ -0,0 +1,153 @@
+//! Shared machinery for indicators calculated from the returns within a period.
+//!
+//! Accepts either returns directly or prices, converting the prices to simple returns.
+
+use super::ReturnKind;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::Snapshot;
+use crate::{Buffer, IndicatorState, Num, TAError};
+
+/// Rolling period of returns, optionally converted from prices.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RollingReturns {
+    /// Last price received, none if the data is returns.
+    last: Option<Num>,
+    /// Returns within the period.
+    returns: Buffer,
+}
+
+impl RollingReturns {
+    /// Creates the returns from the first period of the data, the remaining returns are
+    /// provided to be added by the indicator.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Data must have at least `period` returns, or `period + 1` prices.
+    /// * Prices must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` - Name of the indicator, used for errors.
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of returns or prices.
+    /// * `is_prices` - Data is prices, `push` also expects prices.
+    pub(crate) fn new(
+        name: &'static str,
+        period: usize,
+        data: &[Num],
+        is_prices: bool,
+    ) -> Result<(Self, Vec<Num>), TAError> {
+        if period < 2 {
+            return Err(TAError::InvalidSize(format!(
+                "period cannot be less than 2 to calculate {}",
+                name
+            )));
+        } else if data.len() < period + is_prices as usize {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: name,
+                required: period + is_prices as usize,
+                received: data.len(),
+            });
+        } else if is_prices && data.iter().any(|v| *v <= 0.0) {
+            return Err(TAError::InvalidInput(format!(
+                "prices must be greater than 0 to calculate {}",
+                name
+            )));
+        }
+
+        // Convert the prices into returns.
+        let (returns, last): (Vec<Num>, Option<Num>) = if is_prices {
+            let returns = data
+                .windows(2)
+                .map(|w| ReturnKind::Simple.calculate(w[0], w[1]))
+                .collect();
+            (returns, data.last().copied())
+        } else {
+            (data.to_vec(), None)
+        };
+
+        Ok((
+            Self {
+                last,
+                returns: Buffer::from_array(period, &returns[..period])?,
+            },
+            returns[period..].to_vec(),
+        ))
+    }
+
+    /// Returns within the period.
+    pub(crate) fn returns(&self) -> &Buffer {
+        &self.returns
+    }
+
+    /// Checks if the data is prices, converted into returns.
+    pub(crate) fn is_prices(&self) -> bool {
+        self.last.is_some()
+    }
+
+    /// Checks if the return, or price if created from prices, can be added. Prices that are 0 or
+    /// lower cannot.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Return or price to check.
+    pub(crate) fn accepts(&self, value: Num) -> bool {
+        self.last.is_none() || value > 0.0
+    }
+
+    /// Clears the returns within the period, keeping if the data is prices.
+    pub(crate) fn clear(&mut self) {
+        self.returns.clear();
+    }
+
+    /// Adds a return to the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New return to add.
+    pub(crate) fn add(&mut self, value: Num) {
+        self.returns.shift(value);
+    }
+
+    /// Adds a return, or price if created from prices, to the period. Prices that are 0 or lower
+    /// are ignored, returning false.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New return or price to add.
+    pub(crate) fn push(&mut self, value: Num) -> bool {
+        if !self.accepts(value) {
+            return false;
+        }
+
+        match self.last {
+            Some(last) => {
+                self.last = Some(value);
+                self.add(ReturnKind::Simple.calculate(last, value));
+            }
+            None => self.add(value),
+        }
+
+        true
+    }
+}
+
+impl Snapshot for RollingReturns {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("rolling returns")
+            .optional(self.last)
+            .child(&self.returns)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "rolling returns")?;
+        Ok(Self {
+            last: reader.optional()?,
+            returns: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,252 @@
+//! Rolling Sum, the sum of the values within a period.
+//!
+//! Sums the raw values supplied rather than the output of another indicator, such as signed
+//! volume or a count of events. The sum is kept incrementally and recalculated from the values
+//! once every period to prevent drift.
+//!
+//! # Formula
+//!
+//! Sum = x\[t\] + x\[t - 1\] + ... + x\[t - n + 1\]
+//!
+//! where:
+//!
+//! * `x[t]` = current value (most recent)
+//! * `n` = period
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
+
+/// Rolling Sum, the sum of the values within a period.
+///
+/// Sums the raw values supplied rather than the output of another indicator, such as signed
+/// volume or a count of events. The sum is kept incrementally and recalculated from the values
+/// once every period to prevent drift.
+///
+/// # Formula
+///
+/// Sum = x\[t\] + x\[t - 1\] + ... + x\[t - n + 1\]
+///
+/// where:
+///
+/// * `x[t]` = current value (most recent)
+/// * `n` = period
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct RollingSum {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Sum of the period.
+    value: Num,
+    /// Values added since the sum was last recalculated.
+    shifts: usize,
+    /// Holds all of the current period's values.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl RollingSum {
+    /// Creates a new Rolling Sum with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the Rolling Sum from.
+    pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate Rolling Sum.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate rolling sum",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "rolling sum",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        let buffer = Buffer::from_array(period, data)?;
+        Ok(Self {
+            period,
+            value: buffer.queue().iter().sum(),
+            shifts: 0,
+            buffer,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new Rolling Sum with the supplied period, without initial data. The values are
+    /// supplied with `next` instead, until ready the value is calculated from the values supplied
+    /// so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period)))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+}
+
+impl Next<Num> for RollingSum {
+    /// Next value for the Rolling Sum.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new Rolling Sum.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        // Rotate the buffer, removing the oldest value from the sum.
+        let oldest = self.buffer.shift(value);
+        self.shifts += 1;
+
+        // Recalculate from the values once per period to remove accumulated error.
+        if self.shifts >= self.period {
+            self.shifts = 0;
+            self.value = self.buffer.queue().iter().sum();
+        } else {
+            self.value += value - oldest;
+        }
+
+        self.value
+    }
+}
+
+impl<T> Next<T> for RollingSum
+where
+    T: AsValue,
+{
+    /// Next value for the Rolling Sum.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new Rolling Sum.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Stats for RollingSum {
+    /// Obtains the total sum of the buffer for Rolling Sum.
+    fn sum(&self) -> Num {
+        self.value
+    }
+
+    /// Mean for the period of the Rolling Sum.
+    fn mean(&self) -> Num {
+        self.value / self.period as Num
+    }
+
+    /// Current variance for the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn variance(&self, is_sample: bool) -> Num {
+        self.buffer.variance(is_sample)
+    }
+
+    /// Current standard deviation for the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn stdev(&self, is_sample: bool) -> Num {
+        self.buffer.stdev(is_sample)
+    }
+}
+
+impl Reset for RollingSum {
+    /// Clears the Rolling Sum back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.shifts = 0;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for RollingSum {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for RollingSum {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for RollingSum {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Rolling Sum")
+            .count(self.period)
+            .scalar(self.value)
+            .count(self.shifts)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Rolling Sum")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            shifts: reader.count()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
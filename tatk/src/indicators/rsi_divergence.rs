@@ -0,0 +1,270 @@
+//! RSI Divergence, disagreement between the swings of the price and the swings of its RSI.
+//!
+//! Swings of both the price and the RSI are pivots greater (highs) or less (lows) than the
+//! `lookback` values on either side, confirmed `lookback` values after they occur. Each price
+//! swing is paired with the RSI swing of the same kind at or before it, within `lookback` values,
+//! and compared to the prior pair of that kind if they are at least `min_distance` values apart.
+//!
+//! * Regular Bullish: price makes a lower low, RSI makes a higher low.
+//! * Hidden Bullish: price makes a higher low, RSI makes a lower low.
+//! * Regular Bearish: price makes a higher high, RSI makes a lower high.
+//! * Hidden Bearish: price makes a lower high, RSI makes a higher high.
+
+use super::{RelativeStrengthIndex, Swing, SwingKind, SwingPoints};
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{Next, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+
+/// Type of divergence between the price and the RSI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DivergenceEvent {
+    /// Price made a lower low while the RSI made a higher low, a possible reversal upwards.
+    RegularBullish,
+    /// Price made a higher low while the RSI made a lower low, a possible continuation upwards.
+    HiddenBullish,
+    /// Price made a higher high while the RSI made a lower high, a possible reversal downwards.
+    RegularBearish,
+    /// Price made a lower high while the RSI made a higher high, a possible continuation
+    /// downwards.
+    HiddenBearish,
+}
+
+impl Variants for DivergenceEvent {
+    const ALL: &'static [Self] = &[
+        DivergenceEvent::RegularBullish,
+        DivergenceEvent::HiddenBullish,
+        DivergenceEvent::RegularBearish,
+        DivergenceEvent::HiddenBearish,
+    ];
+}
+
+/// Price swing paired with an RSI swing of the same kind.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Pivot {
+    /// Position of the price swing, 0 being the first price supplied.
+    index: usize,
+    /// Price of the swing.
+    price: Num,
+    /// RSI of the paired swing.
+    rsi: Num,
+}
+
+/// RSI Divergence, disagreement between the swings of the price and the swings of its RSI.
+///
+/// Swings of both the price and the RSI are pivots greater (highs) or less (lows) than the
+/// `lookback` values on either side, confirmed `lookback` values after they occur. Each price
+/// swing is paired with the RSI swing of the same kind at or before it, within `lookback` values,
+/// and compared to the prior pair of that kind if they are at least `min_distance` values apart.
+///
+/// * Regular Bullish: price makes a lower low, RSI makes a higher low.
+/// * Hidden Bullish: price makes a higher low, RSI makes a lower low.
+/// * Regular Bearish: price makes a higher high, RSI makes a lower high.
+/// * Hidden Bearish: price makes a lower high, RSI makes a higher high.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RsiDivergence {
+    /// RSI of the prices supplied.
+    rsi: RelativeStrengthIndex,
+    /// Values required on either side of a pivot.
+    lookback: usize,
+    /// Minimum values between the pivots compared.
+    min_distance: usize,
+    /// Swings of the prices.
+    price_swings: SwingPoints,
+    /// Swings of the RSI.
+    rsi_swings: SwingPoints,
+    /// Most recent paired swing low.
+    low: Option<Pivot>,
+    /// Most recent paired swing high.
+    high: Option<Pivot>,
+    /// Divergence of the most recent price.
+    event: Option<DivergenceEvent>,
+}
+
+impl RsiDivergence {
+    /// Creates a new RSI Divergence from an RSI, swings are only detected from the prices supplied
+    /// afterwards.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Lookback must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `rsi` - RSI to calculate from the prices supplied.
+    /// * `lookback` - Values required on either side of a pivot, the delay to confirm it.
+    /// * `min_distance` - Minimum values between the pivots compared.
+    pub fn new(
+        rsi: RelativeStrengthIndex,
+        lookback: usize,
+        min_distance: usize,
+    ) -> Result<Self, TAError> {
+        Ok(Self {
+            rsi,
+            lookback,
+            min_distance,
+            price_swings: SwingPoints::new(lookback, lookback, lookback)?,
+            rsi_swings: SwingPoints::new(lookback, lookback, lookback)?,
+            low: None,
+            high: None,
+            event: None,
+        })
+    }
+
+    /// RSI of the prices supplied.
+    pub fn rsi(&self) -> &RelativeStrengthIndex {
+        &self.rsi
+    }
+
+    /// Values required on either side of a pivot.
+    pub fn lookback(&self) -> usize {
+        self.lookback
+    }
+
+    /// Minimum values between the pivots compared.
+    pub fn min_distance(&self) -> usize {
+        self.min_distance
+    }
+
+    /// Divergence confirmed by the most recent price, `None` if there was none.
+    pub fn event(&self) -> Option<DivergenceEvent> {
+        self.event
+    }
+
+    /// Supply an additional price to recalculate the RSI, returning the divergence confirmed by
+    /// it. Divergences are confirmed `lookback` prices after the pivot.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - New price to add to period.
+    pub fn next_with_price(&mut self, price: Num) -> Option<DivergenceEvent> {
+        let rsi = self.rsi.next(price);
+        self.rsi_swings.next((rsi, rsi));
+        let confirmed = self.price_swings.next((price, price));
+
+        self.event = None;
+        for swing in confirmed.iter() {
+            if let Some(event) = self.compare(swing) {
+                self.event = Some(event);
+            }
+        }
+
+        self.event
+    }
+
+    /// Pairs a price swing with an RSI swing, comparing it to the prior pair of the same kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `swing` - Confirmed price swing.
+    fn compare(&mut self, swing: &Swing) -> Option<DivergenceEvent> {
+        let paired = self
+            .rsi_swings
+            .recent_swings(2 * (self.lookback + 1))
+            .into_iter()
+            .rev()
+            .find(|s| {
+                s.kind == swing.kind
+                    && s.index <= swing.index
+                    && swing.index - s.index <= self.lookback
+            })?;
+
+        let pivot = Pivot {
+            index: swing.index,
+            price: swing.price,
+            rsi: paired.price,
+        };
+        let prior = match swing.kind {
+            SwingKind::Low => self.low.replace(pivot),
+            SwingKind::High => self.high.replace(pivot),
+        }?;
+
+        if pivot.index - prior.index < self.min_distance {
+            return None;
+        }
+
+        match swing.kind {
+            SwingKind::Low if pivot.price < prior.price && pivot.rsi > prior.rsi => {
+                Some(DivergenceEvent::RegularBullish)
+            }
+            SwingKind::Low if pivot.price > prior.price && pivot.rsi < prior.rsi => {
+                Some(DivergenceEvent::HiddenBullish)
+            }
+            SwingKind::High if pivot.price > prior.price && pivot.rsi < prior.rsi => {
+                Some(DivergenceEvent::RegularBearish)
+            }
+            SwingKind::High if pivot.price < prior.price && pivot.rsi > prior.rsi => {
+                Some(DivergenceEvent::HiddenBearish)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Reset for RsiDivergence {
+    /// Clears the RSI and swings back to having seen no data.
+    fn reset(&mut self) {
+        self.rsi.reset();
+        self.price_swings.reset();
+        self.rsi_swings.reset();
+        self.low = None;
+        self.high = None;
+        self.event = None;
+    }
+}
+
+impl RsiDivergence {
+    /// Adds an optional pivot to a state, held as a flag followed by its parts or 0.
+    fn pivot_state(state: IndicatorState, pivot: Option<Pivot>) -> IndicatorState {
+        let Pivot { index, price, rsi } = pivot.unwrap_or(Pivot {
+            index: 0,
+            price: 0.0,
+            rsi: 0.0,
+        });
+
+        state
+            .flag(pivot.is_some())
+            .count(index)
+            .scalar(price)
+            .scalar(rsi)
+    }
+
+    /// Reads an optional pivot from a state.
+    fn read_pivot(reader: &mut StateReader) -> Result<Option<Pivot>, TAError> {
+        let is_some = reader.flag()?;
+        let pivot = Pivot {
+            index: reader.count()?,
+            price: reader.scalar()?,
+            rsi: reader.scalar()?,
+        };
+
+        Ok(is_some.then_some(pivot))
+    }
+}
+
+impl Snapshot for RsiDivergence {
+    fn snapshot(&self) -> IndicatorState {
+        let state = IndicatorState::new("RSI Divergence")
+            .count(self.lookback)
+            .count(self.min_distance)
+            .optional_variant(self.event)
+            .child(&self.rsi)
+            .child(&self.price_swings)
+            .child(&self.rsi_swings);
+        let state = Self::pivot_state(state, self.low);
+        Self::pivot_state(state, self.high)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "RSI Divergence")?;
+        Ok(Self {
+            lookback: reader.count()?,
+            min_distance: reader.count()?,
+            event: reader.optional_variant()?,
+            rsi: reader.child()?,
+            price_swings: reader.child()?,
+            rsi_swings: reader.child()?,
+            low: Self::read_pivot(&mut reader)?,
+            high: Self::read_pivot(&mut reader)?,
+        })
+    }
+}
@@ -0,0 +1,358 @@
+//! Savitzky-Golay Filter, smooths a line by fitting a polynomial to each period.
+//!
+//! A polynomial of the degree is fit to the period using the least squares approach, the value
+//! is the fit at the newest value. The first derivative of the fit is a low noise estimate of the
+//! slope. Fitting reduces to a weighted sum of the period with coefficients that only depend on
+//! the period and degree, they are calculated once on creation.
+//!
+//! # Formula
+//!
+//! SG = c\[0\] * x\[t - n + 1\] + ... + c\[n - 1\] * x\[t\]
+//!
+//! where:
+//!
+//! * `x[t]` = current value (most recent)
+//! * `c` = coefficients for the period and degree.
+//! * `n` = period
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Savitzky-Golay Filter, smooths a line by fitting a polynomial to each period.
+///
+/// A polynomial of the degree is fit to the period using the least squares approach, the value
+/// is the fit at the newest value. The first derivative of the fit is a low noise estimate of the
+/// slope. Fitting reduces to a weighted sum of the period with coefficients that only depend on
+/// the period and degree, they are calculated once on creation.
+///
+/// # Formula
+///
+/// SG = c\[0\] * x\[t - n + 1\] + ... + c\[n - 1\] * x\[t\]
+///
+/// where:
+///
+/// * `x[t]` = current value (most recent)
+/// * `c` = coefficients for the period and degree.
+/// * `n` = period
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct SavitzkyGolay {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Fit at the newest value.
+    value: Num,
+    /// Degree of the polynomial.
+    degree: usize,
+    /// Slope of the fit at the newest value.
+    derivative: Num,
+    /// Coefficients for the fit at the newest value, oldest to newest.
+    coefficients: Vec<Num>,
+    /// Coefficients for the slope at the newest value, oldest to newest.
+    derivatives: Vec<Num>,
+    /// Holds all of the current period's values.
+    values: Buffer,
+    /// Holds `period` amount of generated fits.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl SavitzkyGolay {
+    /// Creates a new Savitzky-Golay Filter with the supplied period, degree, and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Degree must be less than the period.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `degree` - Degree of the polynomial fit to each period.
+    /// * `data` - Array of values to create the Savitzky-Golay Filter from.
+    pub fn new(period: usize, degree: usize, data: &[Num]) -> Result<Self, TAError> {
+        if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "savitzky-golay filter",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Fit at the newest value, the end of the period.
+        let coefficients =
+            Self::compute_coefficients(period, degree, period.saturating_sub(1), false)?;
+        let derivatives =
+            Self::compute_coefficients(period, degree, period.saturating_sub(1), true)?;
+
+        // Build the buffer containing the `period` of values.
+        let values = Buffer::from_array(period, &data[..period])?;
+        let value = values.weighted_sum(&coefficients);
+
+        let mut sg = Self {
+            period,
+            value,
+            degree,
+            derivative: values.weighted_sum(&derivatives),
+            coefficients,
+            derivatives,
+            values,
+            buffer: Buffer::from_array(period, &[value])?,
+            seed: Seed::new(period),
+        };
+
+        // Calculate the remainder of the fits.
+        for v in data[period..].iter() {
+            sg.next(*v);
+        }
+
+        Ok(sg)
+    }
+
+    /// Creates a new Savitzky-Golay Filter with the supplied period and degree, without initial
+    /// data. The values are supplied with `next` instead, the value is 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Degree must be less than the period.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `degree` - Degree of the polynomial fit to each period.
+    pub fn with_period(period: usize, degree: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, degree, &seed::placeholder(1.0, period)))
+    }
+
+    /// Calculates the coefficients for the fit, or its slope, at a position within a period.
+    /// Positions are 0 for the oldest value to `period - 1` for the newest, the middle of the
+    /// period produces the centered coefficients of published tables.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Degree must be less than the period.
+    /// * Position must be less than the period.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `degree` - Degree of the polynomial.
+    /// * `position` - Position of the fit within the period.
+    /// * `is_derivative` - Calculate the coefficients of the slope instead of the fit.
+    pub fn compute_coefficients(
+        period: usize,
+        degree: usize,
+        position: usize,
+        is_derivative: bool,
+    ) -> Result<Vec<Num>, TAError> {
+        // Check we can calculate Savitzky-Golay Filter.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate savitzky-golay filter",
+            )));
+        } else if degree >= period {
+            return Err(TAError::InvalidSize(String::from(
+                "degree must be less than period to calculate savitzky-golay filter",
+            )));
+        } else if position >= period {
+            return Err(TAError::InvalidSize(String::from(
+                "position must be less than period to calculate savitzky-golay filter",
+            )));
+        }
+
+        // Powers of each x relative to the position, the fit and slope at 0 are the first two
+        // polynomial terms.
+        let size = degree + 1;
+        let powers: Vec<Vec<Num>> = (0..period)
+            .map(|i| {
+                let x = i as Num - position as Num;
+                (0..size).map(|j| x.powi(j as i32)).collect()
+            })
+            .collect();
+
+        // Normal equations (A^T * A), augmented with the unit vector of the term.
+        let term = usize::from(is_derivative && degree > 0);
+        let mut matrix: Vec<Vec<Num>> = (0..size)
+            .map(|r| {
+                let mut row: Vec<Num> = (0..size)
+                    .map(|c| powers.iter().map(|p| p[r] * p[c]).sum())
+                    .collect();
+                row.push(if r == term { 1.0 } else { 0.0 });
+                row
+            })
+            .collect();
+
+        // Solve with Gauss-Jordan elimination and partial pivoting.
+        for col in 0..size {
+            let pivot = (col..size)
+                .max_by(|a, b| matrix[*a][col].abs().total_cmp(&matrix[*b][col].abs()))
+                .unwrap_or(col);
+            matrix.swap(col, pivot);
+
+            let div = matrix[col][col];
+            for value in matrix[col].iter_mut() {
+                *value /= div;
+            }
+
+            let pivot_row = matrix[col].clone();
+            for (r, row) in matrix.iter_mut().enumerate() {
+                if r != col {
+                    let factor = row[col];
+                    for (value, p) in row.iter_mut().zip(pivot_row.iter()) {
+                        *value -= factor * p;
+                    }
+                }
+            }
+        }
+
+        // Constant fits have no slope.
+        if is_derivative && degree == 0 {
+            return Ok(vec![0.0; period]);
+        }
+
+        let solution: Vec<Num> = matrix.iter().map(|row| row[size]).collect();
+        Ok(powers
+            .iter()
+            .map(|p| p.iter().zip(solution.iter()).map(|(a, b)| a * b).sum())
+            .collect())
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Degree of the polynomial.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Slope of the fit at the newest value, the change per value.
+    pub fn derivative(&self) -> Num {
+        self.derivative
+    }
+
+    /// Coefficients for the fit at the newest value, oldest to newest.
+    pub fn coefficients(&self) -> &[Num] {
+        &self.coefficients
+    }
+}
+
+impl Next<Num> for SavitzkyGolay {
+    /// Next value for the Savitzky-Golay Filter.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate the fit.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.values.shift(value);
+        self.value = self.values.weighted_sum(&self.coefficients);
+        self.derivative = self.values.weighted_sum(&self.derivatives);
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl<T> Next<T> for SavitzkyGolay
+where
+    T: AsValue,
+{
+    /// Next value for the Savitzky-Golay Filter.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate the fit.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for SavitzkyGolay {
+    /// Clears the Savitzky-Golay Filter back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.derivative = 0.0;
+        self.values.clear();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for SavitzkyGolay {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for SavitzkyGolay {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, self.degree, data)
+    }
+}
+
+impl Snapshot for SavitzkyGolay {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Savitzky-Golay Filter")
+            .count(self.period)
+            .scalar(self.value)
+            .count(self.degree)
+            .scalar(self.derivative)
+            .buffer(&self.coefficients)
+            .buffer(&self.derivatives)
+            .child(&self.values)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Savitzky-Golay Filter")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            degree: reader.count()?,
+            derivative: reader.scalar()?,
+            coefficients: reader.buffer()?.to_vec(),
+            derivatives: reader.buffer()?.to_vec(),
+            values: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,258 @@
+//! Values collected after an indicator is reset, used to recreate the indicator.
+//!
+//! Indicators that require initial data on creation cannot produce a value after being reset
+//! until they have been supplied as much data again. The values supplied are collected until
+//! there are enough, then the indicator is recreated from them exactly as it would have been on
+//! creation. Indicators created without initial data start out collecting the same way.
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+
+/// Values collected after an indicator is reset until there are enough to recreate it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Seed<T> {
+    /// Values collected since the reset, none if the indicator is not collecting.
+    values: Option<Vec<T>>,
+    /// Amount of values supplied since the indicator was created or reset.
+    seen: usize,
+}
+
+impl<T> Seed<T> {
+    /// Creates a new seed that is not collecting.
+    ///
+    /// # Arguments
+    ///
+    /// * `seen` - Amount of values the indicator was created from.
+    pub(crate) fn new(seen: usize) -> Self {
+        Self { values: None, seen }
+    }
+
+    /// Starts collecting values, discarding any already collected.
+    pub(crate) fn start(&mut self) {
+        self.values = Some(Vec::new());
+        self.seen = 0;
+    }
+
+    /// Amount of values supplied since the indicator was created or reset.
+    pub(crate) fn seen(&self) -> usize {
+        self.seen
+    }
+
+    /// Counts a value that was skipped, neither collected nor used by the indicator.
+    pub(crate) fn skip(&mut self) {
+        self.seen += 1;
+    }
+
+    /// Checks if values are being collected.
+    pub(crate) fn is_collecting(&self) -> bool {
+        self.values.is_some()
+    }
+
+    /// Values collected so far, empty if not collecting.
+    pub(crate) fn values(&self) -> &[T] {
+        self.values.as_deref().unwrap_or(&[])
+    }
+
+    /// Adds a value, once `required` values are collected they are returned and collecting stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to collect.
+    /// * `required` - Amount of values required to recreate the indicator.
+    pub(crate) fn push(&mut self, value: T, required: usize) -> Option<Vec<T>> {
+        let values = self.values.as_mut()?;
+        values.push(value);
+        if values.len() < required {
+            return None;
+        }
+
+        self.values.take()
+    }
+}
+
+/// Value collected that can be held in a state as consecutive numbers.
+pub(crate) trait Flatten: Sized {
+    /// Amount of numbers held for each value.
+    const WIDTH: usize;
+
+    /// Adds the numbers of the value.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - Numbers held in the state.
+    fn flatten(&self, out: &mut Vec<Num>);
+
+    /// Creates the value from `WIDTH` numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Numbers of a single value.
+    fn unflatten(values: &[Num]) -> Self;
+}
+
+impl Flatten for Num {
+    const WIDTH: usize = 1;
+
+    fn flatten(&self, out: &mut Vec<Num>) {
+        out.push(*self);
+    }
+
+    fn unflatten(values: &[Num]) -> Self {
+        values[0]
+    }
+}
+
+impl Flatten for (Num, Num) {
+    const WIDTH: usize = 2;
+
+    fn flatten(&self, out: &mut Vec<Num>) {
+        out.extend([self.0, self.1]);
+    }
+
+    fn unflatten(values: &[Num]) -> Self {
+        (values[0], values[1])
+    }
+}
+
+/// Implements `Flatten` for a tuple struct of numbers, such as the parts of a candle collected.
+macro_rules! impl_flatten {
+    ($data:ident($($index:tt),+)) => {
+        impl $crate::indicators::seed::Flatten for $data {
+            const WIDTH: usize = [$($index),+].len();
+
+            fn flatten(&self, out: &mut Vec<Num>) {
+                out.extend([$(self.$index),+]);
+            }
+
+            fn unflatten(values: &[Num]) -> Self {
+                Self($(values[$index]),+)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_flatten;
+
+impl<T: Flatten> Snapshot for Seed<T> {
+    fn snapshot(&self) -> IndicatorState {
+        let mut values: Vec<Num> = Vec::new();
+        for value in self.values() {
+            value.flatten(&mut values);
+        }
+
+        IndicatorState::new("seed")
+            .flag(self.is_collecting())
+            .count(self.seen)
+            .buffer(&values)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "seed")?;
+        let (is_collecting, seen) = (reader.flag()?, reader.count()?);
+        let values = reader.buffer()?;
+        if values.len() % T::WIDTH != 0 {
+            return Err(TAError::InvalidState(String::from(
+                "seed holds a partial value",
+            )));
+        }
+
+        let values = values.chunks(T::WIDTH).map(T::unflatten).collect();
+        Ok(Self {
+            values: is_collecting.then_some(values),
+            seen,
+        })
+    }
+}
+
+/// Indicator that can be recreated from the values collected after a reset.
+pub(crate) trait Reseed: Sized {
+    /// Value collected, the same as the initial data on creation.
+    type Input: Clone;
+
+    /// Values collected since the reset.
+    fn seed(&mut self) -> &mut Seed<Self::Input>;
+
+    /// Amount of values required to create the indicator.
+    fn required(&self) -> usize;
+
+    /// Creates the indicator from the values collected, using the same configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Values collected since the reset.
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError>;
+
+    /// Updates the value from the values collected so far, while there are not enough to recreate
+    /// the indicator. By default the value is left as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Values collected since the reset.
+    fn partial(&mut self, _data: &[Self::Input]) {}
+}
+
+/// Creates `len` copies of a value, used as initial data to validate the configuration of an
+/// indicator created without data.
+///
+/// # Arguments
+///
+/// * `value` - Valid value for the indicator.
+/// * `len` - Amount of values the indicator requires.
+pub(crate) fn placeholder<T: Clone>(value: T, len: usize) -> Vec<T> {
+    vec![value; len]
+}
+
+/// Resets an indicator created from placeholder values so it starts out collecting, the values
+/// supplied with `next` are then used to create it.
+///
+/// # Arguments
+///
+/// * `created` - Indicator created from placeholder values.
+pub(crate) fn streaming<I: Reset>(created: Result<I, TAError>) -> Result<I, TAError> {
+    let mut indicator = created?;
+    indicator.reset();
+    Ok(indicator)
+}
+
+/// Counts the value and collects it if the indicator is collecting, recreating the indicator once
+/// enough are collected, until then the indicator is given a partial value. Values that fail to
+/// recreate the indicator are discarded and collecting restarts. Returns true if the value was
+/// collected.
+///
+/// # Arguments
+///
+/// * `indicator` - Indicator to collect for.
+/// * `value` - Value supplied to the indicator.
+pub(crate) fn collect<I: Reseed>(indicator: &mut I, value: I::Input) -> bool {
+    indicator.seed().seen += 1;
+    if !indicator.seed().is_collecting() {
+        return false;
+    }
+
+    let required = indicator.required();
+    match indicator.seed().push(value, required) {
+        Some(data) => match indicator.recreate(&data) {
+            Ok(recreated) => {
+                // Keep the count of values skipped while collecting.
+                let seen = indicator.seed().seen;
+                *indicator = recreated;
+                indicator.seed().seen = seen;
+            }
+            Err(_) => {
+                // Restart collecting, the values already supplied are still counted.
+                indicator.seed().values = Some(Vec::new());
+            }
+        },
+        None => {
+            // Moved out while the partial value is calculated, then put back.
+            let data = indicator.seed().values.take().unwrap_or_default();
+            indicator.partial(&data);
+            indicator.seed().values = Some(data);
+        }
+    }
+
+    true
+}
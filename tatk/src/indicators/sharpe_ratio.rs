@@ -0,0 +1,289 @@
+//! Sharpe Ratio, risk-adjusted return within a period.
+//!
+//! Mean excess return over the standard deviation of the returns, annualized. If there is no
+//! deviation within the period the ratio is 0.0.
+//!
+//! # Formula
+//!
+//! Sharpe = [(μ - r) / σ] * sqrt(a)
+//!
+//! where:
+//!
+//! * `μ` = mean of the returns within the period.
+//! * `r` = risk-free rate per period.
+//! * `σ` = sample standard deviation of the returns within the period.
+//! * `a` = periods per year (annualization), 1.0 for none.
+
+use super::rolling_returns::RollingReturns;
+use super::seed::{self, Reseed, Seed};
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Sharpe Ratio, risk-adjusted return within a period.
+///
+/// Mean excess return over the standard deviation of the returns, annualized. If there is no
+/// deviation within the period the ratio is 0.0.
+///
+/// # Formula
+///
+/// Sharpe = [(μ - r) / σ] * sqrt(a)
+///
+/// where:
+///
+/// * `μ` = mean of the returns within the period.
+/// * `r` = risk-free rate per period.
+/// * `σ` = sample standard deviation of the returns within the period.
+/// * `a` = periods per year (annualization), 1.0 for none.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct SharpeRatio {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Sharpe Ratio's current value.
+    value: Num,
+    /// Risk-free rate per period.
+    risk_free: Num,
+    /// Periods per year used to annualize.
+    annualization: Num,
+    /// Returns within the period.
+    returns: RollingReturns,
+    /// Holds `period` amount of generated Sharpe Ratios.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl SharpeRatio {
+    /// Creates a new Sharpe Ratio with the supplied period, rates, and initial data. The data is
+    /// either returns or prices (such as an equity curve) converted to simple returns.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Annualization must be finite and greater than 0, 1.0 for none.
+    /// * Data must have at least `period` returns, or `period + 1` prices.
+    /// * Prices must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `risk_free` - Risk-free rate per period.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    /// * `data` - Array of returns or prices to create the Sharpe Ratio from.
+    /// * `is_prices` - Data is prices, `next` also expects prices.
+    pub fn new(
+        period: usize,
+        risk_free: Num,
+        annualization: Num,
+        data: &[Num],
+        is_prices: bool,
+    ) -> Result<Self, TAError> {
+        // Check we can calculate Sharpe Ratio.
+        TAError::check_annualization(annualization, "sharpe ratio")?;
+
+        // Seed with the first period of returns.
+        let (returns, remaining) = RollingReturns::new("sharpe ratio", period, data, is_prices)?;
+        let value = Self::calculate(returns.returns(), risk_free, annualization);
+        let mut sharpe = Self {
+            period,
+            value,
+            risk_free,
+            annualization,
+            returns,
+            buffer: Buffer::from_array(period, &[value])?,
+            seed: Seed::new(data.len()),
+        };
+
+        // Calculate the remainder of the Sharpe Ratios.
+        for r in remaining.iter() {
+            sharpe.returns.add(*r);
+            sharpe.update();
+        }
+
+        Ok(sharpe)
+    }
+
+    /// Creates a new Sharpe Ratio with the supplied period and rates, without initial data. The
+    /// values are supplied with `next` instead, the value is 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Annualization must be finite and greater than 0, 1.0 for none.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `risk_free` - Risk-free rate per period.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    /// * `is_prices` - Data is prices, `next` also expects prices.
+    pub fn with_period(
+        period: usize,
+        risk_free: Num,
+        annualization: Num,
+        is_prices: bool,
+    ) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            risk_free,
+            annualization,
+            &seed::placeholder(1.0, period + is_prices as usize),
+            is_prices,
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Risk-free rate per period.
+    pub fn risk_free(&self) -> Num {
+        self.risk_free
+    }
+
+    /// Periods per year used to annualize.
+    pub fn annualization(&self) -> Num {
+        self.annualization
+    }
+
+    /// Recalculates the Sharpe Ratio after a new return is added.
+    fn update(&mut self) -> Num {
+        self.value = Self::calculate(self.returns.returns(), self.risk_free, self.annualization);
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+
+    /// Calculates the Sharpe Ratio of the returns within a period.
+    ///
+    /// # Arguments
+    ///
+    /// * `returns` - Returns within the period.
+    /// * `risk_free` - Risk-free rate per period.
+    /// * `annualization` - Periods per year used to annualize.
+    fn calculate(returns: &Buffer, risk_free: Num, annualization: Num) -> Num {
+        let stdev = returns.stdev(true);
+        if stdev == 0.0 {
+            return 0.0;
+        }
+
+        (returns.mean() - risk_free) / stdev * annualization.sqrt()
+    }
+}
+
+impl Next<Num> for SharpeRatio {
+    /// Next value for the Sharpe Ratio.
+    type Output = Num;
+
+    /// Supply an additional return, or price if created from prices, to recalculate a new Sharpe
+    /// Ratio. Prices that are 0 or lower are ignored and the current value is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New return or price to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if !self.returns.accepts(value) {
+            self.seed.skip();
+            return self.value;
+        } else if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.returns.push(value);
+        self.update()
+    }
+}
+
+impl<T> Next<T> for SharpeRatio
+where
+    T: AsValue,
+{
+    /// Next value for the Sharpe Ratio.
+    type Output = Num;
+
+    /// Supply an additional return, or price if created from prices, to recalculate a new Sharpe
+    /// Ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New return or price to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for SharpeRatio {
+    /// Clears the Sharpe Ratio back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.returns.clear();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for SharpeRatio {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for SharpeRatio {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period + self.returns.is_prices() as usize
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(
+            self.period,
+            self.risk_free,
+            self.annualization,
+            data,
+            self.returns.is_prices(),
+        )
+    }
+}
+
+impl Snapshot for SharpeRatio {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Sharpe Ratio")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.risk_free)
+            .scalar(self.annualization)
+            .child(&self.returns)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Sharpe Ratio")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            risk_free: reader.scalar()?,
+            annualization: reader.scalar()?,
+            returns: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -1,20 +1,36 @@
 //! Simple Moving Average (SMA)
 //!
 //! Average moves within a period.
+//!
+//! The period's values are held in a `Buffer` by default, any `Window` can be used instead such as
+//! a `StaticBuffer` to avoid heap allocations.
 
-use crate::traits::{AsValue, InternalValue, Next, Period, Stats};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    AsValue, CheckedNext, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value, Window,
+};
+use crate::{Buffer, IndicatorState, NonFinite, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
 
 /// Simple Moving Average (SMA), the average within a period that moves as data is added.
-#[derive(Debug, Period, InternalValue)]
-pub struct SimpleMovingAverage {
+///
+/// The period's values are held in a `Buffer` by default, any `Window` can be used instead such as
+/// a `StaticBuffer` to avoid heap allocations.
+#[derive(Debug, Clone, PartialEq, Period, InternalValue, Value)]
+pub struct SimpleMovingAverage<B = Buffer> {
     /// Size of the period (window) in which data is looked at.
     period: usize,
     /// SMA's current value.
     value: Num,
     /// Holds all of the current period's values.
-    buffer: Buffer,
+    buffer: B,
+    /// How NaN or infinite values supplied with `next` are handled.
+    non_finite: NonFinite,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
 }
 
 impl SimpleMovingAverage {
@@ -30,6 +46,41 @@ impl SimpleMovingAverage {
     /// * `period` - Size of the period / window used.
     /// * `data` - Array of values to create the SMA from.
     pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::with_window(period, data)
+    }
+
+    /// Creates a new SMA with the supplied period, without initial data. The values are supplied
+    /// with `next` instead, until ready the value is calculated from the values supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period)))
+    }
+}
+
+impl<B> SimpleMovingAverage<B>
+where
+    B: Window,
+{
+    /// Creates a new SMA with the supplied period and initial data, held in the window `B`.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    /// * Period must be a capacity the window supports, `N` for a `StaticBuffer<N>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the SMA from.
+    pub fn with_window(period: usize, data: &[Num]) -> Result<Self, TAError> {
         // Check we can calculate SMA.
         if period < 1 {
             return Err(TAError::InvalidSize(String::from(
@@ -37,21 +88,25 @@ impl SimpleMovingAverage {
             )));
         } else if data.len() < period {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data for period provided",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "simple moving average",
+                required: period,
+                received: data.len(),
+            });
         }
 
+        // Make sure the data is usable.
+        TAError::check_finite(data.iter().copied())?;
+
         // Build the buffer from the data provided.
-        let buffer: Buffer = match Buffer::from_array(period, data) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        let buffer = B::from_array(period, data)?;
 
         Ok(Self {
             period,
             value: buffer.mean(),
             buffer,
+            non_finite: NonFinite::default(),
+            seed: Seed::new(data.len()),
         })
     }
 
@@ -59,9 +114,18 @@ impl SimpleMovingAverage {
     pub fn value(&self) -> Num {
         self.value
     }
+
+    /// Changes how NaN or infinite values supplied with `next` are handled, the default being to
+    /// propagate them.
+    pub fn set_non_finite(&mut self, policy: NonFinite) {
+        self.non_finite = policy;
+    }
 }
 
-impl Next<Num> for SimpleMovingAverage {
+impl<B> Next<Num> for SimpleMovingAverage<B>
+where
+    B: Window,
+{
     /// Next value for the SMA.
     type Output = Num;
 
@@ -71,6 +135,15 @@ impl Next<Num> for SimpleMovingAverage {
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
+        if !self.non_finite.accepts(&[value]) {
+            self.seed.skip();
+            return self.value;
+        }
+
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
         // Rotate the buffer.
         self.buffer.shift(value);
 
@@ -80,9 +153,10 @@ impl Next<Num> for SimpleMovingAverage {
     }
 }
 
-impl<T> Next<T> for SimpleMovingAverage
+impl<T, B> Next<T> for SimpleMovingAverage<B>
 where
     T: AsValue,
+    B: Window,
 {
     /// Next value for the SMA.
     type Output = Num;
@@ -97,7 +171,25 @@ where
     }
 }
 
-impl Stats for SimpleMovingAverage {
+impl<B> CheckedNext<Num> for SimpleMovingAverage<B>
+where
+    B: Window,
+{
+    /// Supply an additional value to recalculate a new SMA, rejecting NaN or infinite values.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn checked_next(&mut self, value: Num) -> Result<Self::Output, TAError> {
+        TAError::check_finite([value])?;
+        Ok(self.next(value))
+    }
+}
+
+impl<B> Stats for SimpleMovingAverage<B>
+where
+    B: Window,
+{
     /// Obtains the total sum of the buffer for SMA.
     fn sum(&self) -> Num {
         self.buffer.sum()
@@ -126,3 +218,85 @@ impl Stats for SimpleMovingAverage {
         self.buffer.stdev(is_sample)
     }
 }
+
+impl<B> Reset for SimpleMovingAverage<B>
+where
+    B: Window,
+{
+    /// Clears the SMA back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl<B> Ready for SimpleMovingAverage<B>
+where
+    B: Window,
+{
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl<B> Reseed for SimpleMovingAverage<B>
+where
+    B: Window,
+{
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        // Keep the policy that was set.
+        let mut sma = Self::with_window(self.period, data)?;
+        sma.non_finite = self.non_finite;
+        Ok(sma)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        // Window of the values so far, the window `B` may not support a smaller capacity.
+        if let Ok(partial) = Buffer::from_array(data.len(), data) {
+            self.value = partial.mean();
+        }
+    }
+}
+
+impl<B> Snapshot for SimpleMovingAverage<B>
+where
+    B: Window + Snapshot,
+{
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("SMA")
+            .count(self.period)
+            .scalar(self.value)
+            .variant(self.non_finite)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "SMA")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            non_finite: reader.variant()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
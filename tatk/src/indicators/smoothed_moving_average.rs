@@ -0,0 +1,224 @@
+//! Smoothed Moving Average (SMMA)
+//!
+//! Also known as the Running Moving Average (RMA) or Wilder's smoothing, seeded with a Simple
+//! Moving Average (SMA) of the first period.
+//!
+//! # Formula
+//!
+//! SMMA = [y * (n - 1) + x] / n
+//!
+//! where:
+//!
+//! * `x` = current value (most recent)
+//! * `y` = last SMMA
+//! * `n` = period
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Smoothed Moving Average (SMMA). Older data is never dropped, only weighted lighter.
+///
+/// # Formula
+///
+/// SMMA = [y * (n - 1) + x] / n
+///
+/// where:
+/// * `x` = current value (most recent)
+/// * `y` = last SMMA
+/// * `n` = period
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct SmoothedMovingAverage {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Current value for the SMMA.
+    value: Num,
+    /// Holds `period` amount of generated SMMAs.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl SmoothedMovingAverage {
+    /// Creates a new SMMA with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the SMMA from.
+    pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate SMMA.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate smoothed moving average",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "smoothed moving average",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Temporary buffer to get seed SMA for SMMA.
+        let mut last_smma = Buffer::from_array(period, &data[..period])?.mean();
+
+        // Buffer will hold last `period` SMMAs.
+        let mut buffer = Buffer::from_array(period, &[last_smma])?;
+
+        // Calculate the remainder of the datas SMMA, using the prior SMMA.
+        for value in data[period..].iter() {
+            last_smma = Self::calculate(period, &last_smma, value);
+            buffer.shift(last_smma);
+        }
+
+        Ok(Self {
+            period,
+            value: last_smma,
+            buffer,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new SMMA with the supplied period, without initial data. The values are supplied
+    /// with `next` instead, until ready the value is calculated from the values supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period)))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Calculates an SMMA with newly provided data and the last SMMA.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `last_smma` - Last SMMA calculated.
+    /// * `value` - Most recent value.
+    fn calculate(period: usize, last_smma: &Num, value: &Num) -> Num {
+        (last_smma * (period - 1) as Num + value) / period as Num
+    }
+}
+
+impl Next<Num> for SmoothedMovingAverage {
+    /// Next value for the SMMA.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new SMMA.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        // Get the next SMMA value.
+        self.value = Self::calculate(self.period, &self.value(), &value);
+        self.buffer.shift(self.value());
+        self.value
+    }
+}
+
+impl<T> Next<T> for SmoothedMovingAverage
+where
+    T: AsValue,
+{
+    /// Next value for the SMMA.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new SMMA.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for SmoothedMovingAverage {
+    /// Clears the SMMA back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for SmoothedMovingAverage {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for SmoothedMovingAverage {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for SmoothedMovingAverage {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("SMMA")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "SMMA")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
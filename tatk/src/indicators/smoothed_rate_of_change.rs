@@ -0,0 +1,272 @@
+//! Smoothed Rate of Change (SROC), Rate of Change (ROC) of an Exponential Moving Average (EMA).
+//!
+//! Smoothing the values before measuring the momentum removes most of the whipsaw of a plain
+//! ROC, at the cost of some lag.
+//!
+//! # Formula
+//!
+//! SROC = ROC(EMA(x, n), m)
+//!
+//! where:
+//!
+//! * `x` = current value (most recent)
+//! * `n` = period of the EMA.
+//! * `m` = period of the ROC.
+
+use super::seed::{self, Reseed, Seed};
+use super::{ExponentialMovingAverage, RateOfChange};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
+
+/// Smoothed Rate of Change (SROC), Rate of Change (ROC) of an Exponential Moving Average (EMA).
+///
+/// Smoothing the values before measuring the momentum removes most of the whipsaw of a plain
+/// ROC, at the cost of some lag.
+///
+/// # Formula
+///
+/// SROC = ROC(EMA(x, n), m)
+///
+/// where:
+///
+/// * `x` = current value (most recent)
+/// * `n` = period of the EMA.
+/// * `m` = period of the ROC.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct SmoothedRateOfChange {
+    /// Size of the period (window) of the ROC.
+    period: usize,
+    /// SROC's current value.
+    value: Num,
+    /// Smooths the values.
+    ema: ExponentialMovingAverage,
+    /// ROC of the smoothed values.
+    roc: RateOfChange,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl SmoothedRateOfChange {
+    /// Creates a new SROC with the supplied periods and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * EMA period must be greater than 0.
+    /// * Period must be greater than 1.
+    /// * Data must have at least `ema_period + period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ema_period` - Period of the EMA smoothing the values.
+    /// * `period` - Period of the ROC.
+    /// * `data` - Array of values to create the SROC from.
+    pub fn new(ema_period: usize, period: usize, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate SROC.
+        if ema_period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "ema period cannot be less than 1 to calculate smoothed rate of change",
+            )));
+        } else if period < 2 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 2 to calculate smoothed rate of change",
+            )));
+        } else if data.len() < ema_period + period {
+            // Make sure we have enough data for both the EMA and ROC.
+            return Err(TAError::InvalidData {
+                indicator: "smoothed rate of change",
+                required: ema_period + period,
+                received: data.len(),
+            });
+        }
+
+        // EMA needs `ema_period` values, each value after produces another EMA.
+        let mut ema = ExponentialMovingAverage::new(ema_period, &data[..ema_period])?;
+        let mut emas: Vec<Num> = vec![ema.value()];
+        for v in data[ema_period..(ema_period + period)].iter() {
+            emas.push(ema.next(*v));
+        }
+
+        // ROC needs `period + 1` EMAs.
+        let mut roc = RateOfChange::new(period, &emas)?;
+
+        // Calculate the remainder of the data.
+        for v in data[(ema_period + period)..].iter() {
+            roc.next(ema.next(*v));
+        }
+
+        Ok(Self {
+            period,
+            value: roc.value(),
+            ema,
+            roc,
+            seed: Seed::new(data.len()),
+        })
+    }
+
+    /// Creates a new SROC with the supplied periods, without initial data. The values are supplied
+    /// with `next` instead, the value is 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * EMA period must be greater than 0.
+    /// * Period must be greater than 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ema_period` - Period of the EMA smoothing the values.
+    /// * `period` - Period of the ROC.
+    pub fn with_period(ema_period: usize, period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            ema_period,
+            period,
+            &seed::placeholder(1.0, ema_period + period),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Current EMA the ROC is calculated from.
+    pub fn ema(&self) -> Num {
+        self.ema.value()
+    }
+
+    /// Period of the EMA smoothing the values.
+    pub fn ema_period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl Next<Num> for SmoothedRateOfChange {
+    /// Next value for the SROC.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new SROC.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        let ema = self.ema.next(value);
+        self.value = self.roc.next(ema);
+        self.value
+    }
+}
+
+impl<T> Next<T> for SmoothedRateOfChange
+where
+    T: AsValue,
+{
+    /// Next value for the SROC.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new SROC.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Stats for SmoothedRateOfChange {
+    /// Obtains the total sum of the buffer for SROC.
+    fn sum(&self) -> Num {
+        self.roc.sum()
+    }
+
+    /// Mean for the period of the SROC.
+    fn mean(&self) -> Num {
+        self.roc.mean()
+    }
+
+    /// Current variance for the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn variance(&self, is_sample: bool) -> Num {
+        self.roc.variance(is_sample)
+    }
+
+    /// Current standard deviation for the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn stdev(&self, is_sample: bool) -> Num {
+        self.roc.stdev(is_sample)
+    }
+}
+
+impl Reset for SmoothedRateOfChange {
+    /// Clears the SROC back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.ema.reset();
+        self.roc.reset();
+        self.seed.start();
+    }
+}
+
+impl Ready for SmoothedRateOfChange {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for SmoothedRateOfChange {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.ema.period() + self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.ema.period(), self.period, data)
+    }
+}
+
+impl Snapshot for SmoothedRateOfChange {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("SROC")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.ema)
+            .child(&self.roc)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "SROC")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            ema: reader.child()?,
+            roc: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
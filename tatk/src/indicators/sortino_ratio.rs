@@ -0,0 +1,324 @@
+//! Sortino Ratio, downside risk-adjusted return within a period.
+//!
+//! Mean excess return over the downside deviation, annualized. Unlike the Sharpe Ratio only the
+//! returns below the minimum acceptable return are considered risk. If no returns within the
+//! period are below the target there is no downside deviation, and the last value is kept (0.0
+//! if there has never been a defined value).
+//!
+//! # Formula
+//!
+//! Sortino = [(μ - t) / DD] * sqrt(a)
+//!
+//! DD = sqrt((1 / n) * Σ min(0, x - t)²)
+//!
+//! where:
+//!
+//! * `μ` = mean of the returns within the period.
+//! * `t` = target, minimum acceptable return per period.
+//! * `x` = return within the period.
+//! * `n` = period
+//! * `a` = periods per year (annualization), 1.0 for none.
+
+use super::rolling_returns::RollingReturns;
+use super::seed::{self, Reseed, Seed};
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Sortino Ratio, downside risk-adjusted return within a period.
+///
+/// Mean excess return over the downside deviation, annualized. Unlike the Sharpe Ratio only the
+/// returns below the minimum acceptable return are considered risk. If no returns within the
+/// period are below the target there is no downside deviation, and the last value is kept (0.0
+/// if there has never been a defined value).
+///
+/// # Formula
+///
+/// Sortino = [(μ - t) / DD] * sqrt(a)
+///
+/// DD = sqrt((1 / n) * Σ min(0, x - t)²)
+///
+/// where:
+///
+/// * `μ` = mean of the returns within the period.
+/// * `t` = target, minimum acceptable return per period.
+/// * `x` = return within the period.
+/// * `n` = period
+/// * `a` = periods per year (annualization), 1.0 for none.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct SortinoRatio {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Sortino Ratio's current value.
+    value: Num,
+    /// Minimum acceptable return per period.
+    target: Num,
+    /// Periods per year used to annualize.
+    annualization: Num,
+    /// Returns within the period.
+    returns: RollingReturns,
+    /// Holds `period` amount of generated Sortino Ratios.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl SortinoRatio {
+    /// Creates a new Sortino Ratio with the supplied period, rates, and initial data. The data is
+    /// either returns or prices (such as an equity curve) converted to simple returns.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Annualization must be finite and greater than 0, 1.0 for none.
+    /// * Data must have at least `period` returns, or `period + 1` prices.
+    /// * Prices must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `target` - Minimum acceptable return per period.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    /// * `data` - Array of returns or prices to create the Sortino Ratio from.
+    /// * `is_prices` - Data is prices, `next` also expects prices.
+    pub fn new(
+        period: usize,
+        target: Num,
+        annualization: Num,
+        data: &[Num],
+        is_prices: bool,
+    ) -> Result<Self, TAError> {
+        // Check we can calculate Sortino Ratio.
+        TAError::check_annualization(annualization, "sortino ratio")?;
+
+        // Seed with the first period of returns.
+        let (returns, remaining) = RollingReturns::new("sortino ratio", period, data, is_prices)?;
+        let value = Self::calculate(returns.returns(), target, annualization).unwrap_or(0.0);
+        let mut sortino = Self {
+            period,
+            value,
+            target,
+            annualization,
+            returns,
+            buffer: Buffer::from_array(period, &[value])?,
+            seed: Seed::new(data.len()),
+        };
+
+        // Calculate the remainder of the Sortino Ratios.
+        for r in remaining.iter() {
+            sortino.returns.add(*r);
+            sortino.update();
+        }
+
+        Ok(sortino)
+    }
+
+    /// Creates a new Sortino Ratio with the supplied period and rates, without initial data. The
+    /// values are supplied with `next` instead, the value is 0 until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Annualization must be finite and greater than 0, 1.0 for none.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `target` - Minimum acceptable return per period.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    /// * `is_prices` - Data is prices, `next` also expects prices.
+    pub fn with_period(
+        period: usize,
+        target: Num,
+        annualization: Num,
+        is_prices: bool,
+    ) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            target,
+            annualization,
+            &seed::placeholder(1.0, period + is_prices as usize),
+            is_prices,
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Minimum acceptable return per period.
+    pub fn target(&self) -> Num {
+        self.target
+    }
+
+    /// Downside deviation of the returns within the period, before annualization.
+    pub fn downside_deviation(&self) -> Num {
+        Self::downside(self.returns.returns(), self.target)
+    }
+
+    /// Periods per year used to annualize.
+    pub fn annualization(&self) -> Num {
+        self.annualization
+    }
+
+    /// Recalculates the Sortino Ratio after a new return is added.
+    fn update(&mut self) -> Num {
+        if let Some(value) =
+            Self::calculate(self.returns.returns(), self.target, self.annualization)
+        {
+            self.value = value;
+        }
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+
+    /// Calculates the Sortino Ratio of the returns within a period, none if there is no downside
+    /// deviation.
+    ///
+    /// # Arguments
+    ///
+    /// * `returns` - Returns within the period.
+    /// * `target` - Minimum acceptable return per period.
+    /// * `annualization` - Periods per year used to annualize.
+    fn calculate(returns: &Buffer, target: Num, annualization: Num) -> Option<Num> {
+        let downside = Self::downside(returns, target);
+        if downside == 0.0 {
+            return None;
+        }
+
+        Some((returns.mean() - target) / downside * annualization.sqrt())
+    }
+
+    /// Calculates the downside deviation of the returns within a period.
+    ///
+    /// # Arguments
+    ///
+    /// * `returns` - Returns within the period.
+    /// * `target` - Minimum acceptable return per period.
+    fn downside(returns: &Buffer, target: Num) -> Num {
+        let sum: Num = returns
+            .queue()
+            .iter()
+            .map(|r| (r - target).min(0.0).powi(2))
+            .sum();
+        (sum / returns.capacity() as Num).sqrt()
+    }
+}
+
+impl Next<Num> for SortinoRatio {
+    /// Next value for the Sortino Ratio.
+    type Output = Num;
+
+    /// Supply an additional return, or price if created from prices, to recalculate a new Sortino
+    /// Ratio. Prices that are 0 or lower are ignored and the current value is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New return or price to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if !self.returns.accepts(value) {
+            self.seed.skip();
+            return self.value;
+        } else if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.returns.push(value);
+        self.update()
+    }
+}
+
+impl<T> Next<T> for SortinoRatio
+where
+    T: AsValue,
+{
+    /// Next value for the Sortino Ratio.
+    type Output = Num;
+
+    /// Supply an additional return, or price if created from prices, to recalculate a new Sortino
+    /// Ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New return or price to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for SortinoRatio {
+    /// Clears the Sortino Ratio back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.returns.clear();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for SortinoRatio {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for SortinoRatio {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period + self.returns.is_prices() as usize
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(
+            self.period,
+            self.target,
+            self.annualization,
+            data,
+            self.returns.is_prices(),
+        )
+    }
+}
+
+impl Snapshot for SortinoRatio {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Sortino Ratio")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.target)
+            .scalar(self.annualization)
+            .child(&self.returns)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Sortino Ratio")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            target: reader.scalar()?,
+            annualization: reader.scalar()?,
+            returns: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,420 @@
+//! Squeeze, Bollinger Bands inside of Keltner Channels signalling a compression in volatility.
+//!
+//! The squeeze is on while both Bollinger Bands are within the Keltner Channels and fires on the
+//! first value they are no longer within them. The direction of the release is given by the
+//! momentum, a linear regression of the close minus the midline.
+//!
+//! # Formula
+//!
+//! Squeezed = (BB_lower > KC_lower) and (BB_upper < KC_upper)
+//!
+//! Momentum = LR(c - [ ((H + L) / 2) + SMA ] / 2, n)
+//!
+//! where:
+//!
+//! * `c` = close of the candle.
+//! * `H` = highest high for the period.
+//! * `L` = lowest low for the period.
+//! * `SMA` = simple moving average of the closes for the period, the middle of the Bollinger Bands.
+//! * `n` = period
+
+use super::seed::{self, Reseed, Seed};
+use super::{
+    BollingerBands, KeltnerChannels, LinearRegression, RollingExtrema, SimpleMovingAverage,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{Close, High, InternalValue, Low, Next, Period, Ready, Reset, Snapshot, Value};
+use crate::{IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
+
+/// Used for conversions. Holds High (0), Low (1), and Close (2) values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num, Num);
+
+seed::impl_flatten!(Data(0, 1, 2));
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.2
+    }
+}
+
+/// State of the Squeeze for a single candle supplied.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SqueezeOutput {
+    /// Bollinger Bands are inside of the Keltner Channels.
+    pub is_squeezed: bool,
+    /// Squeeze was released by this candle.
+    pub fired: bool,
+    /// Momentum of the close, positive for a release upwards.
+    pub momentum: Num,
+}
+
+/// Squeeze, Bollinger Bands inside of Keltner Channels signalling a compression in volatility.
+///
+/// The squeeze is on while both Bollinger Bands are within the Keltner Channels and fires on the
+/// first value they are no longer within them. The direction of the release is given by the
+/// momentum, a linear regression of the close minus the midline.
+///
+/// # Formula
+///
+/// Squeezed = (BB_lower > KC_lower) and (BB_upper < KC_upper)
+///
+/// Momentum = LR(c - [ ((H + L) / 2) + SMA ] / 2, n)
+///
+/// where:
+///
+/// * `c` = close of the candle.
+/// * `H` = highest high for the period.
+/// * `L` = lowest low for the period.
+/// * `SMA` = simple moving average of the closes for the period, the middle of the Bollinger Bands.
+/// * `n` = period
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct Squeeze {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Current momentum.
+    value: Num,
+    /// Bollinger Bands of the closes.
+    bbands: BollingerBands<SimpleMovingAverage>,
+    /// Keltner Channels of the candles.
+    keltner: KeltnerChannels,
+    /// Highest high and lowest low of the period.
+    extrema: RollingExtrema,
+    /// Linear regression of the close minus the midline.
+    momentum: LinearRegression,
+    /// Bollinger Bands are inside of the Keltner Channels.
+    is_squeezed: bool,
+    /// Squeeze was released by the last candle.
+    fired: bool,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
+}
+
+impl Squeeze {
+    /// Creates a new Squeeze with the supplied period, distances, and initial data. The squeeze
+    /// can only fire once there is more data than required.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Multiplier must be greater than 0.
+    /// * Data must have at least `(period * 2) - 1` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `distance` - Distance of the Bollinger Bands (in standard deviations). default 2.0
+    /// * `multiplier` - Multiplier of the ATR for the Keltner Channels. default 1.5
+    /// * `data` - Array of candles to create the Squeeze from.
+    pub fn new<T>(
+        period: usize,
+        distance: Num,
+        multiplier: Num,
+        data: &[T],
+    ) -> Result<Self, TAError>
+    where
+        T: High + Low + Close,
+    {
+        // Check we can calculate Squeeze.
+        if period < 2 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 2 to calculate squeeze",
+            )));
+        } else if data.len() < Self::required_for(period) {
+            // Make sure we have enough data for a period of momentum.
+            return Err(TAError::InvalidData {
+                indicator: "squeeze",
+                required: Self::required_for(period),
+                received: data.len(),
+            });
+        }
+
+        let required = Self::required_for(period);
+        let closes: Vec<Num> = data[..period].iter().map(|v| v.close()).collect();
+        let mut bbands = BollingerBands::new(period, &closes, distance)?;
+        let mut extrema = RollingExtrema::from_candles(period, &data[..period])?;
+
+        // Differences from the midline for the first period of momentum.
+        let mut deltas = vec![Self::delta(&bbands, &extrema, data[period - 1].close())];
+        for v in data[period..required].iter() {
+            bbands.next(v.close());
+            extrema.next(v);
+            deltas.push(Self::delta(&bbands, &extrema, v.close()));
+        }
+
+        let keltner = KeltnerChannels::new(period, multiplier, &data[..required])?;
+        let momentum = LinearRegression::new(period, &deltas)?;
+        let mut squeeze = Self {
+            period,
+            value: momentum.value(),
+            is_squeezed: Self::inside(&bbands, &keltner),
+            fired: false,
+            bbands,
+            keltner,
+            extrema,
+            momentum,
+            seed: Seed::new(required),
+        };
+
+        // Calculate the remainder of the squeeze.
+        for v in data[required..].iter() {
+            squeeze.next(v);
+        }
+
+        Ok(squeeze)
+    }
+
+    /// Creates a new Squeeze with the common settings, a period of 20 with the Bollinger Bands
+    /// 2.0 standard deviations and the Keltner Channels 1.5 ATRs from the middle.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Data must have at least 39 elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - Array of candles to create the Squeeze from.
+    pub fn with_defaults<T>(data: &[T]) -> Result<Self, TAError>
+    where
+        T: High + Low + Close,
+    {
+        Self::new(20, 2.0, 1.5, data)
+    }
+
+    /// Creates a new Squeeze with the supplied period and distances, without initial data. The
+    /// candles are supplied with `next` instead, the squeeze is off with no momentum until ready.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Multiplier must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `distance` - Distance of the Bollinger Bands (in standard deviations). default 2.0
+    /// * `multiplier` - Multiplier of the ATR for the Keltner Channels. default 1.5
+    pub fn with_period(period: usize, distance: Num, multiplier: Num) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            distance,
+            multiplier,
+            &seed::placeholder(Data(1.0, 1.0, 1.0), Self::required_for(period)),
+        ))
+    }
+
+    /// Current and most recent momentum calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Momentum of the close, positive when above the midline. The sign on the candle the
+    /// squeeze fired is the direction of the release.
+    pub fn momentum(&self) -> Num {
+        self.value
+    }
+
+    /// Checks if the Bollinger Bands are inside of the Keltner Channels.
+    pub fn is_squeezed(&self) -> bool {
+        self.is_squeezed
+    }
+
+    /// Checks if the squeeze was released by the last candle, the Bollinger Bands leaving the
+    /// Keltner Channels.
+    pub fn fired(&self) -> bool {
+        self.fired
+    }
+
+    /// Bollinger Bands compared to the Keltner Channels.
+    pub fn bbands(&self) -> &BollingerBands<SimpleMovingAverage> {
+        &self.bbands
+    }
+
+    /// Keltner Channels compared to the Bollinger Bands.
+    pub fn keltner(&self) -> &KeltnerChannels {
+        &self.keltner
+    }
+
+    /// Amount of candles required to create the Squeeze, a period of momentum.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    fn required_for(period: usize) -> usize {
+        (period * 2).saturating_sub(1)
+    }
+
+    /// Checks if both Bollinger Bands are strictly inside of the Keltner Channels.
+    fn inside(bbands: &BollingerBands<SimpleMovingAverage>, keltner: &KeltnerChannels) -> bool {
+        bbands.lower() > keltner.lower() && bbands.upper() < keltner.upper()
+    }
+
+    /// Difference of the close from the midline, the average of the Donchian middle and SMA.
+    ///
+    /// # Arguments
+    ///
+    /// * `bbands` - Bollinger Bands holding the SMA.
+    /// * `extrema` - Highest high and lowest low of the period.
+    /// * `close` - Close of the candle.
+    fn delta(
+        bbands: &BollingerBands<SimpleMovingAverage>,
+        extrema: &RollingExtrema,
+        close: Num,
+    ) -> Num {
+        let donchian = (extrema.max() + extrema.min()) / 2.0;
+        close - (donchian + bbands.value()) / 2.0
+    }
+
+    /// Current state of the squeeze.
+    fn output(&self) -> SqueezeOutput {
+        SqueezeOutput {
+            is_squeezed: self.is_squeezed,
+            fired: self.fired,
+            momentum: self.value,
+        }
+    }
+}
+
+impl<T> Next<&T> for Squeeze
+where
+    T: High + Low + Close,
+{
+    /// Squeeze state, if it fired, and the momentum.
+    type Output = SqueezeOutput;
+
+    /// Supply an additional candle to recalculate the Squeeze.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        if seed::collect(self, Data(value.high(), value.low(), value.close())) {
+            return self.output();
+        }
+
+        self.bbands.next(value.close());
+        self.keltner.next(value);
+        self.extrema.next(value);
+        self.value = self
+            .momentum
+            .next(Self::delta(&self.bbands, &self.extrema, value.close()));
+
+        let was_squeezed = self.is_squeezed;
+        self.is_squeezed = Self::inside(&self.bbands, &self.keltner);
+        self.fired = was_squeezed && !self.is_squeezed;
+        self.output()
+    }
+}
+
+impl Next<(Num, Num, Num)> for Squeeze {
+    /// Squeeze state, if it fired, and the momentum.
+    type Output = SqueezeOutput;
+
+    /// Supply an additional value to recalculate the Squeeze.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    ///     * 0 = High
+    ///     * 1 = Low
+    ///     * 2 = Close
+    fn next(&mut self, value: (Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2))
+    }
+}
+
+impl Reset for Squeeze {
+    /// Clears the Squeeze back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.bbands.reset();
+        self.keltner.reset();
+        self.extrema.reset();
+        self.momentum.reset();
+        self.is_squeezed = false;
+        self.fired = false;
+        self.seed.start();
+    }
+}
+
+impl Ready for Squeeze {
+    /// Checks if enough candles have been supplied for the momentum to be complete, false while
+    /// collecting the initial candles after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of candles supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for Squeeze {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        Self::required_for(self.period)
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(
+            self.period,
+            self.bbands.distance(),
+            self.keltner.multiplier(),
+            data,
+        )
+    }
+}
+
+impl Snapshot for Squeeze {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Squeeze")
+            .count(self.period)
+            .scalar(self.value)
+            .flag(self.is_squeezed)
+            .flag(self.fired)
+            .child(&self.bbands)
+            .child(&self.keltner)
+            .child(&self.extrema)
+            .child(&self.momentum)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Squeeze")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            is_squeezed: reader.flag()?,
+            fired: reader.flag()?,
+            bbands: reader.child()?,
+            keltner: reader.child()?,
+            extrema: reader.child()?,
+            momentum: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -12,10 +12,19 @@
 //! * `x` is the current value in a set.
 //! * `μ` is the mean of the set.
 //! * `∑` is the sum.
+//!
+//! The period's values are held in a `Buffer` by default, any `Window` can be used instead such as
+//! a `StaticBuffer` to avoid heap allocations.
 
-use crate::traits::{AsValue, InternalValue, Next, Period};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value, Window,
+};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
 
 /// Standard Deviation (SD/STDEV)
 ///
@@ -31,16 +40,21 @@ use tatk_derive::{InternalValue, Period};
 /// * `x` is the current value in a set.
 /// * `μ` is the mean of the set.
 /// * `∑` is the sum.
-#[derive(Debug, InternalValue, Period)]
-pub struct StandardDeviation {
+///
+/// The period's values are held in a `Buffer` by default, any `Window` can be used instead such as
+/// a `StaticBuffer` to avoid heap allocations.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct StandardDeviation<B = Buffer> {
     /// Size of the period (window) in which data is looked at.
     period: usize,
     /// STDEV's current value.
     value: Num,
     /// Holds all of the current period's values.
-    buffer: Buffer,
+    buffer: B,
     /// Labels it as sample or population.
     is_sample: bool,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
 }
 
 impl StandardDeviation {
@@ -57,6 +71,49 @@ impl StandardDeviation {
     /// * `data` - Array of values to create the STDEV from.
     /// * `is_sample` - If the data is a Sample or Population, default should be True.
     pub fn new(period: usize, data: &[Num], is_sample: bool) -> Result<Self, TAError> {
+        Self::with_window(period, data, is_sample)
+    }
+
+    /// Creates a new standard deviation with the supplied period, without initial data. The values
+    /// are supplied with `next` instead, until ready the value is calculated from the values
+    /// supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    pub fn with_period(period: usize, is_sample: bool) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            &seed::placeholder(1.0, period),
+            is_sample,
+        ))
+    }
+}
+
+impl<B> StandardDeviation<B>
+where
+    B: Window,
+{
+    /// Creates a new standard deviation with the supplied period and initial data, held in the
+    /// window `B`.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    /// * Period must be a capacity the window supports, `N` for a `StaticBuffer<N>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the STDEV from.
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    pub fn with_window(period: usize, data: &[Num], is_sample: bool) -> Result<Self, TAError> {
         // Check we can calculate Standard Deviation.
         if period < 1 {
             return Err(TAError::InvalidSize(String::from(
@@ -64,22 +121,22 @@ impl StandardDeviation {
             )));
         } else if data.len() < period {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data for period provided",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "standard deviation",
+                required: period,
+                received: data.len(),
+            });
         }
 
         // Build the buffer from the data provided.
-        let buffer: Buffer = match Buffer::from_array(period, data) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        let buffer = B::from_array(period, data)?;
 
         Ok(Self {
             period,
             value: buffer.stdev(is_sample),
             buffer,
             is_sample,
+            seed: Seed::new(data.len()),
         })
     }
 
@@ -94,7 +151,10 @@ impl StandardDeviation {
     }
 }
 
-impl Next<Num> for StandardDeviation {
+impl<B> Next<Num> for StandardDeviation<B>
+where
+    B: Window,
+{
     /// Value for the next STDEV.
     type Output = Num;
 
@@ -104,6 +164,10 @@ impl Next<Num> for StandardDeviation {
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
         // Rotate the buffer.
         self.buffer.shift(value);
 
@@ -113,9 +177,10 @@ impl Next<Num> for StandardDeviation {
     }
 }
 
-impl<T> Next<T> for StandardDeviation
+impl<T, B> Next<T> for StandardDeviation<B>
 where
     T: AsValue,
+    B: Window,
 {
     /// Value for the next STDEV.
     type Output = Num;
@@ -129,3 +194,115 @@ where
         self.next(value.as_value())
     }
 }
+
+impl<B> Reset for StandardDeviation<B>
+where
+    B: Window,
+{
+    /// Clears the STDEV back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl<B> Ready for StandardDeviation<B>
+where
+    B: Window,
+{
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl<B> Reseed for StandardDeviation<B>
+where
+    B: Window,
+{
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::with_window(self.period, data, self.is_sample)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        // The window `B` may not support a smaller capacity.
+        if let Ok(partial) = StandardDeviation::new(data.len(), data, self.is_sample) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl<B> Stats for StandardDeviation<B>
+where
+    B: Window,
+{
+    /// Obtains the total sum of the buffer for the STDEV.
+    fn sum(&self) -> Num {
+        self.buffer.sum()
+    }
+
+    /// Mean for the period of the STDEV.
+    fn mean(&self) -> Num {
+        self.buffer.mean()
+    }
+
+    /// Current variance for the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn variance(&self, is_sample: bool) -> Num {
+        self.buffer.variance(is_sample)
+    }
+
+    /// Current standard deviation for the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn stdev(&self, is_sample: bool) -> Num {
+        self.buffer.stdev(is_sample)
+    }
+}
+
+impl<B> Snapshot for StandardDeviation<B>
+where
+    B: Window + Snapshot,
+{
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("STDEV")
+            .count(self.period)
+            .scalar(self.value)
+            .flag(self.is_sample)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "STDEV")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            is_sample: reader.flag()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,395 @@
+//! Support and Resistance, horizontal levels clustered from swing points.
+//!
+//! Each confirmed swing high or low touches the closest level within the tolerance, otherwise it
+//! starts a new level. Levels that drift within the tolerance of each other are merged. A level
+//! is scored by its touches, decaying as it ages and pruned once it is older than the max age.
+//!
+//! # Formula
+//!
+//! Score = t * (1 - (a / m))
+//!
+//! where:
+//!
+//! * `t` = touches of the level.
+//! * `a` = candles since the last touch.
+//! * `m` = max age of a level.
+
+use super::SwingPoints;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{Close, High, Low, Next, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+
+/// Used for conversions. Holds High (0), Low (1), and Close (2) values.
+#[derive(Copy, Clone, PartialEq)]
+struct Data(Num, Num, Num);
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.2
+    }
+}
+
+/// Distance within which a swing touches a level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// Distance in price.
+    Absolute(Num),
+    /// Distance as a percent of the level, 0.5 is 0.5%.
+    Percent(Num),
+}
+
+impl Tolerance {
+    /// Checks if two prices are within the tolerance of each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Price of the level.
+    /// * `price` - Price to compare to the level.
+    fn contains(&self, level: Num, price: Num) -> bool {
+        (level - price).abs() <= self.distance(level)
+    }
+
+    /// Distance in price from a level.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Price of the level.
+    pub(crate) fn distance(&self, level: Num) -> Num {
+        match self {
+            Tolerance::Absolute(d) => *d,
+            Tolerance::Percent(p) => level.abs() * p / 100.0,
+        }
+    }
+
+    /// Position of the variant and the distance, held in a state.
+    pub(crate) fn to_state(self) -> (usize, Num) {
+        match self {
+            Tolerance::Absolute(d) => (0, d),
+            Tolerance::Percent(p) => (1, p),
+        }
+    }
+
+    /// Creates the tolerance from the position of the variant and the distance.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the variant.
+    /// * `distance` - Absolute distance or percent of the variant.
+    pub(crate) fn from_state(index: usize, distance: Num) -> Result<Self, TAError> {
+        match index {
+            0 => Ok(Tolerance::Absolute(distance)),
+            1 => Ok(Tolerance::Percent(distance)),
+            _ => Err(TAError::InvalidState(String::from("unknown tolerance"))),
+        }
+    }
+}
+
+/// Horizontal price level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Level {
+    /// Price of the level, the mean of its touches.
+    pub price: Num,
+    /// Swings that touched the level.
+    pub touches: usize,
+    /// Position of the candle that last touched the level, 0 being the first candle supplied.
+    pub last_touch: usize,
+    /// Touches decayed by the age of the level.
+    pub score: Num,
+}
+
+/// Support and Resistance, horizontal levels clustered from swing points.
+///
+/// Each confirmed swing high or low touches the closest level within the tolerance, otherwise it
+/// starts a new level. Levels that drift within the tolerance of each other are merged. A level
+/// is scored by its touches, decaying as it ages and pruned once it is older than the max age.
+///
+/// # Formula
+///
+/// Score = t * (1 - (a / m))
+///
+/// where:
+///
+/// * `t` = touches of the level.
+/// * `a` = candles since the last touch.
+/// * `m` = max age of a level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SupportResistance {
+    /// Detects the swings that touch the levels.
+    swings: SwingPoints,
+    /// Distance within which a swing touches a level.
+    tolerance: Tolerance,
+    /// Candles since the last touch before a level is pruned.
+    max_age: usize,
+    /// Amount of candles supplied.
+    count: usize,
+    /// Close of the last candle.
+    close: Option<Num>,
+    /// Current levels, ordered by price.
+    levels: Vec<Level>,
+}
+
+impl SupportResistance {
+    /// Creates a new Support and Resistance from the swing detector, tolerance, and max age.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Tolerance must be 0 or greater.
+    /// * Max age must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `swings` - Detects the swings that touch the levels.
+    /// * `tolerance` - Distance within which a swing touches a level.
+    /// * `max_age` - Candles since the last touch before a level is pruned.
+    pub fn new(swings: SwingPoints, tolerance: Tolerance, max_age: usize) -> Result<Self, TAError> {
+        let distance = match tolerance {
+            Tolerance::Absolute(d) | Tolerance::Percent(d) => d,
+        };
+
+        // Check we can cluster levels.
+        if distance.is_nan() || distance < 0.0 {
+            return Err(TAError::InvalidSize(String::from(
+                "tolerance cannot be less than 0 to cluster levels",
+            )));
+        } else if max_age < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "max age cannot be less than 1 to cluster levels",
+            )));
+        }
+
+        Ok(Self {
+            swings,
+            tolerance,
+            max_age,
+            count: 0,
+            close: None,
+            levels: vec![],
+        })
+    }
+
+    /// Detector of the swings that touch the levels.
+    pub fn swings(&self) -> &SwingPoints {
+        &self.swings
+    }
+
+    /// Current levels, highest score first.
+    pub fn levels(&self) -> Vec<Level> {
+        let mut levels = self.levels.clone();
+        levels.sort_by(|a, b| b.score.total_cmp(&a.score));
+        levels
+    }
+
+    /// Closest level above the price.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - Price to search above.
+    pub fn nearest_above(&self, price: Num) -> Option<Level> {
+        self.levels.iter().find(|l| l.price > price).copied()
+    }
+
+    /// Closest level below the price.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - Price to search below.
+    pub fn nearest_below(&self, price: Num) -> Option<Level> {
+        self.levels.iter().rev().find(|l| l.price < price).copied()
+    }
+
+    /// Closest level above the last close.
+    pub fn resistance(&self) -> Option<Level> {
+        self.nearest_above(self.close?)
+    }
+
+    /// Closest level below the last close.
+    pub fn support(&self) -> Option<Level> {
+        self.nearest_below(self.close?)
+    }
+
+    /// Adds a touch to the closest level within the tolerance, or starts a new level.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - Price of the swing.
+    /// * `index` - Position of the candle of the swing.
+    fn touch(&mut self, price: Num, index: usize) {
+        let tolerance = self.tolerance;
+        let closest = self
+            .levels
+            .iter_mut()
+            .filter(|l| tolerance.contains(l.price, price))
+            .min_by(|a, b| (a.price - price).abs().total_cmp(&(b.price - price).abs()));
+
+        match closest {
+            Some(level) => {
+                let touches = level.touches as Num;
+                level.price = (level.price * touches + price) / (touches + 1.0);
+                level.touches += 1;
+                level.last_touch = level.last_touch.max(index);
+            }
+            None => self.levels.push(Level {
+                price,
+                touches: 1,
+                last_touch: index,
+                score: 0.0,
+            }),
+        }
+
+        self.levels.sort_by(|a, b| a.price.total_cmp(&b.price));
+    }
+
+    /// Merges neighbouring levels within the tolerance of each other.
+    fn merge(&mut self) {
+        let mut merged: Vec<Level> = Vec::with_capacity(self.levels.len());
+        for level in self.levels.drain(..) {
+            match merged.last_mut() {
+                Some(last) if self.tolerance.contains(last.price, level.price) => {
+                    let touches = (last.touches + level.touches) as Num;
+                    last.price = (last.price * last.touches as Num
+                        + level.price * level.touches as Num)
+                        / touches;
+                    last.touches += level.touches;
+                    last.last_touch = last.last_touch.max(level.last_touch);
+                }
+                _ => merged.push(level),
+            }
+        }
+
+        self.levels = merged;
+    }
+}
+
+impl<T> Next<&T> for SupportResistance
+where
+    T: High + Low + Close,
+{
+    /// Current levels, highest score first.
+    type Output = Vec<Level>;
+
+    /// Supply an additional candle to touch the levels with any swings it confirms. Levels are
+    /// scored and stale levels are pruned.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add.
+    fn next(&mut self, value: &T) -> Self::Output {
+        self.count += 1;
+        self.close = Some(value.close());
+
+        for swing in self.swings.next(value) {
+            self.touch(swing.price, swing.index);
+        }
+        self.merge();
+
+        // Decay the scores by age, pruning the stale levels.
+        let (now, max_age) = (self.count - 1, self.max_age);
+        self.levels.retain(|l| now - l.last_touch <= max_age);
+        for level in self.levels.iter_mut() {
+            let age = (now - level.last_touch) as Num;
+            level.score = level.touches as Num * (1.0 - age / max_age as Num);
+        }
+
+        self.levels()
+    }
+}
+
+impl Next<(Num, Num, Num)> for SupportResistance {
+    /// Current levels, highest score first.
+    type Output = Vec<Level>;
+
+    /// Supply an additional value to touch the levels with any swings it confirms.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add.
+    ///     * 0 = High
+    ///     * 1 = Low
+    ///     * 2 = Close
+    fn next(&mut self, value: (Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2))
+    }
+}
+
+impl Reset for SupportResistance {
+    /// Clears the swings and levels back to having seen no data.
+    fn reset(&mut self) {
+        self.swings.reset();
+        self.count = 0;
+        self.close = None;
+        self.levels.clear();
+    }
+}
+
+impl Snapshot for SupportResistance {
+    fn snapshot(&self) -> IndicatorState {
+        let (tolerance, distance) = self.tolerance.to_state();
+        let levels: Vec<Num> = self
+            .levels
+            .iter()
+            .flat_map(|l| [l.price, l.score])
+            .collect();
+        let state = IndicatorState::new("Support and Resistance")
+            .count(tolerance)
+            .scalar(distance)
+            .count(self.max_age)
+            .count(self.count)
+            .optional(self.close)
+            .buffer(&levels)
+            .child(&self.swings);
+
+        // Touches of each level are held as counts.
+        self.levels
+            .iter()
+            .fold(state, |state, l| state.count(l.touches).count(l.last_touch))
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Support and Resistance")?;
+        let tolerance = Tolerance::from_state(reader.count()?, reader.scalar()?)?;
+        let (max_age, count) = (reader.count()?, reader.count()?);
+        let close = reader.optional()?;
+        let values = reader.buffer()?;
+        if values.len() % 2 != 0 {
+            return Err(TAError::InvalidState(String::from(
+                "support and resistance holds a partial level",
+            )));
+        }
+
+        let mut levels: Vec<Level> = Vec::with_capacity(values.len() / 2);
+        for level in values.chunks(2) {
+            levels.push(Level {
+                price: level[0],
+                touches: reader.count()?,
+                last_touch: reader.count()?,
+                score: level[1],
+            });
+        }
+
+        Ok(Self {
+            swings: reader.child()?,
+            tolerance,
+            max_age,
+            count,
+            close,
+            levels,
+        })
+    }
+}
@@ -0,0 +1,378 @@
+//! Swing Points, confirmed swing highs and lows ranked by strength.
+//!
+//! A swing high has a high greater than the highs of `left` candles before it and `right`
+//! candles after it, a swing low is the inverse with the lows. Swings are confirmed `right`
+//! candles after they occur.
+//!
+//! Strength is how many consecutive candles a swing dominates on each side. The left strength is
+//! counted within the lookback, the right strength grows until a later candle exceeds the swing.
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{High, Low, Next, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+use alloc::collections::VecDeque;
+
+/// Maximum amount of confirmed swings retained.
+const MAX_SWINGS: usize = 100;
+
+/// Used for conversions. Holds High (0) and Low (1) values.
+#[derive(Copy, Clone, PartialEq)]
+struct Data(Num, Num);
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+/// Type of swing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwingKind {
+    /// High greater than the surrounding highs.
+    High,
+    /// Low less than the surrounding lows.
+    Low,
+}
+
+impl Variants for SwingKind {
+    const ALL: &'static [Self] = &[SwingKind::High, SwingKind::Low];
+}
+
+/// Structure of the market from the most recent swings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketStructure {
+    /// Higher highs and higher lows.
+    Uptrend,
+    /// Lower highs and lower lows.
+    Downtrend,
+    /// Neither, or not enough swings.
+    Range,
+}
+
+/// Confirmed swing high or low.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Swing {
+    /// Type of swing.
+    pub kind: SwingKind,
+    /// High of a swing high, low of a swing low.
+    pub price: Num,
+    /// Position of the candle, 0 being the first candle supplied.
+    pub index: usize,
+    /// Candles between the swing and the candle that confirmed it.
+    pub offset: usize,
+    /// Consecutive candles dominated before the swing.
+    pub left_strength: usize,
+    /// Consecutive candles dominated after the swing.
+    pub right_strength: usize,
+}
+
+impl Swing {
+    /// Checks if the swing dominates the candle.
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - High of the candle.
+    /// * `low` - Low of the candle.
+    fn dominates(&self, high: Num, low: Num) -> bool {
+        match self.kind {
+            SwingKind::High => self.price > high,
+            SwingKind::Low => self.price < low,
+        }
+    }
+}
+
+/// Swing Points, confirmed swing highs and lows ranked by strength.
+///
+/// A swing high has a high greater than the highs of `left` candles before it and `right`
+/// candles after it, a swing low is the inverse with the lows. Swings are confirmed `right`
+/// candles after they occur.
+///
+/// Strength is how many consecutive candles a swing dominates on each side. The left strength is
+/// counted within the lookback, the right strength grows until a later candle exceeds the swing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwingPoints {
+    /// Candles required before a swing.
+    left: usize,
+    /// Candles required after a swing.
+    right: usize,
+    /// Candles before a swing used for the left strength.
+    lookback: usize,
+    /// Highs and lows of the recent candles, oldest to newest.
+    candles: VecDeque<(Num, Num)>,
+    /// Amount of candles supplied.
+    count: usize,
+    /// Confirmed swings, oldest to newest, with if their right strength is still growing.
+    swings: VecDeque<(Swing, bool)>,
+}
+
+impl SwingPoints {
+    /// Creates a new Swing Points with the supplied candle counts.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Left and right must be greater than 0.
+    /// * Lookback must be at least left.
+    ///
+    /// ## Arguments
+    ///
+    /// * `left` - Candles required before a swing.
+    /// * `right` - Candles required after a swing, the delay to confirm it.
+    /// * `lookback` - Candles before a swing used for the left strength.
+    pub fn new(left: usize, right: usize, lookback: usize) -> Result<Self, TAError> {
+        // Check we can detect swings.
+        if left < 1 || right < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "left and right cannot be less than 1 to detect swings",
+            )));
+        } else if lookback < left {
+            return Err(TAError::InvalidSize(String::from(
+                "lookback cannot be less than left to detect swings",
+            )));
+        }
+
+        Ok(Self {
+            left,
+            right,
+            lookback,
+            candles: VecDeque::with_capacity(lookback + right + 1),
+            count: 0,
+            swings: VecDeque::new(),
+        })
+    }
+
+    /// Candles required before a swing.
+    pub fn left(&self) -> usize {
+        self.left
+    }
+
+    /// Candles required after a swing.
+    pub fn right(&self) -> usize {
+        self.right
+    }
+
+    /// Most recent `n` confirmed swings, oldest to newest.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Amount of swings to obtain.
+    pub fn recent_swings(&self, n: usize) -> Vec<Swing> {
+        let skip = self.swings.len().saturating_sub(n);
+        self.swings.iter().skip(skip).map(|(s, _)| *s).collect()
+    }
+
+    /// Structure of the market from the last four swings. Requires two highs and two lows
+    /// within them, otherwise it is a range.
+    pub fn market_structure(&self) -> MarketStructure {
+        let recent = self.recent_swings(4);
+        let highs: Vec<Num> = recent
+            .iter()
+            .filter(|s| s.kind == SwingKind::High)
+            .map(|s| s.price)
+            .collect();
+        let lows: Vec<Num> = recent
+            .iter()
+            .filter(|s| s.kind == SwingKind::Low)
+            .map(|s| s.price)
+            .collect();
+
+        if highs.len() < 2 || lows.len() < 2 {
+            return MarketStructure::Range;
+        }
+
+        let (h1, h2) = (highs[highs.len() - 2], highs[highs.len() - 1]);
+        let (l1, l2) = (lows[lows.len() - 2], lows[lows.len() - 1]);
+        if h2 > h1 && l2 > l1 {
+            MarketStructure::Uptrend
+        } else if h2 < h1 && l2 < l1 {
+            MarketStructure::Downtrend
+        } else {
+            MarketStructure::Range
+        }
+    }
+
+    /// Checks if the candle `right` candles back is a swing of the kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Type of swing to check for.
+    fn confirm(&self, kind: SwingKind) -> Option<Swing> {
+        let pivot = self.candles.len() - 1 - self.right;
+        let (high, low) = self.candles[pivot];
+        let swing = Swing {
+            kind,
+            price: match kind {
+                SwingKind::High => high,
+                SwingKind::Low => low,
+            },
+            index: self.count - 1 - self.right,
+            offset: self.right,
+            left_strength: 0,
+            right_strength: self.right,
+        };
+
+        let dominates = |c: &(Num, Num)| swing.dominates(c.0, c.1);
+        if !self.candles.iter().skip(pivot + 1).all(dominates) {
+            return None;
+        }
+
+        let left_strength = self
+            .candles
+            .iter()
+            .take(pivot)
+            .rev()
+            .take_while(|c| dominates(c))
+            .count();
+        if left_strength < self.left {
+            return None;
+        }
+
+        Some(Swing {
+            left_strength,
+            ..swing
+        })
+    }
+}
+
+impl<T> Next<&T> for SwingPoints
+where
+    T: High + Low,
+{
+    /// Swings confirmed by the candle.
+    type Output = Vec<Swing>;
+
+    /// Supply an additional candle to confirm swings `right` candles back.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add.
+    fn next(&mut self, value: &T) -> Self::Output {
+        let (high, low) = (value.high(), value.low());
+        if self.candles.len() == self.lookback + self.right + 1 {
+            self.candles.pop_front();
+        }
+        self.candles.push_back((high, low));
+        self.count += 1;
+
+        // Grow the right strength of the swings not yet exceeded.
+        for (swing, is_growing) in self.swings.iter_mut().filter(|(_, g)| *g) {
+            *is_growing = swing.dominates(high, low);
+            if *is_growing {
+                swing.right_strength += 1;
+            }
+        }
+
+        if self.candles.len() < self.left + self.right + 1 {
+            return vec![];
+        }
+
+        let confirmed: Vec<Swing> = [SwingKind::High, SwingKind::Low]
+            .into_iter()
+            .filter_map(|kind| self.confirm(kind))
+            .collect();
+
+        for swing in confirmed.iter() {
+            if self.swings.len() == MAX_SWINGS {
+                self.swings.pop_front();
+            }
+            self.swings.push_back((*swing, true));
+        }
+
+        confirmed
+    }
+}
+
+impl Next<(Num, Num)> for SwingPoints {
+    /// Swings confirmed by the candle.
+    type Output = Vec<Swing>;
+
+    /// Supply an additional value to confirm swings `right` candles back.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add.
+    ///     * 0 = High
+    ///     * 1 = Low
+    fn next(&mut self, value: (Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1))
+    }
+}
+
+impl Reset for SwingPoints {
+    /// Clears the candles and swings back to having seen no data.
+    fn reset(&mut self) {
+        self.candles.clear();
+        self.count = 0;
+        self.swings.clear();
+    }
+}
+
+impl Snapshot for SwingPoints {
+    fn snapshot(&self) -> IndicatorState {
+        let candles: Vec<Num> = self.candles.iter().flat_map(|(h, l)| [*h, *l]).collect();
+        let prices: Vec<Num> = self.swings.iter().map(|(swing, _)| swing.price).collect();
+        let state = IndicatorState::new("Swing Points")
+            .count(self.left)
+            .count(self.right)
+            .count(self.lookback)
+            .count(self.count)
+            .buffer(&candles)
+            .buffer(&prices);
+
+        // Parts of each swing other than the price are held as counts.
+        self.swings
+            .iter()
+            .fold(state, |state, (swing, is_growing)| {
+                state
+                    .variant(swing.kind)
+                    .count(swing.index)
+                    .count(swing.offset)
+                    .count(swing.left_strength)
+                    .count(swing.right_strength)
+                    .flag(*is_growing)
+            })
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Swing Points")?;
+        let (left, right) = (reader.count()?, reader.count()?);
+        let (lookback, count) = (reader.count()?, reader.count()?);
+        let candles = reader.buffer()?;
+        if candles.len() % 2 != 0 {
+            return Err(TAError::InvalidState(String::from(
+                "swing points holds a partial candle",
+            )));
+        }
+
+        let mut swings: VecDeque<(Swing, bool)> = VecDeque::new();
+        for price in reader.buffer()?.iter() {
+            let swing = Swing {
+                kind: reader.variant()?,
+                price: *price,
+                index: reader.count()?,
+                offset: reader.count()?,
+                left_strength: reader.count()?,
+                right_strength: reader.count()?,
+            };
+            swings.push_back((swing, reader.flag()?));
+        }
+
+        Ok(Self {
+            left,
+            right,
+            lookback,
+            candles: candles.chunks(2).map(|c| (c[0], c[1])).collect(),
+            count,
+            swings,
+        })
+    }
+}
@@ -0,0 +1,271 @@
+//! Timed, supplies an indicator with timestamped values, handling missing bars and sessions.
+//!
+//! Bars are expected every interval, a timestamp more than an interval after the last is a gap of
+//! the bars missing in between. The gap is handled by the `GapPolicy`, forward-filling the last
+//! value, skipping the gap, or resetting the indicator. With a `Session`, the indicator is also
+//! reset once a timestamp is in a later session than the last, such as for a daily VWAP.
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::traits::{InternalValue, Next, Ready, Reset, Timestamped};
+use crate::{Num, TAError};
+
+/// How missing bars between two timestamps are handled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GapPolicy {
+    /// Supplies the last value once for each missing bar, as if the value had not changed.
+    ForwardFill,
+    /// Ignores the gap, the value is supplied as if the bars were consecutive.
+    #[default]
+    Skip,
+    /// Resets the indicator, the value is supplied as if it was the first.
+    Reset,
+}
+
+/// When a new session starts, resetting the indicator.
+#[derive(Debug, Clone, Copy)]
+pub enum Session {
+    /// Sessions of a fixed duration, such as a day, starting at multiples of the duration.
+    Duration(i64),
+    /// Session of a timestamp, such as `|ts| (ts - open) / day` for sessions opening mid-day. A new
+    /// session starts once the session of a timestamp differs from the last.
+    Custom(fn(i64) -> i64),
+}
+
+impl Session {
+    /// Session the timestamp is within.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Timestamp to find the session of.
+    pub fn of(&self, timestamp: i64) -> i64 {
+        match self {
+            Session::Duration(duration) => timestamp.div_euclid(*duration),
+            Session::Custom(session) => session(timestamp),
+        }
+    }
+}
+
+/// Timed, supplies an indicator with timestamped values, handling missing bars and sessions.
+///
+/// Bars are expected every interval, a timestamp more than an interval after the last is a gap of
+/// the bars missing in between. The gap is handled by the `GapPolicy`, forward-filling the last
+/// value, skipping the gap, or resetting the indicator. With a `Session`, the indicator is also
+/// reset once a timestamp is in a later session than the last, such as for a daily VWAP.
+///
+/// Values are supplied with `next_at`, or with `next` as a `(timestamp, value)` tuple or a value
+/// that is `Timestamped`. Timestamps at or before the last are supplied without a gap.
+#[derive(Debug, Clone)]
+pub struct Timed<I, V> {
+    /// Indicator supplied the values.
+    indicator: I,
+    /// Expected time between bars.
+    interval: i64,
+    /// How missing bars are handled.
+    policy: GapPolicy,
+    /// When the indicator is reset for a new session, if any.
+    session: Option<Session>,
+    /// Timestamp and value last supplied.
+    last: Option<(i64, V)>,
+    /// Bars missing before the last value.
+    missing: usize,
+}
+
+impl<I, V> Timed<I, V>
+where
+    I: Next<V> + Reset,
+    V: Clone,
+{
+    /// Creates a new Timed supplying the indicator, expecting a bar every interval.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Interval must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `indicator` - Indicator to supply the values to.
+    /// * `interval` - Expected time between bars, in the units of the timestamps.
+    /// * `policy` - How missing bars are handled.
+    pub fn new(indicator: I, interval: i64, policy: GapPolicy) -> Result<Self, TAError> {
+        if interval <= 0 {
+            return Err(TAError::InvalidSize(String::from(
+                "interval between bars must be greater than 0",
+            )));
+        }
+
+        Ok(Self {
+            indicator,
+            interval,
+            policy,
+            session: None,
+            last: None,
+            missing: 0,
+        })
+    }
+
+    /// Resets the indicator once a timestamp is in a later session than the last.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Duration of a session must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `session` - When a new session starts.
+    pub fn with_session(mut self, session: Session) -> Result<Self, TAError> {
+        if matches!(session, Session::Duration(duration) if duration <= 0) {
+            return Err(TAError::InvalidSize(String::from(
+                "duration of a session must be greater than 0",
+            )));
+        }
+
+        self.session = Some(session);
+        Ok(self)
+    }
+
+    /// Indicator supplied the values.
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+
+    /// Consumes the Timed, returning the indicator.
+    pub fn into_indicator(self) -> I {
+        self.indicator
+    }
+
+    /// Expected time between bars.
+    pub fn interval(&self) -> i64 {
+        self.interval
+    }
+
+    /// How missing bars are handled.
+    pub fn policy(&self) -> GapPolicy {
+        self.policy
+    }
+
+    /// When the indicator is reset for a new session, if any.
+    pub fn session(&self) -> Option<Session> {
+        self.session
+    }
+
+    /// Timestamp last supplied, `None` if there have been none.
+    pub fn last_timestamp(&self) -> Option<i64> {
+        self.last.as_ref().map(|(ts, _)| *ts)
+    }
+
+    /// Bars missing before the last value, 0 if it followed the prior value or started a session.
+    pub fn missing(&self) -> usize {
+        self.missing
+    }
+
+    /// Supply an additional value at the timestamp, handling the bars missing since the last
+    /// value and the start of a new session.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Timestamp of the value.
+    /// * `value` - New value to supply to the indicator.
+    pub fn next_at(&mut self, timestamp: i64, value: V) -> I::Output {
+        self.missing = 0;
+        if let Some((last, last_value)) = self.last.take() {
+            let new_session = self.session.is_some_and(|s| s.of(timestamp) != s.of(last));
+
+            if new_session {
+                self.indicator.reset();
+            } else if timestamp > last {
+                self.missing = ((timestamp - last) / self.interval).saturating_sub(1) as usize;
+                if self.missing > 0 {
+                    match self.policy {
+                        GapPolicy::ForwardFill => {
+                            for _ in 0..self.missing {
+                                self.indicator.next(last_value.clone());
+                            }
+                        }
+                        GapPolicy::Skip => {}
+                        GapPolicy::Reset => self.indicator.reset(),
+                    }
+                }
+            }
+        }
+
+        self.last = Some((timestamp, value.clone()));
+        self.indicator.next(value)
+    }
+}
+
+impl<I, V> Next<(i64, V)> for Timed<I, V>
+where
+    I: Next<V> + Reset,
+    V: Clone,
+{
+    /// Output of the indicator for the value.
+    type Output = I::Output;
+
+    /// Supply an additional value at the timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to supply.
+    ///     * 0 = Timestamp
+    ///     * 1 = Value
+    fn next(&mut self, value: (i64, V)) -> Self::Output {
+        self.next_at(value.0, value.1)
+    }
+}
+
+impl<I, V> Next<V> for Timed<I, V>
+where
+    I: Next<V> + Reset,
+    V: Timestamped + Clone,
+{
+    /// Output of the indicator for the value.
+    type Output = I::Output;
+
+    /// Supply an additional value at its own timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to supply.
+    fn next(&mut self, value: V) -> Self::Output {
+        self.next_at(value.timestamp(), value)
+    }
+}
+
+impl<I, V> InternalValue for Timed<I, V>
+where
+    I: InternalValue,
+{
+    /// Current value of the indicator.
+    fn internal_value(&self) -> Num {
+        self.indicator.internal_value()
+    }
+}
+
+impl<I, V> Reset for Timed<I, V>
+where
+    I: Reset,
+{
+    /// Clears the indicator and the last timestamp back to having seen no data.
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.last = None;
+        self.missing = 0;
+    }
+}
+
+impl<I, V> Ready for Timed<I, V>
+where
+    I: Ready,
+{
+    /// Checks if the indicator is ready.
+    fn is_ready(&self) -> bool {
+        self.indicator.is_ready()
+    }
+
+    /// Amount of values supplied to the indicator since created or reset, including those
+    /// forward-filled.
+    fn samples_seen(&self) -> usize {
+        self.indicator.samples_seen()
+    }
+}
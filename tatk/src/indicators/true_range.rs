@@ -10,14 +10,32 @@
 //! * `L` = lowest value for the data point / candle.
 //! * `C` = last close prior to this data point.
 
-use crate::traits::{Close, High, InternalValue, Low, Next, Period, Stats};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+use super::seed::{self, Flatten, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    Close, High, InternalValue, Low, Next, Period, Ready, Reset, Snapshot, Stats, Value,
+};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
 
 /// Used for conversions. Holds High (0), Low (1), and Close (2) values.
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub(crate) struct TrueRangeData(pub Num, pub Num, pub Num);
 
+impl Flatten for TrueRangeData {
+    const WIDTH: usize = 3;
+
+    fn flatten(&self, out: &mut Vec<Num>) {
+        out.extend([self.0, self.1, self.2]);
+    }
+
+    fn unflatten(values: &[Num]) -> Self {
+        Self(values[0], values[1], values[2])
+    }
+}
+
 // Highest value.
 impl High for TrueRangeData {
     fn high(&self) -> Num {
@@ -50,7 +68,7 @@ impl Close for TrueRangeData {
 /// * `H` = highest value for the data point / candle.
 /// * `L` = lowest value for the data point / candle.
 /// * `C` = last close prior to this data point.
-#[derive(Debug, InternalValue, Period)]
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
 pub struct TrueRange {
     /// Size of the period (window) in which data is looked at.
     period: usize,
@@ -58,8 +76,12 @@ pub struct TrueRange {
     value: Num,
     /// Last close used not used for calculation.
     last_close: Num,
+    /// If the first true range is the high - low of the first candle, without a prior close.
+    first_bar: bool,
     /// Holds `period` amount of generated TRs.
     buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<TrueRangeData>,
 }
 
 impl TrueRange {
@@ -75,52 +97,137 @@ impl TrueRange {
     /// * `period` - Size of the period / window used.
     /// * `data` - Array of values to create the TR from.
     pub fn new<T>(period: usize, data: &[T]) -> Result<Self, TAError>
+    where
+        T: Close + Low + High,
+    {
+        Self::create(period, data, false)
+    }
+
+    /// Creates a new TR with the supplied period and initial data, the first true range being the
+    /// high - low of the first candle. This is the TA-Lib / Wilder convention, allowing exactly
+    /// `period` candles.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the TR from.
+    pub fn new_from_first_bar<T>(period: usize, data: &[T]) -> Result<Self, TAError>
+    where
+        T: Close + Low + High,
+    {
+        Self::create(period, data, true)
+    }
+
+    /// Creates a new TR, validating the period and data.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the TR from.
+    /// * `first_bar` - If the first true range is the high - low of the first candle.
+    fn create<T>(period: usize, data: &[T], first_bar: bool) -> Result<Self, TAError>
     where
         T: Close + Low + High,
     {
         // Check we can calculate True Range.
+        let required = Self::required_for(period, first_bar);
         if period < 1 {
             return Err(TAError::InvalidSize(String::from(
                 "period cannot be less than 1 to calculate true range",
             )));
-        } else if data.len() < period + 1 {
+        } else if data.len() < required {
             // Make sure we have enough data. Requires additional data point for `last_close`
-            return Err(TAError::InvalidData(String::from(
-                "not enough data to calculate true range",
-            )));
+            // unless the first true range is from the first candle alone.
+            return Err(TAError::InvalidData {
+                indicator: "true range",
+                required,
+                received: data.len(),
+            });
         }
 
         // First close and TR to use.
         let mut last_close = data[0].close();
-        let mut last_tr = Self::calculate(&data[1], &mut last_close);
+        let (mut last_tr, remainder) = if first_bar {
+            ((data[0].high() - data[0].low()).abs(), &data[1..])
+        } else {
+            (Self::calculate(&data[1], &mut last_close), &data[2..])
+        };
 
         // Buffer will hold last `period` of TRs.
-        let mut buffer = match Buffer::from_array(period, &[last_tr]) {
-            Ok(v) => v,
-            Err(error) => return Err(error),
-        };
+        let mut buffer = Buffer::from_array(period, &[last_tr])?;
 
         // Calculate the remainder of TRs.
-        if data.len() > 2 {
-            for v in data[2..].iter() {
-                last_tr = Self::calculate(v, &mut last_close);
-                buffer.shift(last_tr);
-            }
+        for v in remainder.iter() {
+            last_tr = Self::calculate(v, &mut last_close);
+            buffer.shift(last_tr);
         }
 
         Ok(Self {
             period,
             last_close,
+            first_bar,
             value: last_tr,
             buffer,
+            seed: Seed::new(data.len()),
         })
     }
 
+    /// Amount of candles required for a period of true ranges.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `first_bar` - If the first true range is the high - low of the first candle.
+    pub(crate) fn required_for(period: usize, first_bar: bool) -> usize {
+        if first_bar {
+            period
+        } else {
+            period + 1
+        }
+    }
+
+    /// Creates a new TR with the supplied period, without initial data. The candles are supplied
+    /// with `next` instead, until ready the value is calculated from the candles supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            &seed::placeholder(TrueRangeData(1.0, 1.0, 1.0), period + 1),
+        ))
+    }
+
     /// Current and most recent value calculated.
     pub fn value(&self) -> Num {
         self.value
     }
 
+    /// Close of the most recent candle, used by the next true range.
+    pub fn last_close(&self) -> Num {
+        self.last_close
+    }
+
+    /// Checks if the first true range is the high - low of the first candle.
+    pub(crate) fn is_first_bar(&self) -> bool {
+        self.first_bar
+    }
+
+    /// True ranges within the current period, oldest to newest.
+    pub(crate) fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
     /// Calculates a new TR, requring a prior close.
     /// * 0 = High
     /// * 1 = Low
@@ -151,6 +258,13 @@ where
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: &T) -> Self::Output {
+        if seed::collect(
+            self,
+            TrueRangeData(value.high(), value.low(), value.close()),
+        ) {
+            return self.value;
+        }
+
         self.value = Self::calculate(value, &mut self.last_close);
 
         // Rotate the buffer.
@@ -172,42 +286,78 @@ impl Next<(Num, Num, Num)> for TrueRange {
     ///     * 1 = Low
     ///     * 2 = Close
     fn next(&mut self, value: (Num, Num, Num)) -> Self::Output {
-        let v = TrueRangeData {
-            0: value.0, // High
-            1: value.1, // Low
-            2: value.2, // Close
-        };
+        let v = TrueRangeData(value.0, value.1, value.2);
 
         self.next(&v)
     }
 }
 
-impl Stats for TrueRange {
-    /// Obtains the total sum of the buffer for TR.
-    fn sum(&self) -> Num {
-        self.buffer.sum()
+impl Reset for TrueRange {
+    /// Clears the True Range back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.last_close = 0.0;
+        self.buffer.clear();
+        self.seed.start();
     }
+}
 
-    /// Mean for the period of the TR.
-    fn mean(&self) -> Num {
-        self.buffer.mean()
+impl Ready for TrueRange {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
     }
 
-    /// Current variance for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn variance(&self, is_sample: bool) -> Num {
-        self.buffer.variance(is_sample)
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
     }
+}
 
-    /// Current standard deviation for the period.
-    ///
-    /// # Arguments
-    ///
-    /// * `is_sample` - If the data is a Sample or Population, default should be True.
-    fn stdev(&self, is_sample: bool) -> Num {
-        self.buffer.stdev(is_sample)
+impl Reseed for TrueRange {
+    type Input = TrueRangeData;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        Self::required_for(self.period, self.first_bar)
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::create(self.period, data, self.first_bar)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        let period = data.len() + 1 - Self::required_for(1, self.first_bar);
+        if let Ok(partial) = Self::create(period, data, self.first_bar) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for TrueRange {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("TR")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.last_close)
+            .flag(self.first_bar)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "TR")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            last_close: reader.scalar()?,
+            first_bar: reader.flag()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
     }
 }
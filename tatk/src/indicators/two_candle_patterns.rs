@@ -0,0 +1,286 @@
+//! Two Candle Patterns, classifies a candle against the prior candle.
+//!
+//! Patterns are reversals based on how the body of a candle relates to the body of the prior
+//! candle. Strict engulfing requires the body to cover the prior high and low, not only the prior
+//! body.
+//!
+//! # Patterns
+//!
+//! * Bullish Engulfing: bullish body covers the prior bearish body.
+//! * Bearish Engulfing: bearish body covers the prior bullish body.
+//! * Bullish Harami: small bullish body within the prior bearish body.
+//! * Bearish Harami: small bearish body within the prior bullish body.
+//! * Harami Cross: doji within the prior body.
+//! * Piercing Line: opens below the prior bearish close, closes above the middle of its body.
+//! * Dark Cloud Cover: opens above the prior bullish close, closes below the middle of its body.
+
+use super::{CandlePattern, PatternConfig, PatternKind};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{Close, High, Low, Next, Open, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+
+/// Used for conversions. Holds Open (0), High (1), Low (2), and Close (3) values.
+#[derive(Copy, Clone, PartialEq)]
+struct Data(Num, Num, Num, Num);
+
+// Opening value.
+impl Open for Data {
+    fn open(&self) -> Num {
+        self.0
+    }
+}
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.1
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.2
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.3
+    }
+}
+
+/// Two Candle Patterns, classifies a candle against the prior candle.
+///
+/// Patterns are reversals based on how the body of a candle relates to the body of the prior
+/// candle. Strict engulfing requires the body to cover the prior high and low, not only the prior
+/// body.
+///
+/// # Patterns
+///
+/// * Bullish Engulfing: bullish body covers the prior bearish body.
+/// * Bearish Engulfing: bearish body covers the prior bullish body.
+/// * Bullish Harami: small bullish body within the prior bearish body.
+/// * Bearish Harami: small bearish body within the prior bullish body.
+/// * Harami Cross: doji within the prior body.
+/// * Piercing Line: opens below the prior bearish close, closes above the middle of its body.
+/// * Dark Cloud Cover: opens above the prior bullish close, closes below the middle of its body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoCandlePatterns {
+    /// Ratios used to classify the candles, the doji body is used for the harami cross.
+    config: PatternConfig,
+    /// Engulfing must cover the prior wicks, not only the prior body.
+    is_strict: bool,
+    /// Open, high, low, and close of the prior candle.
+    prior: Option<(Num, Num, Num, Num)>,
+    /// Patterns matched by the last candle.
+    patterns: Vec<PatternKind>,
+}
+
+impl TwoCandlePatterns {
+    /// Creates a new Two Candle Patterns with the supplied ratios and strictness.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Fractions of the range must be between 0 and 1.
+    /// * Long wick multiple must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `config` - Ratios used to classify the candles.
+    /// * `is_strict` - Engulfing must cover the prior wicks, not only the prior body.
+    pub fn new(config: PatternConfig, is_strict: bool) -> Result<Self, TAError> {
+        config.validate()?;
+        Ok(Self {
+            config,
+            is_strict,
+            prior: None,
+            patterns: vec![],
+        })
+    }
+
+    /// Classifies a candle against the prior candle.
+    ///
+    /// # Arguments
+    ///
+    /// * `prior` - Candle before the current candle.
+    /// * `candle` - Candle to classify.
+    /// * `config` - Ratios used to classify the candle.
+    /// * `is_strict` - Engulfing must cover the prior wicks, not only the prior body.
+    pub fn classify<P, T>(
+        prior: &P,
+        candle: &T,
+        config: &PatternConfig,
+        is_strict: bool,
+    ) -> Vec<PatternKind>
+    where
+        P: Open + High + Low + Close,
+        T: Open + High + Low + Close,
+    {
+        let (p_open, p_close) = (prior.open(), prior.close());
+        let (open, close) = (candle.open(), candle.close());
+        let (p_top, p_bottom) = (p_open.max(p_close), p_open.min(p_close));
+        let (top, bottom) = (open.max(close), open.min(close));
+        let p_body = p_top - p_bottom;
+        let body = top - bottom;
+
+        let p_bullish = p_close > p_open;
+        let p_bearish = p_close < p_open;
+        let bullish = close > open;
+        let bearish = close < open;
+        let is_doji = CandlePattern::classify(candle, config).contains(&PatternKind::Doji);
+
+        let mut patterns: Vec<PatternKind> = vec![];
+
+        // Body, or the entire range if strict, must be covered by a larger body.
+        let (cover_top, cover_bottom) = match is_strict {
+            true => (prior.high(), prior.low()),
+            false => (p_top, p_bottom),
+        };
+        let engulfs = body > p_body && top >= cover_top && bottom <= cover_bottom;
+        if engulfs && p_bearish && bullish {
+            patterns.push(PatternKind::BullishEngulfing);
+        } else if engulfs && p_bullish && bearish {
+            patterns.push(PatternKind::BearishEngulfing);
+        }
+
+        // Smaller body within the prior body.
+        let is_inside = body < p_body && top <= p_top && bottom >= p_bottom;
+        if is_inside && is_doji {
+            patterns.push(PatternKind::HaramiCross);
+        } else if is_inside && p_bearish && bullish {
+            patterns.push(PatternKind::BullishHarami);
+        } else if is_inside && p_bullish && bearish {
+            patterns.push(PatternKind::BearishHarami);
+        }
+
+        // Opens beyond the prior close, closes past the middle of the prior body.
+        let middle = (p_open + p_close) / 2.0;
+        if p_bearish && bullish && open < p_close && close > middle && close < p_open {
+            patterns.push(PatternKind::PiercingLine);
+        } else if p_bullish && bearish && open > p_close && close < middle && close > p_open {
+            patterns.push(PatternKind::DarkCloudCover);
+        }
+
+        patterns
+    }
+
+    /// Ratios used to classify the candles.
+    pub fn config(&self) -> &PatternConfig {
+        &self.config
+    }
+
+    /// Checks if engulfing must cover the prior wicks, not only the prior body.
+    pub fn is_strict(&self) -> bool {
+        self.is_strict
+    }
+
+    /// Patterns matched by the last candle.
+    pub fn patterns(&self) -> &[PatternKind] {
+        &self.patterns
+    }
+
+    /// Checks if the last candle matched the pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Pattern to check for.
+    pub fn is(&self, kind: PatternKind) -> bool {
+        self.patterns.contains(&kind)
+    }
+}
+
+impl<T> Next<&T> for TwoCandlePatterns
+where
+    T: Open + High + Low + Close,
+{
+    /// Patterns matched by the candle.
+    type Output = Vec<PatternKind>;
+
+    /// Supply an additional candle to classify against the prior candle. The first candle has
+    /// nothing to compare against and matches no patterns.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to classify.
+    fn next(&mut self, value: &T) -> Self::Output {
+        let current = (value.open(), value.high(), value.low(), value.close());
+        self.patterns = match self.prior.replace(current) {
+            Some(p) => {
+                let prior = Data(p.0, p.1, p.2, p.3);
+                Self::classify(&prior, value, &self.config, self.is_strict)
+            }
+            None => vec![],
+        };
+
+        self.patterns.clone()
+    }
+}
+
+impl Next<(Num, Num, Num, Num)> for TwoCandlePatterns {
+    /// Patterns matched by the candle.
+    type Output = Vec<PatternKind>;
+
+    /// Supply an additional value to classify against the prior candle.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to classify.
+    ///     * 0 = Open
+    ///     * 1 = High
+    ///     * 2 = Low
+    ///     * 3 = Close
+    fn next(&mut self, value: (Num, Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2, value.3))
+    }
+}
+
+impl Reset for TwoCandlePatterns {
+    /// Clears the prior candle and the patterns found.
+    fn reset(&mut self) {
+        self.prior = None;
+        self.patterns.clear();
+    }
+}
+
+impl Snapshot for TwoCandlePatterns {
+    fn snapshot(&self) -> IndicatorState {
+        let prior = self.prior.unwrap_or_default();
+        let state = IndicatorState::new("Two Candle Patterns")
+            .flag(self.is_strict)
+            .flag(self.prior.is_some())
+            .scalar(prior.0)
+            .scalar(prior.1)
+            .scalar(prior.2)
+            .scalar(prior.3)
+            .count(self.patterns.len())
+            .child(&self.config);
+        self.patterns
+            .iter()
+            .fold(state, |state, pattern| state.variant(*pattern))
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Two Candle Patterns")?;
+        let (is_strict, is_prior) = (reader.flag()?, reader.flag()?);
+        let prior = (
+            reader.scalar()?,
+            reader.scalar()?,
+            reader.scalar()?,
+            reader.scalar()?,
+        );
+
+        let len = reader.count()?;
+        Ok(Self {
+            config: reader.child()?,
+            is_strict,
+            prior: is_prior.then_some(prior),
+            patterns: (0..len)
+                .map(|_| reader.variant())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
@@ -0,0 +1,288 @@
+//! Typical Price (HLC3), the average of the high, low, and close of a candle.
+//!
+//! Smoothed by a Simple Moving Average (SMA) of the period, a period of 1 is unsmoothed.
+//!
+//! # Formula
+//!
+//! TP = SMA((h + l + c) / 3, n)
+//!
+//! where:
+//!
+//! * `h` = high of a candle.
+//! * `l` = low of a candle.
+//! * `c` = close of a candle.
+//! * `n` = period
+
+use super::seed::{self, Reseed, Seed};
+use super::SimpleMovingAverage;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    AsValue, Close, High, InternalValue, Low, Next, Period, Ready, Reset, Snapshot, Stats, Value,
+};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Used for conversions. Holds High (0), Low (1), and Close (2) values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num, Num);
+
+seed::impl_flatten!(Data(0, 1, 2));
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.2
+    }
+}
+
+/// Typical Price (HLC3), the average of the high, low, and close of a candle.
+///
+/// Smoothed by a Simple Moving Average (SMA) of the period, a period of 1 is unsmoothed.
+///
+/// # Formula
+///
+/// TP = SMA((h + l + c) / 3, n)
+///
+/// where:
+///
+/// * `h` = high of a candle.
+/// * `l` = low of a candle.
+/// * `c` = close of a candle.
+/// * `n` = period
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct TypicalPrice {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Typical Price's current value.
+    value: Num,
+    /// Smooths the prices.
+    sma: SimpleMovingAverage,
+    /// Holds `period` amount of generated Typical Prices.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
+}
+
+impl TypicalPrice {
+    /// Creates a new Typical Price with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0, 1 being unsmoothed.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used to smooth.
+    /// * `data` - Array of candles to create the Typical Price from.
+    pub fn new<T>(period: usize, data: &[T]) -> Result<Self, TAError>
+    where
+        T: High + Low + Close,
+    {
+        // Check we can calculate Typical Price.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate typical price",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "typical price",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Smooth the first period of prices, used as the seed value.
+        let prices: Vec<Num> = data.iter().map(Self::price).collect();
+        let sma = SimpleMovingAverage::new(period, &prices[..period])?;
+        let value = sma.value();
+
+        let mut tp = Self {
+            period,
+            value,
+            sma,
+            buffer: Buffer::from_array(period, &[value])?,
+            seed: Seed::new(data.len()),
+        };
+
+        // Calculate the remainder of the Typical Prices.
+        for p in prices[period..].iter() {
+            tp.add(*p);
+        }
+
+        Ok(tp)
+    }
+
+    /// Creates a new Typical Price with the supplied period, without initial data. The candles are
+    /// supplied with `next` instead, until ready the value is calculated from the candles supplied
+    /// so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0, 1 being unsmoothed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used to smooth.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            &seed::placeholder(Data(1.0, 1.0, 1.0), period),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Unsmoothed Typical Price of a single candle.
+    ///
+    /// # Arguments
+    ///
+    /// * `candle` - Candle to obtain the price of.
+    pub fn price<T>(candle: &T) -> Num
+    where
+        T: High + Low + Close,
+    {
+        (candle.high() + candle.low() + candle.close()) / 3.0
+    }
+
+    /// Adds a new price, recalculating the Typical Price.
+    fn add(&mut self, price: Num) -> Num {
+        self.value = self.sma.next(price);
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl AsValue for TypicalPrice {
+    /// Current Typical Price, allowing it to be passed to other indicators.
+    fn as_value(&self) -> Num {
+        self.value
+    }
+}
+
+impl<T> Next<&T> for TypicalPrice
+where
+    T: High + Low + Close,
+{
+    /// Next value for the Typical Price.
+    type Output = Num;
+
+    /// Supply an additional candle to recalculate a new Typical Price.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        if seed::collect(self, Data(value.high(), value.low(), value.close())) {
+            return self.value;
+        }
+
+        self.add(Self::price(value))
+    }
+}
+
+impl Next<(Num, Num, Num)> for TypicalPrice {
+    /// Next value for the Typical Price.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new Typical Price.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    ///     * 0 = High
+    ///     * 1 = Low
+    ///     * 2 = Close
+    fn next(&mut self, value: (Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2))
+    }
+}
+
+impl Reset for TypicalPrice {
+    /// Clears the Typical Price back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.sma.reset();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for TypicalPrice {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for TypicalPrice {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for TypicalPrice {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("HLC3")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.sma)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "HLC3")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            sma: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
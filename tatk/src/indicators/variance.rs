@@ -12,9 +12,13 @@
 //! * `μ` is the mean of the set.
 //! * `∑` is the sum.
 
-use crate::traits::{AsValue, InternalValue, Next, Period};
-use crate::{Buffer, Num, TAError};
-use tatk_derive::{InternalValue, Period};
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
 
 /// Variance (Var(X))
 ///
@@ -29,7 +33,7 @@ use tatk_derive::{InternalValue, Period};
 /// * `x` is the current value in a set.
 /// * `μ` is the mean of the set.
 /// * `∑` is the sum.
-#[derive(Debug, InternalValue, Period)]
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
 pub struct Variance {
     /// Size of the period (window) in which data is looked at.
     period: usize,
@@ -39,6 +43,8 @@ pub struct Variance {
     buffer: Buffer,
     /// Labels it as sample or population.
     is_sample: bool,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
 }
 
 impl Variance {
@@ -62,25 +68,44 @@ impl Variance {
             )));
         } else if data.len() < period {
             // Make sure we have enough data.
-            return Err(TAError::InvalidData(String::from(
-                "not enough data for period provided",
-            )));
+            return Err(TAError::InvalidData {
+                indicator: "variance",
+                required: period,
+                received: data.len(),
+            });
         }
 
         // Build the buffer from the data provided.
-        let buffer: Buffer = match Buffer::from_array(period, data) {
-            Ok(value) => value,
-            Err(error) => return Err(error),
-        };
+        let buffer: Buffer = Buffer::from_array(period, data)?;
 
         Ok(Self {
             period,
             value: buffer.variance(is_sample),
             buffer,
             is_sample,
+            seed: Seed::new(data.len()),
         })
     }
 
+    /// Creates a new Var(X) with the supplied period, without initial data. The values are supplied
+    /// with `next` instead, until ready the value is calculated from the values supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    pub fn with_period(period: usize, is_sample: bool) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            &seed::placeholder(1.0, period),
+            is_sample,
+        ))
+    }
+
     /// Current and most recent value calculated.
     pub fn value(&self) -> Num {
         self.value
@@ -102,6 +127,10 @@ impl Next<Num> for Variance {
     ///
     /// * `value` - New value to add to period.
     fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
         // Rotate the buffer.
         self.buffer.shift(value);
 
@@ -127,3 +156,69 @@ where
         self.next(value.as_value())
     }
 }
+
+impl Reset for Variance {
+    /// Clears the Var(X) back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for Variance {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for Variance {
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data, self.is_sample)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data, self.is_sample) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for Variance {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Var(X)")
+            .count(self.period)
+            .scalar(self.value)
+            .flag(self.is_sample)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Var(X)")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            is_sample: reader.flag()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
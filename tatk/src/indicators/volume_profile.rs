@@ -0,0 +1,379 @@
+//! Volume Profile, traded volume binned by price over a window or session.
+//!
+//! Volume of each candle is either spread across its range, proportional to the overlap with
+//! each bin, or assigned to the bin of its close. Bins are a fixed size aligned to multiples of
+//! the size, or a fixed count spanning the range of the candles.
+//!
+//! The Point of Control (POC) is the bin with the most volume. The Value Area grows from the POC
+//! towards the neighbouring bin with more volume until it contains the percent of the volume.
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::{StateReader, Variants};
+use crate::traits::{Close, High, Low, Next, Reset, Snapshot, Volume};
+use crate::{IndicatorState, Num, TAError};
+use alloc::collections::VecDeque;
+
+/// Used for conversions. Holds High (0), Low (1), Close (2), and Volume (3) values.
+#[derive(Copy, Clone, PartialEq)]
+struct Data(Num, Num, Num, Num);
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.2
+    }
+}
+
+// Volume value.
+impl Volume for Data {
+    fn volume(&self) -> Num {
+        self.3
+    }
+}
+
+/// How the prices are divided into bins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Binning {
+    /// Bins of a fixed price width, aligned to multiples of the width.
+    Size(Num),
+    /// Fixed amount of bins spanning the lowest low to the highest high.
+    Count(usize),
+}
+
+/// Where the volume of a candle is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distribution {
+    /// Spread across the range of the candle.
+    Range,
+    /// Assigned to the close of the candle.
+    Close,
+}
+
+impl Variants for Distribution {
+    const ALL: &'static [Self] = &[Distribution::Range, Distribution::Close];
+}
+
+/// Price range and the volume traded within it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bin {
+    /// Lowest price of the bin.
+    pub low: Num,
+    /// Highest price of the bin.
+    pub high: Num,
+    /// Volume traded within the bin.
+    pub volume: Num,
+}
+
+/// Volume Profile, traded volume binned by price over a window or session.
+///
+/// Volume of each candle is either spread across its range, proportional to the overlap with
+/// each bin, or assigned to the bin of its close. Bins are a fixed size aligned to multiples of
+/// the size, or a fixed count spanning the range of the candles.
+///
+/// The Point of Control (POC) is the bin with the most volume. The Value Area grows from the POC
+/// towards the neighbouring bin with more volume until it contains the percent of the volume.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeProfile {
+    /// How the prices are divided into bins.
+    binning: Binning,
+    /// Where the volume of a candle is placed.
+    distribution: Distribution,
+    /// Amount of candles kept, none for the entire session.
+    window: Option<usize>,
+    /// High, low, close, and volume of the candles, oldest to newest.
+    candles: VecDeque<(Num, Num, Num, Num)>,
+}
+
+impl VolumeProfile {
+    /// Creates a new Volume Profile with the supplied binning, distribution, and window.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Bin size must be greater than 0, or bin count greater than 0.
+    /// * Window must be greater than 0 if supplied.
+    ///
+    /// ## Arguments
+    ///
+    /// * `binning` - How the prices are divided into bins.
+    /// * `distribution` - Where the volume of a candle is placed.
+    /// * `window` - Amount of candles kept, none to keep the session until `reset`.
+    pub fn new(
+        binning: Binning,
+        distribution: Distribution,
+        window: Option<usize>,
+    ) -> Result<Self, TAError> {
+        // Check we can bin the volume.
+        match binning {
+            Binning::Size(s) if s.is_nan() || s <= 0.0 => {
+                return Err(TAError::InvalidSize(String::from(
+                    "bin size must be greater than 0 to calculate volume profile",
+                )))
+            }
+            Binning::Count(0) => {
+                return Err(TAError::InvalidSize(String::from(
+                    "bin count must be greater than 0 to calculate volume profile",
+                )))
+            }
+            _ => (),
+        }
+
+        if window == Some(0) {
+            return Err(TAError::InvalidSize(String::from(
+                "window must be greater than 0 to calculate volume profile",
+            )));
+        }
+
+        Ok(Self {
+            binning,
+            distribution,
+            window,
+            candles: VecDeque::new(),
+        })
+    }
+
+    /// Amount of candles within the profile.
+    pub fn len(&self) -> usize {
+        self.candles.len()
+    }
+
+    /// Checks if the profile has no candles.
+    pub fn is_empty(&self) -> bool {
+        self.candles.is_empty()
+    }
+
+    /// Contiguous bins from the lowest to the highest price, empty bins included.
+    pub fn histogram(&self) -> Vec<Bin> {
+        let Some((origin, width, count)) = self.grid() else {
+            return vec![];
+        };
+
+        let mut bins: Vec<Bin> = (0..count)
+            .map(|i| Bin {
+                low: origin + i as Num * width,
+                high: origin + (i + 1) as Num * width,
+                volume: 0.0,
+            })
+            .collect();
+
+        let index = |price: Num| -> usize {
+            if width == 0.0 {
+                return 0;
+            }
+            (((price - origin) / width).floor().max(0.0) as usize).min(count - 1)
+        };
+
+        for (high, low, close, volume) in self.candles.iter().copied() {
+            if self.distribution == Distribution::Close || high <= low || width == 0.0 {
+                bins[index(close)].volume += volume;
+                continue;
+            }
+
+            // Spread proportional to the overlap with each bin.
+            for bin in bins[index(low)..=index(high)].iter_mut() {
+                let overlap = high.min(bin.high) - low.max(bin.low);
+                if overlap > 0.0 {
+                    bin.volume += volume * overlap / (high - low);
+                }
+            }
+        }
+
+        bins
+    }
+
+    /// Bin with the most volume, the lowest bin if tied.
+    pub fn point_of_control(&self) -> Option<Bin> {
+        let bins = self.histogram();
+        Self::poc_index(&bins).map(|i| bins[i])
+    }
+
+    /// Low (0) and high (1) bounds around the Point of Control containing the percent of the
+    /// volume. None if there is no volume or the percent is not within 0 to 100.
+    ///
+    /// # Arguments
+    ///
+    /// * `pct` - Percent of the volume to contain, 70.0 is 70%.
+    pub fn value_area(&self, pct: Num) -> Option<(Num, Num)> {
+        if !(0.0..=100.0).contains(&pct) {
+            return None;
+        }
+
+        let bins = self.histogram();
+        let poc = Self::poc_index(&bins)?;
+        let target = bins.iter().map(|b| b.volume).sum::<Num>() * pct / 100.0;
+
+        // Grow towards the neighbour with more volume.
+        let (mut lower, mut upper) = (poc, poc);
+        let mut volume = bins[poc].volume;
+        while volume < target && (lower > 0 || upper < bins.len() - 1) {
+            let below = match lower {
+                0 => None,
+                _ => Some(bins[lower - 1].volume),
+            };
+            let above = bins.get(upper + 1).map(|b| b.volume);
+
+            match (below, above) {
+                (Some(b), Some(a)) if b > a => {
+                    lower -= 1;
+                    volume += b;
+                }
+                (_, Some(a)) => {
+                    upper += 1;
+                    volume += a;
+                }
+                (Some(b), None) => {
+                    lower -= 1;
+                    volume += b;
+                }
+                (None, None) => break,
+            }
+        }
+
+        Some((bins[lower].low, bins[upper].high))
+    }
+
+    /// Origin, width, and count of the bins covering the candles.
+    fn grid(&self) -> Option<(Num, Num, usize)> {
+        let low = self.candles.iter().map(|c| c.1.min(c.2)).reduce(Num::min)?;
+        let high = self.candles.iter().map(|c| c.0.max(c.2)).reduce(Num::max)?;
+
+        Some(match self.binning {
+            Binning::Size(size) => {
+                let first = (low / size).floor();
+                let last = (high / size).floor();
+                (first * size, size, (last - first) as usize + 1)
+            }
+            Binning::Count(_) if high <= low => (low, 0.0, 1),
+            Binning::Count(count) => (low, (high - low) / count as Num, count),
+        })
+    }
+
+    /// Position of the bin with the most volume, none if there is no volume.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - Histogram to search.
+    fn poc_index(bins: &[Bin]) -> Option<usize> {
+        let mut poc: Option<usize> = None;
+        for (i, bin) in bins.iter().enumerate() {
+            if bin.volume > poc.map_or(0.0, |p| bins[p].volume) {
+                poc = Some(i);
+            }
+        }
+
+        poc
+    }
+}
+
+impl<T> Next<&T> for VolumeProfile
+where
+    T: High + Low + Close + Volume,
+{
+    /// Point of Control after adding the candle.
+    type Output = Option<Bin>;
+
+    /// Supply an additional candle to the profile, removing the oldest if the window is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add.
+    fn next(&mut self, value: &T) -> Self::Output {
+        if self.window == Some(self.candles.len()) {
+            self.candles.pop_front();
+        }
+
+        self.candles
+            .push_back((value.high(), value.low(), value.close(), value.volume()));
+        self.point_of_control()
+    }
+}
+
+impl Next<(Num, Num, Num, Num)> for VolumeProfile {
+    /// Point of Control after adding the candle.
+    type Output = Option<Bin>;
+
+    /// Supply an additional value to the profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add.
+    ///     * 0 = High
+    ///     * 1 = Low
+    ///     * 2 = Close
+    ///     * 3 = Volume
+    fn next(&mut self, value: (Num, Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2, value.3))
+    }
+}
+
+impl Reset for VolumeProfile {
+    /// Clears the candles, starting a new session.
+    fn reset(&mut self) {
+        self.candles.clear();
+    }
+}
+
+impl Snapshot for VolumeProfile {
+    fn snapshot(&self) -> IndicatorState {
+        let (binning, size, count) = match self.binning {
+            Binning::Size(size) => (0, size, 0),
+            Binning::Count(count) => (1, 0.0, count),
+        };
+
+        let candles: Vec<Num> = self
+            .candles
+            .iter()
+            .flat_map(|(h, l, c, v)| [*h, *l, *c, *v])
+            .collect();
+
+        IndicatorState::new("Volume Profile")
+            .count(binning)
+            .scalar(size)
+            .count(count)
+            .variant(self.distribution)
+            .optional_count(self.window)
+            .buffer(&candles)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Volume Profile")?;
+        let binning = match (reader.count()?, reader.scalar()?, reader.count()?) {
+            (0, size, _) => Binning::Size(size),
+            (1, _, count) => Binning::Count(count),
+            _ => return Err(TAError::InvalidState(String::from("unknown binning"))),
+        };
+
+        let distribution = reader.variant()?;
+        let window = reader.optional_count()?;
+        let candles = reader.buffer()?;
+        if candles.len() % 4 != 0 {
+            return Err(TAError::InvalidState(String::from(
+                "volume profile holds a partial candle",
+            )));
+        }
+
+        Ok(Self {
+            binning,
+            distribution,
+            window,
+            candles: candles
+                .chunks(4)
+                .map(|c| (c[0], c[1], c[2], c[3]))
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,165 @@
+//! Volume Weighted Average Price (VWAP), the average typical price weighted by volume.
+//!
+//! Cumulative since created or reset, commonly reset every session by supplying it through `Timed`
+//! with a `Session`. Until there is volume the value is the typical price of the last candle.
+//!
+//! # Formula
+//!
+//! VWAP = sum(((h + l + c) / 3) * v) / sum(v)
+//!
+//! where:
+//!
+//! * `h` = high of a candle.
+//! * `l` = low of a candle.
+//! * `c` = close of a candle.
+//! * `v` = volume of a candle.
+
+use crate::snapshot::StateReader;
+use crate::traits::{Close, High, InternalValue, Low, Next, Ready, Reset, Snapshot, Value, Volume};
+use crate::{IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Value};
+
+/// Volume Weighted Average Price (VWAP), the average typical price weighted by volume.
+///
+/// Cumulative since created or reset, commonly reset every session by supplying it through `Timed`
+/// with a `Session`. Until there is volume the value is the typical price of the last candle.
+///
+/// # Formula
+///
+/// VWAP = sum(((h + l + c) / 3) * v) / sum(v)
+///
+/// where:
+///
+/// * `h` = high of a candle.
+/// * `l` = low of a candle.
+/// * `c` = close of a candle.
+/// * `v` = volume of a candle.
+#[derive(Debug, Default, Clone, PartialEq, InternalValue, Value)]
+pub struct VolumeWeightedAveragePrice {
+    /// VWAP's current value.
+    value: Num,
+    /// Sum of the typical prices weighted by volume.
+    price_volume: Num,
+    /// Sum of the volume.
+    volume: Num,
+    /// Amount of candles supplied since created or reset.
+    seen: usize,
+}
+
+impl VolumeWeightedAveragePrice {
+    /// Creates a new Volume Weighted Average Price without data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new Volume Weighted Average Price from the candles.
+    ///
+    /// ## Arguments
+    ///
+    /// * `data` - Array of candles to create the VWAP from, can be empty.
+    pub fn from_candles<T>(data: &[T]) -> Self
+    where
+        T: High + Low + Close + Volume,
+    {
+        let mut vwap = Self::new();
+        for candle in data.iter() {
+            vwap.next(candle);
+        }
+
+        vwap
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Volume supplied since created or reset.
+    pub fn volume(&self) -> Num {
+        self.volume
+    }
+}
+
+impl<T> Next<&T> for VolumeWeightedAveragePrice
+where
+    T: High + Low + Close + Volume,
+{
+    /// Next value for the VWAP.
+    type Output = Num;
+
+    /// Supply an additional candle to recalculate a new VWAP.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add.
+    fn next(&mut self, value: &T) -> Self::Output {
+        self.next((value.high(), value.low(), value.close(), value.volume()))
+    }
+}
+
+impl Next<(Num, Num, Num, Num)> for VolumeWeightedAveragePrice {
+    /// Next value for the VWAP.
+    type Output = Num;
+
+    /// Supply an additional candle to recalculate a new VWAP.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New values to calculate.
+    ///     * 0 = High
+    ///     * 1 = Low
+    ///     * 2 = Close
+    ///     * 3 = Volume
+    fn next(&mut self, value: (Num, Num, Num, Num)) -> Self::Output {
+        let typical = (value.0 + value.1 + value.2) / 3.0;
+        self.price_volume += typical * value.3;
+        self.volume += value.3;
+        self.seen += 1;
+
+        self.value = if self.volume > 0.0 {
+            self.price_volume / self.volume
+        } else {
+            typical
+        };
+        self.value
+    }
+}
+
+impl Reset for VolumeWeightedAveragePrice {
+    /// Clears the VWAP back to having seen no data, such as at the start of a session.
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl Ready for VolumeWeightedAveragePrice {
+    /// Checks if a candle has been supplied since created or reset.
+    fn is_ready(&self) -> bool {
+        self.seen > 0
+    }
+
+    /// Amount of candles supplied since created or reset.
+    fn samples_seen(&self) -> usize {
+        self.seen
+    }
+}
+
+impl Snapshot for VolumeWeightedAveragePrice {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("VWAP")
+            .scalar(self.value)
+            .scalar(self.price_volume)
+            .scalar(self.volume)
+            .count(self.seen)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "VWAP")?;
+        Ok(Self {
+            value: reader.scalar()?,
+            price_volume: reader.scalar()?,
+            volume: reader.scalar()?,
+            seen: reader.count()?,
+        })
+    }
+}
@@ -0,0 +1,288 @@
+//! Weighted Close, the average of the high, low, and close of a candle with the close doubled.
+//!
+//! Smoothed by a Simple Moving Average (SMA) of the period, a period of 1 is unsmoothed.
+//!
+//! # Formula
+//!
+//! WC = SMA((h + l + 2c) / 4, n)
+//!
+//! where:
+//!
+//! * `h` = high of a candle.
+//! * `l` = low of a candle.
+//! * `c` = close of a candle.
+//! * `n` = period
+
+use super::seed::{self, Reseed, Seed};
+use super::SimpleMovingAverage;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    AsValue, Close, High, InternalValue, Low, Next, Period, Ready, Reset, Snapshot, Stats, Value,
+};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Used for conversions. Holds High (0), Low (1), and Close (2) values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num, Num);
+
+seed::impl_flatten!(Data(0, 1, 2));
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.0
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.1
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.2
+    }
+}
+
+/// Weighted Close, the average of the high, low, and close of a candle with the close doubled.
+///
+/// Smoothed by a Simple Moving Average (SMA) of the period, a period of 1 is unsmoothed.
+///
+/// # Formula
+///
+/// WC = SMA((h + l + 2c) / 4, n)
+///
+/// where:
+///
+/// * `h` = high of a candle.
+/// * `l` = low of a candle.
+/// * `c` = close of a candle.
+/// * `n` = period
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct WeightedClose {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Weighted Close's current value.
+    value: Num,
+    /// Smooths the prices.
+    sma: SimpleMovingAverage,
+    /// Holds `period` amount of generated Weighted Closes.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
+}
+
+impl WeightedClose {
+    /// Creates a new Weighted Close with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0, 1 being unsmoothed.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used to smooth.
+    /// * `data` - Array of candles to create the Weighted Close from.
+    pub fn new<T>(period: usize, data: &[T]) -> Result<Self, TAError>
+    where
+        T: High + Low + Close,
+    {
+        // Check we can calculate Weighted Close.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate weighted close",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "weighted close",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Smooth the first period of prices, used as the seed value.
+        let prices: Vec<Num> = data.iter().map(Self::price).collect();
+        let sma = SimpleMovingAverage::new(period, &prices[..period])?;
+        let value = sma.value();
+
+        let mut wc = Self {
+            period,
+            value,
+            sma,
+            buffer: Buffer::from_array(period, &[value])?,
+            seed: Seed::new(data.len()),
+        };
+
+        // Calculate the remainder of the Weighted Closes.
+        for p in prices[period..].iter() {
+            wc.add(*p);
+        }
+
+        Ok(wc)
+    }
+
+    /// Creates a new Weighted Close with the supplied period, without initial data. The candles are
+    /// supplied with `next` instead, until ready the value is calculated from the candles supplied
+    /// so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0, 1 being unsmoothed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used to smooth.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            &seed::placeholder(Data(1.0, 1.0, 1.0), period),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Unsmoothed Weighted Close of a single candle.
+    ///
+    /// # Arguments
+    ///
+    /// * `candle` - Candle to obtain the price of.
+    pub fn price<T>(candle: &T) -> Num
+    where
+        T: High + Low + Close,
+    {
+        (candle.high() + candle.low() + 2.0 * candle.close()) / 4.0
+    }
+
+    /// Adds a new price, recalculating the Weighted Close.
+    fn add(&mut self, price: Num) -> Num {
+        self.value = self.sma.next(price);
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl AsValue for WeightedClose {
+    /// Current Weighted Close, allowing it to be passed to other indicators.
+    fn as_value(&self) -> Num {
+        self.value
+    }
+}
+
+impl<T> Next<&T> for WeightedClose
+where
+    T: High + Low + Close,
+{
+    /// Next value for the Weighted Close.
+    type Output = Num;
+
+    /// Supply an additional candle to recalculate a new Weighted Close.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        if seed::collect(self, Data(value.high(), value.low(), value.close())) {
+            return self.value;
+        }
+
+        self.add(Self::price(value))
+    }
+}
+
+impl Next<(Num, Num, Num)> for WeightedClose {
+    /// Next value for the Weighted Close.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new Weighted Close.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    ///     * 0 = High
+    ///     * 1 = Low
+    ///     * 2 = Close
+    fn next(&mut self, value: (Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2))
+    }
+}
+
+impl Reset for WeightedClose {
+    /// Clears the Weighted Close back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.sma.reset();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for WeightedClose {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for WeightedClose {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for WeightedClose {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Weighted Close")
+            .count(self.period)
+            .scalar(self.value)
+            .child(&self.sma)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Weighted Close")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            sma: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
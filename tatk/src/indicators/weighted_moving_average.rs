@@ -0,0 +1,311 @@
+//! Weighted Moving Average (WMA), linearly weighted average of a period.
+//!
+//! The newest value has a weight of `n`, the value before it `n - 1`, down to the oldest value
+//! with a weight of 1. Recent data is weighted heavier than with the SMA while older data is
+//! still dropped at the end of the period.
+//!
+//! # Formula
+//!
+//! WMA = (1 * x\[t - n + 1\] + ... + n * x\[t\]) / (n * (n + 1) / 2)
+//!
+//! where:
+//!
+//! * `x[t]` = current value (most recent)
+//! * `n` = period
+//!
+//! The period's values and WMAs are held in a `Buffer` by default, any `Window` can be used instead
+//! such as a `StaticBuffer` to avoid heap allocations.
+
+use super::seed::{self, Reseed, Seed};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    AsValue, InternalValue, Next, Period, Ready, Reset, Snapshot, Stats, Value, Window,
+};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Value};
+
+/// Weighted Moving Average (WMA), linearly weighted average of a period.
+///
+/// The newest value has a weight of `n`, the value before it `n - 1`, down to the oldest value
+/// with a weight of 1. Recent data is weighted heavier than with the SMA while older data is
+/// still dropped at the end of the period.
+///
+/// # Formula
+///
+/// WMA = (1 * x\[t - n + 1\] + ... + n * x\[t\]) / (n * (n + 1) / 2)
+///
+/// where:
+///
+/// * `x[t]` = current value (most recent)
+/// * `n` = period
+///
+/// The period's values and WMAs are held in a `Buffer` by default, any `Window` can be used instead
+/// such as a `StaticBuffer` to avoid heap allocations.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Value)]
+pub struct WeightedMovingAverage<B = Buffer> {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// WMA's current value.
+    value: Num,
+    /// Normalized weights, oldest to newest.
+    weights: Vec<Num>,
+    /// Holds all of the current period's values.
+    values: B,
+    /// Holds `period` amount of generated WMAs.
+    buffer: B,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Num>,
+}
+
+impl WeightedMovingAverage {
+    /// Creates a new WMA with the supplied period and initial data.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the WMA from.
+    pub fn new(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::with_window(period, data)
+    }
+
+    /// Creates a new WMA with the supplied period, without initial data. The values are supplied
+    /// with `next` instead, until ready the value is calculated from the values supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    pub fn with_period(period: usize) -> Result<Self, TAError> {
+        seed::streaming(Self::new(period, &seed::placeholder(1.0, period)))
+    }
+}
+
+impl<B> WeightedMovingAverage<B>
+where
+    B: Window,
+{
+    /// Creates a new WMA with the supplied period and initial data, held in the window `B`.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 0.
+    /// * Data must have at least `period` elements.
+    /// * Period must be a capacity the window supports, `N` for a `StaticBuffer<N>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `data` - Array of values to create the WMA from.
+    pub fn with_window(period: usize, data: &[Num]) -> Result<Self, TAError> {
+        // Check we can calculate WMA.
+        if period < 1 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 1 to calculate weighted moving average",
+            )));
+        } else if data.len() < period {
+            // Make sure we have enough data.
+            return Err(TAError::InvalidData {
+                indicator: "weighted moving average",
+                required: period,
+                received: data.len(),
+            });
+        }
+
+        // Linear weights, normalized to sum to 1.
+        let total = (period * (period + 1)) as Num / 2.0;
+        let weights: Vec<Num> = (1..=period).map(|w| w as Num / total).collect();
+
+        // Build the buffer containing the `period` of values.
+        let values = B::from_array(period, &data[..period])?;
+        let value = values.weighted_sum(&weights);
+        let mut wma = Self {
+            period,
+            value,
+            weights,
+            values,
+            buffer: B::from_array(period, &[value])?,
+            seed: Seed::new(period),
+        };
+
+        // Calculate the remainder of the WMAs.
+        for v in data[period..].iter() {
+            wma.next(*v);
+        }
+
+        Ok(wma)
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+}
+
+impl<B> Next<Num> for WeightedMovingAverage<B>
+where
+    B: Window,
+{
+    /// Next value for the WMA.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new WMA.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: Num) -> Self::Output {
+        if seed::collect(self, value) {
+            return self.value;
+        }
+
+        self.values.shift(value);
+        self.value = self.values.weighted_sum(&self.weights);
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+}
+
+impl<T, B> Next<T> for WeightedMovingAverage<B>
+where
+    T: AsValue,
+    B: Window,
+{
+    /// Next value for the WMA.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new WMA.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl<B> Reset for WeightedMovingAverage<B>
+where
+    B: Window,
+{
+    /// Clears the WMA back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.values.clear();
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl<B> Ready for WeightedMovingAverage<B>
+where
+    B: Window,
+{
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl<B> Reseed for WeightedMovingAverage<B>
+where
+    B: Window,
+{
+    type Input = Num;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::with_window(self.period, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        // The window `B` may not support a smaller capacity.
+        if let Ok(partial) = WeightedMovingAverage::new(data.len(), data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl<B> Stats for WeightedMovingAverage<B>
+where
+    B: Window,
+{
+    /// Sum of the period's WMAs.
+    fn sum(&self) -> Num {
+        self.buffer.sum()
+    }
+
+    /// Mean of the period's WMAs.
+    fn mean(&self) -> Num {
+        self.buffer.mean()
+    }
+
+    /// Variance of the period's WMAs.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn variance(&self, is_sample: bool) -> Num {
+        self.buffer.variance(is_sample)
+    }
+
+    /// Standard deviation of the period's WMAs.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    fn stdev(&self, is_sample: bool) -> Num {
+        self.buffer.stdev(is_sample)
+    }
+}
+
+impl<B> Snapshot for WeightedMovingAverage<B>
+where
+    B: Window + Snapshot,
+{
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("WMA")
+            .count(self.period)
+            .scalar(self.value)
+            .buffer(&self.weights)
+            .child(&self.values)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "WMA")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            weights: reader.buffer()?.to_vec(),
+            values: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
@@ -0,0 +1,442 @@
+//! Yang-Zhang Volatility, gap-aware open-high-low-close volatility estimator.
+//!
+//! Combines the overnight (close to open) variance, the open to close variance, and the
+//! Rogers-Satchell variance. Handles both a non-zero drift and gaps between candles, making it
+//! suited for markets that gap.
+//!
+//! # Formula
+//!
+//! YZV = sqrt(σo² + k * σc² + (1 - k) * σrs²) * sqrt(a)
+//!
+//! where:
+//!
+//! * `σo²` = sample variance of ln(o / p) within the period, the overnight returns.
+//! * `σc²` = sample variance of ln(c / o) within the period, the open to close returns.
+//! * `σrs²` = mean of the Rogers-Satchell terms within the period.
+//! * `k` = 0.34 / (1.34 + (n + 1) / (n - 1))
+//! * `o` = open of a candle.
+//! * `c` = close of a candle.
+//! * `p` = close of the prior candle.
+//! * `n` = period
+//! * `a` = periods per year (annualization), 1.0 for none.
+
+use super::seed::{self, Reseed, Seed};
+use super::RogersSatchellVolatility;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{
+    Close, High, InternalValue, Low, Next, Open, Period, Ready, Reset, Snapshot, Stats, Value,
+};
+use crate::{Buffer, IndicatorState, Num, TAError};
+use tatk_derive::{InternalValue, Period, Stats, Value};
+
+/// Used for conversions. Holds Open (0), High (1), Low (2), and Close (3) values.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Data(Num, Num, Num, Num);
+
+seed::impl_flatten!(Data(0, 1, 2, 3));
+
+// Opening value.
+impl Open for Data {
+    fn open(&self) -> Num {
+        self.0
+    }
+}
+
+// Highest value.
+impl High for Data {
+    fn high(&self) -> Num {
+        self.1
+    }
+}
+
+// Lowest value.
+impl Low for Data {
+    fn low(&self) -> Num {
+        self.2
+    }
+}
+
+// Closing value.
+impl Close for Data {
+    fn close(&self) -> Num {
+        self.3
+    }
+}
+
+/// Terms of a single candle.
+struct Terms {
+    /// ln(o / p), the overnight return.
+    overnight: Num,
+    /// ln(c / o), the open to close return.
+    body: Num,
+    /// Rogers-Satchell term.
+    rs: Num,
+}
+
+/// Yang-Zhang Volatility, gap-aware open-high-low-close volatility estimator.
+///
+/// Combines the overnight (close to open) variance, the open to close variance, and the
+/// Rogers-Satchell variance. Handles both a non-zero drift and gaps between candles, making it
+/// suited for markets that gap.
+///
+/// # Formula
+///
+/// YZV = sqrt(σo² + k * σc² + (1 - k) * σrs²) * sqrt(a)
+///
+/// where:
+///
+/// * `σo²` = sample variance of ln(o / p) within the period, the overnight returns.
+/// * `σc²` = sample variance of ln(c / o) within the period, the open to close returns.
+/// * `σrs²` = mean of the Rogers-Satchell terms within the period.
+/// * `k` = 0.34 / (1.34 + (n + 1) / (n - 1))
+/// * `o` = open of a candle.
+/// * `c` = close of a candle.
+/// * `p` = close of the prior candle.
+/// * `n` = period
+/// * `a` = periods per year (annualization), 1.0 for none.
+#[derive(Debug, Clone, PartialEq, InternalValue, Period, Stats, Value)]
+pub struct YangZhangVolatility {
+    /// Size of the period (window) in which data is looked at.
+    period: usize,
+    /// Yang-Zhang Volatility's current value.
+    value: Num,
+    /// Periods per year used to annualize.
+    annualization: Num,
+    /// Weighting of the open to close variance.
+    k: Num,
+    /// Close of the last valid candle.
+    last_close: Num,
+    /// Overnight returns within the period.
+    overnight: Buffer,
+    /// Open to close returns within the period.
+    body: Buffer,
+    /// Rogers-Satchell terms within the period.
+    rs: Buffer,
+    /// If the last candle was skipped for being invalid.
+    skipped: bool,
+    /// Holds `period` amount of generated volatilities.
+    buffer: Buffer,
+    /// Values collected after a reset until the indicator can be recreated.
+    seed: Seed<Data>,
+}
+
+impl YangZhangVolatility {
+    /// Creates a new Yang-Zhang Volatility with the supplied period, annualization, and initial
+    /// candles. The first candle only provides the prior close for the overnight return.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Annualization must be finite and greater than 0, 1.0 for none.
+    /// * Data must have at least `period + 1` elements.
+    /// * All prices must be greater than 0 and lows no greater than their high.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    /// * `data` - Array of candles to create the volatility from.
+    pub fn new<T>(period: usize, annualization: Num, data: &[T]) -> Result<Self, TAError>
+    where
+        T: Open + High + Low + Close,
+    {
+        // Check we can calculate Yang-Zhang Volatility.
+        if period < 2 {
+            return Err(TAError::InvalidSize(String::from(
+                "period cannot be less than 2 to calculate yang-zhang volatility",
+            )));
+        }
+
+        TAError::check_annualization(annualization, "yang-zhang volatility")?;
+        if data.len() < period + 1 {
+            // Make sure we have enough data, the first candle is only the prior close.
+            return Err(TAError::InvalidData {
+                indicator: "yang-zhang volatility",
+                required: period + 1,
+                received: data.len(),
+            });
+        }
+
+        let invalid = || {
+            TAError::InvalidInput(String::from(
+                "candles must have positive prices with a low no greater than the high to calculate yang-zhang volatility",
+            ))
+        };
+
+        // Terms for every candle after the first.
+        let mut last_close = data[0].close();
+        if last_close <= 0.0 || RogersSatchellVolatility::term(&data[0]).is_none() {
+            return Err(invalid());
+        }
+
+        let mut terms: Vec<Terms> = Vec::with_capacity(data.len() - 1);
+        for candle in data[1..].iter() {
+            terms.push(Self::terms(last_close, candle).ok_or_else(invalid)?);
+            last_close = candle.close();
+        }
+
+        // Seed with the first period of candles.
+        let seed = &terms[..period];
+        let overnight: Vec<Num> = seed.iter().map(|t| t.overnight).collect();
+        let body: Vec<Num> = seed.iter().map(|t| t.body).collect();
+        let rs: Vec<Num> = seed.iter().map(|t| t.rs).collect();
+
+        let k = 0.34 / (1.34 + (period + 1) as Num / (period - 1) as Num);
+        let overnight = Buffer::from_array(period, &overnight)?;
+        let body = Buffer::from_array(period, &body)?;
+        let rs = Buffer::from_array(period, &rs)?;
+        let value = Self::calculate(k, &overnight, &body, &rs) * annualization.sqrt();
+
+        let mut yz = Self {
+            period,
+            value,
+            annualization,
+            k,
+            last_close,
+            overnight,
+            body,
+            rs,
+            skipped: false,
+            buffer: Buffer::from_array(period, &[value])?,
+            seed: Seed::new(data.len()),
+        };
+
+        // Calculate the remainder of the volatilities.
+        for t in terms[period..].iter() {
+            yz.add(t);
+        }
+
+        Ok(yz)
+    }
+
+    /// Creates a new Yang-Zhang Volatility with the supplied period and annualization, without
+    /// initial data. The candles are supplied with `next` instead, until ready the value is
+    /// calculated from the candles supplied so far.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Period must be greater than 1.
+    /// * Annualization must be finite and greater than 0, 1.0 for none.
+    ///
+    /// ## Arguments
+    ///
+    /// * `period` - Size of the period / window used.
+    /// * `annualization` - Periods per year, such as 252 for daily data.
+    pub fn with_period(period: usize, annualization: Num) -> Result<Self, TAError> {
+        seed::streaming(Self::new(
+            period,
+            annualization,
+            &seed::placeholder(Data(1.0, 1.0, 1.0, 1.0), period + 1),
+        ))
+    }
+
+    /// Current and most recent value calculated.
+    pub fn value(&self) -> Num {
+        self.value
+    }
+
+    /// Volatility within the period, before annualization.
+    pub fn period_volatility(&self) -> Num {
+        Self::calculate(self.k, &self.overnight, &self.body, &self.rs)
+    }
+
+    /// Overnight (close to open) volatility within the period, before annualization.
+    pub fn overnight_volatility(&self) -> Num {
+        self.overnight.stdev(true)
+    }
+
+    /// Periods per year used to annualize.
+    pub fn annualization(&self) -> Num {
+        self.annualization
+    }
+
+    /// Weighting of the open to close variance, 0.34 / (1.34 + (n + 1) / (n - 1)).
+    pub fn k(&self) -> Num {
+        self.k
+    }
+
+    /// Checks if the last candle was skipped for having a price of 0 or lower, or a high less than
+    /// the low. A skipped candle does not replace the prior close.
+    pub fn is_skipped(&self) -> bool {
+        self.skipped
+    }
+
+    /// Adds the terms of a new candle, recalculating the volatility.
+    fn add(&mut self, terms: &Terms) -> Num {
+        self.overnight.shift(terms.overnight);
+        self.body.shift(terms.body);
+        self.rs.shift(terms.rs);
+        self.value = self.period_volatility() * self.annualization.sqrt();
+
+        // Rotate the buffer.
+        self.buffer.shift(self.value);
+        self.value
+    }
+
+    /// Calculates the volatility, before annualization, from the terms of a period.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - Weighting of the open to close variance.
+    /// * `overnight` - Overnight returns within the period.
+    /// * `body` - Open to close returns within the period.
+    /// * `rs` - Rogers-Satchell terms within the period.
+    fn calculate(k: Num, overnight: &Buffer, body: &Buffer, rs: &Buffer) -> Num {
+        let variance = overnight.variance(true) + k * body.variance(true) + (1.0 - k) * rs.mean();
+        variance.max(0.0).sqrt()
+    }
+
+    /// Terms of a candle, none if the candle is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `last_close` - Close of the prior candle.
+    /// * `candle` - Candle to obtain the terms of.
+    fn terms<T>(last_close: Num, candle: &T) -> Option<Terms>
+    where
+        T: Open + High + Low + Close,
+    {
+        let rs = RogersSatchellVolatility::term(candle)?;
+        Some(Terms {
+            overnight: (candle.open() / last_close).ln(),
+            body: (candle.close() / candle.open()).ln(),
+            rs,
+        })
+    }
+}
+
+impl<T> Next<&T> for YangZhangVolatility
+where
+    T: Open + High + Low + Close,
+{
+    /// Next value for the Yang-Zhang Volatility.
+    type Output = Num;
+
+    /// Supply an additional candle to recalculate a new volatility. Invalid candles are skipped,
+    /// the current value is returned and `is_skipped` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to add to period.
+    fn next(&mut self, value: &T) -> Self::Output {
+        let Some(terms) = Self::terms(self.last_close, value) else {
+            self.seed.skip();
+            self.skipped = true;
+            return self.value;
+        };
+
+        if seed::collect(
+            self,
+            Data(value.open(), value.high(), value.low(), value.close()),
+        ) {
+            return self.value;
+        }
+
+        self.skipped = false;
+        self.last_close = value.close();
+        self.add(&terms)
+    }
+}
+
+impl Next<(Num, Num, Num, Num)> for YangZhangVolatility {
+    /// Next value for the Yang-Zhang Volatility.
+    type Output = Num;
+
+    /// Supply an additional value to recalculate a new volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to period.
+    ///     * 0 = Open
+    ///     * 1 = High
+    ///     * 2 = Low
+    ///     * 3 = Close
+    fn next(&mut self, value: (Num, Num, Num, Num)) -> Self::Output {
+        self.next(&Data(value.0, value.1, value.2, value.3))
+    }
+}
+
+impl Reset for YangZhangVolatility {
+    /// Clears the Yang-Zhang Volatility back to having seen no data.
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.last_close = 0.0;
+        self.overnight.clear();
+        self.body.clear();
+        self.rs.clear();
+        self.skipped = false;
+        self.buffer.clear();
+        self.seed.start();
+    }
+}
+
+impl Ready for YangZhangVolatility {
+    /// Checks if enough values have been supplied for the value to be complete, false while
+    /// collecting the initial values after being created without data or reset.
+    fn is_ready(&self) -> bool {
+        !self.seed.is_collecting()
+    }
+
+    /// Amount of values supplied since created or reset, including the initial data.
+    fn samples_seen(&self) -> usize {
+        self.seed.seen()
+    }
+}
+
+impl Reseed for YangZhangVolatility {
+    type Input = Data;
+
+    fn seed(&mut self) -> &mut Seed<Self::Input> {
+        &mut self.seed
+    }
+
+    fn required(&self) -> usize {
+        self.period + 1
+    }
+
+    fn recreate(&self, data: &[Self::Input]) -> Result<Self, TAError> {
+        Self::new(self.period, self.annualization, data)
+    }
+
+    fn partial(&mut self, data: &[Self::Input]) {
+        if let Ok(partial) = Self::new(data.len() - 1, self.annualization, data) {
+            self.value = partial.value;
+        }
+    }
+}
+
+impl Snapshot for YangZhangVolatility {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Yang-Zhang Volatility")
+            .count(self.period)
+            .scalar(self.value)
+            .scalar(self.annualization)
+            .scalar(self.k)
+            .scalar(self.last_close)
+            .flag(self.skipped)
+            .child(&self.overnight)
+            .child(&self.body)
+            .child(&self.rs)
+            .child(&self.buffer)
+            .child(&self.seed)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Yang-Zhang Volatility")?;
+        Ok(Self {
+            period: reader.count()?,
+            value: reader.scalar()?,
+            annualization: reader.scalar()?,
+            k: reader.scalar()?,
+            last_close: reader.scalar()?,
+            skipped: reader.flag()?,
+            overnight: reader.child()?,
+            body: reader.child()?,
+            rs: reader.child()?,
+            buffer: reader.child()?,
+            seed: reader.child()?,
+        })
+    }
+}
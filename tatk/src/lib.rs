@@ -1,6 +1,17 @@
 //! Technical Analysis Tools written in Rust.
 //!
 //! This crate is used to an analyze data from samples using common indicators to generate signals.
+//!
+//! Indicators and `Buffer` implement `Clone` and `PartialEq`, a clone can be used to snapshot an
+//! indicator or to supply the same warm indicator with different data. Equality compares all of
+//! the state: configuration, current value, buffered data, and warm-up progress.
+//!
+//! The `std` feature is on by default. Without it the crate is `#![no_std]` and only needs
+//! `alloc`; `IndicatorSet`, `csv`, and `parallel` require `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 /// Represents the internally used numeric type for the crate.
 #[cfg(not(feature = "f32"))]
@@ -13,12 +24,52 @@ pub type Num = f32;
 #[cfg(feature = "test-data")]
 pub mod test_data;
 
+// Also built for its unit tests, which compare it with the `std` float methods.
+#[cfg(any(not(feature = "std"), test))]
+pub(crate) mod math;
+
+/// Items `std` brings into scope by default, imported where needed when built without it.
+#[cfg(not(feature = "std"))]
+pub(crate) mod prelude {
+    #[cfg(not(test))]
+    pub(crate) use crate::math::Float;
+    pub(crate) use alloc::boxed::Box;
+    pub(crate) use alloc::format;
+    pub(crate) use alloc::string::String;
+    pub(crate) use alloc::vec;
+    pub(crate) use alloc::vec::Vec;
+}
+
+pub(crate) mod candle;
+pub use candle::Candle;
+
+pub(crate) mod candle_builder;
+pub use candle_builder::{BarBoundary, CandleBuilder};
+
+#[cfg(feature = "csv")]
+pub(crate) mod csv;
+#[cfg(feature = "csv")]
+pub use csv::{CsvFormat, CsvHeader};
+
 pub(crate) mod error;
-pub use error::TAError;
+pub use error::{NonFinite, TAError};
 
 pub(crate) mod buffer;
 pub use buffer::Buffer;
 
+pub(crate) mod resampler;
+pub use resampler::{ResampleBy, Resampler};
+
+pub(crate) mod signal;
+pub use signal::Signal;
+
+pub(crate) mod snapshot;
+pub use snapshot::IndicatorState;
+
+pub(crate) mod static_buffer;
+pub use static_buffer::StaticBuffer;
+
 pub mod indicators;
 pub mod macros;
 pub mod traits;
+pub mod transforms;
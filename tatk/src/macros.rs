@@ -1,6 +1,68 @@
 //! Shorthand macros used to create indicators.
 
-/// Initialize an Average True Range (ATR) indicator.
+/// Initialize an Acceleration indicator, the Rate of Change of the Rate of Change.
+///
+/// ### Requirements:
+///
+/// * Both periods must be greater than 1.
+/// * Data must have at least `roc_period + period + 1` elements.
+///
+/// ## Arguments
+///
+/// * `roc_period` - Period of the first (momentum) ROC.
+/// * `period` - Period of the second (acceleration) ROC.
+/// * `data` - Array of values to create the Acceleration from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let accel = tatk::accel!(10, 5, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! accel {
+    ($roc_period:expr, $period:expr, $data:expr) => {
+        $crate::indicators::Acceleration::new($roc_period, $period, $data)
+    };
+}
+
+/// Initialize an Alligator indicator. Only supplying the data uses the common settings, jaw
+/// (13, 8), teeth (8, 5), and lips (5, 3).
+///
+/// ### Requirements:
+///
+/// * All periods must be greater than 0.
+/// * Data must have at least `period + shift` elements for each line.
+///
+/// ## Arguments
+///
+/// * `jaw` - Period and shift of the jaw, (`period`, `shift`). default (13, 8)
+/// * `teeth` - Period and shift of the teeth, (`period`, `shift`). default (8, 5)
+/// * `lips` - Period and shift of the lips, (`period`, `shift`). default (5, 3)
+/// * `data` - Array of candles to create the Alligator from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| x as tatk::Num)
+///     .map(|x| tatk::Candle::new(x, x + 2.0, x, x + 1.0, 10.0).unwrap())
+///     .collect();
+/// let alligator = tatk::alligator!(&candles).unwrap();
+/// let alligator = tatk::alligator!((13, 8), (8, 5), (5, 3), &candles).unwrap();
+/// ```
+#[macro_export]
+macro_rules! alligator {
+    ($data:expr) => {
+        $crate::indicators::Alligator::with_defaults($data)
+    };
+    ($jaw:expr, $teeth:expr, $lips:expr, $data:expr) => {
+        $crate::indicators::Alligator::new($jaw, $teeth, $lips, $data)
+    };
+}
+
+/// Initialize an Average True Range (ATR) indicator. Only supplying the data uses the default
+/// period (14), without the smoothing uses Wilder's smoothing.
 ///
 /// ### Requirements:
 ///
@@ -11,14 +73,34 @@
 ///
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the ATR from.
+/// * `smoothing` - Smoothing used for the true ranges. default Wilder
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| tatk::Candle::flat(x as tatk::Num, 10.0))
+///     .collect();
+/// let atr = tatk::atr!(&candles).unwrap();
+/// let atr = tatk::atr!(14, &candles).unwrap();
+/// let sma = tatk::indicators::AtrSmoothing::Sma;
+/// let atr = tatk::atr!(14, &candles, sma).unwrap();
+/// ```
 #[macro_export]
 macro_rules! atr {
+    ($data:expr) => {
+        $crate::indicators::AverageTrueRange::builder().build($data)
+    };
     ($period:expr, $data:expr) => {
         $crate::indicators::AverageTrueRange::new($period, $data)
     };
+    ($period:expr, $data:expr, $smoothing:expr) => {
+        $crate::indicators::AverageTrueRange::with_smoothing($period, $data, $smoothing)
+    };
 }
 
-/// Initialize a Bollinger Bands (BB / BBands) indicator.
+/// Initialize a Bollinger Bands (BB / BBands) indicator. Only supplying the data uses the default
+/// period (20) and distance (2.0), without the distance uses 2.0.
 ///
 /// ### Requirements:
 ///
@@ -30,11 +112,63 @@ macro_rules! atr {
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the BBands from.
 /// * `distance` - Distance the bands (in standard deviations) from the SMA. default 2.0
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let bbands = tatk::bb!(&data).unwrap();
+/// let bbands = tatk::bb!(20, &data).unwrap();
+/// let bbands = tatk::bb!(20, &data, 2.5).unwrap();
+/// ```
 #[macro_export]
 macro_rules! bb {
+    ($data:expr) => {
+        $crate::indicators::BollingerBands::builder().build($data)
+    };
     ($period:expr, $data:expr, $distance:expr) => {
         $crate::indicators::BollingerBands::new($period, $data, $distance)
     };
+    ($period:expr, $data:expr) => {
+        $crate::indicators::BollingerBands::new($period, $data, 2.0)
+    };
+}
+
+/// Initialize a Beta indicator from paired returns, or from the prices of an asset and a
+/// benchmark.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 1.
+/// * Data must have at least `period` elements, or `period + 1` prices.
+/// * Asset and benchmark must have the same amount of prices.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `data` - Array of returns, (`asset`, `benchmark`), to create the Beta from.
+/// * `asset` - Array of prices for the asset.
+/// * `benchmark` - Array of prices for the benchmark.
+/// * `kind` - Method used to convert the prices into returns.
+///
+/// ## Example
+///
+/// ```
+/// let returns: Vec<tatk::Num> = (1..=40).map(|x| ((x % 7) as tatk::Num - 3.0) / 100.0).collect();
+/// let pairs: Vec<(tatk::Num, tatk::Num)> = returns.iter().map(|r| (r * 2.0, *r)).collect();
+/// let beta = tatk::beta!(20, &pairs).unwrap();
+/// let prices: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let kind = tatk::indicators::ReturnKind::Simple;
+/// let beta = tatk::beta!(20, &prices, &prices, kind).unwrap();
+/// ```
+#[macro_export]
+macro_rules! beta {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::Beta::new($period, $data)
+    };
+    ($period:expr, $asset:expr, $benchmark:expr, $kind:expr) => {
+        $crate::indicators::Beta::from_prices($period, $asset, $benchmark, $kind)
+    };
 }
 
 /// Initialize a Cross indicator using two lines.
@@ -47,6 +181,15 @@ macro_rules! bb {
 ///
 /// * `short_line` - Shorter or more reactive line.
 /// * `long_line` - Longer or more historic line.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let short = tatk::sma!(5, &data).unwrap();
+/// let long = tatk::sma!(20, &data).unwrap();
+/// let cross = tatk::cross!(short, long);
+/// ```
 #[macro_export]
 macro_rules! cross {
     ($short_line:expr, $long_line:expr) => {
@@ -54,6 +197,36 @@ macro_rules! cross {
     };
 }
 
+/// Initialize a Cumulative Return indicator from returns. Without the base starts from 1.0.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Base must be greater than 0.
+///
+/// ## Arguments
+///
+/// * `period` - Amount of equity values to keep.
+/// * `base` - Starting equity, such as 1.0 or 100.0. default 1.0
+/// * `data` - Array of returns to compound, can be empty.
+///
+/// ## Example
+///
+/// ```
+/// let returns: Vec<tatk::Num> = (1..=40).map(|x| ((x % 7) as tatk::Num - 3.0) / 100.0).collect();
+/// let cumret = tatk::cumret!(20, &returns).unwrap();
+/// let cumret = tatk::cumret!(20, 100.0, &returns).unwrap();
+/// ```
+#[macro_export]
+macro_rules! cumret {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::CumulativeReturn::new($period, 1.0, $data)
+    };
+    ($period:expr, $base:expr, $data:expr) => {
+        $crate::indicators::CumulativeReturn::new($period, $base, $data)
+    };
+}
+
 /// Initialize a Double Exponential Moving Average (DEMA) indicator.
 ///
 /// ### Requirements:
@@ -65,6 +238,13 @@ macro_rules! cross {
 ///
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the DEMA from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let dema = tatk::dema!(10, &data).unwrap();
+/// ```
 #[macro_export]
 macro_rules! dema {
     ($period:expr, $data:expr) => {
@@ -83,6 +263,13 @@ macro_rules! dema {
 ///
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the EMA from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let ema = tatk::ema!(10, &data).unwrap();
+/// ```
 #[macro_export]
 macro_rules! ema {
     ($period:expr, $data:expr) => {
@@ -90,6 +277,236 @@ macro_rules! ema {
     };
 }
 
+/// Initialize a Gator Oscillator indicator. Only supplying the data uses the common settings,
+/// jaw (13, 8), teeth (8, 5), and lips (5, 3).
+///
+/// ### Requirements:
+///
+/// * All periods must be greater than 0.
+/// * Data must have at least `period + shift` elements for each line.
+///
+/// ## Arguments
+///
+/// * `jaw` - Period and shift of the jaw, (`period`, `shift`). default (13, 8)
+/// * `teeth` - Period and shift of the teeth, (`period`, `shift`). default (8, 5)
+/// * `lips` - Period and shift of the lips, (`period`, `shift`). default (5, 3)
+/// * `data` - Array of candles to create the Gator Oscillator from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| x as tatk::Num)
+///     .map(|x| tatk::Candle::new(x, x + 2.0, x, x + 1.0, 10.0).unwrap())
+///     .collect();
+/// let gator = tatk::gator!(&candles).unwrap();
+/// let gator = tatk::gator!((13, 8), (8, 5), (5, 3), &candles).unwrap();
+/// ```
+#[macro_export]
+macro_rules! gator {
+    ($data:expr) => {
+        $crate::indicators::GatorOscillator::with_defaults($data)
+    };
+    ($jaw:expr, $teeth:expr, $lips:expr, $data:expr) => {
+        $crate::indicators::GatorOscillator::new($jaw, $teeth, $lips, $data)
+    };
+}
+
+/// Initialize a Garman-Klass Volatility (GKV) indicator. Without the annualization uses 252
+/// periods per year, for daily data.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Annualization must be finite and greater than 0, 1.0 for none.
+/// * Data must have at least `period` elements.
+/// * All prices must be greater than 0 and lows no greater than their high.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `annualization` - Periods per year, such as 252 for daily data. default 252.0
+/// * `data` - Array of candles to create the volatility from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| x as tatk::Num)
+///     .map(|x| tatk::Candle::new(x, x + 2.0, x, x + 1.0, 10.0).unwrap())
+///     .collect();
+/// let vol = tatk::gkv!(20, &candles).unwrap();
+/// let vol = tatk::gkv!(20, 365.0, &candles).unwrap();
+/// ```
+#[macro_export]
+macro_rules! gkv {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::GarmanKlassVolatility::new($period, 252.0, $data)
+    };
+    ($period:expr, $annualization:expr, $data:expr) => {
+        $crate::indicators::GarmanKlassVolatility::new($period, $annualization, $data)
+    };
+}
+
+/// Initialize a Gaussian Moving Average (GMA) indicator.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Sigma must be greater than 0.
+/// * Data must have at least `period` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `sigma` - Width of the curve in values.
+/// * `center` - Position the curve is centered on.
+/// * `data` - Array of values to create the GMA from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let center = tatk::indicators::GaussianCenter::Middle;
+/// let gma = tatk::gma!(9, 2.0, center, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! gma {
+    ($period:expr, $sigma:expr, $center:expr, $data:expr) => {
+        $crate::indicators::GaussianMovingAverage::new($period, $sigma, $center, $data)
+    };
+}
+
+/// Initialize a Holt Smoothing indicator, double exponential smoothing of a level and trend.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Alpha and beta must be greater than 0 and less than or equal to 1.
+/// * Data must have at least `period` elements and at least 2 elements.
+///
+/// ## Arguments
+///
+/// * `period` - Amount of levels kept for statistics.
+/// * `alpha` - Smoothing of the level.
+/// * `beta` - Smoothing of the trend.
+/// * `data` - Array of values to create the Holt Smoothing from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let holt = tatk::holt!(10, 0.5, 0.3, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! holt {
+    ($period:expr, $alpha:expr, $beta:expr, $data:expr) => {
+        $crate::indicators::HoltSmoothing::new($period, $alpha, $beta, $data)
+    };
+}
+
+/// Initialize a Historical Volatility (HV) indicator. Without the annualization uses 252 periods
+/// per year, for daily data.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 1.
+/// * Annualization must be finite and greater than 0.
+/// * Data must have at least `period + 1` elements.
+/// * All prices must be greater than 0.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `annualization` - Periods per year, such as 252 for daily data. default 252.0
+/// * `data` - Array of prices to create the HV from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let hv = tatk::hv!(20, &data).unwrap();
+/// let hv = tatk::hv!(20, 365.0, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! hv {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::HistoricalVolatility::new($period, 252.0, $data)
+    };
+    ($period:expr, $annualization:expr, $data:expr) => {
+        $crate::indicators::HistoricalVolatility::new($period, $annualization, $data)
+    };
+}
+
+/// Initialize Keltner Channels (KC). Without the multiplier uses 2.0.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Multiplier must be greater than 0.
+/// * Data must have at least `period + 1` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used for the EMA and ATR.
+/// * `multiplier` - Multiplier of the ATR, distance of the bands from the EMA. default 2.0
+/// * `data` - Array of candles to create the Keltner Channels from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| tatk::Candle::flat(x as tatk::Num, 10.0))
+///     .collect();
+/// let kc = tatk::kc!(20, &candles).unwrap();
+/// let kc = tatk::kc!(20, 1.5, &candles).unwrap();
+/// ```
+#[macro_export]
+macro_rules! kc {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::KeltnerChannels::new($period, 2.0, $data)
+    };
+    ($period:expr, $multiplier:expr, $data:expr) => {
+        $crate::indicators::KeltnerChannels::new($period, $multiplier, $data)
+    };
+}
+
+/// Initialize a Kalman Filter (KF) indicator.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Process and measurement noise must be greater than 0.
+/// * Data must have at least `period` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Amount of estimates kept for statistics.
+/// * `model` - Model of how the true value moves.
+/// * `process_noise` - Expected movement of the true value between values.
+/// * `measurement_noise` - Expected noise of the values supplied.
+/// * `data` - Array of values to create the Kalman Filter from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let model = tatk::indicators::KalmanModel::Velocity;
+/// let kf = tatk::kf!(10, model, 0.01, 1.0, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! kf {
+    ($period:expr, $model:expr, $process_noise:expr, $measurement_noise:expr, $data:expr) => {
+        $crate::indicators::KalmanFilter::new(
+            $period,
+            $model,
+            $process_noise,
+            $measurement_noise,
+            $data,
+        )
+    };
+}
+
 /// Initialize a Linear Regression (LR / LineReg) indicator.
 ///
 /// ### Requirements:
@@ -101,6 +518,13 @@ macro_rules! ema {
 ///
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the LineReg from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let lr = tatk::lr!(10, &data).unwrap();
+/// ```
 #[macro_export]
 macro_rules! lr {
     ($period:expr, $data:expr) => {
@@ -108,6 +532,42 @@ macro_rules! lr {
     };
 }
 
+/// Initialize a Linear Regression Channel (LRC) indicator. Without the width the bands are 2.0
+/// standard deviations of the residuals from the line.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 1.
+/// * Data must have at least `period` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `data` - Array of values to create the LRC from.
+/// * `width` - Distance of the bands from the line. default `ChannelWidth::Deviation(2.0)`
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let lrc = tatk::lrc!(20, &data).unwrap();
+/// let width = tatk::indicators::ChannelWidth::MaxResidual;
+/// let lrc = tatk::lrc!(20, &data, width).unwrap();
+/// ```
+#[macro_export]
+macro_rules! lrc {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::LinearRegressionChannel::new(
+            $period,
+            $data,
+            $crate::indicators::ChannelWidth::Deviation(2.0),
+        )
+    };
+    ($period:expr, $data:expr, $width:expr) => {
+        $crate::indicators::LinearRegressionChannel::new($period, $data, $width)
+    };
+}
+
 /// Initialize a McGinley Dynamic (MDI) indicator.
 ///
 /// ### Requirements:
@@ -120,14 +580,26 @@ macro_rules! lr {
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the MD from.
 /// * `k` - Constant used to modify selected period. Default: 0.6
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let md = tatk::mdi!(10, &data).unwrap();
+/// let md = tatk::mdi!(10, &data, 0.6).unwrap();
+/// ```
 #[macro_export]
 macro_rules! mdi {
     ($period:expr, $data:expr, $k:expr) => {
         $crate::indicators::McGinleyDynamic::new($period, $data, $k)
     };
+    ($period:expr, $data:expr) => {
+        $crate::indicators::McGinleyDynamic::new($period, $data, 0.6)
+    };
 }
 
-/// Initialize a Moving Average Convergence and Divergence (MACD) indicator.
+/// Initialize a Moving Average Convergence and Divergence (MACD) indicator. Only supplying the data
+/// uses the default short (12), long (26), and signal (9) periods.
 ///
 /// ### Requirements:
 ///
@@ -142,14 +614,129 @@ macro_rules! mdi {
 /// * `long` - Period of the long EMA.
 /// * `signal` - Period of the signal EMA.
 /// * `data` - Array of values to create the MACD from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let macd = tatk::macd!(&data).unwrap();
+/// let macd = tatk::macd!(12, 26, 9, &data).unwrap();
+/// ```
 #[macro_export]
 macro_rules! macd {
+    ($data:expr) => {
+        $crate::indicators::MovingAverageConvergenceDivergence::builder().build($data)
+    };
     ($short:expr, $long:expr, $signal:expr, $data:expr) => {
         $crate::indicators::MovingAverageConvergenceDivergence::new($short, $long, $signal, $data)
     };
 }
 
-/// Initialize an On-Balance Volume (OBV) indicator.
+/// Initialize a Median Absolute Deviation (MAD) indicator.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Data must have at least `period` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `data` - Array of values to create the MAD from.
+/// * `is_scaled` - Scale by 1.4826 to be consistent with the standard deviation of normal data.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let mad = tatk::mad!(20, &data, true).unwrap();
+/// ```
+#[macro_export]
+macro_rules! mad {
+    ($period:expr, $data:expr, $is_scaled:expr) => {
+        $crate::indicators::MedianAbsoluteDeviation::new($period, $data, $is_scaled)
+    };
+}
+
+/// Initialize a Median indicator.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Data must have at least `period` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `data` - Array of values to create the Median from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let median = tatk::median!(20, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! median {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::Median::new($period, $data)
+    };
+}
+
+/// Initialize a MidPoint indicator.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Data must have at least `period` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `data` - Array of values to create the MidPoint from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let midpoint = tatk::midpoint!(10, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! midpoint {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::MidPoint::new($period, $data)
+    };
+}
+
+/// Initialize a MidPrice indicator.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Data must have at least `period` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `data` - Array of candles to create the MidPrice from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| tatk::Candle::flat(x as tatk::Num, 10.0))
+///     .collect();
+/// let midprice = tatk::midprice!(10, &candles).unwrap();
+/// ```
+#[macro_export]
+macro_rules! midprice {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::MidPrice::new($period, $data)
+    };
+}
+
+/// Initialize an On-Balance Volume (OBV) indicator, with a signal line if supplied a signal period
+/// and kind of moving average.
 ///
 /// ### Requirements:
 ///
@@ -159,12 +746,64 @@ macro_rules! macd {
 /// ## Arguments
 ///
 /// * `period` - History of values to keep.
+/// * `signal_period` - Size of the period / window used for the signal line.
+/// * `kind` - Kind of moving average used for the signal line.
 /// * `data` - Array of values to create the OBV from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| tatk::Candle::flat(x as tatk::Num, 10.0))
+///     .collect();
+/// let obv = tatk::obv!(10, &candles).unwrap();
+/// let ema = tatk::indicators::MaKind::Ema;
+/// let obv = tatk::obv!(10, 5, ema, &candles).unwrap();
+/// ```
 #[macro_export]
 macro_rules! obv {
     ($period:expr, $data:expr) => {
         $crate::indicators::OnBalanceVolume::new($period, $data)
     };
+    ($period:expr, $signal_period:expr, $kind:expr, $data:expr) => {
+        $crate::indicators::OnBalanceVolume::with_signal($period, $signal_period, $kind, $data)
+    };
+}
+
+/// Initialize a Parkinson Volatility (PKV) indicator. Without the annualization uses 252
+/// periods per year, for daily data.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Annualization must be finite and greater than 0, 1.0 for none.
+/// * Data must have at least `period` elements.
+/// * All lows must be greater than 0 and no greater than their high.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `annualization` - Periods per year, such as 252 for daily data. default 252.0
+/// * `data` - Array of candles to create the volatility from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| x as tatk::Num)
+///     .map(|x| tatk::Candle::new(x, x + 2.0, x, x + 1.0, 10.0).unwrap())
+///     .collect();
+/// let vol = tatk::pkv!(20, &candles).unwrap();
+/// let vol = tatk::pkv!(20, 365.0, &candles).unwrap();
+/// ```
+#[macro_export]
+macro_rules! pkv {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::ParkinsonVolatility::new($period, 252.0, $data)
+    };
+    ($period:expr, $annualization:expr, $data:expr) => {
+        $crate::indicators::ParkinsonVolatility::new($period, $annualization, $data)
+    };
 }
 
 /// Initialize a Rate of Change (ROC) indicator.
@@ -178,6 +817,13 @@ macro_rules! obv {
 ///
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the ROC from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let roc = tatk::roc!(10, &data).unwrap();
+/// ```
 #[macro_export]
 macro_rules! roc {
     ($period:expr, $data:expr) => {
@@ -185,7 +831,8 @@ macro_rules! roc {
     };
 }
 
-/// Initialize a Relative Strength Index (RSI) indicator.
+/// Initialize a Relative Strength Index (RSI) indicator. Only supplying the data uses the default
+/// period (14), without the thresholds uses oversold (20.0) and overbought (80.0).
 ///
 /// ### Requirements:
 ///
@@ -196,11 +843,109 @@ macro_rules! roc {
 ///
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the RSI from.
+/// * `oversold` - Oversold threshold, commonly 30.0.
+/// * `overbought` - Overbought threshold, commonly 70.0.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let rsi = tatk::rsi!(&data).unwrap();
+/// let rsi = tatk::rsi!(14, &data).unwrap();
+/// let rsi = tatk::rsi!(14, &data, 30.0, 70.0).unwrap();
+/// ```
 #[macro_export]
 macro_rules! rsi {
+    ($data:expr) => {
+        $crate::indicators::RelativeStrengthIndex::builder().build($data)
+    };
     ($period:expr, $data:expr) => {
         $crate::indicators::RelativeStrengthIndex::new($period, $data)
     };
+    ($period:expr, $data:expr, $oversold:expr, $overbought:expr) => {
+        $crate::indicators::RelativeStrengthIndex::with_thresholds(
+            $period,
+            $data,
+            $oversold,
+            $overbought,
+        )
+    };
+}
+
+/// Initialize a Rogers-Satchell Volatility (RSV) indicator. Without the annualization uses 252
+/// periods per year, for daily data.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Annualization must be finite and greater than 0, 1.0 for none.
+/// * Data must have at least `period` elements.
+/// * All prices must be greater than 0 and lows no greater than their high.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `annualization` - Periods per year, such as 252 for daily data. default 252.0
+/// * `data` - Array of candles to create the volatility from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| x as tatk::Num)
+///     .map(|x| tatk::Candle::new(x, x + 2.0, x, x + 1.0, 10.0).unwrap())
+///     .collect();
+/// let vol = tatk::rsv!(20, &candles).unwrap();
+/// let vol = tatk::rsv!(20, 365.0, &candles).unwrap();
+/// ```
+#[macro_export]
+macro_rules! rsv {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::RogersSatchellVolatility::new($period, 252.0, $data)
+    };
+    ($period:expr, $annualization:expr, $data:expr) => {
+        $crate::indicators::RogersSatchellVolatility::new($period, $annualization, $data)
+    };
+}
+
+/// Initialize a Sharpe Ratio indicator from returns. Without the rates uses a risk_free of 0.0
+/// and an annualization of 252 periods per year, the data is prices if `is_prices` is true.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 1.
+/// * Annualization must be finite and greater than 0, 1.0 for none.
+/// * Data must have at least `period` returns, or `period + 1` prices.
+/// * Prices must be greater than 0.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `risk_free` - Risk-free rate per period. default 0.0
+/// * `annualization` - Periods per year, such as 252 for daily data. default 252.0
+/// * `data` - Array of returns or prices to create the Sharpe Ratio from.
+/// * `is_prices` - Data is prices, `next` also expects prices. default false
+///
+/// ## Example
+///
+/// ```
+/// let returns: Vec<tatk::Num> = (1..=40).map(|x| ((x % 7) as tatk::Num - 3.0) / 100.0).collect();
+/// let ratio = tatk::sharpe!(20, &returns).unwrap();
+/// let ratio = tatk::sharpe!(20, 0.001, 365.0, &returns).unwrap();
+/// let prices: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let ratio = tatk::sharpe!(20, 0.0, 252.0, &prices, true).unwrap();
+/// ```
+#[macro_export]
+macro_rules! sharpe {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::SharpeRatio::new($period, 0.0, 252.0, $data, false)
+    };
+    ($period:expr, $risk_free:expr, $annualization:expr, $data:expr) => {
+        $crate::indicators::SharpeRatio::new($period, $risk_free, $annualization, $data, false)
+    };
+    ($period:expr, $risk_free:expr, $annualization:expr, $data:expr, $is_prices:expr) => {
+        $crate::indicators::SharpeRatio::new($period, $risk_free, $annualization, $data, $is_prices)
+    };
 }
 
 /// Initialize a Simple Moving Average (SMA) indicator.
@@ -214,6 +959,13 @@ macro_rules! rsi {
 ///
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the SMA from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let sma = tatk::sma!(10, &data).unwrap();
+/// ```
 #[macro_export]
 macro_rules! sma {
     ($period:expr, $data:expr) => {
@@ -221,7 +973,33 @@ macro_rules! sma {
     };
 }
 
-/// Initialize a Standard Deviation (SD / Stdev) for a period of a buffer.
+/// Initialize a Smoothed Moving Average (SMMA) indicator.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Data must have at least `period` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `data` - Array of values to create the SMMA from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let smma = tatk::smma!(10, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! smma {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::SmoothedMovingAverage::new($period, $data)
+    };
+}
+
+/// Initialize a Standard Deviation (SD / Stdev) for a period of a buffer. Without `is_sample` the
+/// data is a sample.
 ///
 /// ### Requirements:
 ///
@@ -233,11 +1011,152 @@ macro_rules! sma {
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the STDEV from.
 /// * `is_sample` - If the data is a Sample or Population, default should be True.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let sd = tatk::sd!(10, &data).unwrap();
+/// let sd = tatk::sd!(10, &data, false).unwrap();
+/// ```
 #[macro_export]
 macro_rules! sd {
     ($period:expr, $data:expr, $is_sample:expr) => {
         $crate::indicators::StandardDeviation::new($period, $data, $is_sample)
     };
+    ($period:expr, $data:expr) => {
+        $crate::indicators::StandardDeviation::new($period, $data, true)
+    };
+}
+
+/// Initialize a Savitzky-Golay Filter (SG) indicator.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Degree must be less than the period.
+/// * Data must have at least `period` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `degree` - Degree of the polynomial fit to each period.
+/// * `data` - Array of values to create the Savitzky-Golay Filter from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let sg = tatk::sg!(11, 3, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! sg {
+    ($period:expr, $degree:expr, $data:expr) => {
+        $crate::indicators::SavitzkyGolay::new($period, $degree, $data)
+    };
+}
+
+/// Initialize a Sortino Ratio indicator from returns. Without the rates uses a target of 0.0
+/// and an annualization of 252 periods per year, the data is prices if `is_prices` is true.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 1.
+/// * Annualization must be finite and greater than 0, 1.0 for none.
+/// * Data must have at least `period` returns, or `period + 1` prices.
+/// * Prices must be greater than 0.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `target` - Minimum acceptable return per period. default 0.0
+/// * `annualization` - Periods per year, such as 252 for daily data. default 252.0
+/// * `data` - Array of returns or prices to create the Sortino Ratio from.
+/// * `is_prices` - Data is prices, `next` also expects prices. default false
+///
+/// ## Example
+///
+/// ```
+/// let returns: Vec<tatk::Num> = (1..=40).map(|x| ((x % 7) as tatk::Num - 3.0) / 100.0).collect();
+/// let ratio = tatk::sortino!(20, &returns).unwrap();
+/// let ratio = tatk::sortino!(20, 0.001, 365.0, &returns).unwrap();
+/// let prices: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let ratio = tatk::sortino!(20, 0.0, 252.0, &prices, true).unwrap();
+/// ```
+#[macro_export]
+macro_rules! sortino {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::SortinoRatio::new($period, 0.0, 252.0, $data, false)
+    };
+    ($period:expr, $target:expr, $annualization:expr, $data:expr) => {
+        $crate::indicators::SortinoRatio::new($period, $target, $annualization, $data, false)
+    };
+    ($period:expr, $target:expr, $annualization:expr, $data:expr, $is_prices:expr) => {
+        $crate::indicators::SortinoRatio::new($period, $target, $annualization, $data, $is_prices)
+    };
+}
+
+/// Initialize a Squeeze indicator, Bollinger Bands inside Keltner Channels. Without the distances
+/// uses 2.0 standard deviations and 1.5 ATRs.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 1.
+/// * Multiplier must be greater than 0.
+/// * Data must have at least `(period * 2) - 1` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `distance` - Distance of the Bollinger Bands (in standard deviations). default 2.0
+/// * `multiplier` - Multiplier of the ATR for the Keltner Channels. default 1.5
+/// * `data` - Array of candles to create the Squeeze from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| x as tatk::Num)
+///     .map(|x| tatk::Candle::new(x, x + 2.0, x, x + 1.0, 10.0).unwrap())
+///     .collect();
+/// let squeeze = tatk::squeeze!(20, &candles).unwrap();
+/// let squeeze = tatk::squeeze!(20, 2.0, 1.5, &candles).unwrap();
+/// ```
+#[macro_export]
+macro_rules! squeeze {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::Squeeze::new($period, 2.0, 1.5, $data)
+    };
+    ($period:expr, $distance:expr, $multiplier:expr, $data:expr) => {
+        $crate::indicators::Squeeze::new($period, $distance, $multiplier, $data)
+    };
+}
+
+/// Initialize a Smoothed Rate of Change (SROC) indicator.
+///
+/// ### Requirements:
+///
+/// * EMA period must be greater than 0.
+/// * Period must be greater than 1.
+/// * Data must have at least `ema_period + period` elements.
+///
+/// ## Arguments
+///
+/// * `ema_period` - Period of the EMA smoothing the values.
+/// * `period` - Period of the ROC.
+/// * `data` - Array of values to create the SROC from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let sroc = tatk::sroc!(5, 10, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! sroc {
+    ($ema_period:expr, $period:expr, $data:expr) => {
+        $crate::indicators::SmoothedRateOfChange::new($ema_period, $period, $data)
+    };
 }
 
 /// Initialize a True Range (TR) indicator.
@@ -251,6 +1170,15 @@ macro_rules! sd {
 ///
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the TR from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| tatk::Candle::flat(x as tatk::Num, 10.0))
+///     .collect();
+/// let tr = tatk::tr!(10, &candles).unwrap();
+/// ```
 #[macro_export]
 macro_rules! tr {
     ($period:expr, $data:expr) => {
@@ -258,7 +1186,8 @@ macro_rules! tr {
     };
 }
 
-/// Initialize a Variance (Var) for a period of a buffer.
+/// Initialize a Variance (Var) for a period of a buffer. Without `is_sample` the data is a
+/// sample.
 ///
 /// ### Requirements:
 ///
@@ -270,9 +1199,107 @@ macro_rules! tr {
 /// * `period` - Size of the period / window used.
 /// * `data` - Array of values to create the Var(X) from.
 /// * `is_sample` - If the data is a Sample or Population, default should be True.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let var = tatk::var!(10, &data).unwrap();
+/// let var = tatk::var!(10, &data, false).unwrap();
+/// ```
 #[macro_export]
 macro_rules! var {
     ($period:expr, $data:expr, $is_sample:expr) => {
         $crate::indicators::Variance::new($period, $data, $is_sample)
     };
+    ($period:expr, $data:expr) => {
+        $crate::indicators::Variance::new($period, $data, true)
+    };
+}
+
+/// Initialize a Volume Weighted Average Price (VWAP) indicator. Without data the VWAP starts
+/// without having seen a candle.
+///
+/// ## Arguments
+///
+/// * `data` - Array of candles to create the VWAP from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| tatk::Candle::flat(x as tatk::Num, 10.0))
+///     .collect();
+/// let vwap = tatk::vwap!();
+/// let vwap = tatk::vwap!(&candles);
+/// ```
+#[macro_export]
+macro_rules! vwap {
+    () => {
+        $crate::indicators::VolumeWeightedAveragePrice::new()
+    };
+    ($data:expr) => {
+        $crate::indicators::VolumeWeightedAveragePrice::from_candles($data)
+    };
+}
+
+/// Initialize a Weighted Moving Average (WMA) indicator.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 0.
+/// * Data must have at least `period` elements.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `data` - Array of values to create the WMA from.
+///
+/// ## Example
+///
+/// ```
+/// let data: Vec<tatk::Num> = (1..=40).map(|x| x as tatk::Num).collect();
+/// let wma = tatk::wma!(10, &data).unwrap();
+/// ```
+#[macro_export]
+macro_rules! wma {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::WeightedMovingAverage::new($period, $data)
+    };
+}
+
+/// Initialize a Yang-Zhang Volatility (YZV) indicator. Without the annualization uses 252
+/// periods per year, for daily data.
+///
+/// ### Requirements:
+///
+/// * Period must be greater than 1.
+/// * Annualization must be finite and greater than 0, 1.0 for none.
+/// * Data must have at least `period + 1` elements.
+/// * All prices must be greater than 0 and lows no greater than their high.
+///
+/// ## Arguments
+///
+/// * `period` - Size of the period / window used.
+/// * `annualization` - Periods per year, such as 252 for daily data. default 252.0
+/// * `data` - Array of candles to create the volatility from.
+///
+/// ## Example
+///
+/// ```
+/// let candles: Vec<tatk::Candle> = (1..=40)
+///     .map(|x| x as tatk::Num)
+///     .map(|x| tatk::Candle::new(x, x + 2.0, x, x + 1.0, 10.0).unwrap())
+///     .collect();
+/// let vol = tatk::yzv!(20, &candles).unwrap();
+/// let vol = tatk::yzv!(20, 365.0, &candles).unwrap();
+/// ```
+#[macro_export]
+macro_rules! yzv {
+    ($period:expr, $data:expr) => {
+        $crate::indicators::YangZhangVolatility::new($period, 252.0, $data)
+    };
+    ($period:expr, $annualization:expr, $data:expr) => {
+        $crate::indicators::YangZhangVolatility::new($period, $annualization, $data)
+    };
 }
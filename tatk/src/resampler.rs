@@ -0,0 +1,166 @@
+//! Resampler, merges candles into candles of a higher timeframe.
+//!
+//! Candles are merged into a bucket of either an amount of candles or a duration of time. A bucket
+//! of candles is completed by the last candle it holds, a bucket of time is completed by the first
+//! candle of the following bucket. The merged candle has the first open, highest high, lowest low,
+//! last close, and summed volume.
+use crate::error::TAError;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::traits::{Next, Ohlcv};
+use crate::Candle;
+
+/// Candles merged into each candle of the higher timeframe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResampleBy {
+    /// Amount of candles, such as 5 for 1 minute candles into 5 minute candles.
+    Count(usize),
+    /// Duration of time, in the units of the timestamps supplied. Buckets start at multiples of
+    /// the duration.
+    Duration(i64),
+}
+
+/// Resampler, merges candles into candles of a higher timeframe.
+///
+/// Candles are merged into a bucket of either an amount of candles or a duration of time. A bucket
+/// of candles is completed by the last candle it holds, a bucket of time is completed by the first
+/// candle of the following bucket. The merged candle has the first open, highest high, lowest low,
+/// last close, and summed volume.
+///
+/// Candles are supplied with `next`, with a timestamp `(timestamp, &candle)` for a duration.
+/// Candles supplied without a timestamp to a duration are merged into the current bucket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resampler {
+    /// Candles merged into each candle.
+    by: ResampleBy,
+    /// Bucket being merged, `None` until its first candle.
+    current: Option<Candle>,
+    /// Amount of candles merged into the current bucket.
+    count: usize,
+    /// Bucket of time the current bucket covers.
+    bucket: Option<i64>,
+}
+
+impl Resampler {
+    /// Creates a new Resampler merging candles by the amount or duration.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Amount or duration must be greater than 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `by` - Candles merged into each candle.
+    pub fn new(by: ResampleBy) -> Result<Self, TAError> {
+        match by {
+            ResampleBy::Count(0) => Err(TAError::InvalidSize(String::from(
+                "candles per bucket cannot be 0",
+            ))),
+            ResampleBy::Duration(duration) if duration <= 0 => Err(TAError::InvalidSize(
+                String::from("duration of a bucket must be greater than 0"),
+            )),
+            _ => Ok(Self {
+                by,
+                current: None,
+                count: 0,
+                bucket: None,
+            }),
+        }
+    }
+
+    /// Candles merged into each candle.
+    pub fn by(&self) -> ResampleBy {
+        self.by
+    }
+
+    /// Bucket being merged from the candles so far, `None` if there have been none.
+    pub fn current(&self) -> Option<Candle> {
+        self.current
+    }
+
+    /// Amount of candles merged into the current bucket.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Completes the current bucket regardless of its amount or duration, such as at the end of
+    /// the candles. `None` if there have been no candles since the last bucket.
+    pub fn flush(&mut self) -> Option<Candle> {
+        self.count = 0;
+        self.bucket = None;
+        self.current.take()
+    }
+
+    /// Merges the candle into the current bucket.
+    ///
+    /// # Arguments
+    ///
+    /// * `candle` - Candle to merge.
+    fn merge<T: Ohlcv>(&mut self, candle: &T) {
+        self.current = Some(match self.current {
+            Some(current) => current.merge(candle),
+            None => Candle {
+                open: candle.open(),
+                close: candle.close(),
+                high: candle.high(),
+                low: candle.low(),
+                volume: candle.volume(),
+            },
+        });
+        self.count += 1;
+    }
+}
+
+impl<T> Next<&T> for Resampler
+where
+    T: Ohlcv,
+{
+    /// Candle of the bucket completed by the candle.
+    type Output = Option<Candle>;
+
+    /// Supply an additional candle to merge into the current bucket.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to merge.
+    fn next(&mut self, value: &T) -> Self::Output {
+        self.merge(value);
+        match self.by {
+            ResampleBy::Count(count) if self.count >= count => self.flush(),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Next<(i64, &T)> for Resampler
+where
+    T: Ohlcv,
+{
+    /// Candle of the bucket completed by the candle.
+    type Output = Option<Candle>;
+
+    /// Supply an additional candle with its timestamp to merge into the bucket of its time.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New candle to merge.
+    ///     * 0 = Timestamp
+    ///     * 1 = Candle
+    fn next(&mut self, value: (i64, &T)) -> Self::Output {
+        let duration = match self.by {
+            ResampleBy::Duration(duration) => duration,
+            ResampleBy::Count(_) => return self.next(value.1),
+        };
+
+        // The first candle of a later bucket completes the current bucket.
+        let bucket = value.0.div_euclid(duration);
+        let completed = match self.bucket {
+            Some(current) if current != bucket => self.flush(),
+            _ => None,
+        };
+
+        self.bucket = Some(bucket);
+        self.merge(value.1);
+        completed
+    }
+}
@@ -0,0 +1,25 @@
+//! Signals, the trading interpretation of an indicator's current state.
+
+/// Trading interpretation of an indicator's current state.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// Indicator suggests buying, such as an oversold RSI or a golden cross.
+    Buy,
+    /// Indicator suggests selling, such as an overbought RSI or a death cross.
+    Sell,
+    /// Indicator suggests neither, including while exactly at a threshold.
+    #[default]
+    Hold,
+}
+
+impl Signal {
+    /// Checks if the signal is to buy.
+    pub fn is_buy(&self) -> bool {
+        *self == Signal::Buy
+    }
+
+    /// Checks if the signal is to sell.
+    pub fn is_sell(&self) -> bool {
+        *self == Signal::Sell
+    }
+}
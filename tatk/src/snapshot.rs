@@ -0,0 +1,250 @@
+//! Plain state of an indicator, captured with `Snapshot` and persisted however the user likes.
+//!
+//! The state holds buffers and scalars only, settings and enumerations are held as counts. An
+//! indicator restored from its state produces the same output as if it never stopped.
+//!
+//! `Timed` and `IndicatorSet` are not captured, they hold session functions and boxed indicators
+//! that have no plain state.
+use crate::error::TAError;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::traits::Snapshot;
+use crate::Num;
+
+/// Plain state of an indicator, captured with `Snapshot::snapshot` and restored with
+/// `Snapshot::restore`. Composite indicators hold the states of the indicators they are made of
+/// as children.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IndicatorState {
+    /// Name of the type the state was captured from, checked when restored.
+    pub name: String,
+    /// Values and settings such as the current value.
+    pub scalars: Vec<Num>,
+    /// Sizes, counters, flags, and kinds such as the period.
+    pub counts: Vec<usize>,
+    /// Values held in buffers, oldest to newest.
+    pub buffers: Vec<Vec<Num>>,
+    /// States of the indicators or buffers held.
+    pub children: Vec<IndicatorState>,
+}
+
+impl IndicatorState {
+    /// Creates a new empty state for the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the type the state is captured from.
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: String::from(name),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a value or setting.
+    pub(crate) fn scalar(mut self, value: Num) -> Self {
+        self.scalars.push(value);
+        self
+    }
+
+    /// Adds a size or counter.
+    pub(crate) fn count(mut self, value: usize) -> Self {
+        self.counts.push(value);
+        self
+    }
+
+    /// Adds a flag, held as a count of 0 or 1.
+    pub(crate) fn flag(self, value: bool) -> Self {
+        self.count(value as usize)
+    }
+
+    /// Adds the position of the variant within its enumeration.
+    pub(crate) fn variant<T: Variants>(self, value: T) -> Self {
+        let index = T::ALL.iter().position(|v| *v == value).unwrap_or(0);
+        self.count(index)
+    }
+
+    /// Adds an optional variant, held as a flag followed by the position of the variant or 0.
+    pub(crate) fn optional_variant<T: Variants>(self, value: Option<T>) -> Self {
+        match value {
+            Some(value) => self.flag(true).variant(value),
+            None => self.flag(false).count(0),
+        }
+    }
+
+    /// Adds an optional value, held as a flag followed by the value or 0.
+    pub(crate) fn optional(self, value: Option<Num>) -> Self {
+        self.flag(value.is_some()).scalar(value.unwrap_or(0.0))
+    }
+
+    /// Adds an optional flag, held as a count of 0 if none, 1 if false, or 2 if true.
+    pub(crate) fn optional_flag(self, value: Option<bool>) -> Self {
+        self.count(value.map_or(0, |v| 1 + v as usize))
+    }
+
+    /// Adds an optional size or counter, held as a flag followed by the count or 0.
+    pub(crate) fn optional_count(self, value: Option<usize>) -> Self {
+        self.flag(value.is_some()).count(value.unwrap_or(0))
+    }
+
+    /// Adds the values of a buffer.
+    pub(crate) fn buffer(mut self, values: &[Num]) -> Self {
+        self.buffers.push(values.to_vec());
+        self
+    }
+
+    /// Adds the state of an indicator or buffer held.
+    pub(crate) fn child<S: Snapshot>(mut self, value: &S) -> Self {
+        self.children.push(value.snapshot());
+        self
+    }
+
+    /// Adds the state of an optional indicator held, as a flag followed by the state if any.
+    pub(crate) fn optional_child<S: Snapshot>(self, value: Option<&S>) -> Self {
+        let state = self.flag(value.is_some());
+        match value {
+            Some(value) => state.child(value),
+            None => state,
+        }
+    }
+}
+
+/// Enumeration without data held in a state as the position of its variant.
+pub(crate) trait Variants: Copy + PartialEq + 'static {
+    /// Every variant, in the order of their positions.
+    const ALL: &'static [Self];
+}
+
+/// Reads the parts of a state in the order they were added.
+pub(crate) struct StateReader<'a> {
+    /// State being read.
+    state: &'a IndicatorState,
+    /// Position of the next scalar.
+    scalars: usize,
+    /// Position of the next count.
+    counts: usize,
+    /// Position of the next buffer.
+    buffers: usize,
+    /// Position of the next child.
+    children: usize,
+}
+
+impl<'a> StateReader<'a> {
+    /// Creates a new reader if the state was captured from the named type.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - State to read.
+    /// * `name` - Name of the type being restored.
+    pub(crate) fn new(state: &'a IndicatorState, name: &str) -> Result<Self, TAError> {
+        if state.name != name {
+            return Err(TAError::InvalidState(format!(
+                "state of {} cannot restore {}",
+                state.name, name
+            )));
+        }
+
+        Ok(Self {
+            state,
+            scalars: 0,
+            counts: 0,
+            buffers: 0,
+            children: 0,
+        })
+    }
+
+    /// Error for a part missing from the state.
+    fn missing(&self, part: &str) -> TAError {
+        TAError::InvalidState(format!(
+            "state of {} is missing a {}",
+            self.state.name, part
+        ))
+    }
+
+    /// Next value or setting.
+    pub(crate) fn scalar(&mut self) -> Result<Num, TAError> {
+        let value = self.state.scalars.get(self.scalars).copied();
+        self.scalars += 1;
+        value.ok_or_else(|| self.missing("scalar"))
+    }
+
+    /// Next size or counter.
+    pub(crate) fn count(&mut self) -> Result<usize, TAError> {
+        let value = self.state.counts.get(self.counts).copied();
+        self.counts += 1;
+        value.ok_or_else(|| self.missing("count"))
+    }
+
+    /// Next flag.
+    pub(crate) fn flag(&mut self) -> Result<bool, TAError> {
+        match self.count()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(self.missing("flag")),
+        }
+    }
+
+    /// Next variant of the enumeration.
+    pub(crate) fn variant<T: Variants>(&mut self) -> Result<T, TAError> {
+        let index = self.count()?;
+        T::ALL
+            .get(index)
+            .copied()
+            .ok_or_else(|| self.missing("variant"))
+    }
+
+    /// Next optional variant of the enumeration.
+    pub(crate) fn optional_variant<T: Variants>(&mut self) -> Result<Option<T>, TAError> {
+        let is_some = self.flag()?;
+        let value = self.variant()?;
+        Ok(is_some.then_some(value))
+    }
+
+    /// Next optional value.
+    pub(crate) fn optional(&mut self) -> Result<Option<Num>, TAError> {
+        let is_some = self.flag()?;
+        let value = self.scalar()?;
+        Ok(is_some.then_some(value))
+    }
+
+    /// Next optional flag.
+    pub(crate) fn optional_flag(&mut self) -> Result<Option<bool>, TAError> {
+        match self.count()? {
+            0 => Ok(None),
+            1 => Ok(Some(false)),
+            2 => Ok(Some(true)),
+            _ => Err(self.missing("flag")),
+        }
+    }
+
+    /// Next optional size or counter.
+    pub(crate) fn optional_count(&mut self) -> Result<Option<usize>, TAError> {
+        let is_some = self.flag()?;
+        let value = self.count()?;
+        Ok(is_some.then_some(value))
+    }
+
+    /// Next values of a buffer.
+    pub(crate) fn buffer(&mut self) -> Result<&'a [Num], TAError> {
+        let values = self.state.buffers.get(self.buffers);
+        self.buffers += 1;
+        values
+            .map(|v| v.as_slice())
+            .ok_or_else(|| self.missing("buffer"))
+    }
+
+    /// Restores the next indicator or buffer held.
+    pub(crate) fn child<S: Snapshot>(&mut self) -> Result<S, TAError> {
+        let state = self.state.children.get(self.children);
+        self.children += 1;
+        S::restore(state.ok_or_else(|| self.missing("child"))?)
+    }
+
+    /// Restores the next optional indicator held.
+    pub(crate) fn optional_child<S: Snapshot>(&mut self) -> Result<Option<S>, TAError> {
+        match self.flag()? {
+            true => Ok(Some(self.child()?)),
+            false => Ok(None),
+        }
+    }
+}
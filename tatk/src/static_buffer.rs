@@ -0,0 +1,257 @@
+//! Buffer with a capacity fixed at compile time that rotates itself.
+//!
+//! Removes oldest values when a newer value is added. The oldest value is returned.
+//!
+//! Values are held in an array within the buffer itself, no heap allocations are made once
+//! created. Adding a value moves the values already held, intended for small capacities where
+//! the move is cheaper than the allocation of a `Buffer`. The sum is kept with compensated
+//! (Neumaier) summation, the same as `Buffer`.
+use crate::buffer::{compensated_add, variance_of};
+use crate::error::TAError;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{Snapshot, Window};
+use crate::{IndicatorState, Num};
+
+/// Buffer with a capacity fixed at compile time that rotates itself.
+///
+/// Equality compares the values held, oldest to newest.
+#[derive(Debug, Clone, Copy)]
+pub struct StaticBuffer<const N: usize> {
+    /// Values held, Oldest -> Newest. Only the first `len` are held.
+    data: [Num; N],
+    /// Amount of values the buffer currently holds.
+    len: usize,
+    /// Sum of the buffer, without the compensation.
+    sum: Num,
+    /// Rounding error lost from the sum, added back when the sum is obtained.
+    compensation: Num,
+}
+
+impl<const N: usize> StaticBuffer<N> {
+    /// Creates a new buffer from the data provided. If the data's length is less than `N` the
+    /// buffer is partially filled and `is_ready()` will be `false`. If the data's length is >= `N`,
+    /// it takes the last values of data and `is_ready()` will be `true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Total size of the buffer, must be `N` and > 0.
+    /// * `data` - Array of data to fill with. Newest -> Oldest.
+    pub fn from_array(capacity: usize, data: &[Num]) -> Result<Self, TAError> {
+        if capacity == 0 {
+            return Err(TAError::InvalidSize(String::from("capacity cannot be 0")));
+        } else if capacity != N {
+            return Err(TAError::InvalidSize(format!(
+                "capacity must be {} for the static buffer",
+                N
+            )));
+        } else if data.is_empty() {
+            return Err(TAError::InvalidData {
+                indicator: "static buffer",
+                required: 1,
+                received: 0,
+            });
+        }
+
+        let values: &[Num] = &data[data.len().saturating_sub(N)..];
+        let mut buffer = Self {
+            data: [0.0; N],
+            len: values.len(),
+            sum: 0.0,
+            compensation: 0.0,
+        };
+
+        buffer.data[..values.len()].copy_from_slice(values);
+        for value in values.iter() {
+            compensated_add(&mut buffer.sum, &mut buffer.compensation, *value);
+        }
+
+        Ok(buffer)
+    }
+
+    /// Maximum capacity the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Checks if the buffer is ready indicating it has data to meet its capacity.
+    pub fn is_ready(&self) -> bool {
+        self.len >= N
+    }
+
+    /// Gets the oldest value in the buffer, this is the next value that will be removed. NaN if
+    /// the buffer is empty.
+    pub fn oldest(&self) -> Num {
+        self.queue().first().copied().unwrap_or(Num::NAN)
+    }
+
+    /// Gets the newest value in the buffer, this value will current live the longest in the
+    /// buffer. NaN if the buffer is empty.
+    pub fn newest(&self) -> Num {
+        self.queue().last().copied().unwrap_or(Num::NAN)
+    }
+
+    /// Returns the data held by the buffer from Oldest -> Newest. Index 0 being the oldest and
+    /// next value to be removed. Index (len-1) being the newest data.
+    pub fn queue(&self) -> &[Num] {
+        &self.data[..self.len]
+    }
+
+    /// Adds a new (newest) value to the buffer. Oldest value is removed and returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New (newest) value to add to the buffer.
+    pub fn shift(&mut self, value: Num) -> Num {
+        let mut oldest = 0.0;
+        if self.is_ready() {
+            // Move every value towards the oldest, the newest takes the last position.
+            oldest = self.data[0];
+            self.data.copy_within(1.., 0);
+            self.data[N - 1] = value;
+        } else {
+            self.data[self.len] = value;
+            self.len += 1;
+        }
+
+        compensated_add(&mut self.sum, &mut self.compensation, value);
+        compensated_add(&mut self.sum, &mut self.compensation, -oldest);
+        oldest
+    }
+
+    /// Clears the buffer, removing all values. The buffer is empty until a value is added.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.sum = 0.0;
+        self.compensation = 0.0;
+    }
+
+    /// Obtain the sum of the buffer.
+    pub fn sum(&self) -> Num {
+        self.sum + self.compensation
+    }
+
+    /// Calculates the sum of the buffer with each value multiplied by its weight. Weights are
+    /// oldest to newest, extra weights or values are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - Weight of each value, oldest to newest.
+    pub fn weighted_sum(&self, weights: &[Num]) -> Num {
+        self.queue()
+            .iter()
+            .zip(weights.iter())
+            .map(|(v, w)| v * w)
+            .sum()
+    }
+
+    /// Calculates the mean of the buffer.
+    pub fn mean(&self) -> Num {
+        self.sum() / self.len as Num
+    }
+
+    /// Calculates the variance of the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    pub fn variance(&self, is_sample: bool) -> Num {
+        variance_of(self.queue(), self.mean(), is_sample)
+    }
+
+    /// Calculates the standard deviation of the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sample` - If the data is a Sample or Population, default should be True.
+    pub fn stdev(&self, is_sample: bool) -> Num {
+        self.variance(is_sample).sqrt()
+    }
+}
+
+impl<const N: usize> PartialEq for StaticBuffer<N> {
+    /// Compares the values held, ignoring the unused positions of the array.
+    fn eq(&self, other: &Self) -> bool {
+        self.sum() == other.sum() && self.queue() == other.queue()
+    }
+}
+
+impl<const N: usize> Window for StaticBuffer<N> {
+    fn from_array(capacity: usize, data: &[Num]) -> Result<Self, TAError> {
+        Self::from_array(capacity, data)
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.is_ready()
+    }
+
+    fn queue(&self) -> &[Num] {
+        self.queue()
+    }
+
+    fn shift(&mut self, value: Num) -> Num {
+        self.shift(value)
+    }
+
+    fn clear(&mut self) {
+        self.clear()
+    }
+
+    fn sum(&self) -> Num {
+        self.sum()
+    }
+
+    fn weighted_sum(&self, weights: &[Num]) -> Num {
+        self.weighted_sum(weights)
+    }
+
+    fn mean(&self) -> Num {
+        self.mean()
+    }
+
+    fn variance(&self, is_sample: bool) -> Num {
+        self.variance(is_sample)
+    }
+
+    fn stdev(&self, is_sample: bool) -> Num {
+        self.stdev(is_sample)
+    }
+}
+
+impl<const N: usize> Snapshot for StaticBuffer<N> {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("static buffer")
+            .count(N)
+            .scalar(self.sum)
+            .scalar(self.compensation)
+            .buffer(self.queue())
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "static buffer")?;
+        let capacity = reader.count()?;
+        let (sum, compensation) = (reader.scalar()?, reader.scalar()?);
+        let values = reader.buffer()?;
+        if capacity != N || values.len() > N {
+            return Err(TAError::InvalidState(format!(
+                "static buffer must hold at most {} values",
+                N
+            )));
+        }
+
+        let mut buffer = Self {
+            data: [0.0; N],
+            len: values.len(),
+            sum,
+            compensation,
+        };
+
+        buffer.data[..values.len()].copy_from_slice(values);
+        Ok(buffer)
+    }
+}
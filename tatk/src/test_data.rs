@@ -1,7 +1,12 @@
 //! Various data sets for testing.
-use crate::traits::{Close, High, Low, Open, Volume};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::Num;
-use tatk_derive::{Close, High, Low, Open, Volume};
+
+pub mod generate;
+
+/// Holds snapshot / bar data for a product, available without the feature from the crate root.
+pub use crate::Candle;
 
 /// These numbers originate from `TA-Lib`:
 ///
@@ -409,16 +414,6 @@ const TEST_CANDLES: &[(f64, f64, f64, f64, f64)] = &[
     (30772.93, 30499.27, 30882.95, 30189.56, 8782.6192239),
 ];
 
-/// Holds snapshot / bar data for a product.
-#[derive(Copy, Clone, Open, Close, Low, High, Volume)]
-pub struct Candle {
-    pub open: f64,
-    pub close: f64,
-    pub high: f64,
-    pub low: f64,
-    pub volume: f64,
-}
-
 /// Various data sets for testing.
 pub struct TestData;
 impl TestData {
@@ -454,11 +449,11 @@ impl TestData {
         TEST_CANDLES
             .iter()
             .map(|c| Candle {
-                open: c.0,
-                close: c.1,
-                high: c.2,
-                low: c.3,
-                volume: c.4,
+                open: c.0 as Num,
+                close: c.1 as Num,
+                high: c.2 as Num,
+                low: c.3 as Num,
+                volume: c.4 as Num,
             })
             .collect()
     }
@@ -0,0 +1,181 @@
+//! Synthetic data, deterministic series of any length generated from a seed.
+//!
+//! The same seed always generates the same series, allowing long controlled series for tests and
+//! benchmarks without static data. Each step is one bar, drift and volatility are per bar.
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Candle, Num};
+
+/// Pseudo-random numbers from a seed (SplitMix64), the same seed producing the same numbers.
+#[derive(Debug, Clone)]
+struct Rng {
+    /// Current state.
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a new generator from the seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed determining the numbers generated.
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Next random bits.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Next uniform value, greater than 0 and less than 1.
+    fn uniform(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    /// Next value of the standard normal distribution (Box-Muller).
+    fn normal(&mut self) -> Num {
+        let (u1, u2) = (self.uniform(), self.uniform());
+        ((-2.0 * u1.ln()).sqrt() * (core::f64::consts::TAU * u2).cos()) as Num
+    }
+}
+
+/// Settings of generated candles, defaulting to a start of 100.0 without drift, a volatility of
+/// 1% per bar, and a volume of 1000.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CandleParams {
+    /// Open of the first candle.
+    pub start: Num,
+    /// Expected return of each candle.
+    pub drift: Num,
+    /// Standard deviation of the return of each candle.
+    pub volatility: Num,
+    /// Median volume of a candle without movement.
+    pub volume: Num,
+    /// Standard deviation of the logarithm of the volume.
+    pub volume_volatility: Num,
+    /// Additional volume for each standard deviation of movement, 0.5 being 50% more volume.
+    pub volume_move: Num,
+}
+
+impl Default for CandleParams {
+    /// Start (100.0), drift (0.0), volatility (0.01), volume (1000.0), volume volatility (0.25),
+    /// and volume per move (0.5).
+    fn default() -> Self {
+        Self {
+            start: 100.0,
+            drift: 0.0,
+            volatility: 0.01,
+            volume: 1000.0,
+            volume_volatility: 0.25,
+            volume_move: 0.5,
+        }
+    }
+}
+
+/// Closes following geometric Brownian motion, starting with `s0`.
+///
+/// # Formula
+///
+/// S = y * exp(mu - sigma^2 / 2 + sigma * Z)
+///
+/// where:
+///
+/// * `y` = prior close.
+/// * `Z` = standard normal value.
+///
+/// # Arguments
+///
+/// * `seed` - Seed determining the series.
+/// * `n` - Amount of values to generate.
+/// * `s0` - First value.
+/// * `mu` - Drift, the expected return of each step.
+/// * `sigma` - Volatility, the standard deviation of the return of each step.
+pub fn gbm(seed: u64, n: usize, s0: Num, mu: Num, sigma: Num) -> Vec<Num> {
+    let mut rng = Rng::new(seed);
+    let mut value = s0;
+    (0..n)
+        .map(|i| {
+            if i > 0 {
+                value *= (mu - sigma * sigma / 2.0 + sigma * rng.normal()).exp();
+            }
+            value
+        })
+        .collect()
+}
+
+/// Mean-reverting series following an Ornstein-Uhlenbeck process, starting with `x0`.
+///
+/// # Formula
+///
+/// X = y + theta * (mean - y) + sigma * Z
+///
+/// where:
+///
+/// * `y` = prior value.
+/// * `Z` = standard normal value.
+///
+/// # Arguments
+///
+/// * `seed` - Seed determining the series.
+/// * `n` - Amount of values to generate.
+/// * `x0` - First value.
+/// * `mean` - Value the series reverts to.
+/// * `theta` - Speed of the reversion, 0 to 1 of the distance each step.
+/// * `sigma` - Standard deviation of the noise of each step.
+pub fn ou(seed: u64, n: usize, x0: Num, mean: Num, theta: Num, sigma: Num) -> Vec<Num> {
+    let mut rng = Rng::new(seed);
+    let mut value = x0;
+    (0..n)
+        .map(|i| {
+            if i > 0 {
+                value += theta * (mean - value) + sigma * rng.normal();
+            }
+            value
+        })
+        .collect()
+}
+
+/// Candles following geometric Brownian motion, each open being the prior close. The high and
+/// low extend past the open and close by the volatility, and larger moves trade more volume.
+///
+/// # Arguments
+///
+/// * `seed` - Seed determining the candles.
+/// * `n` - Amount of candles to generate.
+/// * `params` - Settings of the candles.
+pub fn candles(seed: u64, n: usize, params: &CandleParams) -> Vec<Candle> {
+    let mut rng = Rng::new(seed);
+    let sigma = params.volatility;
+    let mut close = params.start;
+
+    (0..n)
+        .map(|_| {
+            let open = close;
+            let shock = rng.normal();
+            close = open * (params.drift - sigma * sigma / 2.0 + sigma * shock).exp();
+
+            // Wicks past the body, half the volatility on average.
+            let high = open.max(close) * (1.0 + rng.normal().abs() * sigma / 2.0);
+            let low = open.min(close) * (1.0 - rng.normal().abs() * sigma / 2.0).max(0.0);
+
+            let spread = params.volume_volatility;
+            let volume = params.volume
+                * (spread * rng.normal()).exp()
+                * (1.0 + params.volume_move * shock.abs());
+
+            Candle {
+                open,
+                close,
+                high,
+                low,
+                volume,
+            }
+        })
+        .collect()
+}
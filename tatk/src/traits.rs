@@ -1,5 +1,5 @@
 //! Traits for both indicators and user-defined data types.
-use crate::Num;
+use crate::{IndicatorState, Num, Signal, TAError};
 
 /// Indicator: Statistics for the indicator.
 pub trait Stats {
@@ -19,12 +19,52 @@ pub trait Period {
     fn period(&self) -> usize;
 }
 
+/// Indicator: Current value for an indicator, the most recent value calculated.
+pub trait Value {
+    /// Current and most recent value calculated.
+    fn value(&self) -> Num;
+}
+
 /// Indicator: Current internal value for an indicator.
 pub trait InternalValue {
     /// Current alue for an indicator.
     fn internal_value(&self) -> Num;
 }
 
+/// Indicator: Clears an indicator back to having seen no data.
+pub trait Reset {
+    /// Clears the indicator back to having seen no data. Indicators that require initial data on
+    /// creation collect the values supplied afterwards, recalculating once they have as many
+    /// values as creation requires. Until then the value is a partial value where one can be
+    /// calculated, otherwise 0.
+    fn reset(&mut self);
+}
+
+/// Indicator: Whether an indicator has been supplied enough data for its value to be complete.
+pub trait Ready {
+    /// Checks if enough data has been supplied for the value to be complete, false while
+    /// collecting the initial data after being created without data or reset.
+    fn is_ready(&self) -> bool;
+
+    /// Amount of data supplied since the indicator was created or reset, including the initial
+    /// data it was created from.
+    fn samples_seen(&self) -> usize;
+}
+
+/// Indicator: Captures the state of an indicator to be restored later, such as after a restart.
+/// The restored indicator produces the same output as if it never stopped.
+pub trait Snapshot: Sized {
+    /// Plain state of the indicator, including the indicators and buffers it holds.
+    fn snapshot(&self) -> IndicatorState;
+
+    /// Recreates the indicator from a state captured with `snapshot`.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - State captured from the same type of indicator.
+    fn restore(state: &IndicatorState) -> Result<Self, TAError>;
+}
+
 /// Indicator: Add new data to an indicator.
 pub trait Next<T> {
     /// Output from the function.
@@ -32,11 +72,12 @@ pub trait Next<T> {
 
     /// Supply additional data to calculate the next value for the indicator.
     ///
-    /// # Return depends on indicator, all values are in the form of `Num`.
+    /// # Return depends on indicator.
     ///
-    /// * Default: `value`
-    /// * MACD:    (`signal`, `short`, `long`)
-    /// * BBands:  (`lower`, `value`, `upper`)
+    /// * Default: `value` as a `Num`
+    /// * MACD:    `MacdOutput { macd, signal, histogram }`
+    /// * BBands:  `BandsOutput { lower, middle, upper }`
+    /// * Others:  the indicator's `Output`, such as a `CrossEvent` for a Cross
     ///
     /// # Arguments
     ///
@@ -44,6 +85,100 @@ pub trait Next<T> {
     fn next(&mut self, value: T) -> Self::Output;
 }
 
+/// Indicator: Add new data to an indicator, only producing output once the indicator is ready.
+/// Implemented for every indicator that is both `Next` and `Ready`.
+pub trait TryNext<T>: Next<T> + Ready {
+    /// Supply additional data to calculate the next value for the indicator. None while the
+    /// indicator has not been supplied enough data for the output to be complete, the data is
+    /// still used to warm up the indicator.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New data to add to the indicator.
+    fn try_next(&mut self, value: T) -> Option<Self::Output> {
+        let output = self.next(value);
+        self.is_ready().then_some(output)
+    }
+}
+
+impl<T, I> TryNext<T> for I where I: Next<T> + Ready {}
+
+/// Indicator: Add new data to an indicator, rejecting NaN or infinite data instead of using it.
+pub trait CheckedNext<T>: Next<T> {
+    /// Supply additional data to calculate the next value for the indicator. Errors with
+    /// `TAError::InvalidInput` if the data is NaN or infinite, the indicator is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New data to add to the indicator.
+    fn checked_next(&mut self, value: T) -> Result<Self::Output, TAError>;
+}
+
+/// Storage: Rolling window of values with a maximum capacity, the oldest value is removed as a
+/// newer value is added. Implemented by `Buffer` and `StaticBuffer`.
+pub trait Window {
+    /// Creates a new window from the data provided, keeping the last `capacity` values.
+    fn from_array(capacity: usize, data: &[Num]) -> Result<Self, TAError>
+    where
+        Self: Sized;
+    /// Maximum capacity the window can hold.
+    fn capacity(&self) -> usize;
+    /// Checks if the window holds enough values to meet its capacity.
+    fn is_ready(&self) -> bool;
+    /// Values held by the window from Oldest -> Newest.
+    fn queue(&self) -> &[Num];
+    /// Adds a new (newest) value to the window. Oldest value is removed and returned.
+    fn shift(&mut self, value: Num) -> Num;
+    /// Clears the window, removing all values.
+    fn clear(&mut self);
+    /// Sum of the values held.
+    fn sum(&self) -> Num;
+    /// Sum of the values held each multiplied by its weight, weights are oldest to newest.
+    fn weighted_sum(&self, weights: &[Num]) -> Num;
+    /// Mean of the values held.
+    fn mean(&self) -> Num;
+    /// Variance of the values held.
+    fn variance(&self, is_sample: bool) -> Num;
+    /// Standard deviation of the values held.
+    fn stdev(&self, is_sample: bool) -> Num;
+}
+
+/// Indicator: Indicator that consumes single values, usable as a trait object to hold different
+/// indicators together such as in a `Vec<Box<dyn Indicator>>`.
+pub trait Indicator: Period {
+    /// Supply an additional value to recalculate the indicator, the same as `next`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value to add to the indicator.
+    fn update(&mut self, value: Num);
+    /// Current and most recent value calculated.
+    fn value(&self) -> Num;
+    /// Short name of the indicator.
+    fn name(&self) -> &'static str;
+}
+
+/// Indicator: Indicator that consumes candles, usable as a trait object to hold different
+/// indicators together such as in a `Vec<Box<dyn CandleIndicator>>`.
+pub trait CandleIndicator: Period {
+    /// Supply an additional candle to recalculate the indicator, the same as `next`.
+    ///
+    /// # Arguments
+    ///
+    /// * `candle` - New candle to add to the indicator.
+    fn update(&mut self, candle: &dyn Ohlcv);
+    /// Current and most recent value calculated.
+    fn value(&self) -> Num;
+    /// Short name of the indicator.
+    fn name(&self) -> &'static str;
+}
+
+/// Indicator: Trading interpretation of the indicator's current state.
+pub trait SignalSource {
+    /// Current signal, `Hold` while the indicator suggests neither buying nor selling.
+    fn signal(&self) -> Signal;
+}
+
 /// User Defined: Specialized value to pass to indicators. Values such as HL, HLC, OHLC. etc
 pub trait AsValue {
     /// User defined value to pass to indicators. Values such as HL, HLC, OHLC. etc
@@ -56,30 +191,96 @@ pub trait Open {
     fn open(&self) -> Num;
 }
 
+// Opening value of the referenced data type, candles can be supplied by reference.
+impl<T> Open for &T
+where
+    T: Open + ?Sized,
+{
+    fn open(&self) -> Num {
+        (**self).open()
+    }
+}
+
 /// User Defined: Closing value for the data type.
 pub trait Close {
     /// Closing value for the data type.
     fn close(&self) -> Num;
 }
 
+// Closing value of the referenced data type, candles can be supplied by reference.
+impl<T> Close for &T
+where
+    T: Close + ?Sized,
+{
+    fn close(&self) -> Num {
+        (**self).close()
+    }
+}
+
 /// User Defined: Highest value for the data type.
 pub trait High {
     /// Highest value for the data type.
     fn high(&self) -> Num;
 }
 
+// Highest value of the referenced data type, candles can be supplied by reference.
+impl<T> High for &T
+where
+    T: High + ?Sized,
+{
+    fn high(&self) -> Num {
+        (**self).high()
+    }
+}
+
 /// User Defined: Lowest value for the data type.
 pub trait Low {
     /// Lowest value for the data type.
     fn low(&self) -> Num;
 }
 
+// Lowest value of the referenced data type, candles can be supplied by reference.
+impl<T> Low for &T
+where
+    T: Low + ?Sized,
+{
+    fn low(&self) -> Num {
+        (**self).low()
+    }
+}
+
 /// User Defined: Total volume for the data type.
 pub trait Volume {
     /// Total volume for the data type.
     fn volume(&self) -> Num;
 }
 
+// Total volume value of the referenced data type, candles can be supplied by reference.
+impl<T> Volume for &T
+where
+    T: Volume + ?Sized,
+{
+    fn volume(&self) -> Num {
+        (**self).volume()
+    }
+}
+
+/// User Defined: Timestamp for the data type, such as the open time of a candle.
+pub trait Timestamped {
+    /// Timestamp for the data type, in any unit used consistently.
+    fn timestamp(&self) -> i64;
+}
+
+// Timestamp of the referenced data type, candles can be supplied by reference.
+impl<T> Timestamped for &T
+where
+    T: Timestamped + ?Sized,
+{
+    fn timestamp(&self) -> i64 {
+        (**self).timestamp()
+    }
+}
+
 /// Average between High and Low traits.
 pub trait Hl2: High + Low {
     /// Average between High and Low traits.
@@ -103,3 +304,9 @@ pub trait Ohlc4: Open + High + Low + Close {
         (self.open() + self.high() + self.low() + self.close()) / 4.0 as Num
     }
 }
+
+/// Open, High, Low, Close, and Volume of a candle. Implemented for every type with the five
+/// traits, used to pass candles to a `CandleIndicator`.
+pub trait Ohlcv: Open + High + Low + Close + Volume {}
+
+impl<T> Ohlcv for T where T: Open + High + Low + Close + Volume {}
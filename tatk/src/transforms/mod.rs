@@ -0,0 +1,5 @@
+//! Transforms that convert a stream of data into the input of other indicators.
+mod returns;
+
+pub use crate::indicators::ReturnKind;
+pub use returns::Returns;
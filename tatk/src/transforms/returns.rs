@@ -0,0 +1,171 @@
+//! Returns, converts a stream of prices into simple or logarithmic returns.
+//!
+//! The first price only provides the prior price, it produces no return.
+//!
+//! # Formula
+//!
+//! Simple = (x - y) / y
+//!
+//! Log = ln(x / y)
+//!
+//! where:
+//!
+//! * `x` = current price (most recent)
+//! * `y` = prior price
+
+use crate::indicators::ReturnKind;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::snapshot::StateReader;
+use crate::traits::{AsValue, Next, Reset, Snapshot};
+use crate::{IndicatorState, Num, TAError};
+
+/// Returns, converts a stream of prices into simple or logarithmic returns.
+///
+/// The first price only provides the prior price, it produces no return.
+///
+/// # Formula
+///
+/// Simple = (x - y) / y
+///
+/// Log = ln(x / y)
+///
+/// where:
+///
+/// * `x` = current price (most recent)
+/// * `y` = prior price
+#[derive(Debug, Clone, PartialEq)]
+pub struct Returns {
+    /// Method used to convert prices into returns.
+    kind: ReturnKind,
+    /// Last price received.
+    last: Option<Num>,
+    /// Return of the last price, none for the first price.
+    value: Option<Num>,
+}
+
+impl Returns {
+    /// Creates a new Returns without any prior price.
+    ///
+    /// ## Arguments
+    ///
+    /// * `kind` - Method used to convert prices into returns.
+    pub fn new(kind: ReturnKind) -> Self {
+        Self {
+            kind,
+            last: None,
+            value: None,
+        }
+    }
+
+    /// Converts the prices into returns, one less return than prices.
+    ///
+    /// ### Requirements:
+    ///
+    /// * Log returns require all prices to be greater than 0.
+    /// * Simple returns require all prices to not be 0.
+    ///
+    /// ## Arguments
+    ///
+    /// * `kind` - Method used to convert prices into returns.
+    /// * `data` - Array of prices to convert.
+    pub fn compute(kind: ReturnKind, data: &[Num]) -> Result<Vec<Num>, TAError> {
+        let mut returns = Self::new(kind);
+        let mut values: Vec<Num> = Vec::with_capacity(data.len().saturating_sub(1));
+        for v in data.iter() {
+            if let Some(r) = returns.next(*v)? {
+                values.push(r);
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Method used to convert prices into returns.
+    pub fn kind(&self) -> ReturnKind {
+        self.kind
+    }
+
+    /// Return of the last price, none if there was no prior price.
+    pub fn value(&self) -> Option<Num> {
+        self.value
+    }
+
+    /// Checks if the price can be converted with the prior price.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Price to check.
+    fn validate(&self, value: Num) -> Result<(), TAError> {
+        match self.kind {
+            ReturnKind::Log if value <= 0.0 => Err(TAError::InvalidInput(String::from(
+                "prices must be greater than 0 to calculate log returns",
+            ))),
+            ReturnKind::Simple if value == 0.0 => Err(TAError::InvalidInput(String::from(
+                "prices cannot be 0 to calculate simple returns",
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Next<Num> for Returns {
+    /// Return of the price, none for the first price.
+    type Output = Result<Option<Num>, TAError>;
+
+    /// Supply an additional price to calculate the return from the prior price. Invalid prices
+    /// produce an error and are not kept as the prior price.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New price to convert.
+    fn next(&mut self, value: Num) -> Self::Output {
+        self.validate(value)?;
+        self.value = self.last.map(|last| self.kind.calculate(last, value));
+        self.last = Some(value);
+        Ok(self.value)
+    }
+}
+
+impl<T> Next<T> for Returns
+where
+    T: AsValue,
+{
+    /// Return of the price, none for the first price.
+    type Output = Result<Option<Num>, TAError>;
+
+    /// Supply an additional price to calculate the return from the prior price.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New price to convert.
+    fn next(&mut self, value: T) -> Self::Output {
+        self.next(value.as_value())
+    }
+}
+
+impl Reset for Returns {
+    /// Clears the prior price, the next price only provides the prior price.
+    fn reset(&mut self) {
+        self.last = None;
+        self.value = None;
+    }
+}
+
+impl Snapshot for Returns {
+    fn snapshot(&self) -> IndicatorState {
+        IndicatorState::new("Returns")
+            .variant(self.kind)
+            .optional(self.last)
+            .optional(self.value)
+    }
+
+    fn restore(state: &IndicatorState) -> Result<Self, TAError> {
+        let mut reader = StateReader::new(state, "Returns")?;
+        Ok(Self {
+            kind: reader.variant()?,
+            last: reader.optional()?,
+            value: reader.optional()?,
+        })
+    }
+}
@@ -52,6 +52,124 @@ fn next_ema() {
     assert_eq!(indicator.next(DATA[DATA.len() - 1]), 91.6049548722671)
 }
 
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate a Smoothed Moving Average using 19 data points with a period of 10.
+fn create_smma() {
+    use tatk::indicators::SmoothedMovingAverage;
+    use tatk::test_data::TestData;
+
+    const DATA: &[f64] = TestData::talib_small();
+
+    let indicator = SmoothedMovingAverage::new(10, &DATA[..DATA.len() - 1]).unwrap();
+    assert_eq!(indicator.value(), 92.61032343089451)
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Smoothed Moving Average from 19 data points and a period of 10, then adds an
+/// additional data point to move the ensure the window of viewed is moving.
+fn next_smma() {
+    use tatk::indicators::SmoothedMovingAverage;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let mut indicator = SmoothedMovingAverage::new(10, &DATA[..DATA.len() - 1]).unwrap();
+    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 92.33679108780505)
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// An Exponential Moving Average with an alpha of `1 / period` is Wilder's smoothing, matching a
+/// Smoothed Moving Average of the same period.
+fn ema_with_alpha() {
+    use tatk::indicators::{ExponentialMovingAverage, SmoothedMovingAverage};
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Period};
+    use tatk::TAError;
+    const DATA: &[f64] = TestData::talib();
+
+    let mut smma = SmoothedMovingAverage::new(10, &DATA[..10]).unwrap();
+    let mut ema = ExponentialMovingAverage::with_alpha(10, &DATA[..10], 0.1).unwrap();
+    assert_eq!(ema.period(), 10);
+    assert_eq!(ema.alpha(), 0.1);
+    assert_eq!(
+        ExponentialMovingAverage::new(10, DATA).unwrap().alpha(),
+        2.0 / 11.0
+    );
+
+    for value in DATA[10..].iter() {
+        assert!((ema.next(*value) - smma.next(*value)).abs() < 1e-9);
+    }
+
+    for alpha in [0.0, 1.5, f64::NAN] {
+        assert!(matches!(
+            ExponentialMovingAverage::with_alpha(10, DATA, alpha),
+            Err(TAError::InvalidInput(_))
+        ));
+    }
+    assert!(ExponentialMovingAverage::with_alpha(10, DATA, 1.0).is_ok());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Each starting EMA gives different early values that converge as more values are supplied,
+/// passed down to the EMAs of a DEMA and MACD.
+fn ema_seeding() {
+    use tatk::indicators::{
+        DoubleExponentialMovingAverage, EmaSeed, ExponentialMovingAverage,
+        MovingAverageConvergenceDivergence,
+    };
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+
+    let seeds = [EmaSeed::FirstValue, EmaSeed::Custom(0.0)];
+    let mut sma = ExponentialMovingAverage::new(10, &DATA[..10]).unwrap();
+    let mut others: Vec<ExponentialMovingAverage> = seeds
+        .iter()
+        .map(|seed| ExponentialMovingAverage::with_seed(10, &DATA[..10], *seed).unwrap())
+        .collect();
+
+    // First value is the start of the smoothing, a custom start smooths every value from it.
+    let first = ExponentialMovingAverage::with_seed(10, &DATA[..1], EmaSeed::FirstValue);
+    assert!(first.is_err());
+    let first = ExponentialMovingAverage::with_seed(1, &DATA[..1], EmaSeed::FirstValue).unwrap();
+    assert_eq!(first.value(), DATA[0]);
+    let custom = ExponentialMovingAverage::with_seed(1, &DATA[..1], EmaSeed::Custom(0.0)).unwrap();
+    assert_eq!(custom.value(), DATA[0]);
+
+    for other in others.iter() {
+        assert!((sma.value() - other.value()).abs() > 0.1);
+    }
+
+    for value in DATA[10..].iter() {
+        sma.next(*value);
+        for other in others.iter_mut() {
+            other.next(*value);
+        }
+    }
+
+    for other in others.iter() {
+        assert!((sma.value() - other.value()).abs() < 1e-9);
+    }
+
+    // Lines built from EMAs keep the starting EMA.
+    let dema = DoubleExponentialMovingAverage::new(10, &DATA[..19]).unwrap();
+    let first_dema =
+        DoubleExponentialMovingAverage::with_seed(10, &DATA[..19], EmaSeed::FirstValue).unwrap();
+    assert_eq!(first_dema.seeding(), EmaSeed::FirstValue);
+    assert!((dema.value() - first_dema.value()).abs() > 0.1);
+
+    let macd = MovingAverageConvergenceDivergence::new(12, 26, 9, &DATA[..34]).unwrap();
+    let first_macd = MovingAverageConvergenceDivergence::builder()
+        .ema_seed(EmaSeed::FirstValue)
+        .build(&DATA[..34])
+        .unwrap();
+    assert_ne!(macd.value(), first_macd.value());
+}
+
 #[test]
 #[cfg(feature = "test-data")]
 /// Create and calculate a Double Exponential Moving Average using 19 data points with a period of 10.
@@ -160,3 +278,1093 @@ fn next_roc() {
     let mut indicator = RateOfChange::new(10, &DATA[..DATA.len() - 1]).unwrap();
     assert_eq!(indicator.next(DATA[DATA.len() - 1]), -2.806315561803827)
 }
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate a SROC using 251 data points with an EMA period of 13 and ROC period of 21.
+fn create_sroc() {
+    use tatk::indicators::{ExponentialMovingAverage, RateOfChange};
+    use tatk::sroc;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+    assert!(sroc!(13, 21, &DATA[..33]).is_err());
+
+    let indicator = sroc!(13, 21, DATA).unwrap();
+    assert_eq!(indicator.value(), 6.977878095962533);
+
+    // Chaining the EMA and ROC manually.
+    let mut ema = ExponentialMovingAverage::new(13, &DATA[..13]).unwrap();
+    let mut emas: Vec<f64> = vec![ema.value()];
+    for v in DATA[13..].iter() {
+        emas.push(ema.next(*v));
+    }
+    let roc = RateOfChange::new(21, &emas).unwrap();
+    assert_eq!(indicator.value(), roc.value());
+    assert_eq!(indicator.ema(), ema.value());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a SROC from 250 data points, then adds an additional data point.
+fn next_sroc() {
+    use tatk::indicators::SmoothedRateOfChange;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+
+    let mut indicator = SmoothedRateOfChange::new(13, 21, &DATA[..DATA.len() - 1]).unwrap();
+    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 6.977878095962533);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate an Acceleration using 19 data points with periods of 5 and 3, checking
+/// the exact amount of data required.
+fn create_acceleration() {
+    use tatk::indicators::{Acceleration, RateOfChange};
+    use tatk::test_data::TestData;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let indicator = Acceleration::new(5, 3, &DATA[..DATA.len() - 1]).unwrap();
+    assert_eq!(indicator.value(), -156.54673115143973);
+    assert_eq!(
+        indicator.roc(),
+        RateOfChange::new(5, &DATA[..DATA.len() - 1])
+            .unwrap()
+            .value()
+    );
+
+    // Requires `5 + 3 + 1` data points.
+    assert!(Acceleration::new(5, 3, &DATA[..9]).is_ok());
+    assert!(Acceleration::new(5, 3, &DATA[..8]).is_err());
+    assert!(Acceleration::new(1, 3, DATA).is_err());
+    assert!(Acceleration::new(5, 1, DATA).is_err());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates an Acceleration from 18 data points, then adds an additional data point comparing
+/// against chaining two Rate of Changes by hand.
+fn next_acceleration() {
+    use tatk::indicators::{Acceleration, RateOfChange};
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let mut indicator = Acceleration::new(5, 3, &DATA[..DATA.len() - 1]).unwrap();
+    assert_eq!(indicator.next(DATA[DATA.len() - 1]), -85.14803602820898);
+
+    // First ROC for every point, then the ROC of those.
+    let mut roc = RateOfChange::new(5, &DATA[..6]).unwrap();
+    let mut rocs: Vec<f64> = vec![roc.value()];
+    for v in DATA[6..].iter() {
+        rocs.push(roc.next(*v));
+    }
+    let expected = RateOfChange::new(3, &rocs).unwrap();
+    assert_eq!(indicator.value(), expected.value());
+    assert_eq!(indicator.roc(), roc.value());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate a MidPoint using 252 data points with a period of 14.
+fn create_midpoint() {
+    use tatk::midpoint;
+    use tatk::test_data::TestData;
+    const DATA: &[f64] = TestData::talib();
+
+    let indicator = midpoint!(14, &DATA[..DATA.len() - 1]).unwrap();
+    assert_eq!(indicator.value(), 108.56)
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a MidPoint from 252 data points and a period of 14, then adds additional data points,
+/// comparing against TA-Lib's definition of the highest and lowest value of the period.
+fn next_midpoint() {
+    use tatk::indicators::MidPoint;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+    let period: usize = 14;
+
+    let mut indicator = MidPoint::new(period, &DATA[..period]).unwrap();
+    for end in (period + 1)..=DATA.len() {
+        let window = &DATA[(end - period)..end];
+        let max = window.iter().cloned().fold(f64::MIN, f64::max);
+        let min = window.iter().cloned().fold(f64::MAX, f64::min);
+        assert_eq!(indicator.next(DATA[end - 1]), (max + min) / 2.0);
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate a MidPrice using 364 candles with a period of 14.
+fn create_midprice() {
+    use tatk::midprice;
+    use tatk::test_data::{Candle, TestData};
+    let candles: Vec<Candle> = TestData::candles();
+
+    let indicator = midprice!(14, &candles[..candles.len() - 1]).unwrap();
+    assert_eq!(indicator.value(), 30693.605)
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a MidPrice from 364 candles and a period of 14, then adds an additional candle.
+fn next_midprice() {
+    use tatk::indicators::MidPrice;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+    let last = candles[candles.len() - 1];
+
+    let mut indicator = MidPrice::new(14, &candles[..candles.len() - 1]).unwrap();
+    assert_eq!(indicator.next((last.high, last.low)), 30693.605)
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate a Median using 19 data points with an even period of 10 and odd period of 9.
+fn create_median() {
+    use tatk::indicators::Median;
+    use tatk::test_data::TestData;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let indicator = Median::new(10, &DATA[..DATA.len() - 1]).unwrap();
+    assert_eq!(indicator.value(), 92.0475);
+
+    let indicator = Median::new(9, &DATA[..DATA.len() - 1]).unwrap();
+    assert_eq!(indicator.value(), 91.625);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Median from 252 data points, then adds additional data points comparing against
+/// sorting the entire period and the buffer's median.
+fn next_median() {
+    use tatk::indicators::Median;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    use tatk::Buffer;
+    const DATA: &[f64] = TestData::talib();
+
+    for period in [1, 2, 9, 10] {
+        let mut indicator = Median::new(period, &DATA[..period]).unwrap();
+        for end in (period + 1)..=DATA.len() {
+            let mut window = DATA[(end - period)..end].to_vec();
+            window.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let expected = if period % 2 == 0 {
+                (window[period / 2 - 1] + window[period / 2]) / 2.0
+            } else {
+                window[period / 2]
+            };
+
+            let buffer = Buffer::from_array(period, &DATA[..end]).unwrap();
+            assert_eq!(indicator.next(DATA[end - 1]), expected);
+            assert_eq!(buffer.median(), expected);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates an Alligator with the common settings, comparing the displaced lines against SMMAs
+/// that stop `shift` candles early.
+fn create_alligator() {
+    use tatk::indicators::{Alligator, SmoothedMovingAverage};
+    use tatk::test_data::{Candle, TestData};
+    let candles: Vec<Candle> = TestData::candles();
+    let hl2: Vec<f64> = candles.iter().map(|c| (c.high + c.low) / 2.0).collect();
+
+    let indicator = Alligator::with_defaults(&candles).unwrap();
+    assert_eq!(indicator.jaw(), 29615.470318713888);
+    assert_eq!(indicator.teeth(), 30223.22486021151);
+    assert_eq!(indicator.lips(), 30364.56939358114);
+
+    for (period, shift, displaced, undisplaced) in [
+        (13, 8, indicator.jaw(), indicator.jaw_undisplaced()),
+        (8, 5, indicator.teeth(), indicator.teeth_undisplaced()),
+        (5, 3, indicator.lips(), indicator.lips_undisplaced()),
+    ] {
+        let early = SmoothedMovingAverage::new(period, &hl2[..hl2.len() - shift]).unwrap();
+        let full = SmoothedMovingAverage::new(period, &hl2).unwrap();
+        assert_eq!(displaced, early.value());
+        assert_eq!(undisplaced, full.value());
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Adds candles to an Alligator, each displaced line must equal its undisplaced value from
+/// `shift` candles prior.
+fn next_alligator() {
+    use tatk::indicators::Alligator;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+    let start: usize = 21;
+
+    let mut indicator = Alligator::with_defaults(&candles[..start]).unwrap();
+    let mut history: Vec<(f64, f64, f64)> = Vec::new();
+    for candle in candles[start..].iter() {
+        let (jaw, teeth, lips) = indicator.next(candle);
+        history.push((
+            indicator.jaw_undisplaced(),
+            indicator.teeth_undisplaced(),
+            indicator.lips_undisplaced(),
+        ));
+
+        let last = history.len() - 1;
+        if last >= 8 {
+            assert_eq!(jaw, history[last - 8].0);
+            assert_eq!(teeth, history[last - 5].1);
+            assert_eq!(lips, history[last - 3].2);
+        }
+    }
+
+    // Tuple input behaves the same as a candle.
+    let mut tuples = Alligator::with_defaults(&candles[..start]).unwrap();
+    let mut candle = Alligator::with_defaults(&candles[..start]).unwrap();
+    for c in candles[start..].iter() {
+        assert_eq!(tuples.next((c.high, c.low)), candle.next(c));
+    }
+}
+
+#[test]
+/// A shift of 0 has no displacement, not enough data for the shift is rejected, and constant data
+/// puts the Alligator to sleep.
+fn alligator_displacement() {
+    use tatk::indicators::Alligator;
+    use tatk::traits::Next;
+    let data: Vec<(f64, f64)> = (0..10).map(|v| (v as f64 + 1.0, v as f64)).collect();
+    let candles: Vec<HighLow> = data.iter().map(|v| HighLow(v.0, v.1)).collect();
+
+    // Exactly enough data for the largest period and shift.
+    assert!(Alligator::new((5, 5), (3, 0), (2, 0), &candles).is_ok());
+    assert!(Alligator::new((5, 6), (3, 0), (2, 0), &candles).is_err());
+    assert!(Alligator::new((0, 0), (3, 0), (2, 0), &candles).is_err());
+
+    let mut indicator = Alligator::new((5, 0), (3, 0), (2, 0), &candles).unwrap();
+    assert_eq!(indicator.jaw(), indicator.jaw_undisplaced());
+    indicator.next((20.0, 19.0));
+    assert_eq!(indicator.lips(), indicator.lips_undisplaced());
+
+    // Rising data has the lips above the teeth above the jaw.
+    assert!(indicator.is_eating());
+    assert!(!indicator.is_sleeping(0.5));
+
+    let flat = vec![HighLow(2.0, 1.0); 10];
+    let indicator = Alligator::new((5, 3), (3, 2), (2, 1), &flat).unwrap();
+    assert!(indicator.is_sleeping(0.0));
+    assert!(!indicator.is_eating());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Gator Oscillator with the common settings, comparing the histograms against the
+/// Alligator's displaced lines.
+fn create_gator() {
+    use tatk::indicators::{Alligator, GatorOscillator};
+    use tatk::test_data::{Candle, TestData};
+    let candles: Vec<Candle> = TestData::candles();
+
+    let indicator = GatorOscillator::with_defaults(&candles).unwrap();
+    let alligator = Alligator::with_defaults(&candles).unwrap();
+    assert_eq!(indicator.upper(), 607.754541497623);
+    assert_eq!(indicator.lower(), -141.34453336962906);
+    assert_eq!(
+        indicator.upper(),
+        (alligator.jaw() - alligator.teeth()).abs()
+    );
+    assert_eq!(
+        indicator.lower(),
+        -(alligator.teeth() - alligator.lips()).abs()
+    );
+
+    // Not enough data for the Alligator.
+    assert!(GatorOscillator::with_defaults(&candles[..20]).is_err());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Adds candles to a Gator Oscillator, the expanding flags must compare against the prior bar.
+fn next_gator() {
+    use tatk::indicators::GatorOscillator;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+
+    let mut indicator = GatorOscillator::with_defaults(&candles[..21]).unwrap();
+    let first = indicator.value();
+    assert!(!first.is_upper_expanding && !first.is_lower_expanding);
+
+    let mut last = first;
+    for candle in candles[21..].iter() {
+        let bar = indicator.next(candle);
+        assert!(bar.upper >= 0.0 && bar.lower <= 0.0);
+        assert_eq!(bar.is_upper_expanding, bar.upper > last.upper);
+        assert_eq!(bar.is_lower_expanding, bar.lower < last.lower);
+        last = bar;
+    }
+
+    // Creating from all the candles is the same as adding them.
+    let created = GatorOscillator::with_defaults(&candles).unwrap();
+    assert_eq!(created.value(), last);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate a Typical Price using 364 candles, unsmoothed and with a period of 10.
+fn create_typical_price() {
+    use tatk::indicators::TypicalPrice;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::AsValue;
+    let candles: Vec<Candle> = TestData::candles();
+    let last = candles[candles.len() - 1];
+
+    let indicator = TypicalPrice::new(1, &candles).unwrap();
+    assert_eq!(indicator.value(), (last.high + last.low + last.close) / 3.0);
+    assert_eq!(indicator.value(), TypicalPrice::price(&last));
+
+    let indicator = TypicalPrice::new(10, &candles).unwrap();
+    assert_eq!(indicator.value(), 30500.735333333334);
+    assert_eq!(indicator.as_value(), indicator.value());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Typical Price from 363 candles with a period of 10, then adds an additional candle
+/// as both a candle and a tuple.
+fn next_typical_price() {
+    use tatk::indicators::TypicalPrice;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+    let last = candles[candles.len() - 1];
+
+    let mut indicator = TypicalPrice::new(10, &candles[..candles.len() - 1]).unwrap();
+    let mut tuple = TypicalPrice::new(10, &candles[..candles.len() - 1]).unwrap();
+    assert_eq!(indicator.next(&last), 30500.735333333334);
+    assert_eq!(
+        tuple.next((last.high, last.low, last.close)),
+        indicator.value()
+    );
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate a Weighted Close using 364 candles, unsmoothed and with a period of 10.
+fn create_weighted_close() {
+    use tatk::indicators::WeightedClose;
+    use tatk::test_data::{Candle, TestData};
+    let candles: Vec<Candle> = TestData::candles();
+    let last = candles[candles.len() - 1];
+
+    let indicator = WeightedClose::new(1, &candles).unwrap();
+    assert_eq!(
+        indicator.value(),
+        (last.high + last.low + 2.0 * last.close) / 4.0
+    );
+
+    let indicator = WeightedClose::new(10, &candles).unwrap();
+    assert_eq!(indicator.value(), 30486.586499999998);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Weighted Close from 363 candles with a period of 10, then adds an additional candle
+/// as both a candle and a tuple.
+fn next_weighted_close() {
+    use tatk::indicators::WeightedClose;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+    let last = candles[candles.len() - 1];
+
+    let mut indicator = WeightedClose::new(10, &candles[..candles.len() - 1]).unwrap();
+    let mut tuple = WeightedClose::new(10, &candles[..candles.len() - 1]).unwrap();
+    assert_eq!(indicator.next(&last), 30486.586499999998);
+    assert_eq!(
+        tuple.next((last.high, last.low, last.close)),
+        indicator.value()
+    );
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Signal line of an On-Balance Volume matches an EMA computed from the OBV values.
+fn obv_signal_ema() {
+    use tatk::indicators::{MaKind, OnBalanceVolume};
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+    let (period, signal_period) = (10, 9);
+
+    // OBV values and their EMA computed by hand.
+    let mut series: Vec<f64> = vec![0.0];
+    for w in candles.windows(2) {
+        let last = series[series.len() - 1];
+        series.push(match w[1].close {
+            c if c > w[0].close => last + w[1].volume,
+            c if c < w[0].close => last - w[1].volume,
+            _ => last,
+        });
+    }
+
+    let k = 2.0 / (signal_period + 1) as f64;
+    let mut ema: Vec<f64> =
+        vec![series[..signal_period].iter().sum::<f64>() / signal_period as f64];
+    for v in series[signal_period..].iter() {
+        ema.push((v - ema[ema.len() - 1]) * k + ema[ema.len() - 1]);
+    }
+
+    let mut indicator = OnBalanceVolume::with_signal(
+        period,
+        signal_period,
+        MaKind::Ema,
+        &candles[..candles.len() - 1],
+    )
+    .unwrap();
+    let expected = ema[ema.len() - 2];
+    assert!((indicator.signal().unwrap() - expected).abs() < 1e-6);
+    assert_eq!(indicator.signal_kind(), Some(MaKind::Ema));
+
+    indicator.next(candles[candles.len() - 1]);
+    assert_eq!(indicator.value(), series[series.len() - 1]);
+    assert!((indicator.signal().unwrap() - ema[ema.len() - 1]).abs() < 1e-6);
+    assert_eq!(
+        indicator.is_above_signal(),
+        indicator.value() > indicator.signal().unwrap()
+    );
+}
+
+#[test]
+/// Crossing the signal line, created without a signal nothing is reported.
+fn obv_signal_cross() {
+    use tatk::indicators::{MaKind, OnBalanceVolume};
+    use tatk::traits::Next;
+    let data = [
+        CloseVolume(10.0, 0.0),
+        CloseVolume(11.0, 10.0),
+        CloseVolume(12.0, 10.0),
+    ];
+
+    assert!(OnBalanceVolume::with_signal(2, 4, MaKind::Sma, &data).is_err());
+    assert!(OnBalanceVolume::with_signal(2, 0, MaKind::Sma, &data).is_err());
+
+    let mut plain = OnBalanceVolume::new(2, &data).unwrap();
+    plain.next((11.0, 10.0));
+    assert_eq!(plain.signal(), None);
+    assert!(!plain.is_above_signal() && !plain.crossed());
+
+    // OBV of 0, 10, 20 with a signal of 15.
+    let mut indicator = OnBalanceVolume::with_signal(2, 2, MaKind::Sma, &data).unwrap();
+    assert_eq!(indicator.signal(), Some(15.0));
+    assert!(indicator.is_above_signal());
+
+    // Still rising, then falling through the signal.
+    indicator.next((13.0, 10.0));
+    assert!(!indicator.crossed());
+    indicator.next((12.0, 30.0));
+    assert_eq!((indicator.value(), indicator.signal()), (0.0, Some(15.0)));
+    assert!(indicator.crossed());
+    assert!(!indicator.is_above_signal());
+    indicator.next((11.0, 0.0));
+    assert!(!indicator.crossed());
+}
+
+#[test]
+/// Kalman Filter converges on a noisy constant series.
+fn kalman_filter_constant() {
+    use tatk::indicators::{KalmanFilter, KalmanModel};
+    use tatk::traits::Next;
+
+    assert!(KalmanFilter::new(0, KalmanModel::Level, 0.01, 1.0, &[1.0]).is_err());
+    assert!(KalmanFilter::new(1, KalmanModel::Level, 0.0, 1.0, &[1.0]).is_err());
+    assert!(KalmanFilter::new(1, KalmanModel::Level, 0.01, -1.0, &[1.0]).is_err());
+    assert!(KalmanFilter::new(3, KalmanModel::Level, 0.01, 1.0, &[1.0, 2.0]).is_err());
+
+    let mut indicator = KalmanFilter::new(1, KalmanModel::Level, 1e-5, 1.0, &[12.0]).unwrap();
+    assert_eq!(indicator.value(), 12.0);
+    let start = indicator.covariance();
+    for i in 0..500 {
+        indicator.next(if i % 2 == 0 { 9.0 } else { 11.0 });
+    }
+
+    assert!((indicator.value() - 10.0).abs() < 0.1);
+    assert!(indicator.covariance() < start);
+    assert_eq!(indicator.velocity(), 0.0);
+}
+
+#[test]
+/// Velocity model tracks a ramp, the level model lags behind it.
+fn kalman_filter_ramp() {
+    use tatk::indicators::{BollingerBands, Cross, KalmanFilter, KalmanModel};
+    use tatk::traits::Next;
+
+    let mut velocity = KalmanFilter::new(5, KalmanModel::Velocity, 0.01, 1.0, &[0.0; 5]).unwrap();
+    let mut level = KalmanFilter::new(5, KalmanModel::Level, 0.01, 1.0, &[0.0; 5]).unwrap();
+    for i in 1..=200 {
+        velocity.next(i as f64);
+        level.next(i as f64);
+    }
+
+    assert!((velocity.value() - 200.0).abs() < 0.5);
+    assert!((velocity.velocity() - 1.0).abs() < 0.05);
+    assert!(200.0 - level.value() > 5.0);
+
+    // Usable as the line of other indicators.
+    assert!(BollingerBands::with_line(velocity, 2.0).is_ok());
+    let mut cross = Cross::new(
+        KalmanFilter::new(1, KalmanModel::Level, 1.0, 1.0, &[2.0]).unwrap(),
+        KalmanFilter::new(1, KalmanModel::Level, 0.01, 1.0, &[1.0]).unwrap(),
+    );
+    cross.next(-2.0);
+    assert!(cross.is_death());
+}
+
+#[test]
+/// Holt Smoothing worked by hand with an alpha and beta of 0.5.
+fn holt_smoothing() {
+    use tatk::indicators::HoltSmoothing;
+    use tatk::traits::{Next, Period};
+
+    assert!(HoltSmoothing::new(0, 0.5, 0.5, &[1.0, 2.0]).is_err());
+    assert!(HoltSmoothing::new(2, 0.0, 0.5, &[1.0, 2.0]).is_err());
+    assert!(HoltSmoothing::new(2, 0.5, 1.5, &[1.0, 2.0]).is_err());
+    assert!(HoltSmoothing::new(1, 0.5, 0.5, &[1.0]).is_err());
+
+    // Seeded with a level of 12 and a trend of 2.
+    let mut indicator = HoltSmoothing::new(2, 0.5, 0.5, &[10.0, 12.0]).unwrap();
+    assert_eq!((indicator.value(), indicator.trend()), (12.0, 2.0));
+    assert_eq!(indicator.period(), 2);
+
+    assert_eq!(indicator.next(13.0), 13.5);
+    assert_eq!(indicator.trend(), 1.75);
+    assert_eq!(indicator.next(12.0), 13.625);
+    assert_eq!(indicator.trend(), 0.9375);
+
+    // Created with all of the data at once.
+    let indicator = HoltSmoothing::new(3, 0.5, 0.5, &[10.0, 12.0, 13.0, 12.0, 15.0]).unwrap();
+    assert_eq!((indicator.value(), indicator.trend()), (14.78125, 1.046875));
+    assert_eq!(indicator.forecast(0), 14.78125);
+    assert_eq!(indicator.forecast(2), 16.875);
+}
+
+#[test]
+/// Savitzky-Golay coefficients match published tables, fits recover a polynomial exactly.
+fn savitzky_golay() {
+    use tatk::indicators::SavitzkyGolay;
+    use tatk::traits::Next;
+
+    assert!(SavitzkyGolay::new(0, 0, &[1.0]).is_err());
+    assert!(SavitzkyGolay::new(3, 3, &[1.0, 2.0, 3.0]).is_err());
+    assert!(SavitzkyGolay::new(3, 1, &[1.0, 2.0]).is_err());
+    assert!(SavitzkyGolay::compute_coefficients(5, 2, 5, false).is_err());
+
+    let close = |left: &[f64], right: &[f64], scale: f64| {
+        assert_eq!(left.len(), right.len());
+        for (l, r) in left.iter().zip(right.iter()) {
+            assert!((l - r / scale).abs() < 1e-9, "{l} != {r} / {scale}");
+        }
+    };
+
+    // Centered quadratic, smoothing and first derivative.
+    let c = SavitzkyGolay::compute_coefficients(5, 2, 2, false).unwrap();
+    close(&c, &[-3.0, 12.0, 17.0, 12.0, -3.0], 35.0);
+    let c = SavitzkyGolay::compute_coefficients(7, 3, 3, false).unwrap();
+    close(&c, &[-2.0, 3.0, 6.0, 7.0, 6.0, 3.0, -2.0], 21.0);
+    let c = SavitzkyGolay::compute_coefficients(5, 2, 2, true).unwrap();
+    close(&c, &[-2.0, -1.0, 0.0, 1.0, 2.0], 10.0);
+
+    // Endpoint quadratic.
+    let indicator = SavitzkyGolay::new(5, 2, &[0.0; 5]).unwrap();
+    close(
+        indicator.coefficients(),
+        &[3.0, -5.0, -3.0, 9.0, 31.0],
+        35.0,
+    );
+
+    // Quadratic data is fit exactly, including the slope.
+    let f = |x: f64| 2.0 * x * x - 3.0 * x + 1.0;
+    let data: Vec<f64> = (0..5).map(|x| f(x as f64)).collect();
+    let mut indicator = SavitzkyGolay::new(5, 2, &data).unwrap();
+    assert!((indicator.value() - f(4.0)).abs() < 1e-9);
+    assert!((indicator.derivative() - 13.0).abs() < 1e-9);
+    assert!((indicator.next(f(5.0)) - f(5.0)).abs() < 1e-9);
+    assert!((indicator.derivative() - 17.0).abs() < 1e-9);
+
+    // Degree 0 is the mean with no slope.
+    let indicator = SavitzkyGolay::new(4, 0, &[1.0, 2.0, 3.0, 6.0]).unwrap();
+    assert!((indicator.value() - 3.0).abs() < 1e-9);
+    assert_eq!(indicator.derivative(), 0.0);
+}
+
+#[test]
+/// Gaussian Moving Average weights are normalized and centered as selected.
+fn gaussian_moving_average() {
+    use tatk::gma;
+    use tatk::indicators::{GaussianCenter, GaussianMovingAverage};
+    use tatk::traits::Next;
+
+    assert!(gma!(0, 1.0, GaussianCenter::Newest, &[1.0]).is_err());
+    assert!(gma!(2, 0.0, GaussianCenter::Newest, &[1.0, 2.0]).is_err());
+    assert!(gma!(3, 1.0, GaussianCenter::Newest, &[1.0, 2.0]).is_err());
+
+    // Weights of exp(-2), exp(-0.5), and 1 normalized.
+    let mut indicator = gma!(3, 1.0, GaussianCenter::Newest, &[3.0, 0.0, 6.0]).unwrap();
+    let raw = [(-2.0f64).exp(), (-0.5f64).exp(), 1.0];
+    let total: f64 = raw.iter().sum();
+    for (w, r) in indicator.weights().iter().zip(raw.iter()) {
+        assert!((w - r / total).abs() < 1e-12);
+    }
+    assert!((indicator.value() - (3.0 * raw[0] + 6.0) / total).abs() < 1e-12);
+    let expected = (6.0 * raw[1] + 9.0) / total;
+    assert!((indicator.next(9.0) - expected).abs() < 1e-12);
+
+    // Middle is symmetric, a constant series is unchanged.
+    let indicator = GaussianMovingAverage::new(5, 2.0, GaussianCenter::Middle, &[4.0; 5]).unwrap();
+    let weights = indicator.weights();
+    assert!((weights[0] - weights[4]).abs() < 1e-12 && weights[2] > weights[1]);
+    assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    assert!((indicator.value() - 4.0).abs() < 1e-12);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Resets moving averages used on other data, they are not ready until they have as many values
+/// as creation requires and refeeding the data matches moving averages freshly created from it.
+fn reset_moving_averages() {
+    use tatk::indicators::{
+        DoubleExponentialMovingAverage, ExponentialMovingAverage, SimpleMovingAverage,
+    };
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Ready, Reset, Stats};
+    const DATA: &[f64] = TestData::talib();
+
+    let mut sma = SimpleMovingAverage::new(10, &DATA[100..]).unwrap();
+    sma.reset();
+    for v in DATA[..9].iter() {
+        sma.next(*v);
+        assert!(!sma.is_ready());
+    }
+    sma.next(DATA[9]);
+    let mut fresh = SimpleMovingAverage::new(10, &DATA[..10]).unwrap();
+    assert_eq!(sma.value(), fresh.value());
+    for v in DATA[10..].iter() {
+        assert_eq!(sma.next(*v), fresh.next(*v));
+    }
+    assert_eq!(sma.stdev(true), fresh.stdev(true));
+
+    let mut ema = ExponentialMovingAverage::new(10, &DATA[100..]).unwrap();
+    ema.reset();
+    let mut fresh = ExponentialMovingAverage::new(10, &DATA[..10]).unwrap();
+    for v in DATA[..10].iter() {
+        ema.next(*v);
+    }
+    for v in DATA[10..].iter() {
+        assert_eq!(ema.next(*v), fresh.next(*v));
+    }
+
+    // DEMA requires `2 * period - 1` values.
+    let mut dema = DoubleExponentialMovingAverage::new(10, &DATA[100..]).unwrap();
+    dema.reset();
+    for v in DATA[..18].iter() {
+        dema.next(*v);
+        assert!(!dema.is_ready());
+    }
+    dema.next(DATA[18]);
+    let mut fresh = DoubleExponentialMovingAverage::new(10, &DATA[..19]).unwrap();
+    assert_eq!(dema.value(), fresh.value());
+    for v in DATA[19..].iter() {
+        assert_eq!(dema.next(*v), fresh.next(*v));
+    }
+
+    // Resetting again part way through collecting discards what was collected.
+    dema.reset();
+    for v in DATA[..5].iter() {
+        dema.next(*v);
+    }
+    dema.reset();
+    for v in DATA[..19].iter() {
+        dema.next(*v);
+    }
+    assert_eq!(
+        dema.value(),
+        DoubleExponentialMovingAverage::new(10, &DATA[..19])
+            .unwrap()
+            .value()
+    );
+}
+
+#[test]
+/// Partial values of a reset EMA use the smoothing and seeding it was created with.
+fn reset_ema_custom() {
+    use tatk::indicators::{EmaSeed, ExponentialMovingAverage};
+    use tatk::traits::{Next, Reset};
+    const DATA: &[f64] = &[2.0, 4.0, 8.0, 6.0, 5.0];
+
+    let mut ema = ExponentialMovingAverage::with_alpha(4, DATA, 0.5).unwrap();
+    ema.reset();
+    for i in 1..4 {
+        let partial = ExponentialMovingAverage::with_alpha(i, &DATA[..i], 0.5).unwrap();
+        assert_eq!(ema.next(DATA[i - 1]), partial.value());
+    }
+    ema.next(DATA[3]);
+    let mut fresh = ExponentialMovingAverage::with_alpha(4, &DATA[..4], 0.5).unwrap();
+    assert_eq!(ema.next(DATA[4]), fresh.next(DATA[4]));
+
+    // First value seeding smooths from the first value with the EMA's own factor, 2 / (4 + 1).
+    let mut ema = ExponentialMovingAverage::with_seed(4, DATA, EmaSeed::FirstValue).unwrap();
+    ema.reset();
+    let mut expected = DATA[0];
+    assert_eq!(ema.next(DATA[0]), expected);
+    for v in DATA[1..3].iter() {
+        expected += 0.4 * (v - expected);
+        assert!((ema.next(*v) - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+/// Values that fail to recreate a reset indicator restart collecting, but are still counted as
+/// seen.
+fn reset_failed_recreate() {
+    use tatk::indicators::SimpleMovingAverage;
+    use tatk::traits::{Next, Ready, Reset};
+
+    let mut sma = SimpleMovingAverage::new(2, &[1.0, 2.0]).unwrap();
+    sma.reset();
+    sma.next(1.0);
+    sma.next(f64::NAN);
+    assert!(!sma.is_ready());
+    assert_eq!(sma.samples_seen(), 2);
+
+    sma.next(3.0);
+    assert_eq!(sma.next(5.0), 4.0);
+    assert!(sma.is_ready());
+    assert_eq!(sma.samples_seen(), 4);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Resets a Cross, both lines are reset and no cross is reported until the data is refed.
+fn reset_cross() {
+    use tatk::indicators::{Cross, SimpleMovingAverage};
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Reset};
+    const DATA: &[f64] = TestData::talib();
+
+    let line = |period: usize, data: &[f64]| SimpleMovingAverage::new(period, data).unwrap();
+    let mut cross = Cross::new(line(5, &DATA[100..]), line(10, &DATA[100..]));
+    cross.reset();
+    assert!(!cross.crossed());
+
+    // Once both lines are recreated it tracks the same as a freshly created Cross.
+    for v in DATA[..10].iter() {
+        cross.next(*v);
+    }
+    let mut fresh = Cross::new(line(5, &DATA[5..10]), line(10, &DATA[..10]));
+    for v in DATA[10..].iter() {
+        assert_eq!(cross.next(*v), fresh.next(*v));
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Moving averages created without data match ones created from a slice of the same data, the
+/// SMA being of the values supplied so far while warming up.
+fn streaming_moving_averages() {
+    use tatk::indicators::{
+        DoubleExponentialMovingAverage, ExponentialMovingAverage, SimpleMovingAverage,
+    };
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Ready, Stats};
+    const DATA: &[f64] = TestData::talib();
+
+    let mut sma = SimpleMovingAverage::with_period(10).unwrap();
+    assert!(!sma.is_ready());
+    for (i, v) in DATA[..9].iter().enumerate() {
+        let partial = SimpleMovingAverage::new(i + 1, &DATA[..=i]).unwrap();
+        assert_eq!(sma.next(*v), partial.value());
+        assert!(!sma.is_ready());
+    }
+    sma.next(DATA[9]);
+    assert!(sma.is_ready());
+    let mut fresh = SimpleMovingAverage::new(10, &DATA[..10]).unwrap();
+    assert_eq!(sma.value(), fresh.value());
+    for v in DATA[10..].iter() {
+        assert_eq!(sma.next(*v), fresh.next(*v));
+    }
+    assert_eq!(sma.stdev(true), fresh.stdev(true));
+
+    // EMA is seeded by the SMA of the first period.
+    let mut ema = ExponentialMovingAverage::with_period(10).unwrap();
+    for v in DATA[..10].iter() {
+        ema.next(*v);
+    }
+    let mut fresh = ExponentialMovingAverage::new(10, &DATA[..10]).unwrap();
+    assert!(ema.is_ready());
+    assert_eq!(ema.value(), fresh.value());
+    for v in DATA[10..].iter() {
+        assert_eq!(ema.next(*v), fresh.next(*v));
+    }
+
+    // DEMA requires `2 * period - 1` values.
+    let mut dema = DoubleExponentialMovingAverage::with_period(10).unwrap();
+    for v in DATA[..18].iter() {
+        dema.next(*v);
+        assert!(!dema.is_ready());
+    }
+    dema.next(DATA[18]);
+    let mut fresh = DoubleExponentialMovingAverage::new(10, &DATA[..19]).unwrap();
+    assert!(dema.is_ready());
+    for v in DATA[19..].iter() {
+        assert_eq!(dema.next(*v), fresh.next(*v));
+    }
+
+    // Configuration is validated the same as creating from data.
+    assert!(SimpleMovingAverage::with_period(0).is_err());
+    assert!(DoubleExponentialMovingAverage::with_period(0).is_err());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Samples seen include the initial data, a Cross is only ready once both of its lines are.
+fn ready_samples_seen() {
+    use tatk::indicators::{Cross, DoubleExponentialMovingAverage, SimpleMovingAverage};
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Ready, Reset};
+    const DATA: &[f64] = TestData::talib();
+
+    // Seeded with exactly `2 * period - 1` values compared to one that has been running.
+    let mut dema = DoubleExponentialMovingAverage::new(10, &DATA[..19]).unwrap();
+    assert!(dema.is_ready());
+    assert_eq!(dema.samples_seen(), 19);
+    for v in DATA[19..30].iter() {
+        dema.next(*v);
+    }
+    assert_eq!(dema.samples_seen(), 30);
+
+    dema.reset();
+    assert!(!dema.is_ready());
+    assert_eq!(dema.samples_seen(), 0);
+    for v in DATA[..5].iter() {
+        dema.next(*v);
+    }
+    assert_eq!(dema.samples_seen(), 5);
+
+    let line = |period: usize| SimpleMovingAverage::with_period(period).unwrap();
+    let mut cross = Cross::new(line(5), line(10));
+    for v in DATA[..9].iter() {
+        cross.next(*v);
+    }
+    assert!(!cross.is_ready());
+    cross.next(DATA[9]);
+    assert!(cross.is_ready());
+    assert_eq!(cross.samples_seen(), 10);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// SMA held in a StaticBuffer produces the same values as one held in a Buffer, including after
+/// being reset.
+fn static_sma() {
+    use tatk::indicators::SimpleMovingAverage;
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Ready, Reset};
+    use tatk::StaticBuffer;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let mut indicator: SimpleMovingAverage<StaticBuffer<10>> =
+        SimpleMovingAverage::with_window(10, &DATA[..DATA.len() - 1]).unwrap();
+    assert_eq!(indicator.value(), 92.816);
+    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 92.5565);
+
+    // Period must match the capacity of the StaticBuffer.
+    assert!(SimpleMovingAverage::<StaticBuffer<5>>::with_window(10, DATA).is_err());
+
+    let mut heap = SimpleMovingAverage::new(10, &DATA[..10]).unwrap();
+    indicator.reset();
+    heap.reset();
+    for v in DATA.iter() {
+        assert_eq!(indicator.next(*v), heap.next(*v));
+        assert_eq!(indicator.is_ready(), heap.is_ready());
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// EMA and WMA held in StaticBuffers produce the same values as ones held in Buffers, including
+/// after being reset.
+fn static_ema_wma() {
+    use tatk::indicators::{ExponentialMovingAverage, WeightedMovingAverage};
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Ready, Reset, Stats};
+    use tatk::StaticBuffer;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let mut ema: ExponentialMovingAverage<StaticBuffer<10>> =
+        ExponentialMovingAverage::with_window(10, &DATA[..15]).unwrap();
+    let mut ema_heap = ExponentialMovingAverage::new(10, &DATA[..15]).unwrap();
+    let mut wma: WeightedMovingAverage<StaticBuffer<10>> =
+        WeightedMovingAverage::with_window(10, &DATA[..15]).unwrap();
+    let mut wma_heap = WeightedMovingAverage::new(10, &DATA[..15]).unwrap();
+    assert_eq!(ema.value(), ema_heap.value());
+    assert_eq!(wma.value(), wma_heap.value());
+
+    // Period must match the capacity of the StaticBuffer.
+    assert!(ExponentialMovingAverage::<StaticBuffer<5>>::with_window(10, DATA).is_err());
+    assert!(WeightedMovingAverage::<StaticBuffer<5>>::with_window(10, DATA).is_err());
+
+    for v in DATA[15..].iter() {
+        assert_eq!(ema.next(*v), ema_heap.next(*v));
+        assert_eq!(wma.next(*v), wma_heap.next(*v));
+    }
+    assert!((ema.mean() - ema_heap.mean()).abs() < 1e-9);
+    assert!((wma.mean() - wma_heap.mean()).abs() < 1e-9);
+
+    ema.reset();
+    ema_heap.reset();
+    wma.reset();
+    wma_heap.reset();
+    for v in DATA.iter() {
+        assert_eq!(ema.next(*v), ema_heap.next(*v));
+        assert_eq!(wma.next(*v), wma_heap.next(*v));
+        assert_eq!(ema.is_ready(), ema_heap.is_ready());
+        assert_eq!(wma.is_ready(), wma_heap.is_ready());
+    }
+}
+
+#[test]
+/// Cross reports the direction, touching the long line then retreating is not a cross.
+fn cross_events() {
+    use tatk::indicators::{Cross, CrossEvent, SimpleMovingAverage};
+    use tatk::traits::Next;
+
+    let short = SimpleMovingAverage::new(1, &[3.0]).unwrap();
+    let long = SimpleMovingAverage::new(3, &[3.0; 3]).unwrap();
+    let mut cross = Cross::new(short, long);
+    assert_eq!(cross.last_cross(), CrossEvent::None);
+    assert_eq!(cross.bars_since_cross(), None);
+
+    // Starting equal, the first side taken is not a cross.
+    assert_eq!(cross.next(1.0), CrossEvent::None);
+    assert_eq!(cross.next(3.0), CrossEvent::Golden);
+    assert!(cross.is_golden() && cross.crossed());
+    assert_eq!(cross.bars_since_cross(), Some(0));
+
+    // Touch (2.0 == 2.0) then retreat back above.
+    assert_eq!(cross.next(2.0), CrossEvent::None);
+    assert_eq!(cross.next(3.0), CrossEvent::None);
+    assert_eq!(cross.last_cross(), CrossEvent::Golden);
+    assert_eq!(cross.bars_since_cross(), Some(2));
+
+    assert_eq!(cross.next(0.0), CrossEvent::Death);
+    assert!(cross.is_death());
+    assert_eq!(cross.event(), CrossEvent::Death);
+    assert_eq!(cross.last_cross(), CrossEvent::Death);
+    assert_eq!(cross.bars_since_cross(), Some(0));
+
+    #[allow(deprecated)]
+    let crossed = cross.next_crossed(5.0);
+    assert!(crossed);
+    assert_eq!(cross.last_cross(), CrossEvent::Golden);
+}
+
+#[test]
+/// Separation and confirmation bars filter an oscillating series down to the one real cross.
+fn cross_filtered() {
+    use tatk::indicators::{Cross, CrossEvent, SimpleMovingAverage, Tolerance};
+    use tatk::traits::Next;
+
+    let mut data: Vec<f64> = [100.4, 99.6].repeat(3);
+    data.extend([100.4, 103.0, 104.0, 105.0, 106.0]);
+    let create = || {
+        Cross::new(
+            SimpleMovingAverage::new(1, &[99.0]).unwrap(),
+            SimpleMovingAverage::new(20, &[100.0; 20]).unwrap(),
+        )
+    };
+    let events = |mut cross: Cross<SimpleMovingAverage>| -> Vec<(usize, CrossEvent)> {
+        data.iter()
+            .enumerate()
+            .map(|(i, v)| (i, cross.next(*v)))
+            .filter(|(_, event)| *event != CrossEvent::None)
+            .collect()
+    };
+
+    // Unfiltered, every swing is a cross.
+    assert_eq!(events(create()).len(), 7);
+
+    let mut separated = create();
+    separated.set_separation(Tolerance::Absolute(1.0));
+    assert_eq!(events(separated), vec![(7, CrossEvent::Golden)]);
+
+    let mut percent = create();
+    percent.set_separation(Tolerance::Percent(1.0));
+    assert_eq!(events(percent), vec![(7, CrossEvent::Golden)]);
+
+    // Reported on the last value confirming it.
+    let mut confirmed = create();
+    confirmed.set_confirmation(3);
+    assert_eq!(events(confirmed), vec![(8, CrossEvent::Golden)]);
+
+    let mut both = create();
+    both.set_separation(Tolerance::Absolute(1.0));
+    both.set_confirmation(2);
+    assert_eq!(events(both), vec![(8, CrossEvent::Golden)]);
+}
+
+#[test]
+/// Weighted Moving Average worked by hand, the newest value having the largest weight.
+fn weighted_moving_average() {
+    use tatk::indicators::WeightedMovingAverage;
+    use tatk::traits::{Next, Ready};
+
+    let mut wma = WeightedMovingAverage::new(3, &[1.0, 2.0, 3.0]).unwrap();
+    assert!((wma.value() - 14.0 / 6.0).abs() < 1e-12);
+    assert!((wma.next(6.0) - 26.0 / 6.0).abs() < 1e-12);
+    assert!(WeightedMovingAverage::new(0, &[1.0]).is_err());
+    assert!(WeightedMovingAverage::new(3, &[1.0, 2.0]).is_err());
+
+    // Supplied with `next` it matches one created from the same data.
+    let mut streaming = WeightedMovingAverage::with_period(3).unwrap();
+    for v in [1.0, 2.0, 3.0, 6.0] {
+        streaming.next(v);
+    }
+    assert!(streaming.is_ready());
+    assert_eq!(streaming.value(), wma.value());
+}
+
+/// Minimal candle of High (0) and Low (1) values.
+#[derive(Clone)]
+struct HighLow(f64, f64);
+
+impl tatk::traits::High for HighLow {
+    fn high(&self) -> f64 {
+        self.0
+    }
+}
+
+impl tatk::traits::Low for HighLow {
+    fn low(&self) -> f64 {
+        self.1
+    }
+}
+
+/// Minimal candle of Close (0) and Volume (1) values.
+struct CloseVolume(f64, f64);
+
+impl tatk::traits::Close for CloseVolume {
+    fn close(&self) -> f64 {
+        self.0
+    }
+}
+
+impl tatk::traits::Volume for CloseVolume {
+    fn volume(&self) -> f64 {
+        self.1
+    }
+}
@@ -23,7 +23,10 @@ fn next_macd() {
 
     let mut indicator =
         MovingAverageConvergenceDivergence::new(8, 10, 6, &DATA[..DATA.len() - 1]).unwrap();
-    assert_eq!(indicator.next(DATA[DATA.len() - 1]).0, -0.3300712744833305)
+    assert_eq!(
+        indicator.next(DATA[DATA.len() - 1]).macd,
+        -0.3300712744833305
+    )
 }
 
 #[test]
@@ -75,7 +78,35 @@ fn next_bbands() {
     const DATA: &[f64] = TestData::talib_small();
 
     let mut indicator = BollingerBands::new(10, &DATA[..DATA.len() - 1], 2.0).unwrap();
-    assert_eq!(indicator.next(DATA[DATA.len() - 1]).1, 92.5565)
+    assert_eq!(indicator.next(DATA[DATA.len() - 1]).middle, 92.5565)
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Bollinger Bands held in StaticBuffers produce the same bands as ones held in Buffers.
+fn static_bbands() {
+    use tatk::indicators::{BollingerBands, SimpleMovingAverage};
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    use tatk::StaticBuffer;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let mut indicator: BollingerBands<SimpleMovingAverage<StaticBuffer<10>>, StaticBuffer<10>> =
+        BollingerBands::with_window(10, &DATA[..10], 2.0).unwrap();
+    let mut heap = BollingerBands::new(10, &DATA[..10], 2.0).unwrap();
+    assert_eq!(indicator.last(), heap.last());
+    for v in DATA[10..].iter() {
+        let (fixed, bands) = (indicator.next(*v), heap.next(*v));
+        assert_eq!(fixed.middle, bands.middle);
+        assert!((fixed.lower - bands.lower).abs() < 1e-9);
+        assert!((fixed.upper - bands.upper).abs() < 1e-9);
+    }
+    assert_eq!(indicator.bandwidth_history().len(), 10);
+    assert_eq!(indicator.is_squeeze(5), heap.is_squeeze(5));
+
+    // Period must match the capacity of the StaticBuffer.
+    type Small = BollingerBands<SimpleMovingAverage<StaticBuffer<5>>, StaticBuffer<5>>;
+    assert!(Small::with_window(10, DATA, 2.0).is_err());
 }
 
 #[test]
@@ -106,3 +137,1036 @@ fn next_atr() {
         854.3072357665736
     )
 }
+
+#[test]
+#[cfg(feature = "test-data")]
+/// ATR as a percentage of the most recent close, following the candles supplied.
+fn atr_percent() {
+    use tatk::indicators::{AverageTrueRange, TrueRange};
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::{Next, Reset};
+    let candles: Vec<Candle> = TestData::candles();
+
+    let mut atr = AverageTrueRange::new(10, &candles[..11]).unwrap();
+    let tr = TrueRange::new(10, &candles[..11]).unwrap();
+    assert_eq!(tr.last_close(), candles[10].close);
+    assert_eq!(atr.atr_percent(), atr.value() / candles[10].close * 100.0);
+    for candle in candles[11..].iter() {
+        atr.next(*candle);
+        assert_eq!(atr.atr_percent(), atr.value() / candle.close * 100.0);
+    }
+
+    // No close to compare against.
+    atr.reset();
+    assert_eq!(atr.atr_percent(), 0.0);
+    let flat = [Candle {
+        open: 0.0,
+        close: 0.0,
+        high: 0.0,
+        low: 0.0,
+        volume: 0.0,
+    }; 11];
+    assert_eq!(AverageTrueRange::new(10, &flat).unwrap().atr_percent(), 0.0);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// True Range and ATR seeded from the high - low of the first candle, converging with the
+/// convention requiring a prior close.
+fn first_bar_true_range() {
+    use tatk::indicators::{AverageTrueRange, TrueRange};
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::{Next, Reset, Stats};
+
+    // True ranges of 2 (high - low), 2, 3, and 2.
+    let data = [
+        Hlc((10.0, 8.0, 9.0)),
+        Hlc((11.0, 9.0, 10.0)),
+        Hlc((12.0, 9.0, 11.0)),
+        Hlc((12.0, 10.0, 11.0)),
+    ];
+    let tr = TrueRange::new_from_first_bar(3, &data[..3]).unwrap();
+    assert_eq!((tr.value(), tr.sum()), (3.0, 7.0));
+    assert!(TrueRange::new(3, &data[..3]).is_err());
+    assert_eq!(TrueRange::new(3, &data).unwrap().sum(), 7.0);
+
+    let mut atr = AverageTrueRange::new_from_first_bar(3, &data[..3]).unwrap();
+    assert_eq!(atr.value(), 7.0 / 3.0);
+    assert_eq!(atr.next(data[3].0), (14.0 / 3.0 + 2.0) / 3.0);
+    assert_eq!(AverageTrueRange::new(3, &data).unwrap().value(), 7.0 / 3.0);
+    assert!(AverageTrueRange::new_from_first_bar(3, &data[..2]).is_err());
+
+    // Both conventions converge as the seed is smoothed away.
+    let candles: Vec<Candle> = TestData::candles();
+    let mut first = AverageTrueRange::new_from_first_bar(14, &candles[..14]).unwrap();
+    let mut prior = AverageTrueRange::new(14, &candles[..15]).unwrap();
+    first.next(candles[14]);
+    let early = (first.value() - prior.value()).abs();
+    assert!(early > 0.0);
+    for candle in candles[15..].iter() {
+        first.next(*candle);
+        prior.next(*candle);
+    }
+    assert!((first.value() - prior.value()).abs() < early * 1e-6);
+
+    // Builder and a reset keep the convention.
+    let built = AverageTrueRange::builder()
+        .seed_first_bar(true)
+        .build(&candles)
+        .unwrap();
+    assert_eq!(built.value(), first.value());
+    first.reset();
+    for candle in candles.iter() {
+        first.next(*candle);
+    }
+    assert_eq!(first.value(), built.value());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates an ATR Trailing Stop from 364 data points with a period of 14, the stop only ratchets
+/// towards the price unless the position flips.
+fn create_atr_stop() {
+    use tatk::indicators::{AtrTrailingStop, FlipSeed, Position};
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+
+    let indicator = AtrTrailingStop::new(14, 3.0, FlipSeed::Close, &candles).unwrap();
+    assert_eq!(indicator.value(), 29119.562506383063);
+
+    let mut indicator = AtrTrailingStop::new(14, 3.0, FlipSeed::Close, &candles[..15]).unwrap();
+    assert_eq!(indicator.direction(), Position::Long);
+    let mut flips: usize = 0;
+    for candle in candles[15..].iter() {
+        let (last, position) = (indicator.value(), indicator.direction());
+        let stop = indicator.next(candle);
+        if indicator.flipped() {
+            flips += 1;
+            assert_ne!(indicator.direction(), position);
+        } else if position == Position::Long {
+            assert!(stop >= last && candle.close >= last);
+        } else {
+            assert!(stop <= last && candle.close <= last);
+        }
+    }
+    assert!(flips > 0);
+}
+
+#[test]
+/// Steady candles with a range of 2.0, then a drop and a rally through the stop. Checks the stop
+/// seeded on each flip candle for both flip seeds.
+fn atr_stop_flip_seed() {
+    use tatk::indicators::{AtrTrailingStop, FlipSeed, Position};
+    use tatk::traits::Next;
+    let data: Vec<(f64, f64, f64)> = (10..15)
+        .map(|c| (c as f64 + 1.0, c as f64 - 1.0, c as f64))
+        .collect();
+
+    for seed in [FlipSeed::Close, FlipSeed::Extreme] {
+        let mut indicator = AtrTrailingStop::new(3, 1.0, seed, &to_hlc(&data)).unwrap();
+        assert_eq!(indicator.atr(), 2.0);
+        assert_eq!(indicator.value(), 12.0);
+        assert_eq!(indicator.direction(), Position::Long);
+
+        // Holds above the stop, the stop can not fall.
+        indicator.next((14.5, 12.5, 13.5));
+        assert!(!indicator.flipped());
+        assert_eq!(indicator.value(), 12.0);
+
+        // Closes through the stop, flipping short.
+        let stop = indicator.next((9.0, 7.0, 8.0));
+        assert!(indicator.flipped());
+        assert_eq!(indicator.direction(), Position::Short);
+        let expected = match seed {
+            FlipSeed::Close => 8.0 + indicator.atr(),
+            FlipSeed::Extreme => 9.0 + indicator.atr(),
+        };
+        assert_eq!(stop, expected);
+
+        // Short stop can only fall.
+        let stop = indicator.next((8.5, 7.5, 8.0));
+        assert!(!indicator.flipped());
+        assert_eq!(stop, expected.min(8.0 + indicator.atr()));
+
+        // Rallies through the stop, flipping long.
+        let stop = indicator.next((21.0, 19.0, 20.0));
+        assert!(indicator.flipped());
+        assert_eq!(indicator.direction(), Position::Long);
+        let expected = match seed {
+            FlipSeed::Close => 20.0 - indicator.atr(),
+            FlipSeed::Extreme => 19.0 - indicator.atr(),
+        };
+        assert_eq!(stop, expected);
+    }
+
+    assert!(AtrTrailingStop::new(3, 0.0, FlipSeed::Close, &to_hlc(&data)).is_err());
+    assert!(AtrTrailingStop::new(3, 1.0, FlipSeed::Close, &to_hlc(&data[..3])).is_err());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Resets a MACD, RSI, and Bollinger Bands used on other data, refeeding the data matches
+/// indicators freshly created from it.
+fn reset_matches_new() {
+    use tatk::indicators::{
+        BollingerBands, MovingAverageConvergenceDivergence, RelativeStrengthIndex,
+    };
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Reset};
+    const DATA: &[f64] = TestData::talib();
+
+    // MACD requires `long + signal - 1` values.
+    let mut macd = MovingAverageConvergenceDivergence::new(8, 10, 6, &DATA[100..]).unwrap();
+    macd.reset();
+    for v in DATA[..14].iter() {
+        macd.next(*v);
+        assert_eq!(macd.value(), 0.0);
+    }
+    macd.next(DATA[14]);
+    let mut fresh = MovingAverageConvergenceDivergence::new(8, 10, 6, &DATA[..15]).unwrap();
+    assert_eq!(macd.value(), fresh.value());
+    assert_eq!(macd.signal_value(), fresh.signal_value());
+    for v in DATA[15..].iter() {
+        assert_eq!(macd.next(*v), fresh.next(*v));
+        assert_eq!(macd.crossed(), fresh.crossed());
+    }
+
+    // RSI requires `period + 1` values, thresholds are kept.
+    let mut rsi = RelativeStrengthIndex::new(10, &DATA[100..]).unwrap();
+    rsi.set_oversold(100.0);
+    rsi.reset();
+    let mut fresh = RelativeStrengthIndex::new(10, &DATA[..11]).unwrap();
+    for v in DATA[..11].iter() {
+        rsi.next(*v);
+    }
+    assert_eq!(rsi.value(), fresh.value());
+    assert!(rsi.is_oversold());
+    for v in DATA[11..].iter() {
+        assert_eq!(rsi.next(*v), fresh.next(*v));
+    }
+
+    let mut bbands = BollingerBands::new(10, &DATA[100..], 2.0).unwrap();
+    bbands.reset();
+    let mut fresh = BollingerBands::new(10, &DATA[..10], 2.0).unwrap();
+    for v in DATA[..10].iter() {
+        bbands.next(*v);
+    }
+    assert_eq!(
+        (bbands.lower(), bbands.upper()),
+        (fresh.lower(), fresh.upper())
+    );
+    for v in DATA[10..].iter() {
+        assert_eq!(bbands.next(*v), fresh.next(*v));
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Resets an Average True Range used on other candles, refeeding the candles matches an ATR
+/// freshly created from them.
+fn reset_atr() {
+    use tatk::indicators::AverageTrueRange;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::{Next, Ready, Reset, Stats};
+    let candles: Vec<Candle> = TestData::candles();
+
+    let mut indicator = AverageTrueRange::new(14, &candles[100..]).unwrap();
+    indicator.reset();
+    for c in candles[..14].iter() {
+        indicator.next(*c);
+        assert!(!indicator.is_ready());
+    }
+
+    indicator.next(candles[14]);
+    let mut fresh = AverageTrueRange::new(14, &candles[..15]).unwrap();
+    assert_eq!(indicator.value(), fresh.value());
+    for c in candles[15..].iter() {
+        assert_eq!(indicator.next(*c), fresh.next(*c));
+    }
+    assert_eq!(indicator.mean(), fresh.mean());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Oscillators created without data match ones created from a slice of the same data once they
+/// have been supplied as many values as creation requires.
+fn streaming_matches_new() {
+    use tatk::indicators::{
+        AverageTrueRange, BollingerBands, MovingAverageConvergenceDivergence, RelativeStrengthIndex,
+    };
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::{Next, Ready};
+    const DATA: &[f64] = TestData::talib();
+
+    // RSI is seeded by the gains and losses of the first `period + 1` values.
+    let mut rsi = RelativeStrengthIndex::with_period(14).unwrap();
+    for (i, v) in DATA[..14].iter().enumerate() {
+        rsi.next(*v);
+        assert!(!rsi.is_ready());
+        if i > 1 {
+            let partial = RelativeStrengthIndex::new(i, &DATA[..=i]).unwrap();
+            assert_eq!(rsi.value(), partial.value());
+        }
+    }
+    rsi.next(DATA[14]);
+    let mut fresh = RelativeStrengthIndex::new(14, &DATA[..15]).unwrap();
+    assert!(rsi.is_ready());
+    assert_eq!(rsi.value(), fresh.value());
+    for v in DATA[15..].iter() {
+        assert_eq!(rsi.next(*v), fresh.next(*v));
+    }
+
+    // MACD requires `long + signal - 1` values.
+    let mut macd = MovingAverageConvergenceDivergence::with_period(12, 26, 9).unwrap();
+    for v in DATA[..33].iter() {
+        assert_eq!(macd.next(*v).macd, 0.0);
+        assert!(!macd.is_ready());
+    }
+    macd.next(DATA[33]);
+    let mut fresh = MovingAverageConvergenceDivergence::new(12, 26, 9, &DATA[..34]).unwrap();
+    assert!(macd.is_ready());
+    for v in DATA[34..].iter() {
+        assert_eq!(macd.next(*v), fresh.next(*v));
+    }
+    assert!(MovingAverageConvergenceDivergence::with_period(26, 12, 9).is_err());
+
+    let mut bbands = BollingerBands::with_period(20, 2.0).unwrap();
+    for v in DATA[..20].iter() {
+        bbands.next(*v);
+    }
+    let mut fresh = BollingerBands::new(20, &DATA[..20], 2.0).unwrap();
+    assert!(bbands.is_ready());
+    assert_eq!(
+        (bbands.lower(), bbands.upper()),
+        (fresh.lower(), fresh.upper())
+    );
+    for v in DATA[20..].iter() {
+        assert_eq!(bbands.next(*v), fresh.next(*v));
+    }
+
+    let candles: Vec<Candle> = TestData::candles();
+    let mut atr = AverageTrueRange::with_period(14).unwrap();
+    for c in candles[..15].iter() {
+        atr.next(*c);
+    }
+    let mut fresh = AverageTrueRange::new(14, &candles[..15]).unwrap();
+    assert!(atr.is_ready());
+    for c in candles[15..].iter() {
+        assert_eq!(atr.next(*c), fresh.next(*c));
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Composite indicators are ready once all of their parts are, counting every value supplied.
+fn ready_composites() {
+    use tatk::indicators::{AverageTrueRange, BollingerBands, MovingAverageConvergenceDivergence};
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::{Next, Ready};
+    const DATA: &[f64] = TestData::talib();
+
+    let mut macd = MovingAverageConvergenceDivergence::new(12, 26, 9, &DATA[..34]).unwrap();
+    assert!(macd.is_ready());
+    assert_eq!(macd.samples_seen(), 34);
+    macd.next(DATA[34]);
+    assert_eq!(macd.samples_seen(), 35);
+
+    let mut bbands = BollingerBands::with_period(20, 2.0).unwrap();
+    for v in DATA[..19].iter() {
+        bbands.next(*v);
+    }
+    assert!(!bbands.is_ready());
+    assert_eq!(bbands.samples_seen(), 19);
+    bbands.next(DATA[19]);
+    assert!(bbands.is_ready());
+
+    let candles: Vec<Candle> = TestData::candles();
+    let atr = AverageTrueRange::new(14, &candles[..20]).unwrap();
+    assert!(atr.is_ready());
+    assert_eq!(atr.samples_seen(), 20);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Clones a warm MACD, each copy is equal until supplied different data and then diverges
+/// without affecting the other.
+fn clone_macd_diverges() {
+    use tatk::indicators::{
+        BollingerBands, ExponentialMovingAverage, MovingAverageConvergenceDivergence,
+    };
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+
+    let mut macd = MovingAverageConvergenceDivergence::new(12, 26, 9, &DATA[..100]).unwrap();
+    let mut copy = macd.clone();
+    assert_eq!(macd, copy);
+
+    let original = macd.value();
+    macd.next(DATA[100] * 2.0);
+    assert_ne!(macd, copy);
+    assert_eq!(copy.value(), original);
+
+    copy.next(DATA[100] / 2.0);
+    assert!(macd.value() > original);
+    assert!(copy.value() < original);
+
+    // Supplied the same data they track together again from that point.
+    let mut fresh = copy.clone();
+    for v in DATA[101..].iter() {
+        assert_eq!(copy.next(*v), fresh.next(*v));
+    }
+    assert_eq!(copy, fresh);
+
+    // Generic over the line as long as the line can be cloned.
+    let ema = ExponentialMovingAverage::new(20, &DATA[..100]).unwrap();
+    let bbands = BollingerBands::with_line(ema, 2.0).unwrap();
+    assert_eq!(bbands.clone(), bbands);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Builders use the common defaults, match the constructors, and validate on build.
+fn builders_use_defaults() {
+    use tatk::indicators::{
+        AverageTrueRange, BollingerBands, MovingAverageConvergenceDivergence, RelativeStrengthIndex,
+    };
+    use tatk::test_data::{Candle, TestData};
+    use tatk::{atr, bb, macd, rsi};
+    const DATA: &[f64] = TestData::talib();
+
+    let macd = MovingAverageConvergenceDivergence::builder()
+        .build(DATA)
+        .unwrap();
+    assert_eq!(
+        macd,
+        MovingAverageConvergenceDivergence::new(12, 26, 9, DATA).unwrap()
+    );
+    assert_eq!(macd, macd!(DATA).unwrap());
+    let custom = MovingAverageConvergenceDivergence::builder()
+        .short(8)
+        .long(10)
+        .signal(6)
+        .build(DATA)
+        .unwrap();
+    assert_eq!(custom, macd!(8, 10, 6, DATA).unwrap());
+    assert!(MovingAverageConvergenceDivergence::builder()
+        .short(30)
+        .build(DATA)
+        .is_err());
+
+    let bbands = BollingerBands::builder().build(DATA).unwrap();
+    assert_eq!(bbands, BollingerBands::new(20, DATA, 2.0).unwrap());
+    assert_eq!(bbands, bb!(DATA).unwrap());
+    assert!(BollingerBands::builder()
+        .distance(f64::NAN)
+        .build(DATA)
+        .is_err());
+
+    let rsi = RelativeStrengthIndex::builder()
+        .oversold(30.0)
+        .overbought(70.0)
+        .build(DATA)
+        .unwrap();
+    assert_eq!(rsi.value(), rsi!(14, DATA).unwrap().value());
+    assert_eq!(rsi.is_overbought(), rsi.value() > 70.0);
+    assert_eq!(rsi!(DATA).unwrap().value(), rsi.value());
+    assert!(RelativeStrengthIndex::builder()
+        .oversold(80.0)
+        .overbought(20.0)
+        .build(DATA)
+        .is_err());
+    assert!(RelativeStrengthIndex::builder()
+        .period(1)
+        .build(DATA)
+        .is_err());
+
+    let candles: Vec<Candle> = TestData::candles();
+    let atr = AverageTrueRange::builder().build(&candles).unwrap();
+    assert_eq!(atr, AverageTrueRange::new(14, &candles).unwrap());
+    assert_eq!(atr, atr!(&candles).unwrap());
+    assert!(AverageTrueRange::builder()
+        .period(0)
+        .build(&candles)
+        .is_err());
+}
+
+#[test]
+/// Histogram is the MACD minus the signal, its sign flipping with the MACD crossing the signal.
+fn macd_histogram() {
+    use tatk::indicators::MovingAverageConvergenceDivergence;
+    use tatk::traits::{Next, Stats};
+
+    let wave: Vec<f64> = (0..200)
+        .map(|i| 100.0 + (i as f64 / 8.0).sin() * 10.0)
+        .collect();
+    let mut macd = MovingAverageConvergenceDivergence::new(12, 26, 9, &wave[..40]).unwrap();
+    assert_eq!(macd.histogram(), macd.value() - macd.signal_value());
+
+    let mut histograms: Vec<f64> = Vec::new();
+    let mut flips = 0;
+    for v in wave[40..].iter() {
+        let was_above = macd.is_above();
+        let was_positive = macd.histogram() > 0.0;
+        let output = macd.next(*v);
+        assert_eq!(output.macd, macd.value());
+        assert_eq!(output.signal, macd.signal_value());
+        assert_eq!(output.histogram, macd.histogram());
+        assert_eq!(output.histogram > 0.0, macd.is_above());
+        assert_eq!(output.histogram < 0.0, macd.is_below());
+        assert_eq!(
+            was_positive != (output.histogram > 0.0),
+            was_above != macd.is_above()
+        );
+        flips += (was_above != macd.is_above()) as usize;
+        histograms.push(output.histogram);
+    }
+    assert!(flips > 2);
+
+    // Stats are of the histogram for the signal period.
+    let recent = &histograms[histograms.len() - 9..];
+    let sum: f64 = recent.iter().sum();
+    assert!((macd.sum() - sum).abs() < 1e-9);
+    assert!((macd.mean() - sum / 9.0).abs() < 1e-9);
+    assert!(macd.stdev(true) > 0.0);
+}
+
+#[test]
+/// Crosses of the MACD and signal on the exact values worked by hand, short 1, long 2, signal 2.
+fn macd_crossed() {
+    use tatk::indicators::MovingAverageConvergenceDivergence;
+    use tatk::traits::Next;
+
+    let mut macd = MovingAverageConvergenceDivergence::new(1, 2, 2, &[10.0; 3]).unwrap();
+    assert!(!macd.crossed());
+
+    // MACD -1 and signal -2/3, starting equal the first side taken is not a cross.
+    let output = macd.next(7.0);
+    assert!((output.macd + 1.0).abs() < 1e-12);
+    assert!((output.signal + 2.0 / 3.0).abs() < 1e-12);
+    assert!(macd.is_below() && !macd.crossed());
+
+    // MACD -1/3 and signal -4/9.
+    let output = macd.next(7.0);
+    assert!((output.histogram - 1.0 / 9.0).abs() < 1e-12);
+    assert!(macd.crossed() && macd.crossed_above() && !macd.crossed_below());
+
+    // Staying above is not a cross.
+    macd.next(7.0);
+    assert!(macd.is_above() && !macd.crossed());
+
+    macd.next(4.0);
+    assert!(macd.crossed() && macd.crossed_below() && !macd.crossed_above());
+}
+
+#[test]
+/// Named outputs of the Bollinger Bands and MACD match their accessors.
+fn named_outputs() {
+    use tatk::indicators::{
+        BandsOutput, BollingerBands, MacdOutput, MovingAverageConvergenceDivergence,
+    };
+    use tatk::traits::Next;
+
+    let data: Vec<f64> = (0..60).map(|i| 100.0 + ((i * 7) % 11) as f64).collect();
+    let mut bbands = BollingerBands::new(20, &data[..40], 2.0).unwrap();
+    let mut macd = MovingAverageConvergenceDivergence::new(12, 26, 9, &data[..40]).unwrap();
+    for v in data[40..].iter() {
+        let BandsOutput {
+            lower,
+            middle,
+            upper,
+        } = bbands.next(*v);
+        assert_eq!(
+            (lower, middle, upper),
+            (bbands.lower(), bbands.value(), bbands.upper())
+        );
+        assert!(lower < middle && middle < upper);
+
+        let MacdOutput {
+            macd: value,
+            signal,
+            histogram,
+        } = macd.next(*v);
+        assert_eq!((value, signal), (macd.value(), macd.signal_value()));
+        assert_eq!(histogram, value - signal);
+        assert_eq!(value, macd.short_value() - macd.long_value());
+    }
+}
+
+#[test]
+/// MACD on SMAs worked by hand with a short of 2, long of 3, and signal of 2.
+fn macd_sma_kind() {
+    use tatk::indicators::{MaKind, MovingAverageConvergenceDivergence};
+    use tatk::traits::Next;
+
+    // Short SMA 3 and long SMA 7/3, then short 5.5 and long 13/3.
+    let mut macd =
+        MovingAverageConvergenceDivergence::with_kind(2, 3, 2, MaKind::Sma, &[1.0, 2.0, 4.0, 7.0])
+            .unwrap();
+    assert_eq!(macd.kinds(), [MaKind::Sma; 3]);
+    assert!((macd.value() - 7.0 / 6.0).abs() < 1e-12);
+    assert!((macd.signal_value() - 11.0 / 12.0).abs() < 1e-12);
+
+    // Short 9 and long 22/3, the signal is the mean of the last two MACDs.
+    let output = macd.next(11.0);
+    assert!((output.macd - 5.0 / 3.0).abs() < 1e-12);
+    assert!((output.signal - 17.0 / 12.0).abs() < 1e-12);
+    assert!((output.histogram - 0.25).abs() < 1e-12);
+
+    // Lines can be of different kinds, EMA remaining the default.
+    let data: Vec<f64> = (0..60).map(|i| 100.0 + ((i * 7) % 11) as f64).collect();
+    let ema = MovingAverageConvergenceDivergence::builder()
+        .build(&data)
+        .unwrap();
+    assert_eq!(ema.kinds(), [MaKind::Ema; 3]);
+    assert_eq!(
+        ema,
+        MovingAverageConvergenceDivergence::new(12, 26, 9, &data).unwrap()
+    );
+
+    let mixed = MovingAverageConvergenceDivergence::builder()
+        .short_kind(MaKind::Wma)
+        .signal_kind(MaKind::Smma)
+        .build(&data)
+        .unwrap();
+    assert_eq!(mixed.kinds(), [MaKind::Wma, MaKind::Ema, MaKind::Smma]);
+    assert_ne!(mixed.value(), ema.value());
+}
+
+#[test]
+/// Bandwidth, %B, and squeezes of the Bollinger Bands, with equal bands and a 0 middle handled.
+fn bbands_bandwidth() {
+    use tatk::indicators::BollingerBands;
+    use tatk::traits::Next;
+
+    // Mean 3 and standard deviation sqrt(2.5).
+    let bbands = BollingerBands::new(5, &[1.0, 2.0, 3.0, 4.0, 5.0], 2.0).unwrap();
+    let width = 4.0 * 2.5_f64.sqrt();
+    assert!((bbands.bandwidth() - width / 3.0).abs() < 1e-12);
+    assert!((bbands.percent_b(bbands.lower())).abs() < 1e-12);
+    assert!((bbands.percent_b(bbands.upper()) - 1.0).abs() < 1e-12);
+    assert!((bbands.percent_b(3.0) - 0.5).abs() < 1e-12);
+    assert!(bbands.percent_b(10.0) > 1.0);
+
+    // No deviation and a middle of 0.
+    let flat = BollingerBands::new(5, &[5.0; 5], 2.0).unwrap();
+    assert_eq!(flat.bandwidth(), 0.0);
+    assert_eq!(flat.percent_b(7.0), 0.5);
+    assert_eq!(
+        BollingerBands::new(5, &[0.0; 5], 2.0).unwrap().bandwidth(),
+        0.0
+    );
+
+    // Swings narrowing into a squeeze, then a breakout.
+    let mut bbands = BollingerBands::new(5, &[90.0, 110.0, 90.0, 110.0, 90.0], 2.0).unwrap();
+    for amplitude in [8.0, 6.0, 4.0, 2.0, 1.0] {
+        bbands.next(100.0 + amplitude);
+        bbands.next(100.0 - amplitude);
+    }
+    assert!(bbands.is_squeeze(5));
+    bbands.next(120.0);
+    assert!(!bbands.is_squeeze(5));
+    assert!(bbands.is_squeeze(1));
+}
+
+#[test]
+/// Stats of the Bollinger Bands are of the middle line, the band history is bounded by the period.
+fn bbands_history() {
+    use tatk::indicators::{BollingerBands, SimpleMovingAverage};
+    use tatk::traits::{Next, Reset, Stats};
+
+    let data: Vec<f64> = (0..40).map(|i| 100.0 + ((i * 7) % 11) as f64).collect();
+    let mut bbands = BollingerBands::new(10, &data[..10], 2.0).unwrap();
+    let mut sma = SimpleMovingAverage::new(10, &data[..10]).unwrap();
+    assert_eq!(bbands.lower_history(), &[bbands.lower()]);
+
+    let (mut lowers, mut uppers) = (vec![bbands.lower()], vec![bbands.upper()]);
+    for v in data[10..].iter() {
+        bbands.next(*v);
+        sma.next(*v);
+        lowers.push(bbands.lower());
+        uppers.push(bbands.upper());
+    }
+
+    assert_eq!(bbands.sum(), sma.sum());
+    assert_eq!(bbands.mean(), sma.mean());
+    assert_eq!(bbands.stdev(true), sma.stdev(true));
+    assert_eq!(bbands.variance(false), sma.variance(false));
+    assert_eq!(bbands.lower_history(), &lowers[lowers.len() - 10..]);
+    assert_eq!(bbands.upper_history(), &uppers[uppers.len() - 10..]);
+    assert_eq!(bbands.bandwidth_history().len(), 10);
+    assert_eq!(
+        *bbands.bandwidth_history().last().unwrap(),
+        bbands.bandwidth()
+    );
+
+    bbands.reset();
+    assert!(bbands.upper_history().is_empty());
+}
+
+#[test]
+fn keltner_channels() {
+    use tatk::indicators::{AverageTrueRange, ExponentialMovingAverage, KeltnerChannels};
+    use tatk::traits::{Next, Ready, Reset};
+
+    let data: Vec<Hlc> = (0..30)
+        .map(|i| {
+            let close = 100.0 + ((i * 7) % 11) as f64;
+            Hlc((close + 1.5, close - 2.0, close))
+        })
+        .collect();
+    let closes: Vec<f64> = data.iter().map(|v| v.0 .2).collect();
+
+    let mut kc = KeltnerChannels::new(10, 2.0, &data[..11]).unwrap();
+    let mut ema = ExponentialMovingAverage::new(10, &closes[..11]).unwrap();
+    let mut atr = AverageTrueRange::new(10, &data[..11]).unwrap();
+    for v in data[11..].iter() {
+        let bands = kc.next(v);
+        ema.next(v.0 .2);
+        atr.next(v.0);
+        assert_eq!(bands.middle, ema.value());
+        assert_eq!(bands.lower, ema.value() - 2.0 * atr.value());
+        assert_eq!(bands.upper, ema.value() + 2.0 * atr.value());
+    }
+
+    // Created from all of the data, the same as supplied one at a time.
+    let created = KeltnerChannels::new(10, 2.0, &data).unwrap();
+    assert_eq!(created.lower(), kc.lower());
+    assert_eq!(created.upper(), kc.upper());
+
+    kc.reset();
+    assert!(!kc.is_ready());
+    for v in data.iter() {
+        kc.next(v);
+    }
+    assert_eq!(kc.upper(), created.upper());
+
+    assert!(KeltnerChannels::new(10, 0.0, &data).is_err());
+    assert!(KeltnerChannels::new(10, 2.0, &data[..10]).is_err());
+}
+
+#[test]
+fn squeeze_breakout() {
+    use tatk::indicators::Squeeze;
+    use tatk::traits::{Next, Ready, Reset};
+
+    // Closes barely move within wide candles, then break out upwards.
+    let mut data: Vec<Hlc> = (0..20)
+        .map(|i| {
+            let close = 100.0 + (i % 2) as f64 * 0.2;
+            Hlc((close + 1.0, close - 1.0, close))
+        })
+        .collect();
+    data.extend((1..=5).map(|i| {
+        let close = 100.0 + i as f64 * 5.0;
+        Hlc((close + 1.0, close - 1.0, close))
+    }));
+
+    let mut squeeze = Squeeze::new(5, 2.0, 1.5, &data[..20]).unwrap();
+    assert!(squeeze.is_squeezed());
+    assert!(!squeeze.fired());
+
+    let output = squeeze.next(&data[20]);
+    assert!(output.fired && squeeze.fired());
+    assert!(!squeeze.is_squeezed());
+    assert!(squeeze.momentum() > 0.0);
+    assert_eq!(output.momentum, squeeze.momentum());
+
+    // Only fires on the candle that released the squeeze.
+    squeeze.next(&data[21]);
+    assert!(!squeeze.fired());
+
+    // Streaming matches creating from the data.
+    let created = Squeeze::new(5, 2.0, 1.5, &data[..22]).unwrap();
+    let mut streamed = Squeeze::with_period(5, 2.0, 1.5).unwrap();
+    for v in data[..22].iter() {
+        streamed.next(v);
+    }
+    assert!(streamed.is_ready());
+    assert_eq!(streamed.momentum(), created.momentum());
+    assert_eq!(created.momentum(), squeeze.momentum());
+
+    squeeze.reset();
+    assert!(!squeeze.is_ready() && !squeeze.is_squeezed());
+    assert!(Squeeze::new(5, 2.0, 1.5, &data[..8]).is_err());
+    assert!(Squeeze::new(1, 2.0, 1.5, &data).is_err());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+fn rsi_smoothing() {
+    use tatk::indicators::{RelativeStrengthIndex, RsiSmoothing};
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Reset};
+    const DATA: &[f64] = TestData::talib_small();
+
+    // Reference gains and losses between each value.
+    let changes: Vec<(f64, f64)> = DATA
+        .windows(2)
+        .map(|w| ((w[1] - w[0]).max(0.0), (w[0] - w[1]).max(0.0)))
+        .collect();
+    let index = |gain: f64, loss: f64| 100.0 - 100.0 / (1.0 + gain / loss);
+
+    // Wilder, seeded with the average of the first period then smoothed.
+    let (mut gain, mut loss) = changes[..10]
+        .iter()
+        .fold((0.0, 0.0), |(g, l), (cg, cl)| (g + cg, l + cl));
+    (gain, loss) = (gain / 10.0, loss / 10.0);
+    for (g, l) in changes[10..].iter() {
+        gain = (gain * 9.0 + g) / 10.0;
+        loss = (loss * 9.0 + l) / 10.0;
+    }
+    let wilder = RelativeStrengthIndex::new(10, DATA).unwrap();
+    assert!((wilder.value() - index(gain, loss)).abs() < 1e-9);
+    assert_eq!(wilder.smoothing(), RsiSmoothing::Wilder);
+
+    // Cutler, the simple average of the last period of changes.
+    let mut cutler =
+        RelativeStrengthIndex::with_smoothing(10, &DATA[..11], RsiSmoothing::Sma).unwrap();
+    for (i, v) in DATA[11..].iter().enumerate() {
+        let recent = &changes[i + 1..i + 11];
+        let gain: f64 = recent.iter().map(|c| c.0).sum::<f64>() / 10.0;
+        let loss: f64 = recent.iter().map(|c| c.1).sum::<f64>() / 10.0;
+        assert!((cutler.next(*v) - index(gain, loss)).abs() < 1e-9);
+    }
+    let created = RelativeStrengthIndex::with_smoothing(10, DATA, RsiSmoothing::Sma).unwrap();
+    assert!((created.value() - cutler.value()).abs() < 1e-9);
+    assert_ne!(created.value(), wilder.value());
+
+    // EMA, seeded the same as Wilder with a factor of 2 / (n + 1).
+    let ema = RelativeStrengthIndex::with_smoothing(10, &DATA[..12], RsiSmoothing::Ema).unwrap();
+    let (mut gain, mut loss) = changes[..10].iter().fold((0.0, 0.0), |(g, l), (cg, cl)| {
+        (g + cg / 10.0, l + cl / 10.0)
+    });
+    gain += (changes[10].0 - gain) * 2.0 / 11.0;
+    loss += (changes[10].1 - loss) * 2.0 / 11.0;
+    assert!((ema.value() - index(gain, loss)).abs() < 1e-9);
+
+    // Smoothing is kept when recreated after a reset.
+    let mut reset = created.clone();
+    reset.reset();
+    for v in DATA.iter() {
+        reset.next(*v);
+    }
+    assert!((reset.value() - created.value()).abs() < 1e-9);
+
+    let built = RelativeStrengthIndex::builder()
+        .period(10)
+        .smoothing(RsiSmoothing::Sma)
+        .build(DATA)
+        .unwrap();
+    assert_eq!(built.value(), created.value());
+}
+
+#[test]
+fn rsi_divergence() {
+    use tatk::indicators::{DivergenceEvent, RelativeStrengthIndex, RsiDivergence};
+    use tatk::traits::Reset;
+
+    // Sharp drop to a low of 90, a slow drift to a lower low of 89, then two highs of 103 and 104
+    // with the second rising slower.
+    const PRICES: &[f64] = &[
+        100.0, 96.0, 90.0, 93.0, 95.0, 94.0, 93.0, 92.0, 91.0, 89.0, 91.0, 93.0, 97.0, 103.0,
+        101.0, 99.0, 100.0, 102.0, 104.0, 102.0, 100.0,
+    ];
+    let rsi = RelativeStrengthIndex::new(3, &[100.0, 101.0, 100.0, 101.0]).unwrap();
+    let mut divergence = RsiDivergence::new(rsi.clone(), 2, 3).unwrap();
+
+    let events: Vec<(usize, DivergenceEvent)> = PRICES
+        .iter()
+        .enumerate()
+        .filter_map(|(i, p)| divergence.next_with_price(*p).map(|e| (i, e)))
+        .collect();
+
+    // Confirmed `lookback` prices after the second pivot, (9) and (18).
+    assert_eq!(
+        events,
+        vec![
+            (11, DivergenceEvent::RegularBullish),
+            (20, DivergenceEvent::RegularBearish)
+        ]
+    );
+    assert_eq!(divergence.event(), Some(DivergenceEvent::RegularBearish));
+
+    // The lows are 7 prices apart and the highs 5, too close to be compared.
+    let mut distant = RsiDivergence::new(rsi.clone(), 2, 6).unwrap();
+    let events: Vec<DivergenceEvent> = PRICES
+        .iter()
+        .filter_map(|p| distant.next_with_price(*p))
+        .collect();
+    assert_eq!(events, vec![DivergenceEvent::RegularBullish]);
+
+    divergence.reset();
+    assert_eq!(divergence.event(), None);
+    assert!(RsiDivergence::new(rsi, 0, 3).is_err());
+}
+
+#[test]
+fn rsi_signal_edges() {
+    use tatk::indicators::RelativeStrengthIndex;
+    use tatk::traits::{Next, Reset, SignalSource};
+    use tatk::{Signal, TAError};
+
+    // Only losses, starting oversold.
+    let mut rsi = RelativeStrengthIndex::with_thresholds(2, &[10.0, 9.0, 8.0], 30.0, 70.0).unwrap();
+    assert!(rsi.is_oversold());
+    assert_eq!(rsi.signal(), Signal::Hold);
+
+    // (RSI, Signal) for each value, only the value leaving a threshold signals.
+    let expected = [
+        (8.5, Signal::Buy),   // 33.3, left oversold.
+        (9.0, Signal::Hold),  // 60.0
+        (10.0, Signal::Hold), // 84.6, entered overbought.
+        (11.0, Signal::Hold), // 93.1, still overbought.
+        (10.5, Signal::Sell), // 60.0, left overbought.
+        (10.4, Signal::Hold),
+    ];
+    for (value, signal) in expected {
+        rsi.next(value);
+        assert_eq!(rsi.signal(), signal, "value {}", value);
+    }
+
+    // Threshold order and range are validated.
+    for (oversold, overbought) in [(70.0, 30.0), (50.0, 50.0), (-1.0, 70.0), (30.0, 101.0)] {
+        assert!(matches!(
+            RelativeStrengthIndex::with_thresholds(2, &[1.0, 2.0, 3.0], oversold, overbought),
+            Err(TAError::InvalidSize(_))
+        ));
+    }
+
+    rsi.reset();
+    assert_eq!(rsi.signal(), Signal::Hold);
+}
+
+#[test]
+/// A skipped non-finite value holds instead of repeating the signal of the prior value.
+fn rsi_signal_skipped_value() {
+    use tatk::indicators::RelativeStrengthIndex;
+    use tatk::traits::{Next, SignalSource};
+    use tatk::{NonFinite, Signal};
+
+    let mut rsi = RelativeStrengthIndex::with_thresholds(2, &[10.0, 9.0, 8.0], 30.0, 70.0).unwrap();
+    rsi.set_non_finite(NonFinite::Skip);
+
+    // Leaves oversold, then a NaN is skipped.
+    rsi.next(8.5);
+    assert_eq!(rsi.signal(), Signal::Buy);
+    let value = rsi.value();
+    assert_eq!(rsi.next(f64::NAN), value);
+    assert_eq!(rsi.signal(), Signal::Hold);
+
+    // Leaves overbought, then an infinite value is skipped.
+    for v in [9.0, 10.0, 11.0, 10.5] {
+        rsi.next(v);
+    }
+    assert_eq!(rsi.signal(), Signal::Sell);
+    rsi.next(f64::INFINITY);
+    assert_eq!(rsi.signal(), Signal::Hold);
+}
+
+#[test]
+fn rsi_without_losses() {
+    use tatk::indicators::{RelativeStrengthIndex, RsiSmoothing};
+    use tatk::traits::Next;
+
+    let rising: Vec<f64> = (0..20).map(|i| 100.0 + i as f64).collect();
+    let falling: Vec<f64> = rising.iter().rev().copied().collect();
+    let flat = vec![100.0; 20];
+
+    for (data, expected) in [(&rising, 100.0), (&falling, 0.0), (&flat, 50.0)] {
+        for smoothing in [RsiSmoothing::Wilder, RsiSmoothing::Sma, RsiSmoothing::Ema] {
+            // Seed window only, then streamed after it.
+            let mut rsi =
+                RelativeStrengthIndex::with_smoothing(14, &data[..15], smoothing).unwrap();
+            assert_eq!(rsi.value(), expected);
+            for v in data[15..].iter() {
+                assert_eq!(rsi.next(*v), expected);
+            }
+
+            // Streamed from no data.
+            let mut streamed = RelativeStrengthIndex::with_period(14).unwrap();
+            for v in data.iter() {
+                streamed.next(*v);
+            }
+            assert_eq!(streamed.value(), expected);
+        }
+    }
+}
+
+#[test]
+fn atr_smoothing() {
+    use tatk::indicators::{AtrSmoothing, AverageTrueRange};
+    use tatk::traits::{Next, Reset};
+
+    // True ranges of 2, 3, 2, 4, and 2.
+    let data = [
+        Hlc((10.0, 8.0, 9.0)),
+        Hlc((11.0, 9.0, 10.0)),
+        Hlc((12.0, 9.0, 11.0)),
+        Hlc((12.0, 10.0, 11.0)),
+        Hlc((15.0, 11.0, 14.0)),
+        Hlc((14.0, 12.0, 13.0)),
+    ];
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+
+    // Each smoothing starts from the mean of the first period, (2 + 3 + 2) / 3.
+    let expected = [
+        (AtrSmoothing::Wilder, [26.0 / 9.0, 70.0 / 27.0]),
+        (AtrSmoothing::Sma, [3.0, 8.0 / 3.0]),
+        (AtrSmoothing::Ema, [19.0 / 6.0, 31.0 / 12.0]),
+    ];
+    for (smoothing, values) in expected {
+        let mut atr = AverageTrueRange::with_smoothing(3, &data[..4], smoothing).unwrap();
+        assert_eq!(atr.smoothing(), smoothing);
+        assert!(close(atr.value(), 7.0 / 3.0));
+        for (candle, value) in data[4..].iter().zip(values) {
+            assert!(close(atr.next(candle.0), value), "{:?}", smoothing);
+        }
+
+        // Created from all of the candles, by the builder, and after a reset.
+        let created = AverageTrueRange::with_smoothing(3, &data, smoothing).unwrap();
+        assert!(close(created.value(), values[1]));
+        let built = AverageTrueRange::builder()
+            .period(3)
+            .smoothing(smoothing)
+            .build(&data)
+            .unwrap();
+        assert_eq!(built.value(), created.value());
+        atr.reset();
+        for candle in data.iter() {
+            atr.next(candle.0);
+        }
+        assert_eq!(atr.value(), created.value());
+    }
+
+    // Wilder remains the default.
+    let atr = AverageTrueRange::new(3, &data).unwrap();
+    assert_eq!(atr.smoothing(), AtrSmoothing::Wilder);
+}
+
+/// High, Low, and Close of a candle.
+struct Hlc((f64, f64, f64));
+
+impl tatk::traits::High for Hlc {
+    fn high(&self) -> f64 {
+        self.0 .0
+    }
+}
+
+impl tatk::traits::Low for Hlc {
+    fn low(&self) -> f64 {
+        self.0 .1
+    }
+}
+
+impl tatk::traits::Close for Hlc {
+    fn close(&self) -> f64 {
+        self.0 .2
+    }
+}
+
+/// Converts (high, low, close) values into candles.
+fn to_hlc(values: &[(f64, f64, f64)]) -> Vec<Hlc> {
+    values.iter().map(|v| Hlc(*v)).collect()
+}
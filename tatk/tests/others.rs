@@ -21,7 +21,7 @@ fn next_variance_sample() {
     const DATA: &[f64] = TestData::talib_small();
 
     let mut indicator = Variance::new(10, &DATA[..DATA.len() - 1], true).unwrap();
-    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 12.190039166666669)
+    assert!((indicator.next(DATA[DATA.len() - 1]) - 12.190039166666669).abs() < 1e-12)
 }
 
 #[test]
@@ -47,7 +47,7 @@ fn next_variance_population() {
     const DATA: &[f64] = TestData::talib_small();
 
     let mut indicator = Variance::new(10, &DATA[..DATA.len() - 1], false).unwrap();
-    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 10.971035250000002)
+    assert!((indicator.next(DATA[DATA.len() - 1]) - 10.971035250000002).abs() < 1e-12)
 }
 
 #[test]
@@ -73,7 +73,32 @@ fn next_stdev_sample() {
     const DATA: &[f64] = TestData::talib_small();
 
     let mut indicator = StandardDeviation::new(10, &DATA[..DATA.len() - 1], true).unwrap();
-    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 3.491423659005975)
+    assert!((indicator.next(DATA[DATA.len() - 1]) - 3.491423659005975).abs() < 1e-12)
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Standard deviation held in a StaticBuffer produces the same values as one held in a Buffer.
+fn static_stdev() {
+    use tatk::indicators::StandardDeviation;
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Reset};
+    use tatk::StaticBuffer;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let mut indicator: StandardDeviation<StaticBuffer<10>> =
+        StandardDeviation::with_window(10, &DATA[..DATA.len() - 1], true).unwrap();
+    assert!((indicator.value() - 3.3640912591664334).abs() < 1e-12);
+    assert!(StandardDeviation::<StaticBuffer<5>>::with_window(10, DATA, true).is_err());
+
+    let mut heap = StandardDeviation::new(10, &DATA[..10], true).unwrap();
+    indicator.reset();
+    heap.reset();
+    for v in DATA.iter() {
+        // A sample of a single value is NaN for both.
+        let (fixed, value) = (indicator.next(*v), heap.next(*v));
+        assert!((fixed - value).abs() < 1e-12 || (fixed.is_nan() && value.is_nan()));
+    }
 }
 
 #[test]
@@ -99,7 +124,33 @@ fn next_stdev_population() {
     const DATA: &[f64] = TestData::talib_small();
 
     let mut indicator = StandardDeviation::new(10, &DATA[..DATA.len() - 1], false).unwrap();
-    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 3.3122553117173807)
+    assert!((indicator.next(DATA[DATA.len() - 1]) - 3.3122553117173807).abs() < 1e-12)
+}
+
+#[test]
+/// Statistics of a Standard Deviation and Variance are of the values held, the Standard Deviation
+/// being the same as its own value.
+fn deviation_stats() {
+    use tatk::indicators::{BollingerBands, StandardDeviation, Variance};
+    use tatk::traits::{Next, Stats};
+    let data: [f64; 5] = [2.0, 4.0, 4.0, 5.0, 5.0];
+
+    fn mean_of<S: Stats>(stats: &S) -> f64 {
+        stats.mean()
+    }
+
+    let stdev = StandardDeviation::new(5, &data, true).unwrap();
+    let variance = Variance::new(5, &data, true).unwrap();
+    assert_eq!(mean_of(&stdev), 4.0);
+    assert_eq!(mean_of(&variance), 4.0);
+    assert_eq!(variance.sum(), 20.0);
+    assert!((stdev.stdev(true) - stdev.value()).abs() < 1e-12);
+    assert!((variance.variance(true) - variance.value()).abs() < 1e-12);
+
+    // Bands around the deviation are the deviation of the values held.
+    let mut bbands = BollingerBands::with_line(stdev, 1.0).unwrap();
+    bbands.next(6.0);
+    assert!((bbands.upper() - bbands.value() - bbands.value()).abs() < 1e-12);
 }
 
 #[test]
@@ -111,7 +162,7 @@ fn create_linereg() {
     const DATA: &[f64] = TestData::talib_small();
 
     let indicator = LinearRegression::new(10, &DATA[..DATA.len() - 1]).unwrap();
-    assert_eq!(indicator.value(), 89.77590909090917)
+    assert_eq!(indicator.value(), 89.77590909090914)
 }
 
 #[test]
@@ -125,5 +176,3930 @@ fn next_linereg() {
     const DATA: &[f64] = TestData::talib_small();
 
     let mut indicator = LinearRegression::new(10, &DATA[..DATA.len() - 1]).unwrap();
-    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 88.69072727272732)
+    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 88.69072727272729)
+}
+
+#[test]
+/// Incrementally updated line stays within 1e-9 of a line fit to the same period from scratch
+/// over 100,000 values.
+fn linereg_incremental_matches_brute_force() {
+    use tatk::indicators::LinearRegression;
+    use tatk::traits::Next;
+    let period: usize = 200;
+
+    // Simple deterministic pseudo-random walk.
+    let mut seed: u64 = 42;
+    let mut price: f64 = 1_000.0;
+    let mut random = move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        price += (seed >> 33) as f64 / (1u64 << 31) as f64 - 0.5;
+        price
+    };
+
+    let mut data: Vec<f64> = (0..period).map(|_| random()).collect();
+    let mut indicator = LinearRegression::new(period, &data).unwrap();
+
+    for _ in 0..100_000 {
+        data.remove(0);
+        data.push(random());
+        indicator.next(data[period - 1]);
+
+        let expected = LinearRegression::new(period, &data).unwrap();
+        assert!((indicator.value() - expected.value()).abs() < 1e-9);
+        assert!((indicator.slope() - expected.slope()).abs() < 1e-9);
+        assert!((indicator.intercept() - expected.intercept()).abs() < 1e-9);
+    }
+}
+
+#[test]
+/// Angle and percent slope of flat, rising, and falling lines.
+fn linereg_normalized_slope() {
+    use tatk::indicators::LinearRegression;
+
+    let flat = LinearRegression::new(4, &[5.0, 5.0, 5.0, 5.0]).unwrap();
+    assert_eq!(flat.slope_degrees(None), 0.0);
+    assert_eq!(flat.slope_pct(), 0.0);
+
+    // Rises by 1 each value around a mean of 2.5.
+    let rising = LinearRegression::new(4, &[1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert!((rising.slope_degrees(None) - 45.0).abs() < 1e-9);
+    assert!((rising.slope_degrees(Some(0.5)) - 2.0_f64.atan().to_degrees()).abs() < 1e-9);
+    assert!((rising.slope_pct() - 40.0).abs() < 1e-9);
+
+    // Falls by 10 each value around a mean of 85.
+    let falling = LinearRegression::new(4, &[100.0, 90.0, 80.0, 70.0]).unwrap();
+    assert!((falling.slope_degrees(Some(10.0)) + 45.0).abs() < 1e-9);
+    assert!((falling.slope_pct() + 1000.0 / 85.0).abs() < 1e-9);
+}
+
+#[test]
+/// Residuals, MSE, and standard error of a line fit to 1, 3, 2, 5, 4. The line is
+/// 0.6 + 0.8x, the same as `numpy.polyfit` of the values against 1 through 5.
+fn linereg_residuals() {
+    use tatk::indicators::LinearRegression;
+    use tatk::traits::Next;
+    let expected: [f64; 5] = [-0.4, 0.8, -1.0, 1.2, -0.6];
+
+    let mut indicator = LinearRegression::new(5, &[1.0, 3.0, 2.0, 5.0, 4.0]).unwrap();
+    assert!((indicator.intercept() - 0.6).abs() < 1e-12);
+    assert!((indicator.slope() - 0.8).abs() < 1e-12);
+    for (residual, expected) in indicator.residuals().iter().zip(expected) {
+        assert!((residual - expected).abs() < 1e-12);
+    }
+
+    assert!((indicator.mse() - 0.72).abs() < 1e-12);
+    assert!((indicator.standard_error() - 1.2_f64.sqrt()).abs() < 1e-12);
+    assert!((indicator.r_sq() - 0.64).abs() < 1e-12);
+
+    // Moves to 3, 2, 5, 4, 7, fit by 1.2 + 1.0x.
+    let expected: [f64; 5] = [0.8, -1.2, 0.8, -1.2, 0.8];
+    indicator.next(7.0);
+    for (residual, expected) in indicator.residuals().iter().zip(expected) {
+        assert!((residual - expected).abs() < 1e-12);
+    }
+
+    assert!((indicator.mse() - 0.96).abs() < 1e-12);
+    assert!((indicator.standard_error() - 1.6_f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate the linear regression channel using 20 data points with a period of 10.
+fn create_linereg_channel() {
+    use tatk::indicators::{ChannelWidth, LinearRegressionChannel};
+    use tatk::test_data::TestData;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let indicator =
+        LinearRegressionChannel::new(10, &DATA[..DATA.len() - 1], ChannelWidth::Deviation(2.0))
+            .unwrap();
+    assert_eq!(indicator.value(), 89.77590909090914);
+    assert_eq!(indicator.lower(), 84.4342166769716);
+    assert_eq!(indicator.upper(), 95.11760150484668);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate the linear regression channel using 20 data points with a period of 10,
+/// then adds an additional data point.
+fn next_linereg_channel() {
+    use tatk::indicators::{ChannelWidth, LinearRegressionChannel};
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let mut indicator =
+        LinearRegressionChannel::new(10, &DATA[..DATA.len() - 1], ChannelWidth::Deviation(2.0))
+            .unwrap();
+    assert_eq!(
+        indicator.next(DATA[DATA.len() - 1]),
+        (84.0323260354419, 88.69072727272729, 93.34912851001268)
+    )
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Ensures the maximum residual channel contains every value within the period as it moves.
+fn linereg_channel_max_residual_contains() {
+    use tatk::indicators::{ChannelWidth, LinearRegressionChannel};
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+    let period: usize = 14;
+
+    let mut indicator =
+        LinearRegressionChannel::new(period, &DATA[..period], ChannelWidth::MaxResidual).unwrap();
+
+    for end in period..DATA.len() {
+        if end > period {
+            indicator.next(DATA[end - 1]);
+        }
+
+        let offset = indicator.upper() - indicator.value();
+        for (i, y) in DATA[(end - period)..end].iter().enumerate() {
+            let line = indicator.intercept() + indicator.slope() * (i + 1) as f64;
+            assert!(*y <= line + offset + 1e-9);
+            assert!(*y >= line - offset - 1e-9);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate Beta from 20 prices with a period of 10, comparing against a brute-force
+/// covariance / variance of the simple returns.
+fn create_beta() {
+    use tatk::indicators::{Beta, ReturnKind};
+    use tatk::test_data::TestData;
+    const DATA: &[f64] = TestData::talib();
+    let asset = &DATA[..20];
+    let benchmark = &DATA[20..40];
+
+    let indicator = Beta::from_prices(10, asset, benchmark, ReturnKind::Simple).unwrap();
+
+    // Brute-force the last 10 returns.
+    let ret = |p: &[f64]| -> Vec<f64> { p.windows(2).map(|w| (w[1] - w[0]) / w[0]).collect() };
+    let (a, b) = (ret(asset), ret(benchmark));
+    let (a, b) = (&a[a.len() - 10..], &b[b.len() - 10..]);
+    let mean_a = a.iter().sum::<f64>() / 10.0;
+    let mean_b = b.iter().sum::<f64>() / 10.0;
+    let cov: f64 = a
+        .iter()
+        .zip(b)
+        .map(|(x, y)| (x - mean_a) * (y - mean_b))
+        .sum();
+    let var_b: f64 = b.iter().map(|y| (y - mean_b).powi(2)).sum();
+    let var_a: f64 = a.iter().map(|x| (x - mean_a).powi(2)).sum();
+
+    assert!((indicator.value() - cov / var_b).abs() < 1e-12);
+    assert!((indicator.correlation() - cov / (var_a * var_b).sqrt()).abs() < 1e-12);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates Beta of an asset against itself, then adds a full period of returns from an asset that
+/// moves twice as much as the benchmark.
+fn next_beta() {
+    use tatk::indicators::Beta;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+    let returns: Vec<f64> = DATA.windows(2).map(|w| (w[1] - w[0]) / w[0]).collect();
+    let paired: Vec<(f64, f64)> = returns[..10].iter().map(|r| (*r, *r)).collect();
+
+    let mut indicator = Beta::new(10, &paired).unwrap();
+    assert!((indicator.value() - 1.0).abs() < 1e-12);
+    assert!((indicator.correlation() - 1.0).abs() < 1e-12);
+
+    for r in returns[10..20].iter() {
+        indicator.next((r * 2.0, *r));
+    }
+    assert!((indicator.value() - 2.0).abs() < 1e-12);
+    assert!((indicator.correlation() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+/// Beta from prices rejects prices that are not positive, naming the asset or benchmark.
+fn beta_invalid_prices() {
+    use tatk::indicators::{Beta, ReturnKind};
+    use tatk::TAError;
+
+    let prices = [1.0, 1.1, 1.2, 1.3];
+    let zero = [1.0, 0.0, 1.2, 1.3];
+    let negative = [1.0, 1.1, -1.2, 1.3];
+    for kind in [ReturnKind::Simple, ReturnKind::Log] {
+        match Beta::from_prices(2, &zero, &prices, kind) {
+            Err(TAError::InvalidInput(text)) => assert_eq!(
+                text,
+                "asset price 0 at index 1 must be finite and greater than 0 to calculate beta"
+            ),
+            other => panic!("expected an invalid input, got {:?}", other),
+        }
+        match Beta::from_prices(2, &prices, &negative, kind) {
+            Err(TAError::InvalidInput(text)) => assert_eq!(
+                text,
+                "benchmark price -1.2 at index 2 must be finite and greater than 0 to calculate beta"
+            ),
+            other => panic!("expected an invalid input, got {:?}", other),
+        }
+    }
+    assert!(Beta::from_prices(2, &prices, &[1.0, f64::NAN, 1.2, 1.3], ReturnKind::Simple).is_err());
+    assert!(Beta::from_prices(2, &prices, &prices, ReturnKind::Log).is_ok());
+}
+
+#[test]
+/// Beta keeps the previous value when the benchmark has no variance.
+fn beta_zero_variance() {
+    use tatk::indicators::Beta;
+    use tatk::traits::Next;
+
+    // No prior defined value.
+    let mut indicator = Beta::new(3, &[(0.1, 0.0), (0.2, 0.0), (0.3, 0.0)]).unwrap();
+    assert_eq!(indicator.value(), 0.0);
+    assert_eq!(indicator.correlation(), 0.0);
+
+    // Defined, then flat benchmark.
+    assert_eq!(indicator.next((0.4, 1.0)), 0.15000000000000002);
+    indicator.next((0.1, 1.0));
+    let value = indicator.next((0.2, 1.0));
+    assert_eq!(indicator.next((0.3, 1.0)), value);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate the rolling extrema using 252 data points with a period of 14.
+fn create_rolling_extrema() {
+    use tatk::indicators::RollingExtrema;
+    use tatk::test_data::TestData;
+    const DATA: &[f64] = TestData::talib();
+
+    let indicator = RollingExtrema::new(14, &DATA[..DATA.len() - 1]).unwrap();
+    assert_eq!(indicator.max(), 110.12);
+    assert_eq!(indicator.min(), 107.0);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Compares the rolling extrema and their ages against a brute-force scan as values are added.
+fn next_rolling_extrema() {
+    use tatk::indicators::RollingExtrema;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+    let period: usize = 14;
+
+    let mut indicator = RollingExtrema::new(period, &DATA[..period]).unwrap();
+    for end in (period + 1)..=DATA.len() {
+        let (min, max) = indicator.next(DATA[end - 1]);
+        let window = &DATA[(end - period)..end];
+
+        // Most recent position of each extreme, as an age from the newest value.
+        let max_pos = window.iter().rposition(|v| *v == max).unwrap();
+        let min_pos = window.iter().rposition(|v| *v == min).unwrap();
+
+        assert_eq!(max, window.iter().cloned().fold(f64::MIN, f64::max));
+        assert_eq!(min, window.iter().cloned().fold(f64::MAX, f64::min));
+        assert_eq!(indicator.max_index_age(), period - 1 - max_pos);
+        assert_eq!(indicator.min_index_age(), period - 1 - min_pos);
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Tracks the highest high and lowest low of candles as they are added.
+fn next_rolling_extrema_candles() {
+    use tatk::indicators::RollingExtrema;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+    let period: usize = 10;
+
+    let mut indicator = RollingExtrema::from_candles(period, &candles[..period]).unwrap();
+    for end in (period + 1)..=candles.len() {
+        let (low, high) = indicator.next(&candles[end - 1]);
+        let window = &candles[(end - period)..end];
+
+        assert_eq!(high, window.iter().map(|c| c.high).fold(f64::MIN, f64::max));
+        assert_eq!(low, window.iter().map(|c| c.low).fold(f64::MAX, f64::min));
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Median Absolute Deviation from 252 data points, both unscaled and scaled.
+fn create_mad() {
+    use tatk::indicators::MedianAbsoluteDeviation;
+    use tatk::test_data::TestData;
+    const DATA: &[f64] = TestData::talib();
+    let period: usize = 10;
+
+    let indicator = MedianAbsoluteDeviation::new(period, &DATA[..DATA.len() - 1], false).unwrap();
+    assert_eq!(indicator.value(), 0.5649999999999977);
+
+    let scaled = MedianAbsoluteDeviation::new(period, &DATA[..DATA.len() - 1], true).unwrap();
+    assert_eq!(scaled.value(), indicator.value() * 1.4826);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Median Absolute Deviation from 251 data points, then adds an additional data point
+/// comparing against the deviations of the entire period.
+fn next_mad() {
+    use tatk::indicators::MedianAbsoluteDeviation;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    use tatk::Buffer;
+    const DATA: &[f64] = TestData::talib();
+    let period: usize = 10;
+
+    let mut indicator =
+        MedianAbsoluteDeviation::new(period, &DATA[..DATA.len() - 1], false).unwrap();
+    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 0.6800000000000068);
+
+    // Recalculate directly from the final period.
+    let window = Buffer::from_array(period, &DATA[(DATA.len() - period)..]).unwrap();
+    let median = window.median();
+    let deviations: Vec<f64> = window.queue().iter().map(|v| (v - median).abs()).collect();
+    let deviations = Buffer::from_array(period, &deviations).unwrap();
+    assert_eq!(indicator.median(), median);
+    assert_eq!(indicator.value(), deviations.median());
+}
+
+#[test]
+/// Constant data has a MAD of 0.0, the robust z-score must not divide by it.
+fn mad_constant_data() {
+    use tatk::indicators::MedianAbsoluteDeviation;
+    use tatk::traits::Next;
+
+    let mut indicator = MedianAbsoluteDeviation::new(5, &[4.0; 5], true).unwrap();
+    assert_eq!(indicator.value(), 0.0);
+    assert_eq!(indicator.robust_zscore(10.0), 0.0);
+
+    // A single outlier keeps the median and MAD at the constant value.
+    assert_eq!(indicator.next(100.0), 0.0);
+    assert_eq!(indicator.median(), 4.0);
+
+    let indicator = MedianAbsoluteDeviation::new(5, &[1.0, 2.0, 3.0, 4.0, 5.0], false).unwrap();
+    assert_eq!(indicator.value(), 1.0);
+    assert_eq!(indicator.robust_zscore(5.0), 2.0);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Historical Volatility from 251 data points, compared against the log returns of the
+/// final period.
+fn create_hv() {
+    use tatk::indicators::HistoricalVolatility;
+    use tatk::test_data::TestData;
+    use tatk::Buffer;
+    const DATA: &[f64] = TestData::talib();
+    let period: usize = 20;
+
+    let indicator = HistoricalVolatility::new(period, 252.0, &DATA[..DATA.len() - 1]).unwrap();
+    assert!((indicator.value() - 0.3719143620531474).abs() < 1e-12);
+
+    let prices = &DATA[(DATA.len() - 2 - period)..(DATA.len() - 1)];
+    let returns: Vec<f64> = prices.windows(2).map(|w| (w[1] / w[0]).ln()).collect();
+    let returns = Buffer::from_array(period, &returns).unwrap();
+    assert!((indicator.period_volatility() - returns.stdev(true)).abs() < 1e-12);
+    assert_eq!(
+        indicator.value(),
+        indicator.period_volatility() * 252.0_f64.sqrt()
+    );
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Historical Volatility from 251 data points, then adds an additional data point.
+fn next_hv() {
+    use tatk::indicators::HistoricalVolatility;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+
+    let mut indicator = HistoricalVolatility::new(20, 252.0, &DATA[..DATA.len() - 1]).unwrap();
+    assert!((indicator.next(DATA[DATA.len() - 1]) - 0.36919703160285755).abs() < 1e-12);
+
+    // Non-positive prices are ignored.
+    let value = indicator.value();
+    assert_eq!(indicator.next(0.0), value);
+    assert_eq!(indicator.next(-1.0), value);
+}
+
+#[test]
+/// Zero or negative prices, and invalid annualization, are rejected on construction.
+fn hv_invalid_prices() {
+    use tatk::indicators::HistoricalVolatility;
+    use tatk::TAError;
+
+    assert!(HistoricalVolatility::new(2, 252.0, &[1.0, 2.0, 0.0]).is_err());
+    assert!(HistoricalVolatility::new(2, 252.0, &[1.0, -2.0, 3.0]).is_err());
+    assert!(HistoricalVolatility::new(2, 0.0, &[1.0, 2.0, 3.0]).is_err());
+    assert!(HistoricalVolatility::new(2, f64::INFINITY, &[1.0, 2.0, 3.0]).is_err());
+    match HistoricalVolatility::new(2, f64::NAN, &[1.0, 2.0, 3.0]) {
+        Err(TAError::InvalidSize(text)) => assert_eq!(
+            text,
+            "annualization must be finite and greater than 0 to calculate historical volatility"
+        ),
+        other => panic!("expected an invalid size, got {:?}", other),
+    }
+    assert!(HistoricalVolatility::with_period(2, f64::NAN).is_err());
+    assert!(HistoricalVolatility::new(2, 252.0, &[1.0, 2.0]).is_err());
+    assert!(HistoricalVolatility::new(2, 252.0, &[1.0, 2.0, 3.0]).is_ok());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Parkinson Volatility from the test candles, compared against a brute-force
+/// computation of the final period.
+fn create_parkinson() {
+    use tatk::indicators::ParkinsonVolatility;
+    use tatk::test_data::{Candle, TestData};
+    let candles: Vec<Candle> = TestData::candles();
+    let period: usize = 20;
+
+    let indicator = ParkinsonVolatility::new(period, 365.0, &candles).unwrap();
+    assert_eq!(indicator.value(), 0.33747392810605714);
+
+    let sum: f64 = candles[(candles.len() - period)..]
+        .iter()
+        .map(|c| (c.high / c.low).ln().powi(2))
+        .sum();
+    let expected = (sum / (4.0 * 2.0_f64.ln() * period as f64)).sqrt();
+    assert!((indicator.period_volatility() - expected).abs() < 1e-12);
+    assert!((indicator.value() - expected * 365.0_f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Adds candles to a Parkinson Volatility, comparing each against a brute-force computation, and
+/// skips invalid candles.
+fn next_parkinson() {
+    use tatk::indicators::ParkinsonVolatility;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+    let period: usize = 10;
+
+    let mut indicator = ParkinsonVolatility::new(period, 1.0, &candles[..period]).unwrap();
+    for end in (period + 1)..=candles.len() {
+        let value = indicator.next(&candles[end - 1]);
+        let sum: f64 = candles[(end - period)..end]
+            .iter()
+            .map(|c| (c.high / c.low).ln().powi(2))
+            .sum();
+        let expected = (sum / (4.0 * 2.0_f64.ln() * period as f64)).sqrt();
+        assert!((value - expected).abs() < 1e-12);
+        assert!(!indicator.is_skipped());
+    }
+
+    // Invalid candles are skipped, keeping the current value.
+    let value = indicator.value();
+    assert_eq!(indicator.next((10.0, 0.0)), value);
+    assert!(indicator.is_skipped());
+    assert_eq!(indicator.next((10.0, 11.0)), value);
+    assert!(indicator.is_skipped());
+    assert_ne!(indicator.next((11.0, 10.0)), value);
+    assert!(!indicator.is_skipped());
+}
+
+#[test]
+/// Candles with a low of 0 or a high below the low are rejected on construction.
+fn parkinson_invalid_candles() {
+    use tatk::indicators::ParkinsonVolatility;
+    use tatk::traits::{High, Low};
+
+    struct HighLow(f64, f64);
+    impl High for HighLow {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for HighLow {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+
+    let zero = [HighLow(2.0, 1.0), HighLow(2.0, 0.0)];
+    let inverted = [HighLow(2.0, 1.0), HighLow(1.0, 2.0)];
+    let valid = [HighLow(2.0, 1.0), HighLow(2.0, 2.0)];
+    assert!(ParkinsonVolatility::new(2, 1.0, &zero).is_err());
+    assert!(ParkinsonVolatility::new(2, 1.0, &inverted).is_err());
+    assert!(ParkinsonVolatility::new(2, 0.0, &valid).is_err());
+    assert!(ParkinsonVolatility::new(2, 1.0, &valid).is_ok());
+}
+
+#[test]
+/// NaN and infinite annualization are rejected by the bar volatility estimators.
+fn bar_volatility_invalid_annualization() {
+    use tatk::indicators::{GarmanKlassVolatility, ParkinsonVolatility, RogersSatchellVolatility};
+    use tatk::TAError;
+
+    for annualization in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(ParkinsonVolatility::with_period(2, annualization).is_err());
+        assert!(GarmanKlassVolatility::with_period(2, annualization).is_err());
+        assert!(RogersSatchellVolatility::with_period(2, annualization).is_err());
+    }
+
+    match GarmanKlassVolatility::with_period(2, f64::NAN) {
+        Err(TAError::InvalidSize(text)) => assert_eq!(
+            text,
+            "annualization must be finite and greater than 0 to calculate garman-klass volatility"
+        ),
+        other => panic!("expected an invalid size, got {:?}", other),
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Adds candles to a Garman-Klass Volatility, comparing each against a brute-force computation.
+fn next_garman_klass() {
+    use tatk::indicators::GarmanKlassVolatility;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+    let period: usize = 10;
+
+    let term = |c: &Candle| {
+        0.5 * (c.high / c.low).ln().powi(2)
+            - (2.0 * 2.0_f64.ln() - 1.0) * (c.close / c.open).ln().powi(2)
+    };
+
+    let mut indicator = GarmanKlassVolatility::new(period, 252.0, &candles[..period]).unwrap();
+    for end in (period + 1)..=candles.len() {
+        let value = indicator.next(&candles[end - 1]);
+        let sum: f64 = candles[(end - period)..end].iter().map(term).sum();
+        let expected = (sum / period as f64).sqrt();
+        assert!((indicator.period_volatility() - expected).abs() < 1e-12);
+        assert!((value - expected * 252.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    let created = GarmanKlassVolatility::new(period, 252.0, &candles).unwrap();
+    assert_eq!(created.value(), 0.3510469965319257);
+
+    // Invalid candles are skipped, keeping the current value.
+    let value = indicator.value();
+    assert_eq!(indicator.next((0.0, 10.0, 9.0, 9.5)), value);
+    assert!(indicator.is_skipped());
+    assert_ne!(indicator.next((9.5, 10.0, 9.0, 9.5)), value);
+    assert!(!indicator.is_skipped());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Without a body (open equals close) Garman-Klass is the Parkinson estimator scaled by
+/// sqrt(2 * ln(2)).
+fn garman_klass_parkinson_cross_check() {
+    use tatk::indicators::{GarmanKlassVolatility, ParkinsonVolatility};
+    use tatk::test_data::{Candle, TestData};
+    let candles: Vec<Candle> = TestData::candles()
+        .iter()
+        .map(|c| Candle {
+            close: c.open,
+            high: c.high.max(c.open),
+            low: c.low.min(c.open),
+            ..*c
+        })
+        .collect();
+
+    let gk = GarmanKlassVolatility::new(14, 365.0, &candles).unwrap();
+    let pv = ParkinsonVolatility::new(14, 365.0, &candles).unwrap();
+    let expected = pv.value() * (2.0 * 2.0_f64.ln()).sqrt();
+    assert!((gk.value() - expected).abs() < 1e-12);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Adds candles to a Rogers-Satchell Volatility, comparing each against a brute-force
+/// computation.
+fn next_rogers_satchell() {
+    use tatk::indicators::RogersSatchellVolatility;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+    let period: usize = 10;
+
+    let term = |c: &Candle| {
+        (c.high / c.close).ln() * (c.high / c.open).ln()
+            + (c.low / c.close).ln() * (c.low / c.open).ln()
+    };
+
+    let mut indicator = RogersSatchellVolatility::new(period, 1.0, &candles[..period]).unwrap();
+    for end in (period + 1)..=candles.len() {
+        let value = indicator.next(&candles[end - 1]);
+        let sum: f64 = candles[(end - period)..end].iter().map(term).sum();
+        assert!((value - (sum / period as f64).sqrt()).abs() < 1e-12);
+    }
+
+    let created = RogersSatchellVolatility::new(period, 1.0, &candles).unwrap();
+    assert_eq!(created.value(), 0.022835041636551142);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Runs the close-to-close, Parkinson, Garman-Klass, and Rogers-Satchell estimators on the same
+/// candles, each should measure a similar volatility.
+fn volatility_estimators_magnitudes() {
+    use tatk::indicators::{
+        GarmanKlassVolatility, HistoricalVolatility, ParkinsonVolatility, RogersSatchellVolatility,
+    };
+    use tatk::test_data::{Candle, TestData};
+    let candles: Vec<Candle> = TestData::candles();
+    let closes: Vec<f64> = candles.iter().map(|c| c.close).collect();
+    let period: usize = 20;
+
+    let cc = HistoricalVolatility::new(period, 365.0, &closes)
+        .unwrap()
+        .value();
+    let pv = ParkinsonVolatility::new(period, 365.0, &candles)
+        .unwrap()
+        .value();
+    let gk = GarmanKlassVolatility::new(period, 365.0, &candles)
+        .unwrap()
+        .value();
+    let rs = RogersSatchellVolatility::new(period, 365.0, &candles)
+        .unwrap()
+        .value();
+    assert!((cc - 0.2942885076329763).abs() < 1e-12);
+    assert_eq!(pv, 0.33747392810605714);
+    assert_eq!(gk, 0.353433267225232);
+    assert_eq!(rs, 0.36465089215482505);
+
+    // Range-based estimators are within a factor of 2 of close-to-close, and within 20% of
+    // each other.
+    for estimate in [pv, gk, rs] {
+        assert!(estimate > cc / 2.0 && estimate < cc * 2.0);
+        assert!((estimate - gk).abs() / gk < 0.2);
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Adds candles to a Yang-Zhang Volatility, comparing each against a brute-force computation
+/// where the overnight return uses the prior candle's close.
+fn next_yang_zhang() {
+    use tatk::indicators::YangZhangVolatility;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    use tatk::Buffer;
+    let candles: Vec<Candle> = TestData::candles();
+    let period: usize = 10;
+    let k = 0.34 / (1.34 + (period + 1) as f64 / (period - 1) as f64);
+
+    let mut indicator = YangZhangVolatility::new(period, 1.0, &candles[..=period]).unwrap();
+    assert_eq!(indicator.k(), k);
+    for end in (period + 2)..=candles.len() {
+        let value = indicator.next(&candles[end - 1]);
+
+        let window = &candles[(end - period - 1)..end];
+        let overnight: Vec<f64> = window
+            .windows(2)
+            .map(|w| (w[1].open / w[0].close).ln())
+            .collect();
+        let body: Vec<f64> = window[1..]
+            .iter()
+            .map(|c| (c.close / c.open).ln())
+            .collect();
+        let rs: f64 = window[1..]
+            .iter()
+            .map(|c| {
+                (c.high / c.close).ln() * (c.high / c.open).ln()
+                    + (c.low / c.close).ln() * (c.low / c.open).ln()
+            })
+            .sum::<f64>()
+            / period as f64;
+
+        let overnight = Buffer::from_array(period, &overnight).unwrap();
+        let body = Buffer::from_array(period, &body).unwrap();
+        let expected = (overnight.variance(true) + k * body.variance(true) + (1.0 - k) * rs).sqrt();
+        assert!((value - expected).abs() < 1e-12);
+    }
+
+    let created = YangZhangVolatility::new(period, 1.0, &candles).unwrap();
+    assert_eq!(created.value(), 0.02978022720368952);
+}
+
+#[test]
+/// NaN, infinite, and non-positive annualization are rejected by the Yang-Zhang Volatility.
+fn yang_zhang_invalid_annualization() {
+    use tatk::indicators::YangZhangVolatility;
+    use tatk::TAError;
+
+    for annualization in [0.0, -1.0, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(YangZhangVolatility::with_period(2, annualization).is_err());
+    }
+
+    match YangZhangVolatility::with_period(2, f64::NAN) {
+        Err(TAError::InvalidSize(text)) => assert_eq!(
+            text,
+            "annualization must be finite and greater than 0 to calculate yang-zhang volatility"
+        ),
+        other => panic!("expected an invalid size, got {:?}", other),
+    }
+}
+
+#[test]
+/// Overnight gaps only change the Yang-Zhang Volatility through the overnight term, and a
+/// skipped candle keeps the prior close.
+fn yang_zhang_overnight_gaps() {
+    use tatk::indicators::YangZhangVolatility;
+    use tatk::traits::Next;
+
+    // Each candle is (open, high, low, close), opening at the prior close.
+    let bodies = [0.02, -0.01, 0.015, -0.02, 0.01, -0.005, 0.02];
+    let mut close = 100.0;
+    let mut continuous: Vec<(f64, f64, f64, f64)> = Vec::new();
+    let mut gapping: Vec<(f64, f64, f64, f64)> = Vec::new();
+    let mut gapped_close = 100.0;
+    for (i, body) in bodies.iter().enumerate() {
+        let open: f64 = close;
+        let new_close = open * (1.0 + body);
+        continuous.push((
+            open,
+            open.max(new_close) * 1.01,
+            open.min(new_close) * 0.99,
+            new_close,
+        ));
+        close = new_close;
+
+        // Alternate gaps up and down, with the same candle shape.
+        let gap = if i % 2 == 0 { 1.03 } else { 0.97 };
+        let open = gapped_close * gap;
+        let new_close = open * (1.0 + body);
+        gapping.push((
+            open,
+            open.max(new_close) * 1.01,
+            open.min(new_close) * 0.99,
+            new_close,
+        ));
+        gapped_close = new_close;
+    }
+
+    // Requires `period + 1` candles, the first is only the prior close.
+    assert!(YangZhangVolatility::new(3, 1.0, &to_ohlc(&continuous[..3])).is_err());
+
+    let mut smooth = YangZhangVolatility::new(3, 1.0, &to_ohlc(&continuous[..4])).unwrap();
+    let mut gapped = YangZhangVolatility::new(3, 1.0, &to_ohlc(&gapping[..4])).unwrap();
+    for i in 4..bodies.len() {
+        smooth.next(continuous[i]);
+        gapped.next(gapping[i]);
+    }
+
+    // No gaps, no overnight volatility. Gaps increase the volatility.
+    assert!(smooth.overnight_volatility() < 1e-12);
+    assert!(gapped.overnight_volatility() > 0.02);
+    assert!(gapped.value() > smooth.value());
+
+    // Skipping a candle keeps the prior close, the next overnight return is from it.
+    let value = gapped.value();
+    assert_eq!(gapped.next((0.0, 1.0, 1.0, 1.0)), value);
+    assert!(gapped.is_skipped());
+    let last = gapping[bodies.len() - 1].3;
+    gapped.next((last, last * 1.01, last * 0.99, last));
+    assert!(!gapped.is_skipped());
+    assert_ne!(gapped.value(), value);
+
+    // Removing the gaps that remain in the period removes the overnight volatility.
+    for _ in 0..2 {
+        gapped.next((last, last * 1.01, last * 0.99, last));
+    }
+    assert!(gapped.overnight_volatility() < 1e-12);
+}
+
+#[test]
+/// Gaps are classified by direction, size, and if they open beyond the prior range.
+fn gap_detector_classify() {
+    use tatk::indicators::{GapDetector, GapDirection};
+    use tatk::traits::Next;
+
+    assert!(GapDetector::new(-1.0).is_err());
+    let mut detector = GapDetector::new(0.0).unwrap();
+
+    // First candle has nothing to compare against.
+    assert_eq!(detector.next((100.0, 105.0, 95.0, 100.0)), None);
+
+    // Opens above the prior high.
+    let gap = detector.next((110.0, 112.0, 108.0, 111.0)).unwrap();
+    assert_eq!(gap.direction, GapDirection::Up);
+    assert_eq!(gap.size, 10.0);
+    assert_eq!(gap.percent, 10.0);
+    assert_eq!(gap.prior_close, 100.0);
+    assert!(gap.is_full);
+
+    // Opens below the prior close, within the prior range.
+    let gap = detector.next((109.0, 110.0, 109.0, 109.5)).unwrap();
+    assert_eq!(gap.direction, GapDirection::Down);
+    assert_eq!(gap.size, 2.0);
+    assert!(!gap.is_full);
+
+    // Opens at the prior close.
+    assert_eq!(detector.next((109.5, 111.0, 109.6, 110.0)), None);
+
+    // Gaps smaller than the threshold are ignored.
+    let mut detector = GapDetector::new(1.0).unwrap();
+    detector.next((100.0, 101.0, 99.0, 100.0));
+    assert_eq!(detector.next((100.5, 101.0, 100.2, 100.8)), None);
+    assert!(detector.next((102.0, 103.0, 101.9, 102.5)).is_some());
+    assert_eq!(detector.open_gaps().len(), 1);
+}
+
+#[test]
+/// Open gaps are tracked until a later candle trades back to the prior close.
+fn gap_detector_fills() {
+    use tatk::indicators::{GapDetector, GapDirection};
+    use tatk::traits::Next;
+
+    let mut detector = GapDetector::new(0.0).unwrap();
+    detector.next((100.0, 101.0, 99.0, 100.0));
+    detector.next((103.0, 104.0, 102.0, 103.5));
+    detector.next((106.0, 107.0, 105.0, 106.5));
+    assert_eq!(detector.open_gaps().len(), 2);
+
+    // Trades back into the newer gap only.
+    detector.next((106.5, 106.5, 102.8, 103.0));
+    assert_eq!(detector.open_gaps().len(), 1);
+    assert_eq!(detector.open_gaps()[0].prior_close, 100.0);
+
+    // A gap filled by the candle that created it is not tracked.
+    let gap = detector.next((101.0, 103.0, 100.5, 102.0)).unwrap();
+    assert_eq!(gap.direction, GapDirection::Down);
+    assert_eq!(detector.open_gaps().len(), 1);
+
+    // Checking directly removes and returns the filled gaps.
+    let filled = detector.check_filled(&Ohlc((101.0, 101.0, 99.5, 100.0)));
+    assert_eq!(filled.len(), 1);
+    assert_eq!(filled[0].direction, GapDirection::Up);
+    assert!(detector.open_gaps().is_empty());
+}
+
+#[test]
+/// Hand-constructed candles are classified into their single candle patterns.
+fn candle_pattern_classify() {
+    use tatk::indicators::{CandlePattern, PatternConfig, PatternKind};
+    use tatk::traits::Next;
+    let config = PatternConfig::default();
+    let classify = |c| CandlePattern::classify(&Ohlc(c), &config);
+
+    assert_eq!(
+        classify((100.0, 105.0, 95.0, 100.5)),
+        vec![PatternKind::Doji]
+    );
+    assert_eq!(classify((95.0, 102.0, 94.0, 100.0)), vec![]);
+    assert_eq!(
+        classify((98.0, 100.5, 90.0, 100.0)),
+        vec![PatternKind::Hammer]
+    );
+    assert_eq!(
+        classify((91.0, 101.0, 90.5, 93.0)),
+        vec![PatternKind::InvertedHammer]
+    );
+    assert_eq!(
+        classify((93.0, 101.0, 90.5, 91.0)),
+        vec![PatternKind::ShootingStar]
+    );
+    assert_eq!(
+        classify((99.0, 104.0, 96.0, 101.0)),
+        vec![PatternKind::SpinningTop]
+    );
+    assert_eq!(
+        classify((90.0, 100.2, 89.9, 100.0)),
+        vec![PatternKind::Marubozu]
+    );
+
+    // Dragonfly doji is both a doji and a hammer.
+    assert_eq!(
+        classify((100.0, 100.0, 90.0, 100.0)),
+        vec![PatternKind::Doji, PatternKind::Hammer]
+    );
+
+    // Zero range candles do not divide by zero.
+    assert_eq!(
+        classify((100.0, 100.0, 100.0, 100.0)),
+        vec![PatternKind::Doji]
+    );
+
+    // Ratios are configurable.
+    let loose = PatternConfig {
+        doji_body: 0.25,
+        ..PatternConfig::default()
+    };
+    let candle = Ohlc((99.0, 104.0, 96.0, 101.0));
+    assert_eq!(
+        CandlePattern::classify(&candle, &loose),
+        vec![PatternKind::Doji]
+    );
+
+    // Stateful detector matches the pure function.
+    assert!(CandlePattern::new(PatternConfig {
+        small_body: 1.5,
+        ..PatternConfig::default()
+    })
+    .is_err());
+    let mut indicator = CandlePattern::new(config).unwrap();
+    assert_eq!(indicator.next(&candle), classify(candle.0));
+    assert!(indicator.next((98.0, 100.5, 90.0, 100.0)) == vec![PatternKind::Hammer]);
+    assert!(indicator.is(PatternKind::Hammer));
+}
+
+#[test]
+/// Hand-constructed candle pairs are classified into their two candle patterns.
+fn two_candle_patterns_classify() {
+    use tatk::indicators::{PatternConfig, PatternKind, TwoCandlePatterns};
+    let config = PatternConfig::default();
+    let classify =
+        |p, c, is_strict| TwoCandlePatterns::classify(&Ohlc(p), &Ohlc(c), &config, is_strict);
+    let bearish = (105.0, 106.0, 94.0, 95.0);
+    let bullish = (95.0, 106.0, 94.0, 105.0);
+
+    // Engulfing, strict needs the body to cover the prior wicks.
+    let engulfing = (94.0, 107.0, 93.5, 106.0);
+    assert_eq!(
+        classify(bearish, engulfing, true),
+        vec![PatternKind::BullishEngulfing]
+    );
+    let bodies_only = (94.5, 106.5, 94.0, 105.5);
+    assert_eq!(
+        classify(bearish, bodies_only, false),
+        vec![PatternKind::BullishEngulfing]
+    );
+    assert_eq!(classify(bearish, bodies_only, true), vec![]);
+    assert_eq!(classify(bearish, (95.5, 106.0, 95.0, 105.5), false), vec![]);
+    assert_eq!(
+        classify(bullish, (106.0, 107.0, 93.0, 94.0), true),
+        vec![PatternKind::BearishEngulfing]
+    );
+
+    // Harami, a doji inside the prior body is a harami cross.
+    assert_eq!(
+        classify(bearish, (97.0, 101.0, 96.0, 100.0), false),
+        vec![PatternKind::BullishHarami]
+    );
+    assert_eq!(
+        classify(bullish, (103.0, 104.0, 97.0, 98.0), false),
+        vec![PatternKind::BearishHarami]
+    );
+    assert_eq!(
+        classify(bearish, (100.0, 102.0, 98.0, 100.2), false),
+        vec![PatternKind::HaramiCross]
+    );
+    assert_eq!(classify(bearish, (97.0, 106.0, 96.0, 105.5), false), vec![]);
+
+    // Piercing Line and Dark Cloud Cover need to close past the middle of the prior body.
+    assert_eq!(
+        classify(bearish, (93.0, 102.0, 92.0, 101.0), false),
+        vec![PatternKind::PiercingLine]
+    );
+    assert_eq!(classify(bearish, (93.0, 100.0, 92.0, 99.5), false), vec![]);
+    assert_eq!(
+        classify(bullish, (107.0, 108.0, 98.0, 99.0), false),
+        vec![PatternKind::DarkCloudCover]
+    );
+    assert_eq!(
+        classify(bullish, (107.0, 108.0, 100.0, 101.0), false),
+        vec![]
+    );
+}
+
+#[test]
+/// Two Candle Patterns keeps the prior candle between calls.
+fn next_two_candle_patterns() {
+    use tatk::indicators::{PatternConfig, PatternKind, TwoCandlePatterns};
+    use tatk::traits::Next;
+
+    let mut indicator = TwoCandlePatterns::new(PatternConfig::default(), false).unwrap();
+    assert!(indicator.next((105.0, 106.0, 94.0, 95.0)).is_empty());
+    assert_eq!(
+        indicator.next(&Ohlc((94.0, 107.0, 93.5, 106.0))),
+        vec![PatternKind::BullishEngulfing]
+    );
+    assert_eq!(
+        indicator.next((107.0, 108.0, 99.0, 99.5)),
+        vec![PatternKind::DarkCloudCover]
+    );
+    assert!(indicator.is(PatternKind::DarkCloudCover));
+    assert!(!indicator.is_strict());
+}
+
+#[test]
+/// Consecutive inside bars keep the original mother bar until a close breaks out of it.
+fn inside_bar_chain() {
+    use tatk::indicators::{BarSignal, InsideBar};
+    use tatk::traits::Next;
+
+    let mut indicator = InsideBar::new();
+    assert_eq!(indicator.next((110.0, 90.0, 100.0)), BarSignal::None);
+    assert_eq!(indicator.next((108.0, 92.0, 100.0)), BarSignal::Inside);
+    assert_eq!(indicator.mother_bar(), Some((110.0, 90.0)));
+
+    // Inside of the inside bar, the mother bar is kept.
+    assert_eq!(indicator.next((107.0, 93.0, 101.0)), BarSignal::Inside);
+    assert_eq!(indicator.mother_bar(), Some((110.0, 90.0)));
+
+    // Leaves the prior range without closing beyond the mother bar.
+    assert_eq!(indicator.next((109.0, 95.0, 105.0)), BarSignal::None);
+    assert_eq!(indicator.next((108.0, 96.0, 100.0)), BarSignal::Inside);
+    assert_eq!(indicator.mother_bar(), Some((110.0, 90.0)));
+
+    // Wicks beyond the mother bar are not a breakout, only the close.
+    assert_eq!(indicator.next((111.0, 97.0, 109.0)), BarSignal::None);
+    assert_eq!(indicator.next((112.0, 99.0, 111.0)), BarSignal::BreakoutUp);
+    assert_eq!(indicator.mother_bar(), None);
+
+    // Outside bars do not start tracking.
+    assert_eq!(indicator.next((115.0, 95.0, 100.0)), BarSignal::Outside);
+    assert_eq!(indicator.mother_bar(), None);
+
+    assert_eq!(indicator.next((113.0, 97.0, 100.0)), BarSignal::Inside);
+    assert_eq!(indicator.next((114.0, 90.0, 94.0)), BarSignal::BreakoutDown);
+    assert_eq!(indicator.signal(), BarSignal::BreakoutDown);
+}
+
+#[test]
+/// Swings are confirmed `right` candles later, with strength counted on each side.
+fn swing_points_strength() {
+    use tatk::indicators::{SwingKind, SwingPoints};
+    use tatk::traits::Next;
+
+    assert!(SwingPoints::new(0, 2, 5).is_err());
+    assert!(SwingPoints::new(3, 2, 2).is_err());
+
+    let highs = [1.0, 2.0, 3.0, 5.0, 3.0, 2.0, 4.0, 6.0, 4.0, 3.0, 2.0, 7.0];
+    let mut indicator = SwingPoints::new(2, 2, 5).unwrap();
+    let confirmed: Vec<_> = highs
+        .iter()
+        .map(|h| indicator.next((*h, h - 2.0)))
+        .collect();
+
+    // Nothing within the first candles.
+    assert!(confirmed[..5].iter().all(|c| c.is_empty()));
+
+    // Swing high at 5.0 confirmed two candles later.
+    assert_eq!(confirmed[5].len(), 1);
+    let swing = confirmed[5][0];
+    assert_eq!(swing.kind, SwingKind::High);
+    assert_eq!((swing.price, swing.index, swing.offset), (5.0, 3, 2));
+    assert_eq!((swing.left_strength, swing.right_strength), (3, 2));
+
+    // Swing low and a higher swing high, left strength is limited by the lookback.
+    assert_eq!(confirmed[7][0].kind, SwingKind::Low);
+    assert_eq!((confirmed[7][0].price, confirmed[7][0].index), (0.0, 5));
+    assert_eq!(
+        (confirmed[9][0].price, confirmed[9][0].left_strength),
+        (6.0, 5)
+    );
+
+    // Right strengths grew until each swing was exceeded.
+    let recent = indicator.recent_swings(3);
+    assert_eq!(recent.len(), 3);
+    let strengths: Vec<usize> = recent.iter().map(|s| s.right_strength).collect();
+    assert_eq!(strengths, vec![3, 4, 3]);
+    assert_eq!(indicator.recent_swings(10).len(), 3);
+}
+
+#[test]
+/// Market structure is determined from the last two highs and lows within four swings.
+fn swing_points_market_structure() {
+    use tatk::indicators::{MarketStructure, SwingPoints};
+    use tatk::traits::Next;
+
+    let structure = |highs: &[f64]| {
+        let mut indicator = SwingPoints::new(1, 1, 1).unwrap();
+        for h in highs.iter() {
+            indicator.next((*h, h - 2.0));
+        }
+        indicator.market_structure()
+    };
+
+    assert_eq!(
+        structure(&[1.0, 3.0, 2.0, 4.0, 3.0, 5.0, 4.0]),
+        MarketStructure::Uptrend
+    );
+    assert_eq!(
+        structure(&[10.0, 8.0, 9.0, 7.0, 8.0, 6.0, 7.0]),
+        MarketStructure::Downtrend
+    );
+    assert_eq!(
+        structure(&[1.0, 3.0, 2.0, 4.0, 1.0, 5.0, 4.0]),
+        MarketStructure::Range
+    );
+    assert_eq!(structure(&[1.0, 3.0, 2.0]), MarketStructure::Range);
+}
+
+#[test]
+/// Swings touch the closest level within the tolerance, levels drifting together are merged.
+fn support_resistance_merging() {
+    use tatk::indicators::{SupportResistance, SwingPoints, Tolerance};
+    use tatk::traits::Next;
+
+    let swings = SwingPoints::new(1, 1, 1).unwrap();
+    assert!(SupportResistance::new(swings, Tolerance::Percent(-1.0), 20).is_err());
+
+    let swings = SwingPoints::new(1, 1, 1).unwrap();
+    let mut indicator = SupportResistance::new(swings, Tolerance::Absolute(1.0), 20).unwrap();
+    let highs = [90.0, 100.0, 90.0, 101.5, 90.0, 100.75, 90.0];
+    for h in highs.iter() {
+        indicator.next((*h, h - 1.0, h - 0.5));
+    }
+
+    // Swing lows at 89.0 share a level, the highs are too far apart to merge.
+    let mut levels = indicator.levels();
+    assert!(levels.windows(2).all(|w| w[0].score >= w[1].score));
+    levels.sort_by(|a, b| a.price.total_cmp(&b.price));
+    let prices: Vec<f64> = levels.iter().map(|l| l.price).collect();
+    assert_eq!(prices, vec![89.0, 100.375, 101.5]);
+    assert_eq!((levels[0].touches, levels[1].touches), (2, 2));
+
+    // Touching the upper level pulls it within the tolerance of the lower level.
+    indicator.next((101.0, 100.0, 100.5));
+    indicator.next((90.0, 89.0, 89.5));
+    let level = indicator.nearest_above(95.0).unwrap();
+    assert_eq!(
+        (level.price, level.touches, level.last_touch),
+        (100.8125, 4, 7)
+    );
+    assert_eq!(indicator.nearest_above(level.price), None);
+    assert_eq!(indicator.nearest_below(95.0).unwrap().touches, 3);
+    assert_eq!(indicator.support().unwrap().price, 89.0);
+    assert_eq!(indicator.resistance().unwrap().price, 100.8125);
+}
+
+#[test]
+/// Scores decay with the age of a level until it is pruned by the max age.
+fn support_resistance_decay() {
+    use tatk::indicators::{SupportResistance, SwingPoints, Tolerance};
+    use tatk::traits::Next;
+
+    let swings = SwingPoints::new(1, 1, 1).unwrap();
+    let mut indicator = SupportResistance::new(swings, Tolerance::Percent(1.0), 4).unwrap();
+    for h in [90.0, 100.0, 90.0] {
+        indicator.next((h, h - 1.0, h - 0.5));
+    }
+
+    // Touched at the second candle, confirmed by the third.
+    let levels = indicator.levels();
+    assert_eq!(levels.len(), 1);
+    assert_eq!(levels[0].score, 0.75);
+
+    // Flat candles do not create swings, the level ages out.
+    let mut last = levels[0].score;
+    for _ in 0..3 {
+        let levels = indicator.next((90.0, 89.0, 89.5));
+        assert!(levels[0].score < last);
+        last = levels[0].score;
+    }
+    assert_eq!(last, 0.0);
+    assert!(indicator.next((90.0, 89.0, 89.5)).is_empty());
+}
+
+#[test]
+/// Volume assigned to the closes, the value area grows towards the larger neighbour.
+fn volume_profile_close() {
+    use tatk::indicators::{Binning, Distribution, VolumeProfile};
+    use tatk::traits::Next;
+
+    assert!(VolumeProfile::new(Binning::Size(0.0), Distribution::Close, None).is_err());
+    assert!(VolumeProfile::new(Binning::Count(0), Distribution::Close, None).is_err());
+    assert!(VolumeProfile::new(Binning::Count(4), Distribution::Close, Some(0)).is_err());
+
+    let mut profile = VolumeProfile::new(Binning::Size(1.0), Distribution::Close, None).unwrap();
+    assert_eq!(profile.point_of_control(), None);
+    profile.next((10.5, 9.5, 10.2, 100.0));
+    profile.next((11.5, 10.5, 11.2, 300.0));
+    profile.next((12.5, 11.5, 12.2, 50.0));
+    let poc = profile.next((10.5, 9.2, 9.8, 20.0)).unwrap();
+
+    let volumes: Vec<f64> = profile.histogram().iter().map(|b| b.volume).collect();
+    assert_eq!(volumes, vec![20.0, 100.0, 300.0, 50.0]);
+    assert_eq!((poc.low, poc.high, poc.volume), (11.0, 12.0, 300.0));
+    assert_eq!(profile.value_area(70.0), Some((10.0, 12.0)));
+    assert_eq!(profile.value_area(100.0), Some((9.0, 13.0)));
+    assert_eq!(profile.value_area(120.0), None);
+}
+
+#[test]
+/// Volume spread across the range of a candle, over a window and reset for new sessions.
+fn volume_profile_range() {
+    use tatk::indicators::{Binning, Distribution, VolumeProfile};
+    use tatk::traits::{Next, Reset};
+
+    let mut profile = VolumeProfile::new(Binning::Count(2), Distribution::Range, Some(2)).unwrap();
+    let poc = profile.next((12.0, 10.0, 11.0, 100.0)).unwrap();
+    assert_eq!((poc.low, poc.high, poc.volume), (10.0, 11.0, 50.0));
+
+    // Oldest candle leaves the window.
+    profile.next((14.0, 13.0, 13.5, 10.0));
+    profile.next((16.0, 14.0, 15.0, 40.0));
+    assert_eq!(profile.len(), 2);
+    let bins = profile.histogram();
+    assert_eq!((bins[0].low, bins[1].high), (13.0, 16.0));
+    assert_eq!((bins[0].volume, bins[1].volume), (20.0, 30.0));
+
+    // Zero range candles are placed at the close without dividing by zero.
+    profile.reset();
+    assert!(profile.is_empty());
+    assert_eq!(profile.value_area(70.0), None);
+    profile.next((10.0, 10.0, 10.0, 5.0));
+    assert_eq!(profile.histogram().len(), 1);
+    assert_eq!(profile.value_area(70.0), Some((10.0, 10.0)));
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Converts 252 prices into 251 returns, in batch and as a stream.
+fn returns_compute() {
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    use tatk::transforms::{ReturnKind, Returns};
+    const DATA: &[f64] = TestData::talib();
+
+    let simple = Returns::compute(ReturnKind::Simple, DATA).unwrap();
+    let log = Returns::compute(ReturnKind::Log, DATA).unwrap();
+    assert_eq!(simple.len(), DATA.len() - 1);
+    assert_eq!(simple[0], (DATA[1] - DATA[0]) / DATA[0]);
+    assert_eq!(
+        log[log.len() - 1],
+        (DATA[DATA.len() - 1] / DATA[DATA.len() - 2]).ln()
+    );
+
+    let mut returns = Returns::new(ReturnKind::Log);
+    assert_eq!(returns.next(DATA[0]).unwrap(), None);
+    let streamed: Vec<f64> = DATA[1..]
+        .iter()
+        .map(|v| returns.next(*v).unwrap().unwrap())
+        .collect();
+    assert_eq!(streamed, log);
+}
+
+#[test]
+/// Prices that cannot be converted produce errors instead of NaN or infinity.
+fn returns_invalid_prices() {
+    use tatk::traits::Next;
+    use tatk::transforms::{ReturnKind, Returns};
+
+    assert!(Returns::compute(ReturnKind::Log, &[1.0, 0.0, 2.0]).is_err());
+    assert!(Returns::compute(ReturnKind::Log, &[1.0, -1.0]).is_err());
+    assert!(Returns::compute(ReturnKind::Simple, &[0.0, 1.0]).is_err());
+    assert!(Returns::compute(ReturnKind::Simple, &[1.0, 0.0]).is_err());
+    assert_eq!(
+        Returns::compute(ReturnKind::Simple, &[-1.0, -2.0]).unwrap(),
+        vec![1.0]
+    );
+    assert!(Returns::compute(ReturnKind::Log, &[]).unwrap().is_empty());
+
+    // Invalid prices are not kept as the prior price.
+    let mut returns = Returns::new(ReturnKind::Log);
+    returns.next(2.0).unwrap();
+    assert!(returns.next(0.0).is_err());
+    assert_eq!(returns.next(2.0).unwrap(), Some(0.0));
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Compounding the returns of 252 prices ends at the ratio of the last to the first price.
+fn create_cumulative_return() {
+    use tatk::indicators::CumulativeReturn;
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Stats};
+    const DATA: &[f64] = TestData::talib();
+
+    let mut indicator = CumulativeReturn::from_prices(10, 100.0, &DATA[..DATA.len() - 1]).unwrap();
+    let expected = 100.0 * DATA[DATA.len() - 2] / DATA[0];
+    assert!((indicator.value() - expected).abs() < 1e-9);
+
+    let expected = 100.0 * DATA[DATA.len() - 1] / DATA[0];
+    assert!((indicator.next(DATA[DATA.len() - 1]) - expected).abs() < 1e-9);
+    assert!((indicator.total_return() - (DATA[DATA.len() - 1] / DATA[0] - 1.0)).abs() < 1e-12);
+    assert_eq!(indicator.equity().len(), 10);
+    assert!((indicator.mean() - indicator.equity().iter().sum::<f64>() / 10.0).abs() < 1e-9);
+}
+
+#[test]
+/// Returns are compounded from the base and reset back to it.
+fn cumulative_return_reset() {
+    use tatk::indicators::CumulativeReturn;
+    use tatk::traits::{Next, Reset};
+
+    assert!(CumulativeReturn::new(0, 1.0, &[]).is_err());
+    assert!(CumulativeReturn::new(3, 0.0, &[]).is_err());
+    assert!(CumulativeReturn::from_prices(3, 1.0, &[1.0, 0.0]).is_err());
+
+    let mut indicator = CumulativeReturn::new(3, 1.0, &[]).unwrap();
+    assert_eq!(indicator.value(), 1.0);
+    indicator.next(1.0);
+    assert_eq!(indicator.next(-0.5), 1.0);
+    assert_eq!(indicator.next(0.5), 1.5);
+    assert_eq!(indicator.total_return(), 0.5);
+    assert_eq!(indicator.equity(), &[2.0, 1.0, 1.5]);
+
+    indicator.reset();
+    assert_eq!(indicator.equity(), &[1.0]);
+    assert_eq!(indicator.next(0.1), 1.1);
+
+    // Created from prices, the first price after a reset only provides the prior price.
+    let mut prices = CumulativeReturn::from_prices(3, 100.0, &[10.0, 20.0]).unwrap();
+    assert_eq!(prices.next(0.0), 200.0);
+    assert_eq!(prices.next(10.0), 100.0);
+    prices.reset();
+    assert_eq!(prices.next(50.0), 100.0);
+    assert_eq!(prices.next(75.0), 150.0);
+}
+
+#[test]
+/// Lag returns the oldest available value until `period + 1` values, then the value `period` ago.
+fn lag_warm_up() {
+    use tatk::indicators::Lag;
+    use tatk::traits::{Next, Period, Ready};
+
+    assert!(Lag::new(0, &[1.0]).is_err());
+    assert!(Lag::new(2, &[]).is_err());
+
+    let mut indicator = Lag::new(2, &[1.0]).unwrap();
+    assert_eq!(indicator.period(), 2);
+    assert_eq!(indicator.value(), 1.0);
+    assert!(!indicator.is_ready());
+
+    assert_eq!(indicator.next(2.0), 1.0);
+    assert!(!indicator.is_ready());
+    assert_eq!(indicator.next(3.0), 1.0);
+    assert!(indicator.is_ready());
+    assert_eq!(indicator.next(4.0), 2.0);
+    assert_eq!(indicator.next(5.0), 3.0);
+
+    // Created with more data than required.
+    let indicator = Lag::new(1, &[1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(indicator.value(), 3.0);
+}
+
+#[test]
+/// Rolling Sum of the raw values, recalculated to prevent drift.
+fn rolling_sum() {
+    use tatk::indicators::RollingSum;
+    use tatk::traits::{Next, Stats};
+
+    assert!(RollingSum::new(0, &[1.0]).is_err());
+    assert!(RollingSum::new(3, &[1.0, 2.0]).is_err());
+
+    let mut indicator = RollingSum::new(3, &[1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(indicator.value(), 9.0);
+    assert_eq!(indicator.next(-1.0), 6.0);
+    assert_eq!(indicator.next(0.5), 3.5);
+    assert_eq!(indicator.mean(), 3.5 / 3.0);
+
+    // Large values mixed with small values do not accumulate error.
+    let mut indicator = RollingSum::new(4, &[0.1; 4]).unwrap();
+    for i in 0..10_000 {
+        indicator.next(if i % 2 == 0 { 1e9 } else { 0.1 });
+    }
+    for _ in 0..4 {
+        indicator.next(0.1);
+    }
+    assert!((indicator.value() - 0.4).abs() < 1e-9);
+}
+
+#[test]
+/// Min-Max Scaler maps the newest value relative to the extrema of the period.
+fn min_max_scaler() {
+    use tatk::indicators::MinMaxScaler;
+    use tatk::traits::Next;
+
+    assert!(MinMaxScaler::new(0, &[1.0]).is_err());
+    assert!(MinMaxScaler::new(3, &[1.0, 2.0]).is_err());
+    assert!(MinMaxScaler::with_range(3, 1.0, -1.0, &[1.0, 2.0, 3.0]).is_err());
+
+    let mut indicator = MinMaxScaler::new(3, &[1.0, 5.0, 2.0]).unwrap();
+    assert_eq!(indicator.value(), 0.25);
+    assert_eq!(indicator.next(6.0), 1.0);
+    // Window is [2, 6, 4].
+    assert_eq!(indicator.next(4.0), 0.5);
+    // Window is [6, 4, 0].
+    assert_eq!(indicator.next(0.0), 0.0);
+
+    // Zero range produces the middle of the output range.
+    let mut indicator = MinMaxScaler::with_range(2, -1.0, 1.0, &[3.0, 3.0]).unwrap();
+    assert_eq!(indicator.range(), (-1.0, 1.0));
+    assert_eq!(indicator.value(), 0.0);
+    assert_eq!(indicator.next(5.0), 1.0);
+    assert_eq!(indicator.next(5.0), 0.0);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate the skewness and kurtosis using 20 data points with a period of 10,
+/// reference values match `scipy.stats.skew` and `scipy.stats.kurtosis`.
+fn create_moments() {
+    use tatk::indicators::Moments;
+    use tatk::test_data::TestData;
+    const DATA: &[f64] = TestData::talib_small();
+
+    assert!(Moments::new(1, DATA).is_err());
+    assert!(Moments::new(10, &DATA[..9]).is_err());
+
+    let indicator = Moments::new(10, &DATA[..DATA.len() - 1]).unwrap();
+    assert!((indicator.skewness() - 0.5629674547460997).abs() < 1e-12);
+    assert!((indicator.kurtosis() - -1.1080794883757663).abs() < 1e-12);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Create and calculate the skewness and kurtosis using 20 data points with a period of 10, then
+/// adds an additional data point.
+fn next_moments() {
+    use tatk::indicators::Moments;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib_small();
+
+    let mut indicator = Moments::new(10, &DATA[..DATA.len() - 1]).unwrap();
+    let (skewness, kurtosis) = indicator.next(DATA[DATA.len() - 1]);
+    assert!((skewness - 0.6692207241581742).abs() < 1e-12);
+    assert!((kurtosis - -1.1343385774244314).abs() < 1e-12);
+    assert_eq!(indicator.value(), skewness);
+}
+
+#[test]
+/// Moments of a constant period are 0, symmetric data has no skew.
+fn moments_constant_data() {
+    use tatk::indicators::Moments;
+    use tatk::traits::Next;
+
+    let mut indicator = Moments::new(4, &[5.0; 4]).unwrap();
+    assert_eq!((indicator.skewness(), indicator.kurtosis()), (0.0, 0.0));
+
+    indicator.next(1.0);
+    assert!(indicator.skewness() < 0.0);
+
+    // Two values either side of the mean, the flattest possible distribution.
+    let indicator = Moments::new(4, &[1.0, 3.0, 1.0, 3.0]).unwrap();
+    assert_eq!((indicator.skewness(), indicator.kurtosis()), (0.0, -2.0));
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Resets a Sharpe Ratio created from prices, it still expects prices and refeeding them matches
+/// a Sharpe Ratio freshly created from them.
+fn reset_sharpe_prices() {
+    use tatk::indicators::SharpeRatio;
+    use tatk::test_data::TestData;
+    use tatk::traits::{Next, Reset};
+    const DATA: &[f64] = TestData::talib();
+
+    let mut indicator = SharpeRatio::new(20, 0.0001, 252.0, &DATA[100..], true).unwrap();
+    indicator.reset();
+
+    // Prices that are 0 or lower are ignored rather than collected.
+    indicator.next(-1.0);
+    for v in DATA[..21].iter() {
+        indicator.next(*v);
+    }
+
+    let mut fresh = SharpeRatio::new(20, 0.0001, 252.0, &DATA[..21], true).unwrap();
+    assert_eq!(indicator.value(), fresh.value());
+    for v in DATA[21..].iter() {
+        assert_eq!(indicator.next(*v), fresh.next(*v));
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Resets a Parkinson Volatility, invalid candles are skipped while collecting and refeeding the
+/// candles matches a Parkinson Volatility freshly created from them.
+fn reset_parkinson() {
+    use tatk::indicators::ParkinsonVolatility;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::{Next, Reset};
+    let candles: Vec<Candle> = TestData::candles();
+
+    let mut indicator = ParkinsonVolatility::new(20, 365.0, &candles[100..]).unwrap();
+    indicator.reset();
+    assert_eq!(indicator.next((1.0, 2.0)), 0.0);
+    assert!(indicator.is_skipped());
+    for c in candles[..20].iter() {
+        indicator.next(c);
+    }
+
+    let mut fresh = ParkinsonVolatility::new(20, 365.0, &candles[..20]).unwrap();
+    assert_eq!(indicator.value(), fresh.value());
+    for c in candles[20..].iter() {
+        assert_eq!(indicator.next(c), fresh.next(c));
+    }
+}
+
+#[test]
+/// Resets detectors that need no initial data, they behave as if newly created.
+fn reset_detectors() {
+    use tatk::indicators::{GapDetector, InsideBar, SwingPoints};
+    use tatk::traits::{Next, Reset};
+
+    let mut gaps = GapDetector::new(0.0).unwrap();
+    gaps.next((10.0, 11.0, 9.0, 10.0));
+    assert!(gaps.next((12.0, 13.0, 12.0, 12.5)).is_some());
+    assert_eq!(gaps.open_gaps().len(), 1);
+    gaps.reset();
+    assert!(gaps.open_gaps().is_empty());
+
+    // The prior candle is cleared, the next candle cannot gap.
+    assert!(gaps.next((20.0, 21.0, 20.0, 20.5)).is_none());
+
+    let mut inside = InsideBar::new();
+    inside.next((12.0, 8.0, 10.0));
+    inside.next((11.0, 9.0, 10.0));
+    inside.reset();
+    assert_eq!(
+        inside.next((11.0, 9.0, 10.0)),
+        InsideBar::new().next((11.0, 9.0, 10.0))
+    );
+
+    let mut swings = SwingPoints::new(1, 1, 10).unwrap();
+    let mut fresh = SwingPoints::new(1, 1, 10).unwrap();
+    for v in [(5.0, 4.0), (7.0, 6.0), (6.0, 5.0)] {
+        swings.next(v);
+    }
+    swings.reset();
+    for v in [(1.0, 0.5), (3.0, 2.0), (2.0, 1.5), (2.5, 1.0)] {
+        assert_eq!(swings.next(v), fresh.next(v));
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Indicators created without data match ones created from a slice of the same candles, the
+/// Parkinson Volatility being of the candles supplied so far while warming up.
+fn streaming_matches_new() {
+    use tatk::indicators::{Alligator, ParkinsonVolatility};
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::{Next, Ready};
+    let candles: Vec<Candle> = TestData::candles();
+
+    let mut indicator = ParkinsonVolatility::with_period(20, 365.0).unwrap();
+    for (i, c) in candles[..19].iter().enumerate() {
+        let partial = ParkinsonVolatility::new(i + 1, 365.0, &candles[..=i]).unwrap();
+        assert_eq!(indicator.next(c), partial.value());
+        assert!(!indicator.is_ready());
+    }
+    indicator.next(&candles[19]);
+    let mut fresh = ParkinsonVolatility::new(20, 365.0, &candles[..20]).unwrap();
+    assert!(indicator.is_ready());
+    for c in candles[20..].iter() {
+        assert_eq!(indicator.next(c), fresh.next(c));
+    }
+    assert!(ParkinsonVolatility::with_period(20, 0.0).is_err());
+
+    // Alligator requires the largest `period + shift` of its lines.
+    let mut alligator = Alligator::with_period((13, 8), (8, 5), (5, 3)).unwrap();
+    for c in candles[..20].iter() {
+        alligator.next(c);
+        assert!(!alligator.is_ready());
+    }
+    alligator.next(&candles[20]);
+    let mut fresh = Alligator::with_defaults(&candles[..21]).unwrap();
+    assert!(alligator.is_ready());
+    for c in candles[21..].iter() {
+        assert_eq!(alligator.next(c), fresh.next(c));
+    }
+}
+
+#[test]
+/// Invalid prices skipped by the Historical Volatility are still counted as seen.
+fn ready_skipped_values() {
+    use tatk::indicators::HistoricalVolatility;
+    use tatk::traits::{Next, Ready, Reset};
+
+    let mut indicator = HistoricalVolatility::new(2, 1.0, &[1.0, 2.0, 4.0]).unwrap();
+    assert_eq!(indicator.samples_seen(), 3);
+    indicator.next(-1.0);
+    assert_eq!(indicator.samples_seen(), 4);
+
+    // Skipped while collecting, then recreated from the valid prices.
+    indicator.reset();
+    for v in [1.0, 0.0, 2.0, 4.0] {
+        indicator.next(v);
+    }
+    assert!(indicator.is_ready());
+    assert_eq!(indicator.samples_seen(), 4);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Amount of values each indicator requires before `try_next` produces an output, composites
+/// requiring the most of their parts.
+fn try_next_warm_up() {
+    use tatk::indicators::{
+        Alligator, AverageTrueRange, BollingerBands, DoubleExponentialMovingAverage,
+        ExponentialMovingAverage, HistoricalVolatility, Lag, MovingAverageConvergenceDivergence,
+        ParkinsonVolatility, RateOfChange, RelativeStrengthIndex, SharpeRatio, SimpleMovingAverage,
+        TrueRange,
+    };
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::TryNext;
+    const DATA: &[f64] = TestData::talib();
+    let candles: Vec<Candle> = TestData::candles();
+    let candle_refs: Vec<&Candle> = candles.iter().collect();
+
+    /// Amount of values supplied up to and including the first output.
+    fn warm_up<T: Copy, I: TryNext<T>>(mut indicator: I, data: &[T]) -> usize {
+        data.iter()
+            .position(|v| indicator.try_next(*v).is_some())
+            .map_or(0, |i| i + 1)
+    }
+
+    assert_eq!(
+        warm_up(SimpleMovingAverage::with_period(10).unwrap(), DATA),
+        10
+    );
+    assert_eq!(
+        warm_up(ExponentialMovingAverage::with_period(10).unwrap(), DATA),
+        10
+    );
+    assert_eq!(
+        warm_up(
+            DoubleExponentialMovingAverage::with_period(10).unwrap(),
+            DATA
+        ),
+        19
+    );
+    assert_eq!(warm_up(RateOfChange::with_period(10).unwrap(), DATA), 11);
+    assert_eq!(
+        warm_up(RelativeStrengthIndex::with_period(14).unwrap(), DATA),
+        15
+    );
+    assert_eq!(warm_up(Lag::with_period(5).unwrap(), DATA), 6);
+    assert_eq!(
+        warm_up(HistoricalVolatility::with_period(10, 252.0).unwrap(), DATA),
+        11
+    );
+    assert_eq!(
+        warm_up(
+            SharpeRatio::with_period(10, 0.0, 252.0, true).unwrap(),
+            DATA
+        ),
+        11
+    );
+    assert_eq!(
+        warm_up(BollingerBands::with_period(20, 2.0).unwrap(), DATA),
+        20
+    );
+    assert_eq!(
+        warm_up(
+            MovingAverageConvergenceDivergence::with_period(12, 26, 9).unwrap(),
+            DATA
+        ),
+        34
+    );
+
+    assert_eq!(
+        warm_up(TrueRange::with_period(14).unwrap(), &candle_refs),
+        15
+    );
+    assert_eq!(
+        warm_up(AverageTrueRange::with_period(14).unwrap(), &candles),
+        15
+    );
+    assert_eq!(
+        warm_up(
+            ParkinsonVolatility::with_period(20, 365.0).unwrap(),
+            &candle_refs
+        ),
+        20
+    );
+    assert_eq!(
+        warm_up(
+            Alligator::with_period((13, 8), (8, 5), (5, 3)).unwrap(),
+            &candle_refs
+        ),
+        21
+    );
+
+    // Created from data, the first output is immediate.
+    assert_eq!(
+        warm_up(SimpleMovingAverage::new(10, &DATA[..10]).unwrap(), DATA),
+        1
+    );
+}
+
+#[test]
+/// Buffer held in a ring matches a plainly shifted vector exactly, across the capacity edges and
+/// after being cleared.
+fn buffer_ring_matches_vec() {
+    use tatk::Buffer;
+
+    // Simple deterministic pseudo-random values.
+    let mut seed: u64 = 42;
+    let mut random = move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as f64 / 1_000.0 - 1_000.0
+    };
+
+    for capacity in [1, 2, 3, 7, 50] {
+        let first = random();
+        let mut buffer = Buffer::from_array(capacity, &[first]).unwrap();
+        let mut expected: Vec<f64> = vec![first];
+
+        for i in 0..(capacity * 5) {
+            if i == capacity * 3 {
+                buffer.clear();
+                expected.clear();
+            }
+
+            let value = random();
+            let mut oldest = 0.0;
+            if expected.len() == capacity {
+                oldest = expected.remove(0);
+            }
+            expected.push(value);
+
+            assert_eq!(buffer.shift(value), oldest);
+            assert_eq!(buffer.queue(), &expected[..]);
+            assert!((buffer.sum() - expected.iter().sum::<f64>()).abs() < 1e-6);
+            assert_eq!(buffer.oldest(), expected[0]);
+            assert_eq!(buffer.newest(), value);
+            assert_eq!(buffer.is_ready(), expected.len() == capacity);
+        }
+
+        // Equal to a buffer holding the same values at a different position of the ring.
+        let copy = Buffer::from_array(capacity, &expected).unwrap();
+        assert_eq!(copy.queue(), buffer.queue());
+        assert_eq!(copy.oldest(), buffer.oldest());
+    }
+}
+
+#[test]
+/// Large period SMA over many values, each value is added in constant time regardless of the
+/// period.
+fn buffer_large_period() {
+    use tatk::indicators::SimpleMovingAverage;
+    use tatk::traits::Next;
+
+    let period = 5_000;
+    let data: Vec<f64> = (0..200_000).map(|i| (i % 1_000) as f64).collect();
+    let mut sma = SimpleMovingAverage::new(period, &data[..period]).unwrap();
+    for v in data[period..].iter() {
+        sma.next(*v);
+    }
+
+    // Window of 5 complete cycles of 0 to 999.
+    assert!((sma.value() - 499.5).abs() < 1e-6);
+}
+
+#[test]
+/// Minimum and maximum of the buffer match a scan of the values held, while filling, once full,
+/// and after being cleared or reset.
+fn buffer_min_max() {
+    use tatk::Buffer;
+
+    // Simple deterministic pseudo-random values, some repeated.
+    let mut seed: u64 = 7;
+    let mut random = move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((seed >> 33) % 50) as f64 - 25.0
+    };
+
+    let scan = |buffer: &Buffer| {
+        let queue = buffer.queue();
+        (
+            queue.iter().cloned().fold(f64::MAX, f64::min),
+            queue.iter().cloned().fold(f64::MIN, f64::max),
+        )
+    };
+
+    for capacity in [1, 2, 3, 10, 64] {
+        let data: Vec<f64> = (0..(capacity / 2 + 1)).map(|_| random()).collect();
+        let mut buffer = Buffer::from_array(capacity, &data).unwrap();
+        assert_eq!((buffer.min(), buffer.max()), scan(&buffer));
+
+        for i in 0..(capacity * 10) {
+            if i == capacity * 4 {
+                buffer.clear();
+                assert!(buffer.min().is_nan() && buffer.max().is_nan());
+            }
+
+            buffer.shift(random());
+            assert_eq!((buffer.min(), buffer.max()), scan(&buffer));
+        }
+
+        // Ascending and descending runs push the extremes to the edges of the buffer.
+        for i in 0..(capacity * 2) {
+            buffer.shift(i as f64);
+            assert_eq!((buffer.min(), buffer.max()), scan(&buffer));
+        }
+        for i in (0..(capacity * 2)).rev() {
+            buffer.shift(i as f64);
+            assert_eq!((buffer.min(), buffer.max()), scan(&buffer));
+        }
+    }
+}
+
+#[test]
+/// Sum of the buffer does not drift from the values held after many shifts of values with very
+/// different magnitudes.
+fn buffer_sum_drift() {
+    use tatk::{Buffer, Num};
+
+    // Simple deterministic pseudo-random values between 0 and roughly 1.4 billion.
+    let mut seed: u64 = 11;
+    let mut random = move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let magnitude = (10.0 as Num).powi(((seed >> 20) % 7) as i32);
+        ((seed >> 40) % 10_000) as Num / 7.0 * magnitude
+    };
+
+    let mut buffer = Buffer::from_array(8, &[random()]).unwrap();
+    for _ in 0..10_000_000 {
+        buffer.shift(random());
+    }
+
+    // Recalculated sum only has the rounding error of the 8 values held.
+    let recalculated: Num = buffer.queue().iter().sum();
+    let largest = buffer.queue().iter().fold(0.0, |a: Num, b| a.max(b.abs()));
+    let epsilon = largest * Num::EPSILON * 16.0;
+    assert!((buffer.sum() - recalculated).abs() < epsilon);
+}
+
+#[test]
+/// Variance of the buffer updated with each shift does not drift from the variance of the values
+/// held, for small changes of a large value over many shifts.
+fn buffer_variance_drift() {
+    use tatk::{Buffer, Num};
+
+    // Simple deterministic pseudo-random prices around 30,000 changing by less than 1.
+    let mut seed: u64 = 7;
+    let mut random = move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        30_000.0 + (seed >> 40) as Num / (1u64 << 24) as Num
+    };
+
+    let mut buffer = Buffer::from_array(50, &[random()]).unwrap();
+    for i in 0..1_000_000 {
+        buffer.shift(random());
+
+        // Checked between the recalculations, when the most error has accumulated.
+        if i % 997 == 0 {
+            let mean: Num = buffer.queue().iter().sum::<Num>() / buffer.len() as Num;
+            let m2: Num = buffer.queue().iter().map(|x| (x - mean).powi(2)).sum();
+            let expected = m2 / (buffer.len() - 1) as Num;
+            assert!((buffer.variance(true) - expected).abs() < expected * 1e-9);
+        }
+    }
+}
+
+#[test]
+/// Percentiles of the buffer interpolate between the closest values, for full, partially filled,
+/// and single value buffers.
+fn buffer_percentile() {
+    use tatk::Buffer;
+
+    let buffer = Buffer::from_array(5, &[40.0, 15.0, 50.0, 35.0, 20.0]).unwrap();
+    assert_eq!(buffer.percentile(0.0), 15.0);
+    assert_eq!(buffer.percentile(25.0), 20.0);
+    assert_eq!(buffer.percentile(50.0), 35.0);
+    assert_eq!(buffer.percentile(90.0), 46.0);
+    assert_eq!(buffer.percentile(100.0), 50.0);
+    assert_eq!(buffer.median(), 35.0);
+
+    // Only holds 4 of 10 values.
+    let mut partial = Buffer::from_array(10, &[3.0, 1.0, 4.0]).unwrap();
+    partial.shift(2.0);
+    assert_eq!(partial.percentile(25.0), 1.75);
+    assert_eq!(partial.percentile(50.0), 2.5);
+    assert_eq!(partial.percentile(75.0), 3.25);
+    assert_eq!(partial.median(), 2.5);
+
+    let single = Buffer::from_array(3, &[7.0]).unwrap();
+    assert_eq!(single.percentile(0.0), 7.0);
+    assert_eq!(single.percentile(33.0), 7.0);
+    assert_eq!(single.median(), 7.0);
+
+    // Outside of the range or no values.
+    assert!(buffer.percentile(-1.0).is_nan());
+    assert!(buffer.percentile(100.5).is_nan());
+    let mut empty = single.clone();
+    empty.clear();
+    assert!(empty.percentile(50.0).is_nan());
+    assert!(empty.median().is_nan());
+}
+
+#[test]
+/// Accessing the buffer is always Oldest -> Newest, including once the values wrap around the
+/// ring.
+fn buffer_access_order() {
+    use tatk::Buffer;
+
+    let mut buffer = Buffer::from_array(4, &[1.0, 2.0]).unwrap();
+    assert_eq!(buffer.len(), 2);
+    assert_eq!(buffer.get(0), Some(1.0));
+    assert_eq!(buffer.get(2), None);
+    assert_eq!(buffer.newest_n(5), &[1.0, 2.0]);
+
+    // Wraps the ring twice.
+    for v in 3..=10 {
+        buffer.shift(v as f64);
+    }
+
+    let expected = [7.0, 8.0, 9.0, 10.0];
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), expected);
+    assert_eq!((&buffer).into_iter().count(), 4);
+    for (i, v) in (&buffer).into_iter().enumerate() {
+        assert_eq!(buffer[i], *v);
+        assert_eq!(buffer.get(i), Some(expected[i]));
+    }
+    assert_eq!(buffer[0], buffer.oldest());
+    assert_eq!(buffer[3], buffer.newest());
+    assert_eq!(buffer.get(4), None);
+
+    assert_eq!(buffer.newest_n(2), &[9.0, 10.0]);
+    assert_eq!(buffer.last_n(3), &[8.0, 9.0, 10.0]);
+    assert_eq!(buffer.newest_n(0), &[] as &[f64]);
+    assert_eq!(buffer.newest_n(10), &expected);
+
+    buffer.clear();
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.iter().next(), None);
+}
+
+#[test]
+/// Oldest and newest of an empty buffer are NaN, such as after it is cleared on a reset.
+fn buffer_empty_ends() {
+    use tatk::{Buffer, StaticBuffer};
+
+    let mut buffer = Buffer::from_array(2, &[1.0, 2.0]).unwrap();
+    buffer.clear();
+    assert!(buffer.oldest().is_nan() && buffer.newest().is_nan());
+    buffer.shift(3.0);
+    assert_eq!((buffer.oldest(), buffer.newest()), (3.0, 3.0));
+
+    let mut buffer = StaticBuffer::<2>::from_array(2, &[1.0, 2.0]).unwrap();
+    buffer.clear();
+    assert!(buffer.oldest().is_nan() && buffer.newest().is_nan());
+}
+
+#[test]
+/// StaticBuffer holds the same values as a Buffer of the same capacity, Oldest -> Newest.
+fn static_buffer_matches_buffer() {
+    use tatk::{Buffer, StaticBuffer};
+
+    let mut heap = Buffer::from_array(4, &[1.0, 2.0]).unwrap();
+    let mut fixed = StaticBuffer::<4>::from_array(4, &[1.0, 2.0]).unwrap();
+    assert!(!fixed.is_ready());
+    assert_eq!(fixed.capacity(), 4);
+
+    for v in 3..=10 {
+        let v = (v * v) as f64 / 3.0;
+        assert_eq!(fixed.shift(v), heap.shift(v));
+        assert_eq!(fixed.queue(), heap.queue());
+        assert_eq!(fixed.sum(), heap.sum());
+        assert!((fixed.variance(true) - heap.variance(true)).abs() < 1e-9);
+        assert!((fixed.stdev(false) - heap.stdev(false)).abs() < 1e-9);
+    }
+    assert!(fixed.is_ready());
+    assert_eq!(fixed.oldest(), heap.oldest());
+    assert_eq!(fixed.newest(), heap.newest());
+
+    // Keeps only the newest values, unused positions are not compared.
+    let copy = StaticBuffer::<4>::from_array(4, &[0.0, 0.0, 1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(copy.queue(), &[1.0, 2.0, 3.0, 4.0]);
+    let mut cleared = copy;
+    cleared.clear();
+    cleared.shift(5.0);
+    assert_eq!(cleared, StaticBuffer::<4>::from_array(4, &[5.0]).unwrap());
+
+    // Capacity must match.
+    assert!(StaticBuffer::<4>::from_array(3, &[1.0]).is_err());
+    assert!(StaticBuffer::<4>::from_array(4, &[]).is_err());
+}
+
+#[test]
+/// Errors hold how much data is required, works as a standard error.
+fn error_context() {
+    use tatk::indicators::{MovingAverageConvergenceDivergence, RelativeStrengthIndex};
+    use tatk::TAError;
+
+    let data = [1.0, 2.0, 3.0];
+    match RelativeStrengthIndex::new(14, &data) {
+        Err(TAError::InvalidData {
+            indicator,
+            required,
+            received,
+        }) => {
+            assert_eq!(indicator, "relative strength index");
+            assert_eq!(required, 15);
+            assert_eq!(received, 3);
+        }
+        _ => panic!("expected not enough data"),
+    }
+
+    let error: Box<dyn std::error::Error> =
+        Box::new(MovingAverageConvergenceDivergence::new(2, 10, 5, &data).unwrap_err());
+    assert_eq!(
+        error.to_string(),
+        "invalid data, not enough data to calculate moving average convergence and divergence, requires 14 but received 3"
+    );
+    assert!(error.source().is_none());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Indicators held as trait objects produce the same values as when used directly.
+fn dynamic_matches_static() {
+    use tatk::indicators::{
+        AverageTrueRange, ExponentialMovingAverage, OnBalanceVolume, RelativeStrengthIndex,
+        SimpleMovingAverage, TrueRange,
+    };
+    use tatk::test_data::TestData;
+    use tatk::traits::{CandleIndicator, Indicator, Next};
+
+    let closes = TestData::closes();
+    let candles = TestData::candles();
+    let (start, rest) = closes.split_at(30);
+    let (candle_start, candle_rest) = candles.split_at(30);
+
+    let mut sma = SimpleMovingAverage::new(10, start).unwrap();
+    let mut ema = ExponentialMovingAverage::new(10, start).unwrap();
+    let mut rsi = RelativeStrengthIndex::new(14, start).unwrap();
+    let mut indicators: Vec<Box<dyn Indicator>> = vec![
+        Box::new(sma.clone()),
+        Box::new(ema.clone()),
+        Box::new(rsi.clone()),
+    ];
+
+    let mut tr = TrueRange::new(14, candle_start).unwrap();
+    let mut atr = AverageTrueRange::new(14, candle_start).unwrap();
+    let mut obv = OnBalanceVolume::new(10, candle_start).unwrap();
+    let mut candle_indicators: Vec<Box<dyn CandleIndicator>> = vec![
+        Box::new(tr.clone()),
+        Box::new(atr.clone()),
+        Box::new(obv.clone()),
+    ];
+
+    for (close, candle) in rest.iter().zip(candle_rest.iter()) {
+        for indicator in indicators.iter_mut() {
+            indicator.update(*close);
+        }
+        for indicator in candle_indicators.iter_mut() {
+            indicator.update(candle);
+        }
+
+        let expected = [sma.next(*close), ema.next(*close), rsi.next(*close)];
+        for (indicator, value) in indicators.iter().zip(expected) {
+            assert_eq!(indicator.value(), value);
+        }
+
+        let expected = [tr.next(candle), atr.next(*candle), obv.next(*candle)];
+        for (indicator, value) in candle_indicators.iter().zip(expected) {
+            assert_eq!(indicator.value(), value);
+        }
+    }
+
+    let names: Vec<&str> = indicators.iter().map(|i| i.name()).collect();
+    assert_eq!(names, ["SMA", "EMA", "RSI"]);
+    assert_eq!(candle_indicators[1].name(), "ATR");
+    assert_eq!(candle_indicators[1].period(), 14);
+}
+
+#[test]
+/// Every indicator producing a line can be the middle of Bollinger Bands and the lines of a Cross.
+fn lines_are_interchangeable() {
+    use tatk::indicators::*;
+    use tatk::traits::{InternalValue, Next, Period, Stats};
+    let data: Vec<f64> = (1..=60)
+        .map(|i| 100.0 + (i as f64 / 8.0).sin() * 10.0)
+        .collect();
+
+    fn bands<L: InternalValue + Period + Stats + Next<f64>>(line: L) {
+        let mut bbands = BollingerBands::with_line(line, 2.0).unwrap();
+        bbands.next(100.0);
+        assert!(bbands.lower() <= bbands.upper());
+    }
+
+    fn cross<L: InternalValue + Next<f64> + Clone>(line: L) {
+        let mut cross = Cross::new(line.clone(), line);
+        assert_eq!(Next::next(&mut cross, 100.0), CrossEvent::None);
+    }
+
+    macro_rules! lines {
+        ($($line:expr),+ $(,)?) => {
+            $(
+                bands($line.unwrap());
+                cross($line.unwrap());
+            )+
+        };
+    }
+
+    lines!(
+        Acceleration::new(5, 10, &data),
+        CumulativeReturn::new(10, 1.0, &data),
+        DoubleExponentialMovingAverage::new(10, &data),
+        ExponentialMovingAverage::new(10, &data),
+        GaussianMovingAverage::new(10, 2.0, GaussianCenter::Newest, &data),
+        HistoricalVolatility::new(10, 252.0, &data),
+        HoltSmoothing::new(10, 0.5, 0.5, &data),
+        KalmanFilter::new(10, KalmanModel::Level, 0.1, 1.0, &data),
+        LinearRegression::new(10, &data),
+        McGinleyDynamic::new(10, &data, 0.6),
+        Median::new(10, &data),
+        MedianAbsoluteDeviation::new(10, &data, false),
+        MidPoint::new(10, &data),
+        MinMaxScaler::new(10, &data),
+        Moments::new(10, &data),
+        MovingAverageConvergenceDivergence::new(12, 26, 9, &data),
+        RateOfChange::new(10, &data),
+        RelativeStrengthIndex::new(10, &data),
+        RollingSum::new(10, &data),
+        SavitzkyGolay::new(11, 2, &data),
+        SharpeRatio::new(10, 0.0, 252.0, &data, true),
+        SimpleMovingAverage::new(10, &data),
+        SmoothedMovingAverage::new(10, &data),
+        SmoothedRateOfChange::new(5, 10, &data),
+        SortinoRatio::new(10, 0.0, 252.0, &data, true),
+        WeightedMovingAverage::new(10, &data),
+        BollingerBands::new(10, &data, 2.0),
+        StandardDeviation::new(10, &data, true),
+        Variance::new(10, &data, true),
+    );
+
+    // Lines without statistics of their own can still cross.
+    cross(Lag::new(10, &data).unwrap());
+    cross(LinearRegressionChannel::new(10, &data, ChannelWidth::Deviation(2.0)).unwrap());
+}
+
+#[test]
+/// Signals from the thresholds, bands, and crosses, holding while exactly at a boundary.
+fn signals() {
+    use tatk::indicators::{
+        BollingerBands, Cross, MovingAverageConvergenceDivergence, RelativeStrengthIndex,
+        SimpleMovingAverage,
+    };
+    use tatk::traits::{Next, SignalSource};
+    use tatk::Signal;
+
+    // RSI is only a buy or sell once leaving a threshold, compared to the thresholds set.
+    let data: Vec<f64> = (0..30).map(|i| 100.0 + ((i * 7) % 11) as f64).collect();
+    let mut rsi = RelativeStrengthIndex::new(14, &data).unwrap();
+    let value = rsi.value();
+    rsi.set_oversold(0.0);
+    rsi.set_overbought(0.0);
+    assert_eq!(rsi.signal(), Signal::Hold);
+    rsi.set_overbought(value);
+    assert_eq!(rsi.signal(), Signal::Sell);
+
+    // Bollinger Bands compare the last value supplied, equal to both bands with no deviation.
+    let mut bbands = BollingerBands::new(5, &[5.0; 5], 1.0).unwrap();
+    assert_eq!(bbands.last(), Some(5.0));
+    assert_eq!(bbands.upper(), 5.0);
+    assert_eq!(bbands.signal(), Signal::Hold);
+    bbands.next(6.0);
+    assert_eq!(bbands.signal(), Signal::Sell);
+    bbands.next(4.0);
+    assert_eq!(bbands.signal(), Signal::Buy);
+    bbands.next(5.0);
+    assert_eq!(bbands.signal(), Signal::Hold);
+
+    // Cross signals only on the value that crossed.
+    let short = SimpleMovingAverage::new(2, &[20.0, 15.0, 10.0, 5.0]).unwrap();
+    let long = SimpleMovingAverage::new(4, &[20.0, 15.0, 10.0, 5.0]).unwrap();
+    let mut cross = Cross::new(short, long);
+    assert_eq!(cross.signal(), Signal::Hold);
+    cross.next(30.0);
+    assert_eq!(cross.signal(), Signal::Buy);
+    cross.next(30.0);
+    assert_eq!(cross.signal(), Signal::Hold);
+    cross.next(0.0);
+    assert_eq!(cross.signal(), Signal::Sell);
+    cross.next(0.0);
+    assert_eq!(cross.signal(), Signal::Hold);
+
+    // MACD signals on the value crossing the signal line.
+    let wave: Vec<f64> = (0..200)
+        .map(|i| 100.0 + (i as f64 / 8.0).sin() * 10.0)
+        .collect();
+    let mut macd = MovingAverageConvergenceDivergence::new(12, 26, 9, &wave[..40]).unwrap();
+    let (mut buys, mut sells) = (0, 0);
+    for v in wave[40..].iter() {
+        macd.next(*v);
+        let expected = match (macd.crossed(), macd.is_above(), macd.is_below()) {
+            (true, true, _) => Signal::Buy,
+            (true, _, true) => Signal::Sell,
+            _ => Signal::Hold,
+        };
+        assert_eq!(macd.signal(), expected);
+        buys += macd.signal().is_buy() as usize;
+        sells += macd.signal().is_sell() as usize;
+    }
+    assert!(buys > 0 && sells > 0);
+}
+
+#[test]
+/// Bollinger Bands and Cross accept user defined values, the same as supplying the value.
+fn composites_as_value() {
+    use tatk::indicators::{BollingerBands, Cross, SimpleMovingAverage};
+    use tatk::traits::{AsValue, Next};
+
+    // Candle supplying its close as the value.
+    struct Candle {
+        close: f64,
+    }
+
+    impl AsValue for Candle {
+        fn as_value(&self) -> f64 {
+            self.close
+        }
+    }
+
+    let data: Vec<f64> = (0..40).map(|i| 100.0 + ((i * 7) % 11) as f64).collect();
+    let mut bbands = BollingerBands::new(10, &data[..10], 2.0).unwrap();
+    let mut manual = bbands.clone();
+    let line = |period: usize| SimpleMovingAverage::new(period, &data[..10]).unwrap();
+    let mut cross = Cross::new(line(3), line(10));
+    let mut manual_cross = cross.clone();
+    for v in data[10..].iter() {
+        assert_eq!(bbands.next(Candle { close: *v }), manual.next(*v));
+        assert_eq!(cross.next(Candle { close: *v }), manual_cross.next(*v));
+    }
+    assert_eq!(bbands, manual);
+    assert_eq!(cross, manual_cross);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// True Range, ATR, and OBV accept the same candles by reference, matching the values supplied
+/// by value.
+fn candles_by_reference() {
+    use tatk::indicators::{AverageTrueRange, OnBalanceVolume, TrueRange};
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::Next;
+    let candles: Vec<Candle> = TestData::candles();
+
+    let mut tr = TrueRange::new(14, &candles[..15]).unwrap();
+    let mut atr = AverageTrueRange::new(14, &candles[..15]).unwrap();
+    let mut obv = OnBalanceVolume::new(14, &candles[..15]).unwrap();
+    let (mut atr_value, mut obv_value) = (atr.clone(), obv.clone());
+
+    for candle in candles[15..].iter() {
+        tr.next(candle);
+        assert_eq!(atr.next(candle), atr_value.next(*candle));
+        assert_eq!(obv.next(candle), obv_value.next(*candle));
+    }
+
+    let (prior, last) = (candles[candles.len() - 2], candles[candles.len() - 1]);
+    assert_eq!(
+        tr.value(),
+        last.high.max(prior.close) - last.low.min(prior.close)
+    );
+    assert_eq!(atr.value(), atr_value.value());
+    assert_eq!(obv.value(), obv_value.value());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Last output of each batch calculation is the value of the indicator created from the same
+/// data, the outputs are aligned with the data and NaN until ready.
+fn batch_compute() {
+    use tatk::indicators::*;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::{Next, Ready};
+    const DATA: &[f64] = TestData::talib();
+    let candles: Vec<Candle> = TestData::candles();
+
+    macro_rules! matches_new {
+        ($data:expr, $($indicator:ty => $value:expr),+ $(,)?) => {
+            $(
+                let outputs = <$indicator>::compute(10, $data).unwrap();
+                assert_eq!(outputs.len(), $data.len());
+                assert!((outputs[outputs.len() - 1] - $value).abs() < 1e-9);
+            )+
+        };
+    }
+
+    matches_new!(
+        DATA,
+        DoubleExponentialMovingAverage => DoubleExponentialMovingAverage::new(10, DATA).unwrap().value(),
+        ExponentialMovingAverage => ExponentialMovingAverage::new(10, DATA).unwrap().value(),
+        Lag => Lag::new(10, DATA).unwrap().value(),
+        LinearRegression => LinearRegression::new(10, DATA).unwrap().value(),
+        Median => Median::new(10, DATA).unwrap().value(),
+        MidPoint => MidPoint::new(10, DATA).unwrap().value(),
+        MinMaxScaler => MinMaxScaler::new(10, DATA).unwrap().value(),
+        RateOfChange => RateOfChange::new(10, DATA).unwrap().value(),
+        RelativeStrengthIndex => RelativeStrengthIndex::new(10, DATA).unwrap().value(),
+        RollingSum => RollingSum::new(10, DATA).unwrap().value(),
+        SimpleMovingAverage => SimpleMovingAverage::new(10, DATA).unwrap().value(),
+        SmoothedMovingAverage => SmoothedMovingAverage::new(10, DATA).unwrap().value(),
+        WeightedMovingAverage => WeightedMovingAverage::new(10, DATA).unwrap().value(),
+    );
+
+    matches_new!(
+        &candles,
+        AverageTrueRange => AverageTrueRange::new(10, &candles).unwrap().value(),
+        MidPrice => MidPrice::new(10, &candles).unwrap().value(),
+        OnBalanceVolume => OnBalanceVolume::new(10, &candles).unwrap().value(),
+        TrueRange => TrueRange::new(10, &candles).unwrap().value(),
+        TypicalPrice => TypicalPrice::new(10, &candles).unwrap().value(),
+        WeightedClose => WeightedClose::new(10, &candles).unwrap().value(),
+    );
+
+    // Outputs before the SMA is ready are NaN, or skipped.
+    let outputs = SimpleMovingAverage::compute(10, DATA).unwrap();
+    assert!(outputs[..9].iter().all(|v| v.is_nan()));
+    assert_eq!(
+        outputs[9],
+        SimpleMovingAverage::new(10, &DATA[..10]).unwrap().value()
+    );
+
+    let sma = SimpleMovingAverage::with_period(10).unwrap();
+    let skipped = compute(sma, DATA.iter().copied(), Warmup::Skip);
+    assert_eq!(skipped.len(), DATA.len() - 9);
+    assert_eq!(&skipped[..], &outputs[9..]);
+
+    // Indicators with other settings are supplied to the generic calculation.
+    let mut hv = HistoricalVolatility::with_period(10, 252.0).unwrap();
+    let outputs = compute(hv.clone(), DATA.iter().copied(), Warmup::Nan);
+    for value in DATA.iter() {
+        hv.next(*value);
+    }
+
+    assert!(hv.is_ready());
+    assert_eq!(outputs[outputs.len() - 1], hv.value());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Last output of each batch calculation taking settings is the value of the indicator created
+/// from the same data and settings, lines are compared with their accessors.
+fn batch_compute_settings() {
+    use tatk::indicators::*;
+    use tatk::test_data::{Candle, TestData};
+    const DATA: &[f64] = TestData::talib();
+    let candles: Vec<Candle> = TestData::candles();
+    let returns: Vec<f64> = DATA.windows(2).map(|w| w[1] / w[0] - 1.0).collect();
+    let pairs: Vec<(f64, f64)> = returns.windows(2).map(|w| (w[1], w[0])).collect();
+
+    // Outputs are aligned with the data, returning the last.
+    fn last<T: Clone>(outputs: Vec<T>, len: usize) -> T {
+        assert_eq!(outputs.len(), len);
+        outputs[len - 1].clone()
+    }
+
+    macro_rules! assert_close {
+        ($($output:expr => $value:expr),+ $(,)?) => {
+            $(assert!(($output - $value).abs() < 1e-9, "{} != {}", $output, $value);)+
+        };
+    }
+
+    let len = DATA.len();
+    let clen = candles.len();
+    assert_close!(
+        last(Acceleration::compute(5, 10, DATA).unwrap(), len)
+            => Acceleration::new(5, 10, DATA).unwrap().value(),
+        last(GaussianMovingAverage::compute(10, 2.0, GaussianCenter::Middle, DATA).unwrap(), len)
+            => GaussianMovingAverage::new(10, 2.0, GaussianCenter::Middle, DATA).unwrap().value(),
+        last(HistoricalVolatility::compute(10, 252.0, DATA).unwrap(), len)
+            => HistoricalVolatility::new(10, 252.0, DATA).unwrap().value(),
+        last(HoltSmoothing::compute(10, 0.5, 0.2, DATA).unwrap(), len)
+            => HoltSmoothing::new(10, 0.5, 0.2, DATA).unwrap().value(),
+        last(KalmanFilter::compute(10, KalmanModel::Velocity, 0.1, 1.0, DATA).unwrap(), len)
+            => KalmanFilter::new(10, KalmanModel::Velocity, 0.1, 1.0, DATA).unwrap().value(),
+        last(McGinleyDynamic::compute(10, 0.6, DATA).unwrap(), len)
+            => McGinleyDynamic::new(10, DATA, 0.6).unwrap().value(),
+        last(MedianAbsoluteDeviation::compute(10, true, DATA).unwrap(), len)
+            => MedianAbsoluteDeviation::new(10, DATA, true).unwrap().value(),
+        last(SavitzkyGolay::compute(11, 2, DATA).unwrap(), len)
+            => SavitzkyGolay::new(11, 2, DATA).unwrap().value(),
+        last(SharpeRatio::compute(10, 0.0, 252.0, true, DATA).unwrap(), len)
+            => SharpeRatio::new(10, 0.0, 252.0, DATA, true).unwrap().value(),
+        last(SmoothedRateOfChange::compute(5, 10, DATA).unwrap(), len)
+            => SmoothedRateOfChange::new(5, 10, DATA).unwrap().value(),
+        last(SortinoRatio::compute(10, 0.01, 252.0, true, DATA).unwrap(), len)
+            => SortinoRatio::new(10, 0.01, 252.0, DATA, true).unwrap().value(),
+        last(StandardDeviation::compute(10, true, DATA).unwrap(), len)
+            => StandardDeviation::new(10, DATA, true).unwrap().value(),
+        last(Variance::compute(10, false, DATA).unwrap(), len)
+            => Variance::new(10, DATA, false).unwrap().value(),
+        last(Beta::compute(10, &pairs).unwrap(), pairs.len())
+            => Beta::new(10, &pairs).unwrap().value(),
+        last(CumulativeReturn::compute(10, 100.0, &returns).unwrap(), returns.len())
+            => CumulativeReturn::new(10, 100.0, &returns).unwrap().value(),
+    );
+
+    // Indicators producing several lines.
+    let bbands = BollingerBands::new(10, DATA, 2.0).unwrap();
+    let bands = last(BollingerBands::compute(10, 2.0, DATA).unwrap(), len);
+    assert_close!(
+        bands.lower => bbands.lower(),
+        bands.middle => bbands.value(),
+        bands.upper => bbands.upper(),
+    );
+
+    let lrc = LinearRegressionChannel::new(10, DATA, ChannelWidth::Deviation(2.0)).unwrap();
+    let (lower, value, upper) = last(
+        LinearRegressionChannel::compute(10, ChannelWidth::Deviation(2.0), DATA).unwrap(),
+        len,
+    );
+    assert_close!(lower => lrc.lower(), value => lrc.value(), upper => lrc.upper());
+
+    let macd = MovingAverageConvergenceDivergence::new(12, 26, 9, DATA).unwrap();
+    let output = last(
+        MovingAverageConvergenceDivergence::compute(12, 26, 9, DATA).unwrap(),
+        len,
+    );
+    assert_close!(
+        output.macd => macd.value(),
+        output.signal => macd.signal_value(),
+    );
+
+    let moments = Moments::new(10, DATA).unwrap();
+    let (skewness, kurtosis) = last(Moments::compute(10, DATA).unwrap(), len);
+    assert_close!(skewness => moments.skewness(), kurtosis => moments.kurtosis());
+
+    let extrema = RollingExtrema::new(10, DATA).unwrap();
+    let (min, max) = last(RollingExtrema::compute(10, DATA).unwrap(), len);
+    assert_close!(min => extrema.min(), max => extrema.max());
+
+    // Indicators consuming candles.
+    assert_close!(
+        last(AtrTrailingStop::compute(10, 3.0, FlipSeed::Close, &candles).unwrap(), clen)
+            => AtrTrailingStop::new(10, 3.0, FlipSeed::Close, &candles).unwrap().value(),
+        last(GarmanKlassVolatility::compute(10, 252.0, &candles).unwrap(), clen)
+            => GarmanKlassVolatility::new(10, 252.0, &candles).unwrap().value(),
+        last(ParkinsonVolatility::compute(10, 252.0, &candles).unwrap(), clen)
+            => ParkinsonVolatility::new(10, 252.0, &candles).unwrap().value(),
+        last(RogersSatchellVolatility::compute(10, 252.0, &candles).unwrap(), clen)
+            => RogersSatchellVolatility::new(10, 252.0, &candles).unwrap().value(),
+        last(YangZhangVolatility::compute(10, 252.0, &candles).unwrap(), clen)
+            => YangZhangVolatility::new(10, 252.0, &candles).unwrap().value(),
+        last(VolumeWeightedAveragePrice::compute(&candles), clen)
+            => {
+                let mut vwap = VolumeWeightedAveragePrice::new();
+                candles.iter().for_each(|candle| {
+                    tatk::traits::Next::next(&mut vwap, candle);
+                });
+                vwap.value()
+            },
+    );
+
+    let alligator = Alligator::new((13, 8), (8, 5), (5, 3), &candles).unwrap();
+    let (jaw, teeth, lips) = last(
+        Alligator::compute((13, 8), (8, 5), (5, 3), &candles).unwrap(),
+        clen,
+    );
+    assert_close!(
+        jaw => alligator.jaw(),
+        teeth => alligator.teeth(),
+        lips => alligator.lips(),
+    );
+
+    let gator = GatorOscillator::new((13, 8), (8, 5), (5, 3), &candles).unwrap();
+    let bar = last(
+        GatorOscillator::compute((13, 8), (8, 5), (5, 3), &candles).unwrap(),
+        clen,
+    );
+    assert_close!(bar.upper => gator.upper(), bar.lower => gator.lower());
+    assert_eq!(bar, gator.value());
+
+    let keltner = KeltnerChannels::new(10, 2.0, &candles).unwrap();
+    let bands = last(KeltnerChannels::compute(10, 2.0, &candles).unwrap(), clen);
+    assert_close!(
+        bands.lower => keltner.lower(),
+        bands.middle => keltner.value(),
+        bands.upper => keltner.upper(),
+    );
+
+    let squeeze = Squeeze::new(10, 2.0, 1.5, &candles).unwrap();
+    let output = last(Squeeze::compute(10, 2.0, 1.5, &candles).unwrap(), clen);
+    assert_close!(output.momentum => squeeze.momentum());
+    assert_eq!(output.is_squeezed, squeeze.is_squeezed());
+
+    // Outputs before ready are NaN lines.
+    let outputs = MovingAverageConvergenceDivergence::compute(12, 26, 9, DATA).unwrap();
+    assert!(outputs[0].macd.is_nan() && outputs[0].signal.is_nan());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Iterators supply their items to indicators lazily, matching the batch calculation.
+fn iterator_adapters() {
+    use tatk::indicators::*;
+    use tatk::test_data::{Candle, TestData};
+    const DATA: &[f64] = TestData::talib();
+    let candles: Vec<Candle> = TestData::candles();
+
+    // Outputs are None until ready, matching the NaN of the batch calculation.
+    let outputs: Vec<Option<f64>> = DATA.iter().copied().sma(10).unwrap().collect();
+    let batch = SimpleMovingAverage::compute(10, DATA).unwrap();
+    assert_eq!(outputs.len(), DATA.len());
+    assert!(outputs[..9].iter().all(|v| v.is_none()));
+    for (output, value) in outputs[9..].iter().zip(batch[9..].iter()) {
+        assert_eq!(*output, Some(*value));
+    }
+
+    // Flattening skips the warm-up.
+    let skipped: Vec<f64> = DATA.iter().copied().rsi(10).unwrap().flatten().collect();
+    let rsi = RelativeStrengthIndex::with_period(10).unwrap();
+    assert_eq!(skipped, compute(rsi, DATA.iter().copied(), Warmup::Skip));
+
+    let atr: Vec<f64> = candles.iter().atr(10).unwrap().flatten().collect();
+    let last = AverageTrueRange::new(10, &candles).unwrap().value();
+    assert!((atr[atr.len() - 1] - last).abs() < 1e-9);
+
+    // Lazily driven, only the items taken are supplied.
+    let mut ema = DATA.iter().copied().ema(10).unwrap();
+    assert_eq!(ema.by_ref().take(3).count(), 3);
+    assert_eq!(tatk::traits::Ready::samples_seen(ema.indicator()), 3);
+
+    // Any indicator is applied, with outputs other than values.
+    let bbands = BollingerBands::with_period(10, 2.0).unwrap();
+    let bands: Vec<BandsOutput> = DATA.iter().copied().apply(bbands).flatten().collect();
+    assert_eq!(bands.len(), DATA.len() - 9);
+    assert!(bands
+        .iter()
+        .all(|b| b.lower <= b.middle && b.middle <= b.upper));
+
+    // Invalid periods are returned as errors.
+    assert!(DATA.iter().copied().sma(0).is_err());
+    assert!(candles.iter().atr(0).is_err());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Outputs of one indicator are supplied to another, matching stages chained by hand.
+fn chain_stages() {
+    use tatk::indicators::*;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::{Next, Ready, Reset};
+    const DATA: &[f64] = TestData::talib();
+
+    // Reference, an EMA(10) of the RSI(14) once the RSI is complete.
+    let mut rsi = RelativeStrengthIndex::with_period(14).unwrap();
+    let mut outputs: Vec<f64> = Vec::new();
+    for value in DATA.iter() {
+        let output = rsi.next(*value);
+        if rsi.is_ready() {
+            outputs.push(output);
+        }
+    }
+
+    let ema = ExponentialMovingAverage::new(10, &outputs).unwrap();
+    let mut chain = Chain::new(
+        RelativeStrengthIndex::with_period(14).unwrap(),
+        ExponentialMovingAverage::with_period(10).unwrap(),
+        DATA.iter().copied(),
+    )
+    .unwrap();
+
+    assert!(chain.is_ready());
+    assert_eq!(chain.value(), ema.value());
+    assert_eq!(chain.first().value(), rsi.value());
+    assert_eq!(chain.second().value(), ema.value());
+
+    // Chained when streaming, not ready until both stages are.
+    let mut built = RelativeStrengthIndex::with_period(14)
+        .unwrap()
+        .then(ExponentialMovingAverage::with_period(10).unwrap());
+    for (i, value) in DATA.iter().enumerate() {
+        built.next(*value);
+        assert_eq!(built.is_ready(), i >= 23);
+    }
+
+    assert_eq!(built.value(), chain.value());
+    chain.reset();
+    assert!(!chain.is_ready());
+
+    // Both stages are required from the data.
+    let result = Chain::new(
+        RelativeStrengthIndex::with_period(14).unwrap(),
+        ExponentialMovingAverage::with_period(10).unwrap(),
+        DATA[..20].iter().copied(),
+    );
+    assert!(matches!(
+        result,
+        Err(tatk::TAError::InvalidData {
+            required: 24,
+            received: 20,
+            ..
+        })
+    ));
+
+    // Candles are supplied to the first stage, chains are chained again.
+    let candles: Vec<Candle> = TestData::candles();
+    let obv = OnBalanceVolume::with_period(10).unwrap();
+    let sma = SimpleMovingAverage::with_period(5).unwrap();
+    let wma = WeightedMovingAverage::with_period(3).unwrap();
+    let chain = Chain::new(obv.then(sma), wma, &candles).unwrap();
+    assert!(chain.is_ready());
+    assert_eq!(chain.value(), chain.second().value());
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "test-data"))]
+/// Every indicator of a set is supplied the same candles, values are None until ready.
+fn indicator_set() {
+    use tatk::indicators::*;
+    use tatk::test_data::TestData;
+    use tatk::traits::{Close, Next, Ready, Reset};
+    let candles = TestData::candles();
+
+    let mut set = IndicatorSet::default();
+    set.add("sma", SimpleMovingAverage::with_period(10).unwrap())
+        .unwrap()
+        .add("rsi", RelativeStrengthIndex::with_period(14).unwrap())
+        .unwrap()
+        .add_candle("atr", AverageTrueRange::with_period(14).unwrap())
+        .unwrap()
+        .add(
+            "macd",
+            MovingAverageConvergenceDivergence::with_period(12, 26, 9).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(set.names(), vec!["sma", "rsi", "atr", "macd"]);
+    assert!(set
+        .add("sma", SimpleMovingAverage::with_period(5).unwrap())
+        .is_err());
+
+    let mut sma = SimpleMovingAverage::with_period(10).unwrap();
+    let mut rsi = RelativeStrengthIndex::with_period(14).unwrap();
+    let mut atr = AverageTrueRange::with_period(14).unwrap();
+    let mut macd = MovingAverageConvergenceDivergence::with_period(12, 26, 9).unwrap();
+    for candle in candles.iter() {
+        let values = set.next(candle);
+        sma.next(candle.close());
+        rsi.next(candle.close());
+        atr.next(candle);
+        macd.next(candle.close());
+
+        assert_eq!(values["sma"], sma.is_ready().then(|| sma.value()));
+        assert_eq!(values["rsi"], rsi.is_ready().then(|| rsi.value()));
+        assert_eq!(values["atr"], atr.is_ready().then(|| atr.value()));
+        assert_eq!(values["macd"], macd.is_ready().then(|| macd.value()));
+        assert_eq!(set.is_ready(), macd.is_ready() && rsi.is_ready());
+    }
+
+    assert_eq!(set.samples_seen(), candles.len());
+    assert_eq!(set.value("rsi"), Some(rsi.value()));
+    assert_eq!(set.value("unknown"), None);
+    assert_eq!(set.values().len(), set.len());
+
+    set.reset();
+    assert!(!set.is_ready());
+    assert!(set.values().values().all(|v| v.is_none()));
+
+    // Indicators consuming single values are supplied the price from the source.
+    let mut set = IndicatorSet::new(PriceSource::Hlc3);
+    set.add("sma", SimpleMovingAverage::with_period(3).unwrap())
+        .unwrap();
+    for candle in candles[..3].iter() {
+        set.next(candle);
+    }
+
+    let prices: Vec<f64> = candles[..3]
+        .iter()
+        .map(|c| PriceSource::Hlc3.price(c))
+        .collect();
+    let expected = SimpleMovingAverage::new(3, &prices).unwrap().value();
+    assert_eq!(set.value("sma"), Some(expected));
+}
+
+#[test]
+#[cfg(all(feature = "parallel", feature = "test-data"))]
+/// Configurations and symbols calculated in parallel match calculating each on its own.
+fn parallel_compute() {
+    use std::collections::HashMap;
+    use tatk::indicators::*;
+    use tatk::test_data::{Candle, TestData};
+    const DATA: &[f64] = TestData::talib();
+
+    // Results are in the order of the configurations, errors are kept per configuration.
+    let periods: Vec<usize> = vec![5, 0, 10, 20, 50];
+    let results = compute_many(&periods, DATA, Warmup::Skip, |period| {
+        SimpleMovingAverage::with_period(*period)
+    });
+    assert_eq!(results.len(), periods.len());
+    for (period, result) in periods.iter().zip(results.iter()) {
+        match SimpleMovingAverage::with_period(*period) {
+            Ok(sma) => {
+                let expected = compute(sma, DATA.iter().copied(), Warmup::Skip);
+                assert_eq!(result.as_ref().unwrap(), &expected);
+            }
+            Err(_) => assert!(result.is_err()),
+        }
+    }
+
+    // Results are under the same symbol.
+    let candles: Vec<Candle> = TestData::candles();
+    let mut map: HashMap<&str, Vec<Candle>> = HashMap::new();
+    map.insert("BTC", candles.clone());
+    map.insert("ETH", candles[100..].to_vec());
+    map.insert("SOL", candles[..5].to_vec());
+    map.insert("BAD", candles.clone());
+
+    let results = compute_per_symbol(&map, Warmup::Nan, |symbol| match *symbol {
+        "BAD" => AverageTrueRange::with_period(0),
+        _ => AverageTrueRange::with_period(14),
+    });
+    assert_eq!(results.len(), map.len());
+    assert!(results["BAD"].is_err());
+    for symbol in ["BTC", "ETH", "SOL"] {
+        let atr = AverageTrueRange::with_period(14).unwrap();
+        let expected = compute(atr, map[symbol].iter(), Warmup::Nan);
+        let outputs = results[symbol].as_ref().unwrap();
+        assert_eq!(outputs.len(), expected.len());
+        for (output, value) in outputs.iter().zip(expected.iter()) {
+            assert!(output == value || (output.is_nan() && value.is_nan()));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Indicators restored from a snapshot produce the same output as if they never stopped.
+fn snapshot_restore() {
+    use std::fmt::Debug;
+    use tatk::indicators::*;
+    use tatk::test_data::{Candle, TestData};
+    use tatk::traits::{Next, Reset, Snapshot};
+    use tatk::{Buffer, IndicatorState, StaticBuffer};
+    const DATA: &[f64] = TestData::talib();
+
+    /// Snapshots half way through the data, at the split and again after a reset.
+    fn resumes<I, T>(mut indicator: I, data: &[T])
+    where
+        I: Snapshot + Next<T> + Reset,
+        I::Output: Debug,
+        T: Copy,
+    {
+        let (first, rest) = data.split_at(data.len() / 2);
+        for reset in [false, true] {
+            for value in first.iter() {
+                indicator.next(*value);
+            }
+
+            if reset {
+                // Snapshot while collecting the values to recreate from.
+                indicator.reset();
+                indicator.next(first[0]);
+            }
+
+            let state: IndicatorState = indicator.snapshot();
+            let mut restored = I::restore(&state.clone()).unwrap();
+            assert_eq!(format!("{:?}", restored.snapshot()), format!("{:?}", state));
+            for value in rest.iter() {
+                let expected = format!("{:?}", indicator.next(*value));
+                assert_eq!(format!("{:?}", restored.next(*value)), expected);
+            }
+        }
+    }
+
+    resumes(SimpleMovingAverage::with_period(10).unwrap(), DATA);
+    resumes(ExponentialMovingAverage::with_period(10).unwrap(), DATA);
+    resumes(
+        ExponentialMovingAverage::with_seed(10, &DATA[..10], EmaSeed::Custom(1.5)).unwrap(),
+        DATA,
+    );
+    resumes(WeightedMovingAverage::with_period(10).unwrap(), DATA);
+    resumes(SmoothedMovingAverage::with_period(10).unwrap(), DATA);
+    resumes(StandardDeviation::with_period(10, true).unwrap(), DATA);
+    resumes(Variance::with_period(10, false).unwrap(), DATA);
+    resumes(RelativeStrengthIndex::with_period(14).unwrap(), DATA);
+    resumes(
+        MovingAverageConvergenceDivergence::with_period(12, 26, 9).unwrap(),
+        DATA,
+    );
+    resumes(
+        MovingAverageConvergenceDivergence::builder()
+            .short_kind(MaKind::Wma)
+            .long_kind(MaKind::Smma)
+            .signal_kind(MaKind::Sma)
+            .build(&DATA[..40])
+            .unwrap(),
+        DATA,
+    );
+    resumes(BollingerBands::with_period(20, 2.0).unwrap(), DATA);
+    resumes(
+        DoubleExponentialMovingAverage::with_period(10).unwrap(),
+        DATA,
+    );
+    resumes(Lag::with_period(10).unwrap(), DATA);
+    resumes(LinearRegression::with_period(10).unwrap(), DATA);
+    resumes(Median::with_period(10).unwrap(), DATA);
+    resumes(MidPoint::with_period(10).unwrap(), DATA);
+    resumes(MinMaxScaler::with_period(10).unwrap(), DATA);
+    resumes(RateOfChange::with_period(10).unwrap(), DATA);
+    resumes(RollingSum::with_period(10).unwrap(), DATA);
+    resumes(RollingExtrema::with_period(10).unwrap(), DATA);
+    resumes(Acceleration::with_period(5, 10).unwrap(), DATA);
+    resumes(
+        GaussianMovingAverage::with_period(10, 2.0, GaussianCenter::Middle).unwrap(),
+        DATA,
+    );
+    resumes(HistoricalVolatility::with_period(10, 252.0).unwrap(), DATA);
+    resumes(HoltSmoothing::with_period(10, 0.5, 0.2).unwrap(), DATA);
+    for model in [KalmanModel::Level, KalmanModel::Velocity] {
+        resumes(
+            KalmanFilter::with_period(10, model, 0.1, 1.0).unwrap(),
+            DATA,
+        );
+    }
+    for width in [ChannelWidth::Deviation(2.0), ChannelWidth::MaxResidual] {
+        resumes(
+            LinearRegressionChannel::with_period(10, width).unwrap(),
+            DATA,
+        );
+    }
+    resumes(McGinleyDynamic::with_period(10, 0.6).unwrap(), DATA);
+    resumes(
+        MedianAbsoluteDeviation::with_period(10, true).unwrap(),
+        DATA,
+    );
+    resumes(Moments::with_period(10).unwrap(), DATA);
+    resumes(SavitzkyGolay::with_period(11, 2).unwrap(), DATA);
+    resumes(
+        SharpeRatio::with_period(10, 0.0, 252.0, true).unwrap(),
+        DATA,
+    );
+    resumes(
+        SortinoRatio::with_period(10, 0.01, 252.0, true).unwrap(),
+        DATA,
+    );
+    resumes(SmoothedRateOfChange::with_period(5, 10).unwrap(), DATA);
+
+    let returns: Vec<f64> = DATA.windows(2).map(|w| w[1] / w[0] - 1.0).collect();
+    resumes(CumulativeReturn::new(10, 100.0, &[]).unwrap(), &returns);
+    resumes(
+        CumulativeReturn::from_prices(10, 100.0, &DATA[..2]).unwrap(),
+        DATA,
+    );
+    let pairs: Vec<(f64, f64)> = returns.windows(2).map(|w| (w[1], w[0])).collect();
+    resumes(Beta::with_period(10).unwrap(), &pairs);
+
+    let candles: Vec<Candle> = TestData::candles();
+    let candles: Vec<&Candle> = candles.iter().collect();
+    resumes(TrueRange::with_period(14).unwrap(), &candles);
+    for smoothing in [AtrSmoothing::Wilder, AtrSmoothing::Sma, AtrSmoothing::Ema] {
+        let atr = AverageTrueRange::builder()
+            .period(14)
+            .smoothing(smoothing)
+            .build(&candles[..20])
+            .unwrap();
+        resumes(atr, &candles);
+    }
+
+    resumes(MidPrice::with_period(10).unwrap(), &candles);
+    resumes(OnBalanceVolume::with_period(10).unwrap(), &candles);
+    resumes(
+        OnBalanceVolume::with_signal(10, 5, MaKind::Ema, &candles[..15]).unwrap(),
+        &candles,
+    );
+    resumes(TypicalPrice::with_period(10).unwrap(), &candles);
+    resumes(WeightedClose::with_period(10).unwrap(), &candles);
+    resumes(
+        Alligator::with_period((13, 8), (8, 5), (5, 3)).unwrap(),
+        &candles,
+    );
+    resumes(
+        GatorOscillator::with_period((13, 8), (8, 5), (5, 3)).unwrap(),
+        &candles,
+    );
+    for seed in [FlipSeed::Close, FlipSeed::Extreme] {
+        resumes(
+            AtrTrailingStop::with_period(10, 3.0, seed).unwrap(),
+            &candles,
+        );
+    }
+    resumes(KeltnerChannels::with_period(10, 2.0).unwrap(), &candles);
+    resumes(Squeeze::with_period(10, 2.0, 1.5).unwrap(), &candles);
+    resumes(
+        GarmanKlassVolatility::with_period(10, 252.0).unwrap(),
+        &candles,
+    );
+    resumes(
+        ParkinsonVolatility::with_period(10, 252.0).unwrap(),
+        &candles,
+    );
+    resumes(
+        RogersSatchellVolatility::with_period(10, 252.0).unwrap(),
+        &candles,
+    );
+    resumes(
+        YangZhangVolatility::with_period(10, 252.0).unwrap(),
+        &candles,
+    );
+    resumes(VolumeWeightedAveragePrice::new(), &candles);
+
+    // Lines crossing, stages chained, and the detectors of candles.
+    let mut cross = Cross::new(
+        SimpleMovingAverage::with_period(5).unwrap(),
+        SimpleMovingAverage::with_period(20).unwrap(),
+    );
+    cross.set_separation(Tolerance::Percent(0.1));
+    cross.set_confirmation(2);
+    resumes(cross, DATA);
+    resumes(
+        Chain::with_stages(
+            RateOfChange::with_period(5).unwrap(),
+            ExponentialMovingAverage::with_period(10).unwrap(),
+        ),
+        DATA,
+    );
+    resumes(GapDetector::new(0.0).unwrap(), &candles);
+    resumes(InsideBar::new(), &candles);
+    resumes(SwingPoints::new(2, 2, 10).unwrap(), &candles);
+    resumes(
+        SupportResistance::new(
+            SwingPoints::new(2, 2, 10).unwrap(),
+            Tolerance::Percent(1.0),
+            100,
+        )
+        .unwrap(),
+        &candles,
+    );
+    resumes(
+        VolumeProfile::new(Binning::Count(10), Distribution::Range, Some(20)).unwrap(),
+        &candles,
+    );
+    resumes(
+        VolumeProfile::new(Binning::Size(0.5), Distribution::Close, None).unwrap(),
+        &candles,
+    );
+    resumes(
+        CandlePattern::new(PatternConfig::default()).unwrap(),
+        &candles,
+    );
+    resumes(
+        TwoCandlePatterns::new(PatternConfig::default(), true).unwrap(),
+        &candles,
+    );
+
+    // Divergence is supplied prices directly.
+    let rsi = RelativeStrengthIndex::with_period(14).unwrap();
+    let mut divergence = RsiDivergence::new(rsi, 60, 5).unwrap();
+    let (first, rest) = DATA.split_at(DATA.len() / 2);
+    first.iter().for_each(|price| {
+        divergence.next_with_price(*price);
+    });
+    let state = divergence.snapshot();
+    let mut restored = RsiDivergence::restore(&state).unwrap();
+    assert_eq!(restored.snapshot(), state);
+    for price in rest.iter() {
+        assert_eq!(
+            restored.next_with_price(*price),
+            divergence.next_with_price(*price)
+        );
+    }
+
+    // Buffers held are restored with their extremes and variance.
+    let mut buffer = Buffer::from_array(10, &DATA[..25]).unwrap();
+    let mut restored = Buffer::restore(&buffer.snapshot()).unwrap();
+    for value in DATA[25..].iter() {
+        assert_eq!(
+            restored.shift(*value).to_bits(),
+            buffer.shift(*value).to_bits()
+        );
+        assert_eq!(restored.max(), buffer.max());
+        assert_eq!(restored.min(), buffer.min());
+        assert_eq!(
+            restored.variance(true).to_bits(),
+            buffer.variance(true).to_bits()
+        );
+    }
+
+    let buffer = StaticBuffer::<4>::from_array(4, &DATA[..3]).unwrap();
+    let restored = StaticBuffer::<4>::restore(&buffer.snapshot()).unwrap();
+    assert_eq!(restored, buffer);
+    assert!(StaticBuffer::<5>::restore(&buffer.snapshot()).is_err());
+
+    // States of other indicators, or missing parts, are errors.
+    let sma = SimpleMovingAverage::new(10, DATA).unwrap();
+    assert!(ExponentialMovingAverage::<Buffer>::restore(&sma.snapshot()).is_err());
+    let mut state = sma.snapshot();
+    state.children.pop();
+    assert!(SimpleMovingAverage::<Buffer>::restore(&state).is_err());
+}
+
+#[test]
+/// Candles are validated on creation and supplied directly to candle indicators.
+fn candle_type() {
+    use tatk::indicators::{AverageTrueRange, OnBalanceVolume, SimpleMovingAverage};
+    use tatk::traits::{AsValue, Hl2, Hlc3, Next, Ohlc4};
+    use tatk::Candle;
+
+    let raw = [
+        (10.0, 12.0, 9.0, 11.0, 100.0),
+        (11.0, 13.0, 10.5, 12.5, 150.0),
+        (12.5, 12.75, 11.0, 11.5, 120.0),
+        (11.5, 14.0, 11.25, 13.75, 200.0),
+        (13.75, 14.5, 13.0, 13.25, 90.0),
+        (13.25, 13.5, 12.0, 12.25, 110.0),
+    ];
+    let candles: Vec<Candle> = raw
+        .iter()
+        .map(|c| Candle::new(c.0, c.1, c.2, c.3, c.4).unwrap())
+        .collect();
+
+    let candle = candles[0];
+    assert_eq!(candle.hl2(), 10.5);
+    assert_eq!(candle.hlc3(), (12.0 + 9.0 + 11.0) / 3.0);
+    assert_eq!(candle.ohlc4(), 10.5);
+    assert_eq!(candle.as_value(), 11.0);
+    assert_eq!(candle.range(), 3.0);
+    assert_eq!(candle.body(), 1.0);
+
+    // Supplied to the ATR and OBV the same as the values they hold.
+    let mut atr = AverageTrueRange::new(3, &candles[..4]).unwrap();
+    let mut manual_atr = AverageTrueRange::with_period(3).unwrap();
+    let mut obv = OnBalanceVolume::new(3, &candles[..4]).unwrap();
+    let mut manual_obv = OnBalanceVolume::with_period(3).unwrap();
+    for c in raw[..4].iter() {
+        manual_atr.next((c.1, c.2, c.3));
+        manual_obv.next((c.3, c.4));
+    }
+
+    assert_eq!(atr.value(), manual_atr.value());
+    assert_eq!(obv.value(), manual_obv.value());
+    for (candle, c) in candles[4..].iter().zip(raw[4..].iter()) {
+        assert_eq!(atr.next(candle), manual_atr.next((c.1, c.2, c.3)));
+        assert_eq!(obv.next(candle), manual_obv.next((c.3, c.4)));
+    }
+
+    // Indicators consuming single values are supplied the close.
+    let mut sma = SimpleMovingAverage::with_period(2).unwrap();
+    sma.next(candles[0]);
+    assert_eq!(sma.next(candles[1]), (11.0 + 12.5) / 2.0);
+
+    // Highs and lows must contain the open and close.
+    assert!(Candle::new(10.0, 9.0, 11.0, 10.0, 1.0).is_err());
+    assert!(Candle::new(10.0, 12.0, 9.0, 13.0, 1.0).is_err());
+    assert!(Candle::new(8.0, 12.0, 9.0, 10.0, 1.0).is_err());
+    assert!(Candle::new(10.0, 12.0, 9.0, 10.0, -1.0).is_err());
+    assert!(Candle::new(f64::NAN, 12.0, 9.0, 10.0, 1.0).is_err());
+    assert!(Candle::flat(10.0, 0.0).validate().is_ok());
+}
+
+#[test]
+/// Trades are aggregated into candles on the boundary, the partial candle is flushed.
+fn candle_builder() {
+    use tatk::{BarBoundary, Candle, CandleBuilder};
+
+    let c = |open, high, low, close, volume| Candle::new(open, high, low, close, volume).unwrap();
+    let trades = [
+        (10.0, 1.0),
+        (11.0, 2.0),
+        (9.5, 1.0),
+        (10.5, 3.0),
+        (12.0, 0.5),
+        (11.5, 1.5),
+        (11.0, 4.0),
+    ];
+
+    // Every 3 trades, the last trade is left in the partial candle.
+    let mut builder = CandleBuilder::new(BarBoundary::Trades(3)).unwrap();
+    let candles: Vec<Candle> = trades
+        .iter()
+        .filter_map(|(price, size)| builder.push(*price, *size))
+        .collect();
+    assert_eq!(
+        candles,
+        vec![c(10.0, 11.0, 9.5, 9.5, 4.0), c(10.5, 12.0, 10.5, 11.5, 5.0)]
+    );
+    assert_eq!(builder.trades(), 1);
+    assert_eq!(builder.current(), Some(c(11.0, 11.0, 11.0, 11.0, 4.0)));
+    assert_eq!(builder.flush(), Some(c(11.0, 11.0, 11.0, 11.0, 4.0)));
+    assert_eq!(builder.flush(), None);
+
+    // Single trade candles.
+    let mut builder = CandleBuilder::new(BarBoundary::Trades(1)).unwrap();
+    assert_eq!(builder.push(10.0, 2.0), Some(Candle::flat(10.0, 2.0)));
+    assert_eq!(builder.current(), None);
+
+    // Every 4 volume, the trade reaching the volume is in the candle it completes.
+    let mut builder = CandleBuilder::new(BarBoundary::Volume(4.0)).unwrap();
+    let candles: Vec<Candle> = trades
+        .iter()
+        .filter_map(|(price, size)| builder.push(*price, *size))
+        .collect();
+    assert_eq!(
+        candles,
+        vec![
+            c(10.0, 11.0, 9.5, 9.5, 4.0),
+            c(10.5, 12.0, 10.5, 11.5, 5.0),
+            c(11.0, 11.0, 11.0, 11.0, 4.0),
+        ]
+    );
+    assert_eq!(builder.flush(), None);
+
+    // Invalid trades are ignored, boundaries must be greater than 0.
+    assert_eq!(builder.push(f64::NAN, 1.0), None);
+    assert_eq!(builder.push(10.0, -1.0), None);
+    assert_eq!(builder.current(), None);
+    assert!(CandleBuilder::new(BarBoundary::Trades(0)).is_err());
+    assert!(CandleBuilder::new(BarBoundary::Volume(0.0)).is_err());
+}
+
+#[test]
+/// Resampler merging candles into a higher timeframe.
+fn resampler() {
+    use tatk::traits::Next;
+    use tatk::{Candle, ResampleBy, Resampler};
+
+    let c = |open, high, low, close, volume| Candle::new(open, high, low, close, volume).unwrap();
+    let candles = [
+        c(10.0, 11.0, 9.0, 10.5, 1.0),
+        c(10.5, 12.0, 10.0, 11.5, 2.0),
+        c(11.5, 11.75, 8.5, 9.0, 3.0),
+        c(9.0, 10.0, 8.75, 9.5, 4.0),
+        c(9.5, 9.75, 9.25, 9.5, 5.0),
+    ];
+
+    assert!(Resampler::new(ResampleBy::Count(0)).is_err());
+    assert!(Resampler::new(ResampleBy::Duration(0)).is_err());
+
+    // Every 2 candles, the last candle is left in the partial bucket.
+    let mut resampler = Resampler::new(ResampleBy::Count(2)).unwrap();
+    let merged: Vec<Candle> = candles.iter().filter_map(|c| resampler.next(c)).collect();
+    assert_eq!(
+        merged,
+        vec![c(10.0, 12.0, 9.0, 11.5, 3.0), c(11.5, 11.75, 8.5, 9.5, 7.0)]
+    );
+    assert_eq!(resampler.count(), 1);
+    assert_eq!(resampler.current(), Some(candles[4]));
+    assert_eq!(resampler.flush(), Some(candles[4]));
+    assert_eq!(resampler.flush(), None);
+
+    // Buckets of 60, the first candle of a bucket completes the previous with gaps skipped.
+    let times = [0, 30, 60, 150, 170];
+    let mut resampler = Resampler::new(ResampleBy::Duration(60)).unwrap();
+    let merged: Vec<Candle> = times
+        .iter()
+        .zip(candles.iter())
+        .filter_map(|(t, c)| resampler.next((*t, c)))
+        .collect();
+    assert_eq!(merged, vec![c(10.0, 12.0, 9.0, 11.5, 3.0), candles[2]]);
+    assert_eq!(resampler.current(), Some(c(9.0, 10.0, 8.75, 9.5, 9.0)));
+    assert_eq!(resampler.flush(), Some(c(9.0, 10.0, 8.75, 9.5, 9.0)));
+
+    // Timestamps are ignored when merging by the amount of candles.
+    let mut resampler = Resampler::new(ResampleBy::Count(1)).unwrap();
+    assert_eq!(resampler.next((100, &candles[0])), Some(candles[0]));
+}
+
+#[test]
+/// Timestamped values with missing bars handled by each gap policy.
+fn timed_gap_policy() {
+    use tatk::indicators::{
+        AverageTrueRange, ExponentialMovingAverage, GapPolicy, Session, SimpleMovingAverage, Timed,
+        VolumeWeightedAveragePrice,
+    };
+    use tatk::traits::{Close, High, Low, Next, Ready, Timestamped};
+    use tatk::Candle;
+
+    // Bars every 60, with the 3 bars at 180, 240, and 300 missing.
+    let times = [0, 60, 120, 360, 420];
+    let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+    let sma = SimpleMovingAverage::with_period(3).unwrap();
+    assert!(Timed::<_, f64>::new(sma, 0, GapPolicy::Skip).is_err());
+
+    // Skip: supplied as if consecutive.
+    let sma = SimpleMovingAverage::with_period(3).unwrap();
+    let mut timed = Timed::new(sma, 60, GapPolicy::Skip).unwrap();
+    let out: Vec<f64> = times
+        .iter()
+        .zip(values)
+        .map(|(t, v)| timed.next_at(*t, v))
+        .collect();
+    assert_eq!(out[3], 3.0);
+    assert_eq!(out[4], 4.0);
+    assert_eq!(timed.missing(), 0);
+    assert_eq!(timed.samples_seen(), 5);
+
+    // Forward-fill: the last value is supplied for each missing bar.
+    let sma = SimpleMovingAverage::with_period(3).unwrap();
+    let mut timed = Timed::new(sma, 60, GapPolicy::ForwardFill).unwrap();
+    for (t, v) in times.iter().zip(values).take(4) {
+        timed.next((*t, v));
+    }
+    assert_eq!(timed.missing(), 3);
+    assert_eq!(timed.samples_seen(), 7);
+    assert!((timed.indicator().value() - 10.0 / 3.0).abs() < 1e-9);
+    assert_eq!(timed.next_at(420, 5.0), 4.0);
+    assert_eq!(timed.missing(), 0);
+
+    // Reset: the value after the gap is supplied as if it was the first.
+    let ema = ExponentialMovingAverage::with_period(2).unwrap();
+    let mut timed = Timed::new(ema, 60, GapPolicy::Reset).unwrap();
+    for (t, v) in times.iter().zip(values).take(3) {
+        timed.next_at(*t, v);
+    }
+    assert!(timed.is_ready());
+    assert_eq!(timed.next_at(360, 4.0), 4.0);
+    assert!(!timed.is_ready());
+    assert_eq!(timed.samples_seen(), 1);
+
+    // ATR over timestamped candles of the user.
+    struct Bar(i64, Candle);
+    impl Timestamped for Bar {
+        fn timestamp(&self) -> i64 {
+            self.0
+        }
+    }
+    impl High for Bar {
+        fn high(&self) -> f64 {
+            self.1.high
+        }
+    }
+    impl Low for Bar {
+        fn low(&self) -> f64 {
+            self.1.low
+        }
+    }
+    impl Close for Bar {
+        fn close(&self) -> f64 {
+            self.1.close
+        }
+    }
+
+    let bars: Vec<(i64, Candle)> = times
+        .iter()
+        .zip(values)
+        .map(|(t, v)| (*t, Candle::new(v, v + 1.0, v - 1.0, v, 1.0).unwrap()))
+        .collect();
+    let atr = AverageTrueRange::with_period(2).unwrap();
+    let mut timed = Timed::new(atr, 60, GapPolicy::Reset).unwrap();
+    let bars_at: Vec<Bar> = bars.iter().map(|(t, c)| Bar(*t, *c)).collect();
+    for bar in bars_at.iter() {
+        timed.next(bar);
+    }
+    assert_eq!(timed.samples_seen(), 2);
+    assert_eq!(timed.last_timestamp(), Some(420));
+
+    // VWAP reset every session of 300, unaffected by the gap when skipping.
+    let vwap = VolumeWeightedAveragePrice::new();
+    let mut timed = Timed::new(vwap, 60, GapPolicy::Skip)
+        .unwrap()
+        .with_session(Session::Duration(300))
+        .unwrap();
+    for (t, c) in bars.iter().take(3) {
+        timed.next_at(*t, c);
+    }
+    assert_eq!(timed.indicator().value(), 2.0);
+    assert_eq!(timed.next_at(bars[3].0, &bars[3].1), 4.0);
+    assert_eq!(timed.next_at(bars[4].0, &bars[4].1), 4.5);
+
+    // Sessions from a closure, opening at 100 of every 300.
+    let vwap = VolumeWeightedAveragePrice::new();
+    let mut timed = Timed::new(vwap, 60, GapPolicy::Skip)
+        .unwrap()
+        .with_session(Session::Custom(|ts| (ts - 100).div_euclid(300)))
+        .unwrap();
+    for (t, c) in bars.iter().take(3) {
+        timed.next_at(*t, c);
+    }
+    assert_eq!(timed.indicator().value(), 3.0);
+    assert_eq!(timed.indicator().volume(), 1.0);
+}
+
+#[test]
+#[cfg(feature = "csv")]
+/// Candles loaded from CSV by header names or column positions.
+fn csv_candles() {
+    use tatk::{Candle, CsvFormat, CsvHeader, TAError};
+
+    // Columns found by the header, the blank line ignored.
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/candles.csv");
+    let candles = Candle::from_csv_path(path).unwrap();
+    assert_eq!(candles.len(), 6);
+    assert_eq!(
+        candles[0],
+        Candle::new(100.0, 101.5, 99.5, 101.0, 1200.0).unwrap()
+    );
+    assert_eq!(candles[1].volume, 950.5);
+    assert_eq!(candles[3].low, 99.0);
+    assert_eq!(candles[5].close, 101.0);
+    assert!(Candle::from_csv_path("missing.csv").is_err());
+
+    // Positions without a header, detected from the first row.
+    let text = "1;10;12;9;11;100\n2;11;13;10;12;200\n";
+    let format = CsvFormat::default().delimiter(';').columns(1, 2, 3, 4, 5);
+    let candles = format.read(text.as_bytes()).unwrap();
+    assert_eq!(candles.len(), 2);
+    assert_eq!(
+        candles[1],
+        Candle::new(11.0, 13.0, 10.0, 12.0, 200.0).unwrap()
+    );
+
+    // First five columns by default without a header.
+    let candles = Candle::from_csv_reader("10,12,9,11,100\n".as_bytes()).unwrap();
+    assert_eq!(
+        candles,
+        vec![Candle::new(10.0, 12.0, 9.0, 11.0, 100.0).unwrap()]
+    );
+
+    // Header forced, skipping the first row even if it is numbers.
+    let format = CsvFormat::default()
+        .header(CsvHeader::Present)
+        .columns(0, 1, 2, 3, 4);
+    let candles = format
+        .read("1,2,0,1,5\n10,12,9,11,100\n".as_bytes())
+        .unwrap();
+    assert_eq!(candles.len(), 1);
+
+    // Errors report the line number of the row.
+    let text = "open,high,low,close,volume\n10,12,9,11,100\n10,abc,9,11,100\n";
+    assert!(matches!(
+        Candle::from_csv_reader(text.as_bytes()),
+        Err(TAError::InvalidRow(3, _))
+    ));
+    let text = "open,high,low,close,volume\n10,12,9,11\n";
+    assert!(matches!(
+        Candle::from_csv_reader(text.as_bytes()),
+        Err(TAError::InvalidRow(2, _))
+    ));
+    let text = "open,high,low,close,volume\n\n10,8,9,11,100\n";
+    assert!(matches!(
+        Candle::from_csv_reader(text.as_bytes()),
+        Err(TAError::InvalidRow(3, _))
+    ));
+    assert!(matches!(
+        Candle::from_csv_reader("open,high,low,volume\n".as_bytes()),
+        Err(TAError::InvalidRow(1, _))
+    ));
+}
+
+#[test]
+/// Indicator configs written as text, parsed back, and built into their indicators.
+fn indicator_config() {
+    use tatk::indicators::{IndicatorConfig, RelativeStrengthIndex, SimpleMovingAverage};
+
+    let configs = [
+        IndicatorConfig::Sma { period: 20 },
+        IndicatorConfig::Ema { period: 10 },
+        IndicatorConfig::Rsi {
+            period: 14,
+            oversold: 30.0,
+            overbought: 70.5,
+        },
+        IndicatorConfig::Macd {
+            short: 12,
+            long: 26,
+            signal: 9,
+        },
+        IndicatorConfig::BBands {
+            period: 20,
+            distance: 2.0,
+        },
+    ];
+
+    // Every config round trips through its text.
+    for config in configs.iter() {
+        let text = config.to_string();
+        assert!(text.starts_with(config.name()));
+        assert_eq!(text.parse::<IndicatorConfig>().unwrap(), *config);
+    }
+    assert_eq!(configs[2].to_string(), "rsi(14, 30, 70.5)");
+    assert_eq!(
+        " MACD(12,26 , 9) ".parse::<IndicatorConfig>().unwrap(),
+        configs[3]
+    );
+
+    // Invalid text.
+    for text in [
+        "sma",
+        "sma(20",
+        "sma()",
+        "sma(20, 2)",
+        "kama(10)",
+        "rsi(14, a, 70)",
+        "ema(-1)",
+    ] {
+        assert!(text.parse::<IndicatorConfig>().is_err(), "{}", text);
+    }
+
+    // Built indicators match those created directly.
+    let data: Vec<f64> = (0..60)
+        .map(|x| (x as f64 * 0.3).sin() * 10.0 + 50.0)
+        .collect();
+    let sma = configs[0].build(&data).unwrap();
+    assert_eq!(sma.name(), "SMA");
+    assert_eq!(sma.period(), 20);
+    assert_eq!(
+        sma.value(),
+        SimpleMovingAverage::new(20, &data).unwrap().value()
+    );
+
+    let rsi = configs[2].build(&data).unwrap();
+    assert_eq!(
+        rsi.value(),
+        RelativeStrengthIndex::new(14, &data).unwrap().value()
+    );
+
+    for config in configs.iter() {
+        assert!(config.build(&data).is_ok());
+        assert!(config.build(&data[..5]).is_err());
+    }
+    assert!(IndicatorConfig::Sma { period: 0 }.build(&data).is_err());
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Synthetic series generated from a seed, checking indicators hold over long series.
+fn synthetic_data() {
+    use tatk::indicators::{AverageTrueRange, RelativeStrengthIndex};
+    use tatk::test_data::generate::{self, CandleParams};
+    use tatk::traits::Next;
+
+    // Same seed generates the same series.
+    let closes = generate::gbm(7, 1_000_000, 100.0, 0.0, 0.01);
+    assert_eq!(closes.len(), 1_000_000);
+    assert_eq!(closes[0], 100.0);
+    assert_eq!(closes[..100], generate::gbm(7, 100, 100.0, 0.0, 0.01)[..]);
+    assert_ne!(closes[..100], generate::gbm(8, 100, 100.0, 0.0, 0.01)[..]);
+    assert!(closes.iter().all(|c| c.is_finite() && *c > 0.0));
+
+    // RSI stays within 0 and 100 over a million bars.
+    let mut rsi = RelativeStrengthIndex::with_period(14).unwrap();
+    assert!(closes.iter().all(|c| (0.0..=100.0).contains(&rsi.next(*c))));
+
+    // Mean-reverting series stays around its mean.
+    let series = generate::ou(3, 10_000, 0.0, 50.0, 0.1, 1.0);
+    let mean = series[1000..].iter().sum::<f64>() / 9000.0;
+    assert!((mean - 50.0).abs() < 1.0);
+    assert_eq!(series, generate::ou(3, 10_000, 0.0, 50.0, 0.1, 1.0));
+
+    // Candles are valid, each opening at the prior close, with an ATR that is never negative.
+    let params = CandleParams {
+        volatility: 0.02,
+        ..Default::default()
+    };
+    let candles = generate::candles(11, 100_000, &params);
+    assert_eq!(candles[0].open, params.start);
+    assert!(candles.iter().all(|c| c.validate().is_ok()));
+    assert!(candles.windows(2).all(|w| w[1].open == w[0].close));
+    assert_eq!(candles[..10], generate::candles(11, 10, &params)[..]);
+
+    let mut atr = AverageTrueRange::with_period(14).unwrap();
+    assert!(candles.iter().all(|c| atr.next(c) >= 0.0));
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Macros without the optional arguments use the conventional defaults.
+fn macro_defaults() {
+    use tatk::indicators::{
+        AtrSmoothing, ChannelWidth, MaKind, OnBalanceVolume, RelativeStrengthIndex,
+    };
+    use tatk::test_data::TestData;
+    use tatk::traits::Period;
+    use tatk::{atr, bb, hv, kc, lrc, mad, mdi, median, obv, rsi, sd, sg, smma, var, vwap, wma};
+    const DATA: &[f64] = TestData::talib();
+    let candles = TestData::candles();
+
+    assert_eq!(bb!(20, DATA).unwrap(), bb!(20, DATA, 2.0).unwrap());
+    assert_eq!(mdi!(10, DATA).unwrap(), mdi!(10, DATA, 0.6).unwrap());
+    assert_eq!(sd!(10, DATA).unwrap(), sd!(10, DATA, true).unwrap());
+    assert_eq!(var!(10, DATA).unwrap(), var!(10, DATA, true).unwrap());
+    assert_eq!(hv!(20, DATA).unwrap(), hv!(20, 252.0, DATA).unwrap());
+    assert_eq!(kc!(20, &candles).unwrap(), kc!(20, 2.0, &candles).unwrap());
+    assert_eq!(
+        lrc!(20, DATA).unwrap(),
+        lrc!(20, DATA, ChannelWidth::Deviation(2.0)).unwrap()
+    );
+    assert_eq!(
+        atr!(14, &candles).unwrap(),
+        atr!(14, &candles, AtrSmoothing::Wilder).unwrap()
+    );
+
+    assert_eq!(
+        rsi!(14, DATA, 30.0, 70.0).unwrap(),
+        RelativeStrengthIndex::with_thresholds(14, DATA, 30.0, 70.0).unwrap()
+    );
+    assert_eq!(
+        obv!(10, 5, MaKind::Sma, &candles).unwrap(),
+        OnBalanceVolume::with_signal(10, 5, MaKind::Sma, &candles).unwrap()
+    );
+
+    assert_eq!(smma!(10, DATA).unwrap().period(), 10);
+    assert_eq!(wma!(10, DATA).unwrap().period(), 10);
+    assert_eq!(median!(10, DATA).unwrap().period(), 10);
+    assert_eq!(mad!(10, DATA, true).unwrap().period(), 10);
+    assert_eq!(sg!(11, 3, DATA).unwrap().period(), 11);
+    assert_eq!(
+        vwap!(&candles).volume(),
+        candles.iter().map(|c| c.volume).sum()
+    );
+    assert_eq!(vwap!().volume(), 0.0);
+}
+
+#[test]
+/// Derives reading fields mapped with the `tatk` attribute.
+fn derive_field_mapping() {
+    use tatk::traits::{Close, High, Low, Open, Volume};
+    use tatk::Num;
+    use tatk_derive::{Close, High, Low, Open, Volume};
+
+    // Mapped on the fields, on the struct, and by the field names.
+    #[derive(Open, High, Low, Close, Volume)]
+    #[tatk(high = "h", low = "l")]
+    struct Tick {
+        open: Num,
+        h: Num,
+        l: Num,
+        #[tatk(close)]
+        c: Num,
+        #[tatk(volume)]
+        vol: Num,
+    }
+
+    let tick = Tick {
+        open: 1.0,
+        h: 3.0,
+        l: 0.5,
+        c: 2.0,
+        vol: 10.0,
+    };
+    assert_eq!(
+        (
+            tick.open(),
+            tick.high(),
+            tick.low(),
+            tick.close(),
+            tick.volume()
+        ),
+        (1.0, 3.0, 0.5, 2.0, 10.0)
+    );
+}
+
+#[test]
+/// Value trait reads the same value as the inherent methods, usable generically.
+fn value_trait() {
+    use tatk::indicators::{BollingerBands, ExponentialMovingAverage, SimpleMovingAverage};
+    use tatk::traits::Value;
+    use tatk::Num;
+
+    fn current<V: Value>(indicator: &V) -> Num {
+        indicator.value()
+    }
+
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let sma = SimpleMovingAverage::new(3, &data).unwrap();
+    let ema = ExponentialMovingAverage::new(3, &data).unwrap();
+    let bb = BollingerBands::new(3, &data, 2.0).unwrap();
+
+    assert_eq!(current(&sma), sma.value());
+    assert_eq!(current(&ema), ema.value());
+    assert_eq!(current(&bb), bb.value());
+    assert_eq!(current(&bb), current(&sma));
+}
+
+#[test]
+/// AsValue derived from each source, supplied to indicators consuming single values.
+fn derive_as_value() {
+    use tatk::indicators::SimpleMovingAverage;
+    use tatk::traits::{AsValue, Close, High, Low, Next, Open};
+    use tatk::Num;
+    use tatk_derive::{AsValue, Close, High, Low, Open};
+
+    #[derive(AsValue, Close)]
+    #[as_value(close)]
+    struct Last {
+        close: Num,
+    }
+
+    #[derive(AsValue, High, Low)]
+    #[as_value(hl2)]
+    struct Range {
+        high: Num,
+        low: Num,
+    }
+
+    #[derive(AsValue, High, Low, Close)]
+    #[as_value(hlc3)]
+    struct Typical {
+        high: Num,
+        low: Num,
+        close: Num,
+    }
+
+    #[derive(AsValue, Open, High, Low, Close)]
+    #[as_value(ohlc4)]
+    #[tatk(open = "o", high = "h", low = "l", close = "c")]
+    struct Bar {
+        o: Num,
+        h: Num,
+        l: Num,
+        c: Num,
+    }
+
+    #[derive(AsValue)]
+    #[as_value(field = "mid")]
+    struct Quote {
+        mid: Num,
+    }
+
+    assert_eq!(Last { close: 2.0 }.as_value(), 2.0);
+    assert_eq!(
+        Range {
+            high: 3.0,
+            low: 1.0
+        }
+        .as_value(),
+        2.0
+    );
+    let typical = Typical {
+        high: 4.0,
+        low: 1.0,
+        close: 1.0,
+    };
+    assert_eq!(typical.as_value(), 2.0);
+    let bar = Bar {
+        o: 1.0,
+        h: 4.0,
+        l: 1.0,
+        c: 2.0,
+    };
+    assert_eq!(bar.as_value(), 2.0);
+
+    let mut sma = SimpleMovingAverage::new(2, &[0.0, 0.0]).unwrap();
+    sma.next(Quote { mid: 4.0 });
+    assert_eq!(sma.next(bar), 3.0);
+}
+
+/// Open, High, Low, and Close of a candle.
+struct Ohlc((f64, f64, f64, f64));
+
+impl tatk::traits::Open for Ohlc {
+    fn open(&self) -> f64 {
+        self.0 .0
+    }
+}
+
+impl tatk::traits::High for Ohlc {
+    fn high(&self) -> f64 {
+        self.0 .1
+    }
+}
+
+impl tatk::traits::Low for Ohlc {
+    fn low(&self) -> f64 {
+        self.0 .2
+    }
+}
+
+impl tatk::traits::Close for Ohlc {
+    fn close(&self) -> f64 {
+        self.0 .3
+    }
+}
+
+/// Converts (open, high, low, close) values into candles.
+fn to_ohlc(values: &[(f64, f64, f64, f64)]) -> Vec<Ohlc> {
+    values.iter().map(|v| Ohlc(*v)).collect()
+}
+
+#[test]
+/// NaN and infinite values are rejected on creation and handled by the configured policy when
+/// supplied mid-stream.
+fn non_finite_values() {
+    use tatk::indicators::{
+        AverageTrueRange, ExponentialMovingAverage, RelativeStrengthIndex, SimpleMovingAverage,
+    };
+    use tatk::traits::{CheckedNext, Next, Ready};
+    use tatk::{NonFinite, TAError};
+
+    let data: Vec<f64> = (0..30).map(|i| 100.0 + ((i * 7) % 11) as f64).collect();
+    let mut bad = data.clone();
+    bad[3] = f64::NAN;
+    assert!(matches!(
+        SimpleMovingAverage::new(10, &bad),
+        Err(TAError::InvalidInput(_))
+    ));
+    assert!(ExponentialMovingAverage::new(10, &bad).is_err());
+    bad[3] = f64::INFINITY;
+    assert!(RelativeStrengthIndex::new(10, &bad).is_err());
+
+    // Default propagates the value.
+    let mut sma = SimpleMovingAverage::new(10, &data[..10]).unwrap();
+    assert!(sma.next(f64::NAN).is_nan());
+
+    // Skipping returns the previous value and leaves the indicator as if it was never supplied.
+    let mut sma = SimpleMovingAverage::new(10, &data[..10]).unwrap();
+    let mut ema = ExponentialMovingAverage::new(10, &data[..10]).unwrap();
+    let mut rsi = RelativeStrengthIndex::new(10, &data[..11]).unwrap();
+    let (mut clean_sma, mut clean_ema, mut clean_rsi) = (sma.clone(), ema.clone(), rsi.clone());
+    sma.set_non_finite(NonFinite::Skip);
+    ema.set_non_finite(NonFinite::Skip);
+    rsi.set_non_finite(NonFinite::Skip);
+    for (i, v) in data[11..].iter().enumerate() {
+        if i % 5 == 0 {
+            let bad = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY][i % 3];
+            assert_eq!(sma.next(bad), sma.value());
+            assert_eq!(ema.next(bad), ema.value());
+            assert_eq!(rsi.next(bad), rsi.value());
+        }
+
+        assert_eq!(sma.next(*v), clean_sma.next(*v));
+        assert_eq!(ema.next(*v), clean_ema.next(*v));
+        assert_eq!(rsi.next(*v), clean_rsi.next(*v));
+    }
+    assert_eq!(sma.samples_seen(), clean_sma.samples_seen() + 4);
+
+    // Checked values leave the indicator unchanged.
+    let before = clean_rsi.clone();
+    assert!(matches!(
+        clean_rsi.checked_next(f64::NAN),
+        Err(TAError::InvalidInput(_))
+    ));
+    assert_eq!(clean_rsi, before);
+    assert_eq!(
+        clean_rsi.checked_next(101.0).unwrap(),
+        before.clone().next(101.0)
+    );
+
+    // Candles are rejected if any of the values are not finite.
+    let candles = to_ohlc(&[
+        (1.0, 3.0, 1.0, 2.0),
+        (2.0, 4.0, 2.0, 3.0),
+        (3.0, 5.0, 2.0, 4.0),
+    ]);
+    let mut atr = AverageTrueRange::new(2, &candles).unwrap();
+    let mut clean_atr = atr.clone();
+    assert!(AverageTrueRange::new(2, &to_ohlc(&[(1.0, 3.0, f64::NAN, 2.0); 3])).is_err());
+    assert!(atr
+        .checked_next(Ohlc((4.0, 6.0, 3.0, f64::INFINITY)))
+        .is_err());
+    assert_eq!(atr, clean_atr);
+
+    atr.set_non_finite(NonFinite::Skip);
+    atr.next(Ohlc((4.0, f64::NAN, 3.0, 5.0)));
+    assert_eq!(
+        atr.next(Ohlc((4.0, 6.0, 3.0, 5.0))),
+        clean_atr.next(Ohlc((4.0, 6.0, 3.0, 5.0)))
+    );
+
+    // Panics in debug builds, otherwise skipped.
+    let mut sma = SimpleMovingAverage::new(10, &data[..10]).unwrap();
+    sma.set_non_finite(NonFinite::Panic);
+    let result = std::panic::catch_unwind(move || sma.next(f64::NAN));
+    assert_eq!(result.is_err(), cfg!(debug_assertions));
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Sharpe Ratio from 251 prices, compared against the returns of the final period.
+fn create_sharpe() {
+    use tatk::indicators::SharpeRatio;
+    use tatk::test_data::TestData;
+    use tatk::Buffer;
+    const DATA: &[f64] = TestData::talib();
+    let period: usize = 20;
+
+    let indicator = SharpeRatio::new(period, 0.0001, 252.0, &DATA[..DATA.len() - 1], true).unwrap();
+    assert_eq!(indicator.value(), 1.8038056372473452);
+
+    let prices = &DATA[(DATA.len() - 2 - period)..(DATA.len() - 1)];
+    let returns: Vec<f64> = prices.windows(2).map(|w| (w[1] - w[0]) / w[0]).collect();
+    let returns = Buffer::from_array(period, &returns).unwrap();
+    let expected = (returns.mean() - 0.0001) / returns.stdev(true) * 252.0_f64.sqrt();
+    assert!((indicator.value() - expected).abs() < 1e-9);
+
+    // Returns provided directly match the converted prices.
+    let from_returns = SharpeRatio::new(period, 0.0001, 252.0, returns.queue(), false).unwrap();
+    assert!((from_returns.value() - indicator.value()).abs() < 1e-9);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Sharpe Ratio from 251 prices, then adds an additional price.
+fn next_sharpe() {
+    use tatk::indicators::SharpeRatio;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+
+    let mut indicator = SharpeRatio::new(20, 0.0, 252.0, &DATA[..DATA.len() - 1], true).unwrap();
+    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 1.0029808290488442);
+}
+
+#[test]
+/// A period without any deviation in the returns has a Sharpe Ratio of 0.0.
+fn sharpe_zero_deviation() {
+    use tatk::indicators::SharpeRatio;
+    use tatk::traits::Next;
+
+    let mut indicator = SharpeRatio::new(3, 0.0, 1.0, &[0.01, 0.01, 0.01], false).unwrap();
+    assert_eq!(indicator.value(), 0.0);
+    assert_ne!(indicator.next(0.02), 0.0);
+
+    // Flat equity curve.
+    let indicator = SharpeRatio::new(3, 0.0, 1.0, &[100.0; 4], true).unwrap();
+    assert_eq!(indicator.value(), 0.0);
+    assert!(SharpeRatio::new(3, 0.0, 1.0, &[100.0; 3], true).is_err());
+    assert!(SharpeRatio::new(3, 0.0, 1.0, &[100.0, 0.0, 1.0, 2.0], true).is_err());
+}
+
+#[test]
+/// NaN, infinite, and non-positive annualization are rejected by the Sharpe Ratio.
+fn sharpe_invalid_annualization() {
+    use tatk::indicators::SharpeRatio;
+    use tatk::TAError;
+
+    let returns = [0.01, 0.02, 0.03];
+    for annualization in [0.0, -1.0, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(SharpeRatio::new(3, 0.0, annualization, &returns, false).is_err());
+    }
+
+    match SharpeRatio::new(3, 0.0, f64::NAN, &returns, false) {
+        Err(TAError::InvalidSize(text)) => assert_eq!(
+            text,
+            "annualization must be finite and greater than 0 to calculate sharpe ratio"
+        ),
+        other => panic!("expected an invalid size, got {:?}", other),
+    }
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Sortino Ratio from 251 prices, compared against the downside deviation of the
+/// returns of the final period.
+fn create_sortino() {
+    use tatk::indicators::SortinoRatio;
+    use tatk::test_data::TestData;
+    const DATA: &[f64] = TestData::talib();
+    let period: usize = 20;
+
+    let indicator = SortinoRatio::new(period, 0.0, 252.0, &DATA[..DATA.len() - 1], true).unwrap();
+    assert_eq!(indicator.value(), 3.0673452456822);
+
+    let prices = &DATA[(DATA.len() - 2 - period)..(DATA.len() - 1)];
+    let returns: Vec<f64> = prices.windows(2).map(|w| (w[1] - w[0]) / w[0]).collect();
+    let downside = (returns.iter().map(|r| r.min(0.0).powi(2)).sum::<f64>() / period as f64).sqrt();
+    let mean = returns.iter().sum::<f64>() / period as f64;
+    assert!((indicator.downside_deviation() - downside).abs() < 1e-12);
+    assert!((indicator.value() - mean / downside * 252.0_f64.sqrt()).abs() < 1e-9);
+}
+
+#[test]
+#[cfg(feature = "test-data")]
+/// Creates a Sortino Ratio from 251 prices, then adds an additional price.
+fn next_sortino() {
+    use tatk::indicators::SortinoRatio;
+    use tatk::test_data::TestData;
+    use tatk::traits::Next;
+    const DATA: &[f64] = TestData::talib();
+
+    let mut indicator = SortinoRatio::new(20, 0.0, 252.0, &DATA[..DATA.len() - 1], true).unwrap();
+    assert_eq!(indicator.next(DATA[DATA.len() - 1]), 1.6201187554045073);
+}
+
+#[test]
+/// A period without returns below the target has no downside deviation, the last value is kept.
+fn sortino_no_downside() {
+    use tatk::indicators::SortinoRatio;
+    use tatk::traits::Next;
+
+    // Never defined, starts at 0.0.
+    let mut indicator = SortinoRatio::new(3, 0.0, 1.0, &[0.01, 0.02, 0.03], false).unwrap();
+    assert_eq!(indicator.downside_deviation(), 0.0);
+    assert_eq!(indicator.value(), 0.0);
+
+    // A return below the target defines the ratio.
+    let value = indicator.next(-0.01);
+    assert!(indicator.downside_deviation() > 0.0);
+    assert_ne!(value, 0.0);
+
+    // Target is raised above every return, all are downside.
+    let high = SortinoRatio::new(3, 0.05, 1.0, &[0.01, 0.02, 0.03], false).unwrap();
+    assert!(high.value() < 0.0);
+
+    // Once the negative return leaves the period, the last value is kept.
+    for r in [0.02, 0.03, 0.04] {
+        assert_eq!(indicator.next(r), value);
+    }
+    assert_eq!(indicator.downside_deviation(), 0.0);
+}
+
+#[test]
+/// NaN, infinite, and non-positive annualization are rejected by the Sortino Ratio.
+fn sortino_invalid_annualization() {
+    use tatk::indicators::SortinoRatio;
+    use tatk::TAError;
+
+    let returns = [0.01, -0.02, 0.03];
+    for annualization in [0.0, -1.0, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(SortinoRatio::new(3, 0.0, annualization, &returns, false).is_err());
+    }
+
+    match SortinoRatio::new(3, 0.0, f64::NAN, &returns, false) {
+        Err(TAError::InvalidSize(text)) => assert_eq!(
+            text,
+            "annualization must be finite and greater than 0 to calculate sortino ratio"
+        ),
+        other => panic!("expected an invalid size, got {:?}", other),
+    }
 }
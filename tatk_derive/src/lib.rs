@@ -1,124 +1,919 @@
 //! Derives for the `tatk` crate.
+//!
+//! Each derive reads a field of the struct, by default the field named after the method such as
+//! `close`. Fields with other names are mapped with the `tatk` attribute, either on the field or
+//! on the struct:
+//!
+//! ```
+//! use tatk::traits::{Close, High};
+//! use tatk::Num;
+//! use tatk_derive::{Close, High};
+//!
+//! #[derive(Close, High)]
+//! #[tatk(high = "h")]
+//! struct Tick {
+//!     #[tatk(close)]
+//!     c: Num,
+//!     h: Num,
+//! }
+//!
+//! let tick = Tick { c: 1.0, h: 2.0 };
+//! assert_eq!((tick.close(), tick.high()), (1.0, 2.0));
+//! ```
+//!
+//! The derives require a struct with named fields. A field that is missing, or that is clearly
+//! not a number (a `usize` for the period), is an error pointing at the struct or the field.
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use proc_macro2::Span;
+use quote::{quote, quote_spanned};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::token::Comma;
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, Ident, LitStr, Type};
+
+/// Fields that can be mapped with the `tatk` attribute.
+const KEYS: &[&str] = &["open", "close", "high", "low", "volume", "value", "period"];
+
+/// Floating point types, the types expected for values such as `close`.
+const FLOATS: &[&str] = &["Num", "f64", "f32"];
+
+/// Integer types, `usize` being the type expected for the `period`.
+const INTEGERS: &[&str] = &[
+    "usize", "u8", "u16", "u32", "u64", "u128", "isize", "i8", "i16", "i32", "i64", "i128",
+];
+
+/// Types that are never a number.
+const NOT_NUMBERS: &[&str] = &["bool", "char", "str", "String"];
+
+/// Named fields of the struct being derived.
+///
+/// # Arguments
+///
+/// * `input` - Struct being derived.
+/// * `derive` - Name of the derive, such as `Close`.
+fn named_fields<'a>(
+    input: &'a DeriveInput,
+    derive: &str,
+) -> syn::Result<&'a Punctuated<Field, Comma>> {
+    let kind = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => return Ok(&fields.named),
+            Fields::Unnamed(fields) => {
+                return Err(syn::Error::new_spanned(
+                    fields,
+                    format!(
+                        "`#[derive({})]` requires a struct with named fields, not a tuple struct",
+                        derive
+                    ),
+                ))
+            }
+            Fields::Unit => "a unit struct",
+        },
+        Data::Enum(_) => "an enum",
+        Data::Union(_) => "a union",
+    };
+
+    Err(syn::Error::new_spanned(
+        &input.ident,
+        format!(
+            "`#[derive({})]` requires a struct with named fields, not {}",
+            derive, kind
+        ),
+    ))
+}
+
+/// Checks the type of the field read for the key is roughly right, a `usize` for the `period`
+/// and a number such as `Num` otherwise. Aliases and generic types are assumed to be right.
+///
+/// # Arguments
+///
+/// * `derive` - Name of the derive, such as `Close`.
+/// * `key` - Name of the value read, such as `close`.
+/// * `field` - Field read for the key.
+fn check_type(derive: &str, key: &str, field: &Field) -> syn::Result<()> {
+    let (expected, fits): (&str, fn(&str) -> bool) = match key {
+        "period" => ("a `usize`", |t| {
+            t == "usize" || !(FLOATS.contains(&t) || INTEGERS.contains(&t))
+        }),
+        _ => ("a number such as `Num`", |t| !INTEGERS.contains(&t)),
+    };
+
+    let is_right = match &field.ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last().is_some_and(|s| {
+            let ident = s.ident.to_string();
+            s.arguments.is_none() && !NOT_NUMBERS.contains(&ident.as_str()) && fits(&ident)
+        }),
+        _ => false,
+    };
+
+    if is_right {
+        return Ok(());
+    }
+
+    let name = field
+        .ident
+        .as_ref()
+        .map(|i| i.to_string())
+        .unwrap_or_default();
+    let message = if name == key {
+        format!(
+            "`#[derive({})]` requires `{}` to be {}",
+            derive, key, expected
+        )
+    } else {
+        format!(
+            "`#[derive({})]` reads `{}` from `{}`, which must be {}",
+            derive, key, name, expected
+        )
+    };
+
+    Err(syn::Error::new_spanned(&field.ty, message))
+}
+
+/// Finds the field read for the key, mapped by the `tatk` attribute on the struct
+/// (`#[tatk(close = "c")]`) or on a field (`#[tatk(close)]`), otherwise the field named the key.
+///
+/// # Arguments
+///
+/// * `input` - Struct being derived.
+/// * `derive` - Name of the derive, such as `Close`.
+/// * `key` - Name of the field read, such as `close`.
+fn mapped_field(input: &DeriveInput, derive: &str, key: &str) -> syn::Result<Ident> {
+    // Mapping on the struct, `#[tatk(close = "c")]`.
+    let mut by_struct: Option<LitStr> = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("tatk")) {
+        attr.parse_nested_meta(|meta| {
+            let name = meta.path.require_ident()?.to_string();
+            if !KEYS.contains(&name.as_str()) {
+                return Err(meta.error(format!("unknown field `{}` for `tatk`", name)));
+            }
+
+            let field: LitStr = meta.value()?.parse()?;
+            if name == key {
+                if by_struct.is_some() {
+                    return Err(meta.error(format!("`{}` is mapped more than once", key)));
+                }
+                by_struct = Some(field);
+            }
+            Ok(())
+        })?;
+    }
+
+    let fields = named_fields(input, derive)?;
+
+    // Mapping on a field, `#[tatk(close)]`.
+    let mut by_field: Option<&Field> = None;
+    for field in fields.iter() {
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("tatk")) {
+            attr.parse_nested_meta(|meta| {
+                let name = meta.path.require_ident()?.to_string();
+                if !KEYS.contains(&name.as_str()) {
+                    return Err(meta.error(format!("unknown field `{}` for `tatk`", name)));
+                } else if meta.input.peek(syn::Token![=]) {
+                    return Err(meta.error(format!(
+                        "on a field use `#[tatk({})]`, a name is only given on the struct",
+                        name
+                    )));
+                }
+
+                if name == key {
+                    if by_field.is_some() || by_struct.is_some() {
+                        return Err(meta.error(format!("`{}` is mapped more than once", key)));
+                    }
+                    by_field = Some(field);
+                }
+                Ok(())
+            })?;
+        }
+    }
+
+    let field = match by_field {
+        Some(field) => field,
+        None => {
+            // Field named by the struct, otherwise the field named the key.
+            let (name, span, missing) = match &by_struct {
+                Some(lit) => (
+                    lit.value(),
+                    lit.span(),
+                    format!(
+                        "`#[derive({})]` reads `{}` from `{}`, but there is no field named `{}`",
+                        derive,
+                        key,
+                        lit.value(),
+                        lit.value()
+                    ),
+                ),
+                None => (
+                    key.to_string(),
+                    input.ident.span(),
+                    format!(
+                        "`#[derive({})]` requires a field named `{}` (or use `#[tatk({} = \"...\")]`)",
+                        derive, key, key
+                    ),
+                ),
+            };
+
+            fields
+                .iter()
+                .find(|f| f.ident.as_ref().is_some_and(|i| i == name.as_str()))
+                .ok_or_else(|| syn::Error::new(span, missing))?
+        }
+    };
+
+    check_type(derive, key, field)?;
+    Ok(field.ident.clone().expect("named fields have names"))
+}
+
+/// Finds the `Buffer` field the statistics are read from, named by the `stats` attribute on the
+/// struct (`#[stats(buffer = "values")]`), otherwise the field named `buffer`.
+///
+/// # Arguments
+///
+/// * `input` - Struct being derived.
+fn buffer_field(input: &DeriveInput) -> syn::Result<Ident> {
+    let mut mapped: Option<LitStr> = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("stats")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("buffer") {
+                return Err(meta.error("unknown setting for `stats`, expected `buffer`"));
+            } else if mapped.is_some() {
+                return Err(meta.error("`buffer` is mapped more than once"));
+            }
+
+            mapped = Some(meta.value()?.parse()?);
+            Ok(())
+        })?;
+    }
+
+    let fields = named_fields(input, "Stats")?;
+
+    let (name, span, missing) = match &mapped {
+        Some(lit) => (
+            lit.value(),
+            lit.span(),
+            format!(
+                "`#[derive(Stats)]` reads the statistics from `{}`, but there is no field named `{}`",
+                lit.value(),
+                lit.value()
+            ),
+        ),
+        None => (
+            String::from("buffer"),
+            input.ident.span(),
+            String::from(
+                "`#[derive(Stats)]` requires a field named `buffer` (or use `#[stats(buffer = \"...\")]`)",
+            ),
+        ),
+    };
+
+    let (ident, ty) = fields
+        .iter()
+        .filter_map(|f| Some((f.ident.as_ref()?, &f.ty)))
+        .find(|(ident, _)| *ident == name.as_str())
+        .ok_or_else(|| syn::Error::new(span, missing))?;
+
+    // Only a `Buffer` provides the statistics, any other type is likely a mistake.
+    let is_buffer = match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Buffer"),
+        _ => false,
+    };
+
+    if !is_buffer {
+        return Err(syn::Error::new_spanned(
+            ty,
+            format!(
+                "`#[derive(Stats)]` reads the statistics from `{}`, which must be a `Buffer`",
+                name
+            ),
+        ));
+    }
+
+    Ok(ident.clone())
+}
+
+/// Finds the computation of the value passed to indicators, selected by the `as_value` attribute
+/// on the struct: `close`, `hl2`, `hlc3`, `ohlc4`, or a `field = "..."`.
+///
+/// # Arguments
+///
+/// * `input` - Struct being derived.
+fn as_value_source(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut source: Option<(String, Option<LitStr>, Span)> = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("as_value")) {
+        attr.parse_nested_meta(|meta| {
+            let name = meta.path.require_ident()?.to_string();
+            let field = match name.as_str() {
+                "close" | "hl2" | "hlc3" | "ohlc4" => None,
+                "field" => Some(meta.value()?.parse::<LitStr>()?),
+                _ => {
+                    return Err(meta.error(format!(
+                        "unknown source `{}` for `as_value`, expected `close`, `hl2`, `hlc3`, `ohlc4`, or `field = \"...\"`",
+                        name
+                    )))
+                }
+            };
+
+            if source.is_some() {
+                return Err(meta.error("`#[derive(AsValue)]` takes only one source"));
+            }
+            source = Some((name, field, meta.path.span()));
+            Ok(())
+        })?;
+    }
+
+    let fields = named_fields(input, "AsValue")?;
+    let (name, field, span) = source.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "`#[derive(AsValue)]` requires a source, such as `#[as_value(close)]`",
+        )
+    })?;
+
+    // Sources other than a field require the matching traits, errors point at the source.
+    Ok(match (name.as_str(), field) {
+        ("field", Some(lit)) => {
+            let field = fields
+                .iter()
+                .find(|f| f.ident.as_ref().is_some_and(|i| i == lit.value().as_str()))
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        lit.span(),
+                        format!(
+                            "`#[derive(AsValue)]` reads the value from `{}`, but there is no field named `{}`",
+                            lit.value(),
+                            lit.value()
+                        ),
+                    )
+                })?;
+
+            check_type("AsValue", "value", field)?;
+            let ident = &field.ident;
+            quote_spanned!(span=> self.#ident)
+        }
+        ("close", _) => quote_spanned!(span=> Close::close(self)),
+        ("hl2", _) => quote_spanned!(span=> (High::high(self) + Low::low(self)) / 2.0 as Num),
+        ("hlc3", _) => quote_spanned! {span=>
+            (High::high(self) + Low::low(self) + Close::close(self)) / 3.0 as Num
+        },
+        _ => quote_spanned! {span=>
+            (Open::open(self) + High::high(self) + Low::low(self) + Close::close(self)) / 4.0 as Num
+        },
+    })
+}
 
 /// An internal value used to calculate additional details on composite indicators.
-#[proc_macro_derive(InternalValue)]
+#[proc_macro_derive(InternalValue, attributes(tatk))]
 pub fn internal_value_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens and the name of the struct.
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field = match mapped_field(&input, "InternalValue", "value") {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     // Generate the implementation of the InternalValue trait.
     TokenStream::from(quote! {
-        impl InternalValue for #struct_name {
+        impl #impl_generics InternalValue for #struct_name #ty_generics #where_clause {
             fn internal_value(&self) -> Num {
-                self.value
+                self.#field
+            }
+        }
+    })
+}
+
+/// Enables the `value()` method. Returns the current and most recent value calculated.
+///
+/// ```
+/// use tatk::traits::Value;
+/// use tatk::Num;
+/// use tatk_derive::Value;
+///
+/// #[derive(Value)]
+/// #[tatk(value = "last")]
+/// struct Gauge {
+///     last: Num,
+/// }
+///
+/// assert_eq!(Gauge { last: 1.0 }.value(), 1.0);
+/// ```
+///
+/// A struct without the field or a mapping is an error:
+///
+/// ```compile_fail
+/// use tatk::traits::Value;
+/// use tatk::Num;
+/// use tatk_derive::Value;
+///
+/// #[derive(Value)]
+/// struct Gauge {
+///     last: Num,
+/// }
+/// ```
+#[proc_macro_derive(Value, attributes(tatk))]
+pub fn value_derive(input: TokenStream) -> TokenStream {
+    // Parse the input tokens and the name of the struct.
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field = match mapped_field(&input, "Value", "value") {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // Generate the implementation of the Value trait.
+    TokenStream::from(quote! {
+        impl #impl_generics Value for #struct_name #ty_generics #where_clause {
+            fn value(&self) -> Num {
+                self.#field
             }
         }
     })
 }
 
 /// Enables the `period()` method. Period is the window of data to process.
-#[proc_macro_derive(Period)]
+///
+/// A field that is not a `usize` is an error, pointing at its type:
+///
+/// ```compile_fail
+/// use tatk::traits::Period;
+/// use tatk_derive::Period;
+///
+/// #[derive(Period)]
+/// struct Window {
+///     period: u32,
+/// }
+/// ```
+#[proc_macro_derive(Period, attributes(tatk))]
 pub fn period_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens and the name of the struct.
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field = match mapped_field(&input, "Period", "period") {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     // Generate the implementation of the Period trait.
     TokenStream::from(quote! {
-        impl Period for #struct_name {
+        impl #impl_generics Period for #struct_name #ty_generics #where_clause {
             fn period(&self) -> usize {
-                self.period
+                self.#field
+            }
+        }
+    })
+}
+
+/// Enables the `Stats` methods, the sum, mean, variance, and standard deviation of the values in
+/// the `buffer` field. A buffer under another name is mapped with `#[stats(buffer = "...")]`.
+///
+/// ```
+/// use tatk::Buffer;
+/// use tatk::traits::Stats;
+/// use tatk::Num;
+/// use tatk_derive::Stats;
+///
+/// #[derive(Stats)]
+/// #[stats(buffer = "values")]
+/// struct Window {
+///     values: Buffer,
+/// }
+///
+/// let window = Window { values: Buffer::from_array(3, &[1.0, 2.0, 3.0]).unwrap() };
+/// assert_eq!((window.sum(), window.mean()), (6.0, 2.0));
+/// ```
+///
+/// A struct without the buffer is an error:
+///
+/// ```compile_fail
+/// use tatk::traits::Stats;
+/// use tatk::Num;
+/// use tatk_derive::Stats;
+///
+/// #[derive(Stats)]
+/// struct Window {
+///     values: Vec<Num>,
+/// }
+/// ```
+///
+/// As is a buffer that is not a `Buffer`, even one with the same methods:
+///
+/// ```compile_fail
+/// use tatk::traits::Stats;
+/// use tatk::Num;
+/// use tatk_derive::Stats;
+///
+/// struct Values(Vec<Num>);
+///
+/// impl Values {
+///     fn sum(&self) -> Num {
+///         self.0.iter().sum()
+///     }
+///
+///     fn mean(&self) -> Num {
+///         self.sum() / self.0.len() as Num
+///     }
+///
+///     fn variance(&self, _is_sample: bool) -> Num {
+///         0.0
+///     }
+///
+///     fn stdev(&self, _is_sample: bool) -> Num {
+///         0.0
+///     }
+/// }
+///
+/// #[derive(Stats)]
+/// #[stats(buffer = "values")]
+/// struct Window {
+///     values: Values,
+/// }
+/// ```
+#[proc_macro_derive(Stats, attributes(stats))]
+pub fn stats_derive(input: TokenStream) -> TokenStream {
+    // Parse the input tokens and the name of the struct.
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field = match buffer_field(&input) {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // Generate the implementation of the Stats trait.
+    TokenStream::from(quote! {
+        impl #impl_generics Stats for #struct_name #ty_generics #where_clause {
+            fn sum(&self) -> Num {
+                self.#field.sum()
+            }
+
+            fn mean(&self) -> Num {
+                self.#field.mean()
+            }
+
+            fn variance(&self, is_sample: bool) -> Num {
+                self.#field.variance(is_sample)
+            }
+
+            fn stdev(&self, is_sample: bool) -> Num {
+                self.#field.stdev(is_sample)
             }
         }
     })
 }
 
 /// Enables the `open()` method. Returns the opening value for the candle.
-#[proc_macro_derive(Open)]
+///
+/// Deriving on an enum is an error:
+///
+/// ```compile_fail
+/// use tatk::traits::Open;
+/// use tatk::Num;
+/// use tatk_derive::Open;
+///
+/// #[derive(Open)]
+/// enum Tick {
+///     Trade { open: Num },
+/// }
+/// ```
+#[proc_macro_derive(Open, attributes(tatk))]
 pub fn open_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens and the name of the struct.
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field = match mapped_field(&input, "Open", "open") {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     // Generate the implementation of the Open trait.
     TokenStream::from(quote! {
-        impl Open for #struct_name {
+        impl #impl_generics Open for #struct_name #ty_generics #where_clause {
             fn open(&self) -> Num {
-                self.open
+                self.#field
             }
         }
     })
 }
 
 /// Enables the `close()` method. Returns the closing value for the candle.
-#[proc_macro_derive(Close)]
+///
+/// A field mapped more than once is an error:
+///
+/// ```compile_fail
+/// use tatk::traits::Close;
+/// use tatk::Num;
+/// use tatk_derive::Close;
+///
+/// #[derive(Close)]
+/// #[tatk(close = "c")]
+/// struct Tick {
+///     #[tatk(close)]
+///     last: Num,
+///     c: Num,
+/// }
+/// ```
+///
+/// As is a mapping of an unknown field:
+///
+/// ```compile_fail
+/// use tatk::traits::Close;
+/// use tatk::Num;
+/// use tatk_derive::Close;
+///
+/// #[derive(Close)]
+/// #[tatk(colse = "c")]
+/// struct Tick {
+///     c: Num,
+/// }
+/// ```
+///
+/// Or a struct without the field or a mapping:
+///
+/// ```compile_fail
+/// use tatk::traits::Close;
+/// use tatk::Num;
+/// use tatk_derive::Close;
+///
+/// #[derive(Close)]
+/// struct Tick {
+///     c: Num,
+/// }
+/// ```
+#[proc_macro_derive(Close, attributes(tatk))]
 pub fn close_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens and the name of the struct.
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field = match mapped_field(&input, "Close", "close") {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     // Generate the implementation of the Close trait.
     TokenStream::from(quote! {
-        impl Close for #struct_name {
+        impl #impl_generics Close for #struct_name #ty_generics #where_clause {
             fn close(&self) -> Num {
-                self.close
+                self.#field
             }
         }
     })
 }
 
 /// Enables the `low()` method. Returns the lowest value for the candle.
-#[proc_macro_derive(Low)]
+#[proc_macro_derive(Low, attributes(tatk))]
 pub fn low_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens and the name of the struct.
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field = match mapped_field(&input, "Low", "low") {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     // Generate the implementation of the Low trait.
     TokenStream::from(quote! {
-        impl Low for #struct_name {
+        impl #impl_generics Low for #struct_name #ty_generics #where_clause {
             fn low(&self) -> Num {
-                self.low
+                self.#field
             }
         }
     })
 }
 
 /// Enables the `high()` method. Returns the highest value for the candle.
-#[proc_macro_derive(High)]
+///
+/// Deriving on a tuple struct is an error:
+///
+/// ```compile_fail
+/// use tatk::traits::High;
+/// use tatk::Num;
+/// use tatk_derive::High;
+///
+/// #[derive(High)]
+/// struct Tick(Num);
+/// ```
+#[proc_macro_derive(High, attributes(tatk))]
 pub fn high_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens and the name of the struct.
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field = match mapped_field(&input, "High", "high") {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     // Generate the implementation of the High trait.
     TokenStream::from(quote! {
-        impl High for #struct_name {
+        impl #impl_generics High for #struct_name #ty_generics #where_clause {
             fn high(&self) -> Num {
-                self.high
+                self.#field
             }
         }
     })
 }
 
 /// Enables the `volume()` method. Returns the volume value for the candle.
-#[proc_macro_derive(Volume)]
+///
+/// A mapping naming a field that does not exist is an error:
+///
+/// ```compile_fail
+/// use tatk::traits::Volume;
+/// use tatk::Num;
+/// use tatk_derive::Volume;
+///
+/// #[derive(Volume)]
+/// #[tatk(volume = "vol")]
+/// struct Tick {
+///     v: Num,
+/// }
+/// ```
+#[proc_macro_derive(Volume, attributes(tatk))]
 pub fn volume_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens and the name of the struct.
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
     let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field = match mapped_field(&input, "Volume", "volume") {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     // Generate the implementation of the Volume trait.
     TokenStream::from(quote! {
-        impl Volume for #struct_name {
+        impl #impl_generics Volume for #struct_name #ty_generics #where_clause {
             fn volume(&self) -> Num {
-                self.volume
+                self.#field
+            }
+        }
+    })
+}
+
+/// Enables the `as_value()` method, the value passed to indicators consuming single values. The
+/// source is selected on the struct with `#[as_value(...)]`:
+///
+/// * `close` - Closing value, requires `Close`.
+/// * `hl2` - Average of the high and low, requires `High` and `Low`.
+/// * `hlc3` - Average of the high, low, and close, requires `High`, `Low`, and `Close`.
+/// * `ohlc4` - Average of the open, high, low, and close, requires `Open`, `High`, `Low`, and
+///   `Close`.
+/// * `field = "..."` - Value of the named field.
+///
+/// ```
+/// use tatk::indicators::SimpleMovingAverage;
+/// use tatk::traits::{AsValue, Close, High, Low, Next};
+/// use tatk::Num;
+/// use tatk_derive::{AsValue, Close, High, Low};
+///
+/// #[derive(AsValue, High, Low, Close)]
+/// #[as_value(hlc3)]
+/// struct Tick {
+///     high: Num,
+///     low: Num,
+///     close: Num,
+/// }
+///
+/// let tick = Tick { high: 3.0, low: 1.0, close: 2.0 };
+/// assert_eq!(tick.as_value(), 2.0);
+///
+/// let mut sma = SimpleMovingAverage::new(1, &[0.0]).unwrap();
+/// assert_eq!(sma.next(tick), 2.0);
+/// ```
+///
+/// A struct without a source is an error:
+///
+/// ```compile_fail
+/// use tatk::traits::{AsValue, Close};
+/// use tatk::Num;
+/// use tatk_derive::{AsValue, Close};
+///
+/// #[derive(AsValue, Close)]
+/// struct Tick {
+///     close: Num,
+/// }
+/// ```
+///
+/// As is a source without the traits it requires:
+///
+/// ```compile_fail
+/// use tatk::traits::{AsValue, Close, High, Low};
+/// use tatk::Num;
+/// use tatk_derive::{AsValue, Close};
+///
+/// #[derive(AsValue, Close)]
+/// #[as_value(hl2)]
+/// struct Tick {
+///     close: Num,
+/// }
+/// ```
+#[proc_macro_derive(AsValue, attributes(as_value))]
+pub fn as_value_derive(input: TokenStream) -> TokenStream {
+    // Parse the input tokens and the name of the struct.
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let source = match as_value_source(&input) {
+        Ok(source) => source,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // Generate the implementation of the AsValue trait.
+    TokenStream::from(quote! {
+        impl #impl_generics AsValue for #struct_name #ty_generics #where_clause {
+            fn as_value(&self) -> Num {
+                #source
             }
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Message of the error reading the key from the struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Struct being derived.
+    /// * `derive` - Name of the derive, such as `Close`.
+    /// * `key` - Name of the field read, such as `close`.
+    fn mapped_error(source: &str, derive: &str, key: &str) -> String {
+        let input: DeriveInput = syn::parse_str(source).unwrap();
+        mapped_field(&input, derive, key).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn not_a_struct() {
+        assert_eq!(
+            mapped_error("enum Tick { Trade { close: f64 } }", "Close", "close"),
+            "`#[derive(Close)]` requires a struct with named fields, not an enum"
+        );
+        assert_eq!(
+            mapped_error("struct Tick(f64);", "Close", "close"),
+            "`#[derive(Close)]` requires a struct with named fields, not a tuple struct"
+        );
+        assert_eq!(
+            mapped_error("struct Tick;", "Close", "close"),
+            "`#[derive(Close)]` requires a struct with named fields, not a unit struct"
+        );
+    }
+
+    #[test]
+    fn missing_field() {
+        assert_eq!(
+            mapped_error("struct Tick { c: f64 }", "Close", "close"),
+            "`#[derive(Close)]` requires a field named `close` (or use `#[tatk(close = \"...\")]`)"
+        );
+        assert_eq!(
+            mapped_error(
+                "#[tatk(close = \"last\")] struct Tick { c: f64 }",
+                "Close",
+                "close"
+            ),
+            "`#[derive(Close)]` reads `close` from `last`, but there is no field named `last`"
+        );
+    }
+
+    #[test]
+    fn wrong_type() {
+        assert_eq!(
+            mapped_error("struct Window { period: u32 }", "Period", "period"),
+            "`#[derive(Period)]` requires `period` to be a `usize`"
+        );
+        assert_eq!(
+            mapped_error(
+                "#[tatk(close = \"c\")] struct Tick { c: String }",
+                "Close",
+                "close"
+            ),
+            "`#[derive(Close)]` reads `close` from `c`, which must be a number such as `Num`"
+        );
+
+        let input: DeriveInput = syn::parse_str("struct Window { buffer: Vec<f64> }").unwrap();
+        assert_eq!(
+            buffer_field(&input).unwrap_err().to_string(),
+            "`#[derive(Stats)]` reads the statistics from `buffer`, which must be a `Buffer`"
+        );
+    }
+
+    #[test]
+    fn accepted_types() {
+        let input: DeriveInput =
+            syn::parse_str("struct Tick<T> { close: Price, period: usize, value: T }").unwrap();
+        assert!(mapped_field(&input, "Close", "close").is_ok());
+        assert!(mapped_field(&input, "Period", "period").is_ok());
+        assert!(mapped_field(&input, "Value", "value").is_ok());
+    }
+}